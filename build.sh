@@ -1,8 +1,12 @@
 #!/bin/bash
 set -e
 
-echo "Building lfg..."
-go build -o lfg
+# Tagged builds report their release, e.g. 1.2.0, anything else stays a development build
+VERSION=$(git describe --tags --match 'v[0-9]*' 2>/dev/null | sed 's/^v//' || true)
+VERSION=${VERSION:-dev}
+
+echo "Building lfg $VERSION..."
+go build -ldflags "-X main.version=$VERSION" -o lfg
 
 echo "Installing to ~/.local/bin/lfg..."
 cp ./lfg /Users/markcipolla/.local/bin/lfg
@@ -0,0 +1,920 @@
+package main
+
+import (
+	"errors"
+	"flag"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/clipboard"
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/fanout"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/ide"
+	"github.com/markcipolla/lfg/internal/mirror"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/rpc"
+	"github.com/markcipolla/lfg/internal/setup"
+	"github.com/markcipolla/lfg/internal/tmux"
+	"github.com/markcipolla/lfg/internal/update"
+	"github.com/markcipolla/lfg/internal/webhook"
+	"github.com/markcipolla/lfg/internal/worktree"
+)
+
+// subcommands maps `lfg <name> [args...]` to its handler
+// Subcommands take precedence over jumping to a worktree with the same name
+var subcommands = map[string]func(args []string) error{
+	"branch-off":  runBranchOff,
+	"capture":     runCapture,
+	"code":        runCode,
+	"delete":      runDelete,
+	"exec":        runExec,
+	"find":        runFind,
+	"init":        runInit,
+	"mirror":      runMirror,
+	"new":         runNew,
+	"open":        runOpen,
+	"path":        runPath,
+	"reconcile":   runReconcile,
+	"rename":      runRename,
+	"restore":     runRestore,
+	"review":      runReview,
+	"rpc":         runRPC,
+	"scratch":     runScratch,
+	"self-update": runSelfUpdate,
+	"status":      runStatus,
+	"trash":       runTrash,
+}
+
+// printf writes human-readable output, moving it to stderr when stdout carries --events-json output
+func printf(format string, args ...interface{}) {
+	if events.Enabled() {
+		fmt.Fprintf(os.Stderr, format, args...)
+		return
+	}
+	fmt.Printf(format, args...)
+}
+
+// runOpen starts or attaches to a worktree's tmux session like `lfg <worktree>`, or with --editor
+// opens it in the IDE configured for the repository instead
+func runOpen(args []string) error {
+	fs := flag.NewFlagSet("open", flag.ExitOnError)
+	editor := fs.Bool("editor", false, "Open the worktree in the configured IDE or editor instead of tmux")
+	fs.Parse(args)
+	target := fs.Arg(0)
+	// Allow flags after the name, e.g. `lfg open my-feature --editor`
+	if fs.NArg() > 0 {
+		fs.Parse(fs.Args()[1:])
+	}
+	if target == "" || fs.NArg() > 0 {
+		return fmt.Errorf("usage: lfg open <worktree> [--editor]")
+	}
+
+	if *editor {
+		return runCode([]string{target})
+	}
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+	return git.JumpToWorktree(target, cfg)
+}
+
+// exitError is a subcommand failure that exits with a code of its own rather than 1
+type exitError struct {
+	code int
+	err  error
+}
+
+func (e *exitError) Error() string { return e.err.Error() }
+func (e *exitError) Unwrap() error { return e.err }
+
+// runPath prints a worktree's path and nothing else, for `cd $(lfg path auth)` and editor configs
+// The name is fuzzy matched against worktree names and branches, exiting 2 when it is ambiguous
+func runPath(args []string) error {
+	if len(args) != 1 {
+		return fmt.Errorf("usage: lfg path <worktree>")
+	}
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	wt, err := git.ResolveWorktree(args[0])
+	var ambiguous *git.AmbiguousError
+	if errors.As(err, &ambiguous) {
+		return &exitError{code: 2, err: err}
+	}
+	if err != nil {
+		return err
+	}
+	fmt.Println(wt.Path)
+	return nil
+}
+
+// runCode opens a worktree in the IDE configured for the repository
+func runCode(args []string) error {
+	if len(args) == 0 {
+		return fmt.Errorf("usage: lfg code <worktree>")
+	}
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	path, err := git.GetWorktreePath(args[0])
+	if err != nil {
+		return err
+	}
+
+	return ide.Open(path, cfg)
+}
+
+// runDelete removes a worktree, kills its session and marks its todo done, refusing to lose
+// uncommitted work unless forced
+func runDelete(args []string) error {
+	fs := flag.NewFlagSet("delete", flag.ExitOnError)
+	force := fs.Bool("force", false, "Same as --force-dirty --ignore-locked, and --prune-branch with --delete-branch")
+	forceDirty := fs.Bool("force-dirty", false, "Delete even if the worktree has uncommitted changes or untracked files")
+	ignoreLocked := fs.Bool("ignore-locked", false, "Delete even if the worktree is locked with git worktree lock")
+	deleteBranch := fs.Bool("delete-branch", false, "Delete the worktree's branch too")
+	pruneBranch := fs.Bool("prune-branch", false, "Delete the worktree's branch too, even with unpushed commits")
+	fs.Parse(args)
+	target := fs.Arg(0)
+	// Allow flags after the name, e.g. `lfg delete my-feature --force`
+	if fs.NArg() > 0 {
+		fs.Parse(fs.Args()[1:])
+	}
+	if target == "" || fs.NArg() > 0 {
+		return fmt.Errorf("usage: lfg delete <worktree> [--force-dirty] [--ignore-locked] [--delete-branch | --prune-branch] [--force]")
+	}
+	if *force {
+		*forceDirty, *ignoreLocked = true, true
+		*pruneBranch = *pruneBranch || *deleteBranch
+	}
+	*deleteBranch = *deleteBranch || *pruneBranch
+
+	cfg, err := config.Load()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	wt, err := git.FindWorktree(target)
+	if err != nil {
+		return err
+	}
+	if mainPath, err := git.GetMainWorktreePath(); err == nil && wt.Path == mainPath {
+		return fmt.Errorf("%s is the main worktree and can't be deleted", wt.DisplayName())
+	}
+
+	report, err := git.CheckSafety(*wt)
+	if err != nil {
+		return err
+	}
+	// Each thing deleting would lose or override needs its own flag
+	var refusals []string
+	if report.Dirty() && !*forceDirty {
+		var lost []string
+		if report.Modified > 0 {
+			lost = append(lost, fmt.Sprintf("%d modified file(s)", report.Modified))
+		}
+		if report.Untracked > 0 {
+			lost = append(lost, fmt.Sprintf("%d untracked file(s)", report.Untracked))
+		}
+		refusals = append(refusals, fmt.Sprintf("it has %s, which --force-dirty throws away", strings.Join(lost, ", ")))
+	}
+	if report.Locked && !*ignoreLocked {
+		locked := "it is locked"
+		if report.LockReason != "" {
+			locked += ": " + report.LockReason
+		}
+		refusals = append(refusals, locked+", which --ignore-locked overrides")
+	}
+	if *deleteBranch && !*pruneBranch && report.Unpushed > 0 {
+		refusals = append(refusals, fmt.Sprintf("its branch has %d unpushed commit(s), which --prune-branch deletes anyway", report.Unpushed))
+	}
+	if len(refusals) > 0 {
+		return fmt.Errorf("%s wasn't deleted because %s", wt.DisplayName(), strings.Join(refusals, "; "))
+	}
+
+	// Forcing past uncommitted work moves it to the trash when enabled, keeping the branch so it can be restored
+	trashed, err := worktree.Delete(cfg, *wt, worktree.DeleteOptions{Force: *forceDirty, IgnoreLocked: *ignoreLocked, DeleteBranch: *deleteBranch})
+	if err != nil {
+		return err
+	}
+
+	switch {
+	case trashed != nil:
+		printf("Moved %s to the trash, restore it with: lfg trash restore %s\n", wt.DisplayName(), trashed.ID)
+	case *deleteBranch:
+		printf("Deleted %s and its branch\n", wt.DisplayName())
+	default:
+		printf("Deleted %s\n", wt.DisplayName())
+	}
+	return nil
+}
+
+// runRename moves a worktree to a new name, renaming its branch when it is named after the worktree,
+// and takes its todo and tmux session along
+func runRename(args []string) error {
+	if len(args) != 2 {
+		return fmt.Errorf("usage: lfg rename <worktree> <new-name>")
+	}
+
+	cfg, err := config.Load()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	wt, err := git.FindWorktree(args[0])
+	if err != nil {
+		return err
+	}
+	if mainPath, err := git.GetMainWorktreePath(); err == nil && wt.Path == mainPath {
+		return fmt.Errorf("%s is the main worktree and can't be renamed", wt.DisplayName())
+	}
+
+	renamed, err := worktree.Rename(cfg, *wt, args[1])
+	if err != nil {
+		return err
+	}
+	printf("Renamed %s to %s (%s)\n", wt.DisplayName(), renamed.DisplayName(), renamed.Path)
+	if renamed.Branch != wt.Branch {
+		printf("Renamed branch %s to %s\n", wt.BranchName(), renamed.BranchName())
+	}
+	return nil
+}
+
+// runExec runs a command in every worktree, or those matching --filter, and reports how it went in each
+func runExec(args []string) error {
+	fs := flag.NewFlagSet("exec", flag.ExitOnError)
+	all := fs.Bool("all", false, "Run in every worktree")
+	filter := fs.String("filter", "", "Only run in worktrees whose name matches this glob, e.g. \"*-fix-*\"")
+	parallel := fs.Bool("parallel", false, "Run in all worktrees at once instead of one at a time")
+	fs.Parse(args)
+
+	command := fs.Args()
+	if len(command) == 0 || (!*all && *filter == "") {
+		return fmt.Errorf("usage: lfg exec (--all | --filter <glob>) [--parallel] -- <command>")
+	}
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	worktrees, err := git.ListWorktrees()
+	if err != nil {
+		return err
+	}
+	if *filter != "" {
+		if worktrees, err = fanout.Filter(worktrees, *filter); err != nil {
+			return err
+		}
+		if len(worktrees) == 0 {
+			return fmt.Errorf("no worktree matches %q", *filter)
+		}
+	}
+
+	results := fanout.Run(worktrees, command, *parallel, func(r fanout.Result) {
+		mark := "✓"
+		if r.Err != nil {
+			mark = "✗"
+		}
+		printf("%s %s (exit %d, %s)\n", mark, r.Worktree.DisplayName(), r.ExitCode, r.Duration.Round(time.Millisecond))
+		if r.Output != "" {
+			printf("    %s\n", strings.ReplaceAll(r.Output, "\n", "\n    "))
+		}
+	})
+
+	if failed := fanout.Failed(results); failed > 0 {
+		return fmt.Errorf("failed in %d of %d worktrees", failed, len(results))
+	}
+	return nil
+}
+
+// runFind jumps to the worktree of the todo best matching a search, or lists the candidates
+// when there's no clear winner
+func runFind(args []string) error {
+	query := strings.TrimSpace(strings.Join(args, " "))
+	if query == "" {
+		return fmt.Errorf("usage: lfg find <text>")
+	}
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	matches := cfg.FindTodos(query)
+	if len(matches) == 0 {
+		return fmt.Errorf("no todo matches %q", query)
+	}
+
+	if match, ok := config.ConfidentMatch(matches); ok {
+		if match.Todo.Worktree == "" {
+			return fmt.Errorf("%q has no worktree yet, start it from the TUI", match.Todo.Description)
+		}
+		return git.JumpToWorktree(match.Todo.Worktree, cfg)
+	}
+
+	printf("Several todos match %q:\n", query)
+	for _, match := range matches {
+		linked := match.Todo.Worktree
+		if linked == "" {
+			linked = "(no worktree)"
+		}
+		printf("  %-40s %s\n", linked, match.Todo.Description)
+	}
+	return nil
+}
+
+// runInit sets up lfg-config.yaml in the current repository, with the init wizard or, with --yes,
+// without any prompts so it can run from scripts and CI
+func runInit(args []string) error {
+	fs := flag.NewFlagSet("init", flag.ExitOnError)
+	var opts config.InitOptions
+	fs.StringVar(&opts.Name, "name", "", "Project name (default: the repository directory's name)")
+	fs.StringVar(&opts.Backend, "backend", "", "Where todos are kept: local or github (default: local)")
+	fs.IntVar(&opts.Project, "project", 0, "GitHub project number to use (default: create a new project)")
+	yes := fs.Bool("yes", false, "Don't prompt, use the flags and the defaults for the rest")
+	fs.Parse(args)
+	if fs.NArg() > 0 {
+		return fmt.Errorf("usage: lfg init [--name <name>] [--backend local|github] [--project <number>] [--yes]")
+	}
+	if opts.Project != 0 && opts.Backend == "" {
+		opts.Backend = "github"
+	}
+
+	cfg, err := config.Init(opts, !*yes)
+	if err != nil {
+		return err
+	}
+	printf("Created %s\n", cfg.GetConfigPath())
+	return nil
+}
+
+// runReconcile adds panes that were added to the layout since a worktree's session started
+// With --prune, panes removed from the layout are closed as well
+func runReconcile(args []string) error {
+	fs := flag.NewFlagSet("reconcile", flag.ExitOnError)
+	prune := fs.Bool("prune", false, "Close panes that are no longer in the layout")
+	fs.Parse(args)
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	// Defaults to the worktree containing the current directory
+	name := fs.Arg(0)
+	if name == "" {
+		if name, err = git.GetCurrentWorktree(); err != nil {
+			return err
+		}
+		if name == "" {
+			return fmt.Errorf("usage: lfg reconcile [--prune] [worktree]")
+		}
+	}
+	wt, err := git.FindWorktree(name)
+	if err != nil {
+		return err
+	}
+
+	if err := multiplexer.RequireTmux("lfg reconcile"); err != nil {
+		return err
+	}
+	result, err := tmux.Reconcile(wt.Name(), wt.Path, cfg, *prune)
+	if err != nil {
+		return err
+	}
+	if len(result.Added) == 0 && len(result.Removed) == 0 {
+		printf("Session for %s already matches the layout\n", wt.DisplayName())
+		return nil
+	}
+	for _, pane := range result.Added {
+		printf("Added pane %s\n", pane)
+	}
+	for _, pane := range result.Removed {
+		printf("Closed pane %s\n", pane)
+	}
+	return nil
+}
+
+// runRestore brings back a worktree's session as it was saved when it was killed. A worktree deleted with
+// it is created again, from its branch when that's still there, and --into restores it in another worktree
+func runRestore(args []string) error {
+	fs := flag.NewFlagSet("restore", flag.ExitOnError)
+	into := fs.String("into", "", "Restore the session in this existing worktree instead")
+	fs.Parse(args)
+	if fs.NArg() != 1 {
+		return fmt.Errorf("usage: lfg restore [--into <worktree>] <worktree>")
+	}
+	name := fs.Arg(0)
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+	if err := multiplexer.RequireTmux("lfg restore"); err != nil {
+		return err
+	}
+
+	target := name
+	if *into != "" {
+		target = *into
+	}
+	wt, err := git.FindWorktree(target)
+	if err != nil && *into == "" {
+		if err := git.ValidateWorktreeName(name); err != nil {
+			return err
+		}
+		if _, err := tmux.LoadSavedSession(tmux.SessionName(cfg.Name, name)); err != nil {
+			return err
+		}
+		if git.BranchExists(name) {
+			err = git.CreateWorktreeFromBranch(name, git.Branch{Name: name})
+		} else {
+			err = git.CreateWorktreeWithBranch(name, name)
+		}
+		if err != nil {
+			return err
+		}
+		printf("Recreated worktree %s\n", name)
+		wt, err = git.FindWorktree(name)
+	}
+	if err != nil {
+		return err
+	}
+	return tmux.RestoreSession(name, wt.Name(), wt.Path, cfg)
+}
+
+// runReview checks out a pull request into a review-<number> worktree and jumps to it
+func runReview(args []string) error {
+	if len(args) != 1 {
+		return fmt.Errorf("usage: lfg review <pr-number>")
+	}
+	number, err := strconv.Atoi(strings.TrimPrefix(args[0], "#"))
+	if err != nil || number <= 0 {
+		return fmt.Errorf("invalid pull request number: %s", args[0])
+	}
+
+	cfg, err := config.Load()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	name, err := git.CreateReviewWorktree(number)
+	if err != nil {
+		return err
+	}
+	setUp(cfg, name)
+
+	printf("Checked out pull request #%d in %s\n", number, name)
+	return git.JumpToWorktree(name, cfg)
+}
+
+// runRPC serves JSON-RPC requests on stdin/stdout for editor integrations
+func runRPC(args []string) error {
+	// stdout carries the protocol, so event output would corrupt it
+	events.Enable(nil)
+
+	// Never run the interactive init wizard here, it would take over the editor's stdio
+	configPath, err := config.Path()
+	if err != nil {
+		return err
+	}
+
+	return rpc.NewServer(configPath).Serve(os.Stdin, os.Stdout)
+}
+
+// runCapture appends a todo without a worktree
+// It only reads and writes the config file (no git, no TUI) so it can be bound to a global hotkey
+func runCapture(args []string) error {
+	fs := flag.NewFlagSet("capture", flag.ExitOnError)
+	configPath := fs.String("config", "", "Path to config file (defaults to the repo containing the current directory)")
+	force := fs.Bool("force", false, "Capture the todo even when an open one looks the same")
+	fs.Parse(args)
+
+	description := strings.TrimSpace(strings.Join(fs.Args(), " "))
+	if description == "" {
+		return fmt.Errorf("usage: lfg capture [--config path] [--force] <description>")
+	}
+
+	path := *configPath
+	if path == "" {
+		cwd, err := os.Getwd()
+		if err != nil {
+			return err
+		}
+		path, err = config.Discover(cwd)
+		if err != nil {
+			return err
+		}
+	}
+
+	cfg, err := config.LoadFromPath(path)
+	if err != nil {
+		return err
+	}
+	webhook.Configure(cfg)
+	if err := checkDuplicate(cfg, description, *force); err != nil {
+		return err
+	}
+
+	cfg.AddTodo(description, "")
+	if err := cfg.Save(); err != nil {
+		return err
+	}
+
+	printf("Captured: %s\n", description)
+	return nil
+}
+
+// runMirror copies changed todos to the GitHub Project now, rather than waiting for the TUI to
+func runMirror(args []string) error {
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	backend := cfg.GitHubMirror()
+	if backend == nil {
+		return fmt.Errorf("todos are only mirrored with the hybrid storage backend")
+	}
+
+	changes, syncErr := mirror.Sync(backend, cfg.Todos, cfg.GitHubRemoved)
+	if mirror.Apply(cfg, changes) {
+		if err := cfg.Save(); err != nil {
+			return fmt.Errorf("failed to save config: %w", err)
+		}
+	}
+	printf("Mirrored %d todo(s) to %s/%s project #%d\n", len(changes), backend.Owner, backend.Repo, backend.ProjectNumber)
+	return syncErr
+}
+
+// runNew creates a worktree with a todo and jumps to it. The todo comes from the arguments, or from text
+// piped in or on the clipboard, whose first line is the description and the rest the notes
+func runNew(args []string) error {
+	fs := flag.NewFlagSet("new", flag.ExitOnError)
+	fromStdin := fs.Bool("stdin", false, "Read the description and notes from stdin")
+	fromClipboard := fs.Bool("from-clipboard", false, "Read the description and notes from the clipboard")
+	name := fs.String("name", "", "Worktree name (defaults to one derived from the description)")
+	force := fs.Bool("force", false, "Create the worktree even when an open todo looks the same")
+	fs.Parse(args)
+
+	var text string
+	switch {
+	case *fromStdin && *fromClipboard:
+		return fmt.Errorf("--stdin and --from-clipboard can't be used together")
+	case *fromStdin:
+		data, err := io.ReadAll(os.Stdin)
+		if err != nil {
+			return fmt.Errorf("failed to read stdin: %w", err)
+		}
+		text = string(data)
+	case *fromClipboard:
+		pasted, err := clipboard.Paste()
+		if err != nil {
+			return err
+		}
+		text = pasted
+	default:
+		text = strings.Join(fs.Args(), " ")
+	}
+	description, notes := worktree.SplitPasted(text)
+	if description == "" {
+		return fmt.Errorf("usage: lfg new [--name name] [--force] (<description> | --stdin | --from-clipboard)")
+	}
+
+	cfg, err := config.Load()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+	if err := checkDuplicate(cfg, description, *force); err != nil {
+		return err
+	}
+
+	worktreeName := *name
+	if worktreeName == "" {
+		worktreeName = git.GenerateWorktreeName(cfg.Name, description, cfg.Naming)
+	}
+	if _, err := worktree.Create(cfg, worktree.CreateOptions{Name: worktreeName, Description: description, Notes: notes}); err != nil {
+		if !errors.Is(err, worktree.ErrNotSaved) {
+			return err
+		}
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+	setUp(cfg, worktreeName)
+
+	if cfg.PushOnCreate() {
+		if err := git.PushBranch(worktreeName); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
+	}
+
+	printf("Created %s for: %s\n", worktreeName, description)
+	// Attaching outside tmux needs the terminal that the piped text took the place of
+	if *fromStdin && os.Getenv("TMUX") == "" {
+		printf("Open it with: lfg %s\n", worktreeName)
+		return nil
+	}
+	return git.JumpToWorktree(worktreeName, cfg)
+}
+
+// checkDuplicate returns an error pointing at the open todo a new one closely matches, unless forced
+func checkDuplicate(cfg *config.Config, description string, force bool) error {
+	existing := cfg.FindDuplicate(description)
+	if force || existing == nil {
+		return nil
+	}
+	if existing.Worktree != "" {
+		return fmt.Errorf("%q looks like the open todo %q, open it with `lfg %s` or pass --force to add it anyway", description, existing.Description, existing.Worktree)
+	}
+	return fmt.Errorf("%q looks like the open todo %q, pass --force to add it anyway", description, existing.Description)
+}
+
+// runBranchOff moves the current worktree's uncommitted changes to a new worktree and jumps to it
+// The new branch starts from the current HEAD, and the original worktree is left clean
+func runBranchOff(args []string) error {
+	description := strings.TrimSpace(strings.Join(args, " "))
+	if description == "" {
+		return fmt.Errorf("usage: lfg branch-off <description>")
+	}
+
+	cfg, err := config.Load()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	current, err := git.GetCurrentWorktree()
+	if err != nil {
+		return err
+	}
+	if current == "" {
+		return fmt.Errorf("not inside a worktree")
+	}
+	source, err := git.FindWorktree(current)
+	if err != nil {
+		return err
+	}
+	if err := git.EnsureNoOperation(source.Path); err != nil {
+		return err
+	}
+
+	name := git.GenerateWorktreeName(cfg.Name, description, cfg.Naming)
+	if err := git.CreateWorktree(name); err != nil {
+		return err
+	}
+	path, err := git.GetWorktreePath(name)
+	if err != nil {
+		return err
+	}
+
+	if err := git.MoveChanges(source.Path, path); err != nil {
+		return fmt.Errorf("created %s but failed to move changes: %w", name, err)
+	}
+	if err := git.DescribeBranch(path, name, description, cfg.Templates); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+	setUp(cfg, name)
+
+	if cfg.PushOnCreate() {
+		if err := git.PushBranch(name); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
+	}
+
+	cfg.AddTodo(description, name)
+	if err := cfg.Save(); err != nil {
+		return fmt.Errorf("failed to save config: %w", err)
+	}
+
+	printf("Moved uncommitted changes from %s to %s\n", source.DisplayName(), name)
+	return git.JumpToWorktree(name, cfg)
+}
+
+// runScratch creates a throwaway worktree off main, without a todo, and jumps to it
+func runScratch(args []string) error {
+	if len(args) > 0 {
+		return fmt.Errorf("usage: lfg scratch")
+	}
+
+	cfg, err := config.Load()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	name, err := git.CreateScratchWorktree(time.Now())
+	if err != nil {
+		return err
+	}
+	setUp(cfg, name)
+
+	printf("Created %s, it will be offered for cleanup after %d days\n", name, git.ScratchExpiryDays(cfg.Scratch))
+	return git.JumpToWorktree(name, cfg)
+}
+
+// runStatus prints each worktree's changes, how far it is from the default branch and whether its
+// session is running, from the status cache shared with the TUI
+func runStatus(args []string) error {
+	fs := flag.NewFlagSet("status", flag.ExitOnError)
+	refresh := fs.Bool("refresh", false, "Ask git and tmux again instead of using cached statuses")
+	fs.Parse(args)
+
+	cfg, err := config.LoadOrDefault()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+	worktree.Configure(cfg)
+
+	worktrees, err := git.ListWorktrees()
+	if err != nil {
+		return err
+	}
+
+	cache := git.NewStatusCache(filepath.Dir(cfg.GetConfigPath()))
+	if *refresh {
+		if err := cache.Invalidate(); err != nil {
+			return fmt.Errorf("failed to clear status cache: %w", err)
+		}
+	}
+	statuses := cache.Statuses(worktrees, func(wt git.Worktree) string {
+		return multiplexer.Current().SessionName(cfg.Name, wt.Name())
+	})
+
+	for _, wt := range worktrees {
+		status := statuses[wt.Name()]
+		var notes []string
+		if status.Dirty {
+			notes = append(notes, "modified")
+		}
+		if status.Unpushed > 0 {
+			notes = append(notes, fmt.Sprintf("%d unpushed", status.Unpushed))
+		}
+		if status.Unpulled > 0 {
+			notes = append(notes, fmt.Sprintf("%d to pull", status.Unpulled))
+		}
+		if status.Ahead > 0 || status.Behind > 0 {
+			notes = append(notes, fmt.Sprintf("↑%d ↓%d", status.Ahead, status.Behind))
+		}
+		if status.Session {
+			notes = append(notes, "session running")
+		}
+		if status.Crashed > 0 {
+			notes = append(notes, fmt.Sprintf("%d crashed pane(s)", status.Crashed))
+		}
+		printf("%-40s %s\n", wt.DisplayName(), strings.Join(notes, ", "))
+	}
+	return nil
+}
+
+// runTrash lists, restores or permanently deletes worktrees moved to the trash
+func runTrash(args []string) error {
+	usage := fmt.Errorf("usage: lfg trash list | restore <entry> | empty")
+	if len(args) == 0 {
+		return usage
+	}
+
+	entries, err := git.ListTrash()
+	if err != nil {
+		return err
+	}
+
+	switch args[0] {
+	case "list":
+		if len(entries) == 0 {
+			printf("The trash is empty\n")
+			return nil
+		}
+		for _, entry := range entries {
+			branch := entry.Branch
+			if branch == "" {
+				branch = "(detached)"
+			}
+			printf("%-40s %-30s %s\n", entry.ID, branch, entry.Path)
+		}
+		return nil
+
+	case "restore":
+		if len(args) != 2 {
+			return usage
+		}
+		entry, err := git.FindTrash(entries, args[1])
+		if err != nil {
+			return err
+		}
+		if err := git.RestoreTrash(*entry); err != nil {
+			return err
+		}
+		printf("Restored %s to %s\n", entry.Name, entry.Path)
+		return nil
+
+	case "empty":
+		count, err := git.EmptyTrash()
+		if err != nil {
+			return err
+		}
+		printf("Deleted %d trashed worktree(s)\n", count)
+		return nil
+	}
+	return usage
+}
+
+// setUp runs the configured setup steps in a new worktree and prints how each went
+// Failures are reported but don't stop the jump to the worktree
+func setUp(cfg *config.Config, worktree string) {
+	steps := setup.Steps(cfg.Setup)
+	if len(steps) == 0 {
+		return
+	}
+
+	mainPath, err := git.GetMainWorktreePath()
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to set up %s: %v\n", worktree, err)
+		return
+	}
+	path, err := git.GetWorktreePath(worktree)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to set up %s: %v\n", worktree, err)
+		return
+	}
+
+	printf("Setting up %s...\n", worktree)
+	results := setup.Run(steps, mainPath, path)
+	for i, line := range setup.Summary(results) {
+		printf("  %s\n", line)
+		if results[i].Err != nil && results[i].Output != "" {
+			printf("    %s\n", strings.ReplaceAll(results[i].Output, "\n", "\n    "))
+		}
+	}
+}
+
+// runSelfUpdate replaces the running binary with the latest GitHub release
+func runSelfUpdate(args []string) error {
+	fs := flag.NewFlagSet("self-update", flag.ExitOnError)
+	check := fs.Bool("check", false, "Only report whether an update is available")
+	force := fs.Bool("force", false, "Replace a development build with the latest release")
+	fs.Parse(args)
+
+	exe, err := os.Executable()
+	if err != nil {
+		return fmt.Errorf("failed to locate lfg executable: %w", err)
+	}
+	if resolved, err := filepath.EvalSymlinks(exe); err == nil {
+		exe = resolved
+	}
+
+	release, err := update.Latest()
+	if err != nil {
+		return err
+	}
+
+	// A development build has no version to compare, so only replace it when asked to
+	if !update.IsRelease(version) {
+		if !*force || *check {
+			printf("lfg %s is a development build, the latest release is %s (pass --force to install it)\n", version, release.Version())
+			return nil
+		}
+	} else if !update.IsNewer(version, release.Version()) {
+		printf("lfg %s is up to date\n", version)
+		return nil
+	}
+
+	printf("Update available: %s -> %s\n", version, release.Version())
+	if *check {
+		return nil
+	}
+
+	if manager := update.ManagedBy(exe); manager != "" {
+		return fmt.Errorf("lfg was installed with %s, update it that way instead (e.g. `brew upgrade lfg`)", manager)
+	}
+
+	if err := update.Apply(release, exe); err != nil {
+		return err
+	}
+
+	printf("Updated %s to %s\n", exe, release.Version())
+	return nil
+}
@@ -0,0 +1,50 @@
+package clipboard
+
+import (
+	"fmt"
+	"os"
+
+	sysclip "github.com/atotto/clipboard"
+	osc52 "github.com/aymanbagabas/go-osc52/v2"
+)
+
+// Copy copies text to the system clipboard
+// Over SSH (or when no system clipboard is available) it falls back to an OSC 52
+// escape sequence, which asks the local terminal emulator to set its clipboard
+func Copy(text string) error {
+	if !isRemote() {
+		if err := sysclip.WriteAll(text); err == nil {
+			return nil
+		}
+	}
+
+	return copyOSC52(text)
+}
+
+func copyOSC52(text string) error {
+	seq := osc52.New(text)
+	if os.Getenv("TMUX") != "" {
+		// tmux swallows OSC 52 unless it is wrapped in a passthrough sequence
+		seq = seq.Tmux()
+	}
+	_, err := seq.WriteTo(os.Stderr)
+	return err
+}
+
+// isRemote reports whether lfg is running in an SSH session
+func isRemote() bool {
+	return os.Getenv("SSH_TTY") != "" || os.Getenv("SSH_CONNECTION") != ""
+}
+
+// Paste returns the system clipboard's text
+// Terminals don't let programs read the clipboard over SSH, so it fails there
+func Paste() (string, error) {
+	if isRemote() {
+		return "", fmt.Errorf("the clipboard can't be read over SSH, pipe the text in with --stdin instead")
+	}
+	text, err := sysclip.ReadAll()
+	if err != nil {
+		return "", fmt.Errorf("failed to read the clipboard: %w", err)
+	}
+	return text, nil
+}
@@ -0,0 +1,80 @@
+package config
+
+import (
+	"fmt"
+	"time"
+)
+
+// MarkDone marks the todo as done, recording when for settings.archive_after
+func (t *Todo) MarkDone() {
+	if t.Status != TodoStatusDone {
+		t.Completed = time.Now()
+	}
+	t.Status = TodoStatusDone
+}
+
+// ArchiveAfter returns how many days after a todo is done it's archived, 0 when todos aren't archived
+func (c *Config) ArchiveAfter() int {
+	if c.Settings == nil || c.Settings.ArchiveAfter < 0 {
+		return 0
+	}
+	return c.Settings.ArchiveAfter
+}
+
+// ArchiveTodo moves a done todo from the todos to the top of the archive
+// A todo whose worktree is still checked out stays, as the worktree shows its description and status
+func (c *Config) ArchiveTodo(description string, checkedOut func(worktree string) bool) error {
+	for i := range c.Todos {
+		if c.Todos[i].Description != description {
+			continue
+		}
+		if c.Todos[i].Status != TodoStatusDone {
+			return fmt.Errorf("%q isn't done, only done todos can be archived", description)
+		}
+		if worktree := c.Todos[i].Worktree; worktree != "" && checkedOut(worktree) {
+			return fmt.Errorf("worktree %s is still checked out, delete it before archiving its todo", worktree)
+		}
+		c.Archived = append([]Todo{c.Todos[i]}, c.Archived...)
+		c.Todos = append(c.Todos[:i], c.Todos[i+1:]...)
+		return nil
+	}
+	return fmt.Errorf("no todo %q", description)
+}
+
+// RestoreTodo moves an archived todo back to the top of the todos, still done
+func (c *Config) RestoreTodo(description string) error {
+	for i := range c.Archived {
+		if c.Archived[i].Description == description {
+			c.Todos = append([]Todo{c.Archived[i]}, c.Todos...)
+			c.Archived = append(c.Archived[:i], c.Archived[i+1:]...)
+			return nil
+		}
+	}
+	return fmt.Errorf("no archived todo %q", description)
+}
+
+// ArchiveDone archives the todos done at least ArchiveAfter days before now and returns how many it archived
+// Todos done before lfg recorded when are archived straight away, having been done for a while already,
+// and todos whose worktree is still checked out are left until it's deleted
+func (c *Config) ArchiveDone(now time.Time, checkedOut func(worktree string) bool) int {
+	days := c.ArchiveAfter()
+	if days == 0 {
+		return 0
+	}
+
+	kept := make([]Todo, 0, len(c.Todos))
+	var archived []Todo
+	for _, todo := range c.Todos {
+		linked := todo.Worktree != "" && checkedOut(todo.Worktree)
+		if todo.Status == TodoStatusDone && !linked && now.Sub(todo.Completed) >= time.Duration(days)*24*time.Hour {
+			archived = append(archived, todo)
+			continue
+		}
+		kept = append(kept, todo)
+	}
+	if len(archived) > 0 {
+		c.Todos = kept
+		c.Archived = append(archived, c.Archived...)
+	}
+	return len(archived)
+}
@@ -0,0 +1,82 @@
+package config
+
+import (
+	"testing"
+	"time"
+)
+
+func TestArchiveDone(t *testing.T) {
+	now := time.Date(2025, 6, 20, 12, 0, 0, 0, time.UTC)
+	todos := []Todo{
+		{Description: "Add billing page", Status: TodoStatusPending},
+		{Description: "Fix flaky login spec", Status: TodoStatusDone, Completed: now.AddDate(0, 0, -10)},
+		{Description: "Login with SSO", Status: TodoStatusDone, Completed: now.AddDate(0, 0, -2)},
+		{Description: "Upgrade Rails", Status: TodoStatusDone},
+		{Description: "Add search", Status: TodoStatusDone, Worktree: "myapp-search"},
+	}
+	checkedOut := func(worktree string) bool { return worktree == "myapp-search" }
+
+	tests := []struct {
+		name     string
+		days     int
+		archived []string
+	}{
+		{name: "off", days: 0},
+		{name: "a week", days: 7, archived: []string{"Fix flaky login spec", "Upgrade Rails"}},
+		{name: "a day", days: 1, archived: []string{"Fix flaky login spec", "Login with SSO", "Upgrade Rails"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cfg := &Config{
+				Settings: &Settings{ArchiveAfter: tt.days},
+				Todos:    append([]Todo(nil), todos...),
+				Archived: []Todo{{Description: "Set up CI", Status: TodoStatusDone}},
+			}
+			if got := cfg.ArchiveDone(now, checkedOut); got != len(tt.archived) {
+				t.Fatalf("ArchiveDone() = %d, want %d", got, len(tt.archived))
+			}
+			if len(cfg.Todos)+len(tt.archived) != len(todos) {
+				t.Errorf("%d todos left, want %d", len(cfg.Todos), len(todos)-len(tt.archived))
+			}
+			for i, description := range append(tt.archived, "Set up CI") {
+				if cfg.Archived[i].Description != description {
+					t.Errorf("Archived[%d] = %q, want %q", i, cfg.Archived[i].Description, description)
+				}
+			}
+		})
+	}
+}
+
+func TestArchiveTodo(t *testing.T) {
+	cfg := &Config{Todos: []Todo{
+		{Description: "Add billing page", Status: TodoStatusPending},
+		{Description: "Fix flaky login spec", Status: TodoStatusPending},
+		{Description: "Add search", Status: TodoStatusDone, Worktree: "myapp-search"},
+	}}
+	checkedOut := func(worktree string) bool { return worktree == "myapp-search" }
+
+	if err := cfg.ArchiveTodo("Add billing page", checkedOut); err == nil {
+		t.Error("ArchiveTodo() archived a pending todo")
+	}
+	if err := cfg.ArchiveTodo("Add search", checkedOut); err == nil {
+		t.Error("ArchiveTodo() archived the todo of a checked out worktree")
+	}
+	cfg.Todos[1].MarkDone()
+	if cfg.Todos[1].Completed.IsZero() {
+		t.Error("MarkDone() didn't record when the todo was done")
+	}
+	if err := cfg.ArchiveTodo("Fix flaky login spec", checkedOut); err != nil {
+		t.Fatalf("ArchiveTodo() error = %v", err)
+	}
+	if len(cfg.Todos) != 2 || len(cfg.Archived) != 1 {
+		t.Fatalf("%d todos and %d archived, want 2 and 1", len(cfg.Todos), len(cfg.Archived))
+	}
+
+	if err := cfg.RestoreTodo("Fix flaky login spec"); err != nil {
+		t.Fatalf("RestoreTodo() error = %v", err)
+	}
+	if len(cfg.Archived) != 0 || cfg.Todos[0].Description != "Fix flaky login spec" || cfg.Todos[0].Status != TodoStatusDone {
+		t.Errorf("RestoreTodo() left todos %+v and archive %+v", cfg.Todos, cfg.Archived)
+	}
+}
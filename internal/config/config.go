@@ -6,15 +6,20 @@ import (
 	"os/exec"
 	"path/filepath"
 	"strings"
+	"time"
 
 	"gopkg.in/yaml.v3"
+
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
 )
 
 type TodoStatus string
 
 const (
-	TodoStatusPending TodoStatus = "pending"
-	TodoStatusDone    TodoStatus = "done"
+	TodoStatusPending  TodoStatus = "pending"
+	TodoStatusInReview TodoStatus = "in_review" // A pull request is open for the linked branch
+	TodoStatusDone     TodoStatus = "done"
 )
 
 type Todo struct {
@@ -23,6 +28,13 @@ type Todo struct {
 	Worktree    string     `yaml:"worktree,omitempty"`
 	GitHubBody  string     `yaml:"github_body,omitempty"`
 	GitHubURL   string     `yaml:"github_url,omitempty"`
+	GitHubItem  string     `yaml:"github_item,omitempty"`  // Project item the todo is mirrored to with the hybrid backend
+	GitHubState string     `yaml:"github_state,omitempty"` // Status last mirrored to the item
+	GitHubTitle string     `yaml:"github_title,omitempty"` // Description last mirrored to the item, as its title
+	BlockedBy   string     `yaml:"blocked_by,omitempty"`   // Description of the todo this one waits on
+	Notes       string     `yaml:"notes,omitempty"`        // Longer notes, written in $EDITOR from the TUI
+	Created     time.Time  `yaml:"created,omitempty"`      // When the todo was added, zero for todos from before this was recorded
+	Completed   time.Time  `yaml:"completed,omitempty"`    // When the todo was done, for settings.archive_after
 }
 
 type TmuxWindow struct {
@@ -31,38 +43,215 @@ type TmuxWindow struct {
 }
 
 type Pane struct {
-	Name    string  `yaml:"name"`
-	Width   string  `yaml:"width,omitempty"`   // e.g. "50%", "33%"
-	Command *string `yaml:"command,omitempty"`
+	Name         string  `yaml:"name"`
+	Width        string  `yaml:"width,omitempty"`          // e.g. "50%", "33%", for panes side by side
+	Height       string  `yaml:"height,omitempty"`         // e.g. "60%", for panes stacked in a pane split vertically
+	Command      *string `yaml:"command,omitempty"`
+	RemainOnExit bool    `yaml:"remain_on_exit,omitempty"` // Keep the pane open when its command exits, marked crashed
+	Split        string  `yaml:"split,omitempty"`          // How Panes divide this pane: "vertical" (stacked, the default) or "horizontal"
+	Panes        []Pane  `yaml:"panes,omitempty"`          // Splits this pane further instead of running a command in it
+}
+
+// Split directions for a pane's panes
+const (
+	SplitVertical   = "vertical"
+	SplitHorizontal = "horizontal"
+)
+
+// Leaves returns the panes that run a command, descending into panes that are split further
+func (p Pane) Leaves() []Pane {
+	if len(p.Panes) == 0 {
+		return []Pane{p}
+	}
+	var leaves []Pane
+	for _, pane := range p.Panes {
+		leaves = append(leaves, pane.Leaves()...)
+	}
+	return leaves
 }
 
 type LayoutRow struct {
-	Height  string  `yaml:"height"`            // Height as percentage of work area (excluding description and agent panes)
-	Name    string  `yaml:"name,omitempty"`    // For single-pane rows
-	Command *string `yaml:"command,omitempty"` // For single-pane rows
-	Panes   []Pane  `yaml:"panes,omitempty"`   // For multi-pane rows (split horizontally)
+	Height       string  `yaml:"height"`                   // Height as percentage of work area (excluding description and agent panes)
+	Name         string  `yaml:"name,omitempty"`           // For single-pane rows
+	Command      *string `yaml:"command,omitempty"`        // For single-pane rows
+	RemainOnExit bool    `yaml:"remain_on_exit,omitempty"` // For single-pane rows, keep the pane open when its command exits
+	Panes        []Pane  `yaml:"panes,omitempty"`          // For multi-pane rows (split horizontally, each pane can be split further)
 }
 
 type StorageBackend struct {
-	Type          string `yaml:"type"` // "local" or "github"
+	Type          string `yaml:"type"` // "local", "github", or "hybrid" (local todos mirrored to a GitHub Project)
 	Owner         string `yaml:"owner,omitempty"`
 	Repo          string `yaml:"repo,omitempty"`
 	ProjectNumber int    `yaml:"project_number,omitempty"`
 }
 
+// IDE configures how `lfg code` and the TUI open a worktree in an IDE
+type IDE struct {
+	Type          string `yaml:"type"`                     // "vscode", "vscode-workspace", "jetbrains", "zed" or "terminal"
+	Command       string `yaml:"command,omitempty"`        // Launcher override, e.g. "code-insiders", "goland" or "nvim"
+	WorkspaceFile string `yaml:"workspace_file,omitempty"` // .code-workspace file, relative to the repo root
+}
+
+// Naming customizes how worktree names are derived from todo descriptions
+type Naming struct {
+	StopWords     []string          `yaml:"stop_words,omitempty"`    // Words dropped from names, e.g. "the", "implement"
+	Abbreviations map[string]string `yaml:"abbreviations,omitempty"` // Word replacements, e.g. authentication: auth
+	MaxLength     int               `yaml:"max_length,omitempty"`    // Longer names are shortened with a hash suffix, default 60
+}
+
+// Templates configures the todo type picker in the create dialog
+type Templates struct {
+	Types          []string `yaml:"types,omitempty"`           // e.g. [feat, fix, chore]
+	Description    string   `yaml:"description,omitempty"`     // e.g. "[{type}] {summary}"
+	BranchPrefix   bool     `yaml:"branch_prefix,omitempty"`   // Name branches "<type>/<worktree>"
+	CommitTemplate string   `yaml:"commit_template,omitempty"` // Per-worktree commit message template, e.g. "\n\n# {description}"
+}
+
+// RenderDescription fills the description template with a todo type and summary
+func (t *Templates) RenderDescription(todoType, summary string) string {
+	if t == nil || t.Description == "" || todoType == "" {
+		return summary
+	}
+	return strings.NewReplacer("{type}", todoType, "{summary}", summary).Replace(t.Description)
+}
+
+// BranchName returns the branch for a new worktree, prefixed with the todo type when enabled
+func (t *Templates) BranchName(todoType, worktreeName string) string {
+	if t == nil || !t.BranchPrefix || todoType == "" {
+		return worktreeName
+	}
+	return todoType + "/" + worktreeName
+}
+
+// RenderCommitTemplate fills the commit template with a todo description and branch, or returns "" if none is set
+func (t *Templates) RenderCommitTemplate(description, branch string) string {
+	if t == nil || t.CommitTemplate == "" {
+		return ""
+	}
+	return strings.NewReplacer("{description}", description, "{branch}", branch).Replace(t.CommitTemplate)
+}
+
+// Git overrides how git is invoked, e.g. on machines with several git versions installed
+type Git struct {
+	Executable    string            `yaml:"executable,omitempty"`     // Path to the git binary
+	Args          []string          `yaml:"args,omitempty"`           // Global args, e.g. ["-c", "protocol.version=2"]
+	Env           map[string]string `yaml:"env,omitempty"`            // Extra environment, e.g. GIT_SSH_COMMAND
+	PushOnCreate  bool              `yaml:"push_on_create,omitempty"` // Push new branches to origin with -u when they're created
+	DefaultBranch string            `yaml:"default_branch,omitempty"` // Overrides the default branch detected from origin/HEAD
+	RebaseAfter   int               `yaml:"rebase_after,omitempty"`   // Commits a pull request's base can move on before its worktree needs a rebase
+	Autostash     bool              `yaml:"autostash,omitempty"`      // Stash uncommitted changes around rebases run from the TUI
+	Backend       string            `yaml:"backend,omitempty"`        // "exec" (the default) or "native" to list worktrees without running git
+}
+
+// Tmux selects the tmux server lfg starts sessions on and how their panes are started
+type Tmux struct {
+	SocketName string `yaml:"socket_name,omitempty"` // Server socket name, as with tmux -L
+	SocketPath string `yaml:"socket_path,omitempty"` // Server socket path, as with tmux -S, taking precedence over socket_name
+	Shell      string `yaml:"shell,omitempty"`       // Command panes start with instead of tmux's default-command
+}
+
+// Webhook receives lfg's events as JSON: POSTed to a URL, or on a command's stdin
+type Webhook struct {
+	URL     string   `yaml:"url,omitempty"`
+	Command string   `yaml:"command,omitempty"` // Run with sh -c, with LFG_EVENT set to the event type
+	Events  []string `yaml:"events,omitempty"`  // Event types to send, e.g. worktree_created, all of them when empty
+}
+
+// Settings holds TUI preferences, editable from the settings screen
+type Settings struct {
+	Theme             string `yaml:"theme,omitempty"`              // "default", "light" or "high-contrast"
+	SortOrder         string `yaml:"sort_order,omitempty"`         // "default" (git order), "name" or "recent"
+	Group             string `yaml:"group,omitempty"`              // "status" (the default) or "none"
+	SkipConfirmations bool   `yaml:"skip_confirmations,omitempty"` // Delete without asking first
+	AutoRefresh       int    `yaml:"auto_refresh,omitempty"`       // Seconds between list refreshes, 0 disables
+	HideDone          bool   `yaml:"hide_done,omitempty"`          // Hide worktrees whose todo is done
+	HideBlocked       bool   `yaml:"hide_blocked,omitempty"`       // Hide todos waiting on another open todo
+	Trash             bool   `yaml:"trash,omitempty"`              // Move dirty worktrees to the trash instead of deleting them
+	SaveSessions      bool   `yaml:"save_sessions,omitempty"`      // Save a worktree's session when lfg kills it, for `lfg restore`
+	MaxFPS            int    `yaml:"max_fps,omitempty"`            // Most frames drawn per second, 0 means the default
+	Mouse             bool   `yaml:"mouse,omitempty"`              // Scroll the list and select entries with the mouse
+	Language          string `yaml:"language,omitempty"`           // TUI language, e.g. "de", defaults to the one from LANG
+	ArchiveAfter      int    `yaml:"archive_after,omitempty"`      // Days after a todo is done before it's archived, 0 leaves them
+}
+
+// Scratch configures throwaway worktrees created with `lfg scratch`
+type Scratch struct {
+	ExpiryDays int `yaml:"expiry_days,omitempty"` // Days before a scratch worktree is offered for cleanup
+}
+
+// Aging configures the age shown next to pending todos, nudging long-lived work to be finished or dropped
+type Aging struct {
+	WarnDays     int  `yaml:"warn_days,omitempty"`     // Days before a todo's age is highlighted
+	StaleDays    int  `yaml:"stale_days,omitempty"`    // Days before a todo's age turns red and its work counts as stale
+	StaleSection bool `yaml:"stale_section,omitempty"` // List stale work together at the bottom of the list
+}
+
+// Default aging thresholds in days
+const (
+	DefaultWarnDays  = 7
+	DefaultStaleDays = 14
+)
+
+// Thresholds returns the configured warn and stale ages in days
+func (a *Aging) Thresholds() (warn, stale int) {
+	warn, stale = DefaultWarnDays, DefaultStaleDays
+	if a != nil && a.WarnDays > 0 {
+		warn = a.WarnDays
+	}
+	if a != nil && a.StaleDays > 0 {
+		stale = a.StaleDays
+	}
+	return warn, max(stale, warn)
+}
+
+// Setup prepares new worktrees, e.g. copying untracked config and installing dependencies
+type Setup struct {
+	Copy []string `yaml:"copy,omitempty"` // Files copied from the main worktree, e.g. [".env"]
+	Run  []string `yaml:"run,omitempty"`  // Commands run in the new worktree, e.g. ["bundle install"]
+}
+
+// Review configures the sessions for worktrees created with `lfg review`
+type Review struct {
+	Layout []LayoutRow `yaml:"layout,omitempty"` // Session layout, defaults to a single shell
+}
+
 type Config struct {
-	Name            string          `yaml:"name"`
-	WorktreeNaming  string          `yaml:"worktree_naming"`
-	StorageBackend  *StorageBackend `yaml:"storage_backend,omitempty"`
-	Todos           []Todo          `yaml:"todos"`
-	Windows         []TmuxWindow    `yaml:"windows,omitempty"` // Deprecated, use Layout
-	Layout          []LayoutRow     `yaml:"layout,omitempty"`
-	configPath      string
+	Name           string            `yaml:"name"`
+	WorktreeNaming string            `yaml:"worktree_naming"`
+	StorageBackend *StorageBackend   `yaml:"storage_backend,omitempty"`
+	IDE            *IDE              `yaml:"ide,omitempty"`
+	Naming         *Naming           `yaml:"naming,omitempty"`
+	Templates      *Templates        `yaml:"templates,omitempty"`
+	Settings       *Settings         `yaml:"settings,omitempty"`
+	Git            *Git              `yaml:"git,omitempty"`
+	Tmux           *Tmux             `yaml:"tmux,omitempty"`
+	TmuxSocket     string            `yaml:"tmux_socket,omitempty"` // Deprecated, use the tmux section's socket_name or socket_path
+	Multiplexer    string            `yaml:"multiplexer,omitempty"` // What sessions run in: "tmux" (the default) or "zellij"
+	Webhooks       []Webhook         `yaml:"webhooks,omitempty"`    // Services told about worktree and todo changes
+	Setup          *Setup            `yaml:"setup,omitempty"`
+	Scratch        *Scratch          `yaml:"scratch,omitempty"`
+	Aging          *Aging            `yaml:"aging,omitempty"`
+	Review         *Review           `yaml:"review,omitempty"`
+	CheckCommand   string            `yaml:"check_command,omitempty"` // Run in each worktree when it changes, shown as a pass/fail badge
+	Colors         map[string]string `yaml:"colors,omitempty"` // Accent colour by worktree name, saved to the user state rather than here, see userState
+	Pinned         []string          `yaml:"pinned,omitempty"` // Entries kept at the top of the list, in pin order, saved like Colors
+	Todos          []Todo            `yaml:"todos"`
+	Archived       []Todo            `yaml:"archived,omitempty"`       // Done todos put away, newest first
+	GitHubRemoved  []string          `yaml:"github_removed,omitempty"` // Project items of removed todos, archived by the next mirror
+	Windows        []TmuxWindow      `yaml:"windows,omitempty"`        // Deprecated, use Layout
+	Layout         []LayoutRow       `yaml:"layout,omitempty"`
+	LayoutTemplate string            `yaml:"layout_template,omitempty"` // Shipped layout used without a layout, e.g. "rails"
+	ExtendLayout   bool              `yaml:"extend_layout,omitempty"`   // Add the layout rows after the global ones instead of replacing them
+	configPath     string
+	stateChanges   []stateChange           // Colour and pin changes not saved yet, see saveState
+	global         *Config                 // User-wide config this one is layered over, nil without one
+	layers         map[string]layeredField // Fields set from other config files, see layer
 }
 
 const configFileName = "lfg-config.yaml"
 
 // Load loads the config from the repository root, or creates a default one
+// Scoped configs in the directories between the worktree root and the current directory are applied over it
 func Load() (*Config, error) {
 	repoRoot, err := getRepoRoot()
 	if err != nil {
@@ -73,10 +262,102 @@ func Load() (*Config, error) {
 
 	// If config doesn't exist, run init wizard
 	if _, err := os.Stat(configPath); os.IsNotExist(err) {
-		return runInitWizard(configPath, repoRoot)
+		return runInitWizard(configPath, repoRoot, InitOptions{})
+	}
+	return loadRepo(configPath)
+}
+
+// LoadOrDefault loads the config like Load, but without a config file returns the one the init wizard
+// would create with its defaults instead of running it. Nothing is written until the config is saved,
+// so commands that only read it leave a repository without one as it was
+func LoadOrDefault() (*Config, error) {
+	repoRoot, err := getRepoRoot()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get repo root: %w", err)
+	}
+
+	configPath := filepath.Join(repoRoot, configFileName)
+	if _, err := os.Stat(configPath); os.IsNotExist(err) {
+		return defaultConfig(configPath, repoRoot), nil
+	}
+	return loadRepo(configPath)
+}
+
+// loadRepo loads the repository config, with the scoped configs for the current directory applied over it
+func loadRepo(configPath string) (*Config, error) {
+	cfg, err := LoadFromPath(configPath)
+	if err != nil {
+		return nil, err
 	}
 
-	return LoadFromPath(configPath)
+	// Scoped configs apply below the root of the worktree lfg runs in, which may be a linked one
+	output, err := runner.Output(GitCommand("rev-parse", "--show-toplevel"))
+	if err == nil {
+		if err := cfg.loadScoped(strings.TrimSpace(string(output))); err != nil {
+			return nil, err
+		}
+	}
+	return cfg, nil
+}
+
+// Path returns the path of the repository config file without creating it
+func Path() (string, error) {
+	repoRoot, err := getRepoRoot()
+	if err != nil {
+		return "", fmt.Errorf("failed to get repo root: %w", err)
+	}
+	return filepath.Join(repoRoot, configFileName), nil
+}
+
+// Discover finds the repository config by walking up from dir without running git
+// Linked worktrees are resolved to their main repository through the gitdir in their `.git` file
+func Discover(dir string) (string, error) {
+	for {
+		// Configs below the repository root are scoped overrides, the repository config is the one at the root
+		gitPath := filepath.Join(dir, ".git")
+		if info, err := os.Stat(gitPath); err == nil {
+			candidate := filepath.Join(dir, configFileName)
+			if _, err := os.Stat(candidate); err == nil {
+				return candidate, nil
+			}
+			if !info.IsDir() {
+				// Linked worktree: ".git" contains "gitdir: <main>/.git/worktrees/<id>"
+				if mainRoot, err := mainRootFromGitFile(gitPath); err == nil {
+					candidate = filepath.Join(mainRoot, configFileName)
+					if _, err := os.Stat(candidate); err == nil {
+						return candidate, nil
+					}
+				}
+			}
+			return "", fmt.Errorf("no %s found for repository at %s", configFileName, dir)
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return "", fmt.Errorf("not in a git repository")
+		}
+		dir = parent
+	}
+}
+
+// mainRootFromGitFile resolves a linked worktree's `.git` file to the main repository root
+func mainRootFromGitFile(gitFile string) (string, error) {
+	data, err := os.ReadFile(gitFile)
+	if err != nil {
+		return "", err
+	}
+
+	gitDir := strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(string(data)), "gitdir:"))
+	if !filepath.IsAbs(gitDir) {
+		gitDir = filepath.Join(filepath.Dir(gitFile), gitDir)
+	}
+
+	// <main>/.git/worktrees/<id> -> <main>
+	worktreesDir := filepath.Dir(filepath.Clean(gitDir))
+	if filepath.Base(worktreesDir) != "worktrees" {
+		return "", fmt.Errorf("unexpected gitdir %s", gitDir)
+	}
+	return filepath.Dir(filepath.Dir(worktreesDir)), nil
 }
 
 // LoadFromPath loads the config from a specific path without running init wizard
@@ -91,19 +372,91 @@ func LoadFromPath(configPath string) (*Config, error) {
 	if err := yaml.Unmarshal(data, &cfg); err != nil {
 		return nil, fmt.Errorf("failed to parse config: %w", err)
 	}
+	if err := cfg.validateLayout(); err != nil {
+		return nil, err
+	}
+	if err := cfg.validateMultiplexer(); err != nil {
+		return nil, err
+	}
 
 	cfg.configPath = configPath
+	cfg.migrateTmuxSocket()
+	cfg.loadState()
+
+	global, err := loadGlobal()
+	if err != nil {
+		return nil, err
+	}
+	if global != nil {
+		cfg.applyGlobal(global)
+	}
 	return &cfg, nil
 }
 
+// GlobalPath returns the path of the user-wide config that repository configs are layered over
+func GlobalPath() (string, error) {
+	dir := os.Getenv("XDG_CONFIG_HOME")
+	if dir == "" {
+		home, err := os.UserHomeDir()
+		if err != nil {
+			return "", fmt.Errorf("failed to get home directory: %w", err)
+		}
+		dir = filepath.Join(home, ".config")
+	}
+	return filepath.Join(dir, "lfg", "config.yaml"), nil
+}
+
+// DataDir returns where lfg keeps its data, ~/.local/share/lfg unless XDG_DATA_HOME is set
+func DataDir() (string, error) {
+	dataHome := os.Getenv("XDG_DATA_HOME")
+	if dataHome == "" {
+		home, err := os.UserHomeDir()
+		if err != nil {
+			return "", fmt.Errorf("failed to find home directory: %w", err)
+		}
+		dataHome = filepath.Join(home, ".local", "share")
+	}
+	return filepath.Join(dataHome, "lfg"), nil
+}
+
+// loadGlobal reads the global config, returning nil when there isn't one
+func loadGlobal() (*Config, error) {
+	path, err := GlobalPath()
+	if err != nil {
+		return nil, nil
+	}
+	data, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read global config: %w", err)
+	}
+
+	var global Config
+	if err := yaml.Unmarshal(data, &global); err != nil {
+		return nil, fmt.Errorf("failed to parse global config %s: %w", path, err)
+	}
+
+	// Todos belong to one repository, so a global list would mix every repository's work together.
+	// Old global todos are moved into their repositories by MigrateGlobalTodos instead, which reports the
+	// ones it leaves behind
+	global.Todos = nil
+	return &global, nil
+}
+
 // GetConfigPath returns the path to the config file
 func (c *Config) GetConfigPath() string {
 	return c.configPath
 }
 
 // Save saves the config to disk
+// Settings from the global or scoped configs are left out unless they were changed for this repository
+// Colours and pins go to the user state rather than the file
 func (c *Config) Save() error {
-	data, err := yaml.Marshal(c)
+	out := c.withoutLayers()
+	out.Colors, out.Pinned = nil, nil
+	data, err := yaml.Marshal(out)
 	if err != nil {
 		return fmt.Errorf("failed to marshal config: %w", err)
 	}
@@ -112,7 +465,7 @@ func (c *Config) Save() error {
 		return fmt.Errorf("failed to write config: %w", err)
 	}
 
-	return nil
+	return c.saveState()
 }
 
 // AddTodo adds a new todo to the config
@@ -122,14 +475,100 @@ func (c *Config) AddTodo(description, worktree string) {
 		Description: description,
 		Status:      TodoStatusPending,
 		Worktree:    worktree,
+		Created:     time.Now(),
 	}}, c.Todos...)
+	events.Emit(events.Event{Type: events.TodoCreated, Worktree: worktree, Todo: description})
+}
+
+// findTodo returns the todo with the given description, nil if there's none
+func (c *Config) findTodo(description string) *Todo {
+	for i := range c.Todos {
+		if c.Todos[i].Description == description {
+			return &c.Todos[i]
+		}
+	}
+	return nil
+}
+
+// Blocker returns the open todo that t waits on, nil when t isn't blocked or its blocker is done or gone
+func (c *Config) Blocker(t *Todo) *Todo {
+	if t == nil || t.BlockedBy == "" {
+		return nil
+	}
+	blocker := c.findTodo(t.BlockedBy)
+	if blocker == nil || blocker.Status == TodoStatusDone {
+		return nil
+	}
+	return blocker
+}
+
+// SetBlockedBy records that the todo with the given description waits on blocker, or on nothing when
+// blocker is empty. A todo can't wait on itself, or on one that already waits on it through others
+func (c *Config) SetBlockedBy(description, blocker string) error {
+	todo := c.findTodo(description)
+	if todo == nil {
+		return fmt.Errorf("no todo %q", description)
+	}
+	if blocker != "" {
+		next := c.findTodo(blocker)
+		if next == nil {
+			return fmt.Errorf("no todo %q", blocker)
+		}
+		for range c.Todos {
+			if next.Description == description {
+				return fmt.Errorf("%q can't wait on %q, which waits on it", description, blocker)
+			}
+			if next = c.findTodo(next.BlockedBy); next == nil {
+				break
+			}
+		}
+	}
+	todo.BlockedBy = blocker
+	return nil
+}
+
+// Age returns how many whole days ago a pending todo was added, or -1 when it's no longer pending
+// or was added before todos recorded when
+func (t *Todo) Age(now time.Time) int {
+	if t.Status != TodoStatusPending || t.Created.IsZero() {
+		return -1
+	}
+	return int(now.Sub(t.Created).Hours() / 24)
+}
+
+// ApplyPullRequestState advances the todo through review as its pull request changes state
+// ("OPEN", "MERGED" or "CLOSED", as reported by gh) and reports whether the status changed
+func (t *Todo) ApplyPullRequestState(state string) bool {
+	previous := t.Status
+	switch state {
+	case "OPEN":
+		if t.Status == TodoStatusPending {
+			t.Status = TodoStatusInReview
+		}
+	case "MERGED":
+		t.MarkDone()
+	case "CLOSED":
+		// Closed without merging: back to work
+		if t.Status == TodoStatusInReview {
+			t.Status = TodoStatusPending
+		}
+	}
+
+	if t.Status == previous {
+		return false
+	}
+	if t.Status == TodoStatusDone {
+		events.Emit(events.Event{Type: events.TodoCompleted, Worktree: t.Worktree, Todo: t.Description})
+	}
+	return true
 }
 
 // MarkTodoDone marks a todo as done by worktree name
 func (c *Config) MarkTodoDone(worktree string) {
 	for i := range c.Todos {
 		if c.Todos[i].Worktree == worktree {
-			c.Todos[i].Status = TodoStatusDone
+			c.Todos[i].MarkDone()
+			events.Emit(events.Event{Type: events.TodoCompleted, Worktree: worktree, Todo: c.Todos[i].Description})
 			break
 		}
 	}
@@ -139,13 +578,160 @@ func (c *Config) MarkTodoDone(worktree string) {
 func (c *Config) RemoveTodo(worktree string) {
 	for i := range c.Todos {
 		if c.Todos[i].Worktree == worktree {
-			// Remove by slicing
-			c.Todos = append(c.Todos[:i], c.Todos[i+1:]...)
+			c.removeTodoAt(i)
+			break
+		}
+	}
+}
+
+// RemoveUnlinkedTodo removes a todo without a worktree (e.g. from `lfg capture`) by description
+func (c *Config) RemoveUnlinkedTodo(description string) {
+	for i := range c.Todos {
+		if c.Todos[i].Worktree == "" && c.Todos[i].Description == description {
+			c.removeTodoAt(i)
 			break
 		}
 	}
 }
 
+// removeTodoAt removes the todo at index i, leaving its project item to be archived with the hybrid backend
+// Todos waiting on it stop waiting, so a todo added later with the same description doesn't block them
+func (c *Config) removeTodoAt(i int) {
+	removed := c.Todos[i]
+	if removed.GitHubItem != "" {
+		c.GitHubRemoved = append(c.GitHubRemoved, removed.GitHubItem)
+	}
+	c.Todos = append(c.Todos[:i], c.Todos[i+1:]...)
+	for j := range c.Todos {
+		if c.Todos[j].BlockedBy == removed.Description {
+			c.Todos[j].BlockedBy = ""
+		}
+	}
+}
+
+// PushOnCreate reports whether new branches should be pushed as soon as they're created
+func (c *Config) PushOnCreate() bool {
+	return c.Git != nil && c.Git.PushOnCreate
+}
+
+// DefaultRebaseAfter is how many commits a pull request's base can move on before its worktree is
+// marked as needing a rebase
+const DefaultRebaseAfter = 10
+
+// RebaseAfter returns how many commits a pull request's base can move on before its worktree needs a rebase
+func (c *Config) RebaseAfter() int {
+	if c.Git == nil || c.Git.RebaseAfter <= 0 {
+		return DefaultRebaseAfter
+	}
+	return c.Git.RebaseAfter
+}
+
+// Autostash reports whether rebases run from the TUI stash uncommitted changes by default
+func (c *Config) Autostash() bool {
+	return c.Git != nil && c.Git.Autostash
+}
+
+// WorktreeColor returns the colour assigned to a worktree, or "" if none
+func (c *Config) WorktreeColor(worktree string) string {
+	return c.Colors[worktree]
+}
+
+// SetWorktreeColor assigns a colour to a worktree, or clears it when color is ""
+func (c *Config) SetWorktreeColor(worktree, color string) {
+	c.changeState(func(s *userState) { s.setColor(worktree, color) })
+}
+
+// IsPinned reports whether an entry is pinned to the top of the list
+func (c *Config) IsPinned(key string) bool {
+	return c.PinIndex(key) >= 0
+}
+
+// PinIndex returns the position of a pinned entry, or -1 if it isn't pinned
+func (c *Config) PinIndex(key string) int {
+	return (&userState{Pinned: c.Pinned}).pinIndex(key)
+}
+
+// TogglePin pins an entry below the existing pins, or unpins it, and reports whether it is now pinned
+func (c *Config) TogglePin(key string) bool {
+	pinned := !c.IsPinned(key)
+	c.changeState(func(s *userState) { s.setPinned(key, pinned) })
+	return pinned
+}
+
+// RenamePin keeps an entry's pin when its key changes, e.g. when a captured todo gets a worktree
+func (c *Config) RenamePin(from, to string) {
+	c.changeState(func(s *userState) { s.renamePin(from, to) })
+}
+
+// ForgetWorktree drops the colour and pin of a deleted worktree
+func (c *Config) ForgetWorktree(worktree string) {
+	c.SetWorktreeColor(worktree, "")
+	if c.IsPinned(worktree) {
+		c.TogglePin(worktree)
+	}
+}
+
+// GitHubMirror returns the project local todos are mirrored to, nil unless the storage backend is "hybrid"
+func (c *Config) GitHubMirror() *StorageBackend {
+	if c.StorageBackend == nil || c.StorageBackend.Type != "hybrid" {
+		return nil
+	}
+	return c.StorageBackend
+}
+
+// TmuxOptions returns the tmux settings, empty when there's no tmux section
+func (c *Config) TmuxOptions() *Tmux {
+	var opts Tmux
+	if c.Tmux != nil {
+		opts = *c.Tmux
+	}
+	return &opts
+}
+
+// migrateTmuxSocket moves a tmux_socket set by older versions into the tmux section, unless the section
+// names a server already. A tmux_socket containing a "/" is a socket path, anything else a socket name
+func (c *Config) migrateTmuxSocket() {
+	if c.TmuxSocket == "" {
+		return
+	}
+	if c.Tmux == nil {
+		c.Tmux = &Tmux{}
+	}
+	if c.Tmux.SocketName == "" && c.Tmux.SocketPath == "" {
+		if strings.Contains(c.TmuxSocket, "/") {
+			c.Tmux.SocketPath = c.TmuxSocket
+		} else {
+			c.Tmux.SocketName = c.TmuxSocket
+		}
+	}
+	c.TmuxSocket = ""
+}
+
+// RenameWorktree moves the todos, colour and pin of a renamed worktree to its new name
+func (c *Config) RenameWorktree(from, to string) {
+	for i := range c.Todos {
+		if c.Todos[i].Worktree == from {
+			c.Todos[i].Worktree = to
+		}
+	}
+	if color := c.WorktreeColor(from); color != "" {
+		c.SetWorktreeColor(from, "")
+		c.SetWorktreeColor(to, color)
+	}
+	c.RenamePin(from, to)
+}
+
+// RepurposeWorktree links a new todo to an existing worktree that is being reused for other work
+// Todos previously linked to the worktree are unlinked, so pending ones can be started elsewhere
+func (c *Config) RepurposeWorktree(worktree, description string) {
+	for i := range c.Todos {
+		if c.Todos[i].Worktree == worktree {
+			c.Todos[i].Worktree = ""
+		}
+	}
+	c.AddTodo(description, worktree)
+}
+
 // GetTodoForWorktree returns the todo associated with a worktree
 func (c *Config) GetTodoForWorktree(worktree string) *Todo {
 	for i := range c.Todos {
@@ -156,14 +742,93 @@ func (c *Config) GetTodoForWorktree(worktree string) *Todo {
 	return nil
 }
 
+// ReviewProfile returns the config to start a pull request review session with
+// It is a copy using the review layout, which defaults to a single shell instead of the usual work panes
+func (c *Config) ReviewProfile() *Config {
+	profile := *c
+	profile.Windows = nil
+	profile.ExtendLayout = false
+	profile.Layout = []LayoutRow{{Height: "100%", Name: "shell"}}
+	if c.Review != nil && len(c.Review.Layout) > 0 {
+		profile.Layout = c.Review.Layout
+	}
+	return &profile
+}
+
+// ShellProfile returns the config to start a session for fixing a worktree mid-rebase or with conflicts
+// It is a copy with a single shell instead of the usual work panes, so no servers start on broken code
+func (c *Config) ShellProfile() *Config {
+	profile := *c
+	profile.Windows = nil
+	profile.ExtendLayout = false
+	profile.Layout = []LayoutRow{{Height: "100%", Name: "shell"}}
+	return &profile
+}
+
 // GetLayout returns the layout, converting from old Windows format if necessary
+// Without a layout of its own, or with extend_layout set, the global config's layout is used too
 // Note: Description pane is automatic (always top 10%), so this only returns the work panes
 func (c *Config) GetLayout() []LayoutRow {
+	own := c.ownLayout()
+	if c.global == nil {
+		return own
+	}
+	if len(own) == 0 {
+		return c.global.ownLayout()
+	}
+	if c.ExtendLayout {
+		return append(append([]LayoutRow{}, c.global.ownLayout()...), own...)
+	}
+	return own
+}
+
+// validateLayout checks that layout_template names one of the shipped templates and that panes
+// are split in a direction tmux knows
+func (c *Config) validateLayout() error {
+	if _, ok := LayoutTemplates[c.LayoutTemplate]; c.LayoutTemplate != "" && !ok {
+		return fmt.Errorf("unknown layout_template %q, choose one of: %s", c.LayoutTemplate, strings.Join(LayoutTemplateNames(), ", "))
+	}
+	for _, row := range c.Layout {
+		if err := validateSplits(row.Panes); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// Multiplexers sessions can be run in
+const (
+	MultiplexerTmux   = "tmux"
+	MultiplexerZellij = "zellij"
+)
+
+// validateMultiplexer checks that multiplexer names one lfg can run sessions in
+func (c *Config) validateMultiplexer() error {
+	switch c.Multiplexer {
+	case "", MultiplexerTmux, MultiplexerZellij:
+		return nil
+	}
+	return fmt.Errorf("unknown multiplexer %q, use %q or %q", c.Multiplexer, MultiplexerTmux, MultiplexerZellij)
+}
+
+func validateSplits(panes []Pane) error {
+	for _, pane := range panes {
+		if pane.Split != "" && pane.Split != SplitVertical && pane.Split != SplitHorizontal {
+			return fmt.Errorf("pane %q has unknown split %q, use %q or %q", pane.Name, pane.Split, SplitVertical, SplitHorizontal)
+		}
+		if err := validateSplits(pane.Panes); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// ownLayout returns the layout defined in this config file alone
+func (c *Config) ownLayout() []LayoutRow {
 	// If we have the new layout format, use it
 	if len(c.Layout) > 0 {
 		return c.Layout
 	}
-
 	// Convert old Windows format to Layout
 	// The old format should be converted to the new format (excluding description)
 	if len(c.Windows) > 0 {
@@ -183,15 +848,20 @@ func (c *Config) GetLayout() []LayoutRow {
 		return layout
 	}
 
-	// No layout defined, return empty
-	return nil
+	// A shipped layout template, or no layout at all
+	return LayoutTemplates[c.LayoutTemplate]
+}
+
+// GitCommand builds the git commands that find the repository. The git package sets it to git.Command,
+// which config can't import, so LFG_GIT and LFG_GIT_ARGS apply to them too
+var GitCommand = func(args ...string) *exec.Cmd {
+	return exec.Command("git", args...)
 }
 
 func getRepoRoot() (string, error) {
 	// Try to get the main worktree root by listing all worktrees
 	// The first worktree in the list is always the main worktree
-	cmd := exec.Command("git", "worktree", "list", "--porcelain")
-	output, err := cmd.Output()
+	output, err := runner.Output(GitCommand("worktree", "list", "--porcelain"))
 	if err == nil {
 		// Parse the output to get the first worktree path
 		lines := strings.Split(string(output), "\n")
@@ -203,8 +873,7 @@ func getRepoRoot() (string, error) {
 	}
 
 	// Fallback to rev-parse if worktree list fails (e.g., not using worktrees)
-	cmd = exec.Command("git", "rev-parse", "--show-toplevel")
-	output, err = cmd.Output()
+	output, err = runner.Output(GitCommand("rev-parse", "--show-toplevel"))
 	if err != nil {
 		return "", fmt.Errorf("not in a git repository")
 	}
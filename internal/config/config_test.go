@@ -3,7 +3,9 @@ package config
 import (
 	"os"
 	"path/filepath"
+	"strings"
 	"testing"
+	"time"
 )
 
 func TestAddTodo(t *testing.T) {
@@ -31,6 +33,39 @@ func TestAddTodo(t *testing.T) {
 	}
 }
 
+func TestTodoAge(t *testing.T) {
+	now := time.Date(2026, 3, 20, 12, 0, 0, 0, time.UTC)
+	created := now.Add(-15*24*time.Hour - time.Hour)
+
+	tests := []struct {
+		name     string
+		todo     Todo
+		expected int
+	}{
+		{name: "pending", todo: Todo{Status: TodoStatusPending, Created: created}, expected: 15},
+		{name: "added today", todo: Todo{Status: TodoStatusPending, Created: now.Add(-time.Hour)}, expected: 0},
+		{name: "in review", todo: Todo{Status: TodoStatusInReview, Created: created}, expected: -1},
+		{name: "unknown", todo: Todo{Status: TodoStatusPending}, expected: -1},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.todo.Age(now); got != tt.expected {
+				t.Errorf("Age() = %d, want %d", got, tt.expected)
+			}
+		})
+	}
+
+	var aging *Aging
+	if warn, stale := aging.Thresholds(); warn != DefaultWarnDays || stale != DefaultStaleDays {
+		t.Errorf("Thresholds() = %d, %d, want the defaults", warn, stale)
+	}
+	aging = &Aging{WarnDays: 30}
+	if warn, stale := aging.Thresholds(); warn != 30 || stale != 30 {
+		t.Errorf("Thresholds() = %d, %d, want stale no earlier than warn", warn, stale)
+	}
+}
+
 func TestMarkTodoDone(t *testing.T) {
 	cfg := &Config{
 		Name: "test-project",
@@ -51,6 +86,68 @@ func TestMarkTodoDone(t *testing.T) {
 	}
 }
 
+func TestRemoveTodo(t *testing.T) {
+	cfg := &Config{Todos: []Todo{
+		{Description: "Add login", Worktree: "myapp-login", GitHubItem: "I1"},
+		{Description: "Add search", GitHubItem: "I2"},
+		{Description: "Write docs"},
+	}}
+
+	cfg.RemoveTodo("myapp-login")
+	cfg.RemoveUnlinkedTodo("Add search")
+	cfg.RemoveUnlinkedTodo("Write docs")
+	if len(cfg.Todos) != 0 {
+		t.Fatalf("%d todos left, want none", len(cfg.Todos))
+	}
+	// Their project items are archived by the next mirror
+	if strings.Join(cfg.GitHubRemoved, " ") != "I1 I2" {
+		t.Errorf("GitHubRemoved = %v, want I1 and I2", cfg.GitHubRemoved)
+	}
+}
+
+func TestBlockedBy(t *testing.T) {
+	cfg := &Config{Todos: []Todo{
+		{Description: "Add login", Status: TodoStatusPending},
+		{Description: "Add sessions", Status: TodoStatusPending},
+		{Description: "Add logout", Status: TodoStatusPending},
+	}}
+
+	if err := cfg.SetBlockedBy("Add sessions", "Add login"); err != nil {
+		t.Fatalf("SetBlockedBy() error = %v", err)
+	}
+	if err := cfg.SetBlockedBy("Add logout", "Add sessions"); err != nil {
+		t.Fatalf("SetBlockedBy() error = %v", err)
+	}
+	if blocker := cfg.Blocker(&cfg.Todos[1]); blocker == nil || blocker.Description != "Add login" {
+		t.Errorf("Blocker() = %v, want Add login", blocker)
+	}
+
+	// Waiting in a circle would leave every todo blocked for good
+	for _, blocker := range []string{"Add logout", "Add login"} {
+		if err := cfg.SetBlockedBy("Add login", blocker); err == nil {
+			t.Errorf("SetBlockedBy(Add login, %s) error = nil, want a cycle error", blocker)
+		}
+	}
+	if err := cfg.SetBlockedBy("Add logout", "Add missing"); err == nil {
+		t.Error("SetBlockedBy() with an unknown blocker error = nil")
+	}
+
+	// Finishing the blocker unblocks, as does clearing the link
+	cfg.Todos[0].Status = TodoStatusDone
+	if blocker := cfg.Blocker(&cfg.Todos[1]); blocker != nil {
+		t.Errorf("Blocker() after the blocker is done = %v, want nil", blocker)
+	}
+	if err := cfg.SetBlockedBy("Add logout", ""); err != nil || cfg.Blocker(&cfg.Todos[2]) != nil {
+		t.Errorf("SetBlockedBy(\"\") error = %v, blocker = %v", err, cfg.Blocker(&cfg.Todos[2]))
+	}
+
+	// Removing the blocker clears the link, so a new todo of the same name doesn't block
+	cfg.RemoveUnlinkedTodo("Add login")
+	if cfg.Todos[0].BlockedBy != "" {
+		t.Errorf("BlockedBy after removing the blocker = %q, want none", cfg.Todos[0].BlockedBy)
+	}
+}
+
 func TestGetTodoForWorktree(t *testing.T) {
 	cfg := &Config{
 		Name: "test-project",
@@ -75,6 +172,63 @@ func TestGetTodoForWorktree(t *testing.T) {
 	}
 }
 
+func TestRepurposeWorktree(t *testing.T) {
+	cfg := &Config{
+		Name: "test-project",
+		Todos: []Todo{
+			{Description: "Fix typo", Worktree: "scratch", Status: TodoStatusDone},
+			{Description: "Feature 2", Worktree: "worktree-2", Status: TodoStatusPending},
+		},
+		configPath: "/tmp/test-config.yaml",
+	}
+
+	cfg.RepurposeWorktree("scratch", "Fix footer link")
+
+	todo := cfg.GetTodoForWorktree("scratch")
+	if todo == nil || todo.Description != "Fix footer link" {
+		t.Fatalf("Expected scratch to be linked to the new todo, got %+v", todo)
+	}
+	if todo.Status != TodoStatusPending {
+		t.Errorf("Expected new todo to be pending, got %q", todo.Status)
+	}
+	if cfg.Todos[1].Worktree != "" {
+		t.Errorf("Expected previous todo to be unlinked, got worktree %q", cfg.Todos[1].Worktree)
+	}
+	if cfg.GetTodoForWorktree("worktree-2") == nil {
+		t.Error("Expected other worktrees to keep their todos")
+	}
+}
+
+func TestApplyPullRequestState(t *testing.T) {
+	tests := []struct {
+		name     string
+		status   TodoStatus
+		state    string
+		expected TodoStatus
+		changed  bool
+	}{
+		{name: "opened", status: TodoStatusPending, state: "OPEN", expected: TodoStatusInReview, changed: true},
+		{name: "still open", status: TodoStatusInReview, state: "OPEN", expected: TodoStatusInReview, changed: false},
+		{name: "merged", status: TodoStatusInReview, state: "MERGED", expected: TodoStatusDone, changed: true},
+		{name: "closed unmerged", status: TodoStatusInReview, state: "CLOSED", expected: TodoStatusPending, changed: true},
+		{name: "done stays done", status: TodoStatusDone, state: "OPEN", expected: TodoStatusDone, changed: false},
+		{name: "no pull request", status: TodoStatusPending, state: "", expected: TodoStatusPending, changed: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			todo := &Todo{Description: "Feature", Worktree: "worktree-1", Status: tt.status}
+			changed := todo.ApplyPullRequestState(tt.state)
+			if todo.Status != tt.expected {
+				t.Errorf("ApplyPullRequestState(%q) on %q = %q, want %q", tt.state, tt.status, todo.Status, tt.expected)
+			}
+			if changed != tt.changed {
+				t.Errorf("ApplyPullRequestState(%q) on %q changed = %v, want %v", tt.state, tt.status, changed, tt.changed)
+			}
+		})
+	}
+}
+
 func TestSaveAndLoad(t *testing.T) {
 	tmpDir := t.TempDir()
 	configPath := filepath.Join(tmpDir, "test-config.yaml")
@@ -119,3 +273,337 @@ func TestSaveAndLoad(t *testing.T) {
 func testStringPtr(s string) *string {
 	return &s
 }
+
+func TestTemplates(t *testing.T) {
+	templates := &Templates{
+		Types:        []string{"feat", "fix", "chore"},
+		Description:  "[{type}] {summary}",
+		BranchPrefix: true,
+	}
+
+	if got := templates.RenderDescription("fix", "Flaky login spec"); got != "[fix] Flaky login spec" {
+		t.Errorf("RenderDescription() = %q, want %q", got, "[fix] Flaky login spec")
+	}
+	if got := templates.BranchName("fix", "myapp-flaky-login-spec"); got != "fix/myapp-flaky-login-spec" {
+		t.Errorf("BranchName() = %q, want %q", got, "fix/myapp-flaky-login-spec")
+	}
+
+	templates.CommitTemplate = "\n\n# {description} ({branch})"
+	if got := templates.RenderCommitTemplate("Flaky login spec", "fix/myapp-flaky-login-spec"); got != "\n\n# Flaky login spec (fix/myapp-flaky-login-spec)" {
+		t.Errorf("RenderCommitTemplate() = %q", got)
+	}
+
+	// Without templates configured, descriptions and branches pass through unchanged
+	var none *Templates
+	if got := none.RenderCommitTemplate("Flaky login spec", "myapp-flaky-login-spec"); got != "" {
+		t.Errorf("RenderCommitTemplate() on nil = %q, want no template", got)
+	}
+	if got := none.RenderDescription("", "Flaky login spec"); got != "Flaky login spec" {
+		t.Errorf("RenderDescription() on nil = %q, want summary unchanged", got)
+	}
+	if got := none.BranchName("", "myapp-flaky-login-spec"); got != "myapp-flaky-login-spec" {
+		t.Errorf("BranchName() on nil = %q, want worktree name unchanged", got)
+	}
+}
+
+func TestReviewProfile(t *testing.T) {
+	cfg := &Config{
+		Name:   "myapp",
+		Layout: []LayoutRow{{Height: "50%", Name: "server"}, {Height: "50%", Name: "tests"}},
+	}
+
+	profile := cfg.ReviewProfile()
+	if len(profile.GetLayout()) != 1 || profile.GetLayout()[0].Name != "shell" {
+		t.Errorf("ReviewProfile() layout = %+v, want a single shell", profile.GetLayout())
+	}
+	if profile.Name != "myapp" {
+		t.Errorf("ReviewProfile() name = %q, want %q", profile.Name, "myapp")
+	}
+	if len(cfg.Layout) != 2 {
+		t.Error("ReviewProfile() modified the original config")
+	}
+
+	cfg.Review = &Review{Layout: []LayoutRow{{Height: "100%", Name: "diff", Command: testStringPtr("git diff main")}}}
+	if got := cfg.ReviewProfile().GetLayout(); len(got) != 1 || got[0].Name != "diff" {
+		t.Errorf("ReviewProfile() layout = %+v, want the configured review layout", got)
+	}
+}
+
+func TestPins(t *testing.T) {
+	cfg := &Config{Colors: map[string]string{"myapp-login": "blue"}}
+
+	if !cfg.TogglePin("myapp-login") || !cfg.TogglePin("todo:Fix flaky spec") {
+		t.Fatal("TogglePin() should pin unpinned entries")
+	}
+	if got := cfg.PinIndex("todo:Fix flaky spec"); got != 1 {
+		t.Errorf("PinIndex() = %d, want 1 (pins keep their order)", got)
+	}
+
+	cfg.RenamePin("todo:Fix flaky spec", "myapp-fix-flaky-spec")
+	if !cfg.IsPinned("myapp-fix-flaky-spec") || cfg.IsPinned("todo:Fix flaky spec") {
+		t.Errorf("RenamePin() pins = %v", cfg.Pinned)
+	}
+
+	cfg.ForgetWorktree("myapp-login")
+	if cfg.IsPinned("myapp-login") || cfg.WorktreeColor("myapp-login") != "" {
+		t.Errorf("ForgetWorktree() left pins %v and colours %v", cfg.Pinned, cfg.Colors)
+	}
+
+	if cfg.TogglePin("myapp-fix-flaky-spec") || len(cfg.Pinned) != 0 {
+		t.Errorf("TogglePin() should unpin pinned entries, pins = %v", cfg.Pinned)
+	}
+}
+
+func TestMigrateTmuxSocket(t *testing.T) {
+	tests := []struct {
+		name     string
+		cfg      Config
+		expected Tmux
+	}{
+		{name: "default server", expected: Tmux{}},
+		{name: "socket name", cfg: Config{TmuxSocket: "work"}, expected: Tmux{SocketName: "work"}},
+		{name: "socket path", cfg: Config{TmuxSocket: "/tmp/lfg.sock"}, expected: Tmux{SocketPath: "/tmp/lfg.sock"}},
+		{name: "tmux section wins", cfg: Config{TmuxSocket: "work", Tmux: &Tmux{SocketPath: "/tmp/lfg.sock", Shell: "zsh"}}, expected: Tmux{SocketPath: "/tmp/lfg.sock", Shell: "zsh"}},
+		{name: "shell kept", cfg: Config{TmuxSocket: "work", Tmux: &Tmux{Shell: "zsh"}}, expected: Tmux{SocketName: "work", Shell: "zsh"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			tt.cfg.migrateTmuxSocket()
+			if got := tt.cfg.TmuxOptions(); *got != tt.expected {
+				t.Errorf("TmuxOptions() = %+v, want %+v", *got, tt.expected)
+			}
+			if tt.cfg.TmuxSocket != "" {
+				t.Errorf("TmuxSocket = %q after migrating, want it moved to the tmux section", tt.cfg.TmuxSocket)
+			}
+		})
+	}
+}
+
+func TestDiscover(t *testing.T) {
+	tmpDir := t.TempDir()
+
+	// Main repository with a config and a nested directory
+	mainRoot := filepath.Join(tmpDir, "myapp")
+	nested := filepath.Join(mainRoot, "src", "pkg")
+	if err := os.MkdirAll(filepath.Join(mainRoot, ".git", "worktrees", "myapp-login"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.MkdirAll(nested, 0755); err != nil {
+		t.Fatal(err)
+	}
+	configPath := filepath.Join(mainRoot, configFileName)
+	if err := os.WriteFile(configPath, []byte("name: myapp\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Linked worktree next to the main repository
+	worktree := filepath.Join(tmpDir, "myapp-login")
+	if err := os.MkdirAll(worktree, 0755); err != nil {
+		t.Fatal(err)
+	}
+	gitFile := "gitdir: " + filepath.Join(mainRoot, ".git", "worktrees", "myapp-login") + "\n"
+	if err := os.WriteFile(filepath.Join(worktree, ".git"), []byte(gitFile), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	for _, dir := range []string{mainRoot, nested, worktree} {
+		got, err := Discover(dir)
+		if err != nil {
+			t.Errorf("Discover(%q) error = %v", dir, err)
+			continue
+		}
+		if got != configPath {
+			t.Errorf("Discover(%q) = %q, want %q", dir, got, configPath)
+		}
+	}
+
+	if _, err := Discover(tmpDir); err == nil {
+		t.Error("Expected error outside a repository")
+	}
+}
+
+func TestGlobalConfig(t *testing.T) {
+	configHome := t.TempDir()
+	t.Setenv("XDG_CONFIG_HOME", configHome)
+	if err := os.MkdirAll(filepath.Join(configHome, "lfg"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	global := `
+settings:
+  theme: light
+git:
+  push_on_create: true
+layout:
+  - height: 50%
+    name: code
+  - height: 50%
+    name: shell
+`
+	if err := os.WriteFile(filepath.Join(configHome, "lfg", "config.yaml"), []byte(global), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	tests := []struct {
+		name   string
+		repo   string
+		panes  []string
+		theme  string
+		pushOn bool
+	}{
+		{
+			name:   "inherits everything",
+			repo:   "name: demo\n",
+			panes:  []string{"code", "shell"},
+			theme:  "light",
+			pushOn: true,
+		},
+		{
+			name:   "overrides layout and settings",
+			repo:   "name: demo\nsettings:\n  theme: high-contrast\nlayout:\n  - height: 100%\n    name: server\n",
+			panes:  []string{"server"},
+			theme:  "high-contrast",
+			pushOn: true,
+		},
+		{
+			name:   "extends layout",
+			repo:   "name: demo\nextend_layout: true\nlayout:\n  - height: 100%\n    name: server\n",
+			panes:  []string{"code", "shell", "server"},
+			theme:  "light",
+			pushOn: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			configPath := filepath.Join(t.TempDir(), configFileName)
+			if err := os.WriteFile(configPath, []byte(tt.repo), 0644); err != nil {
+				t.Fatal(err)
+			}
+			cfg, err := LoadFromPath(configPath)
+			if err != nil {
+				t.Fatalf("LoadFromPath() error = %v", err)
+			}
+
+			var panes []string
+			for _, row := range cfg.GetLayout() {
+				panes = append(panes, row.Name)
+			}
+			if strings.Join(panes, ",") != strings.Join(tt.panes, ",") {
+				t.Errorf("GetLayout() panes = %v, want %v", panes, tt.panes)
+			}
+			if cfg.Settings.Theme != tt.theme {
+				t.Errorf("Settings.Theme = %q, want %q", cfg.Settings.Theme, tt.theme)
+			}
+			if cfg.PushOnCreate() != tt.pushOn {
+				t.Errorf("PushOnCreate() = %v, want %v", cfg.PushOnCreate(), tt.pushOn)
+			}
+		})
+	}
+}
+
+func TestSaveLeavesOutGlobalSettings(t *testing.T) {
+	configHome := t.TempDir()
+	t.Setenv("XDG_CONFIG_HOME", configHome)
+	if err := os.MkdirAll(filepath.Join(configHome, "lfg"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(configHome, "lfg", "config.yaml"), []byte("settings:\n  theme: light\ngit:\n  push_on_create: true\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	configPath := filepath.Join(t.TempDir(), configFileName)
+	if err := os.WriteFile(configPath, []byte("name: demo\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	cfg, err := LoadFromPath(configPath)
+	if err != nil {
+		t.Fatalf("LoadFromPath() error = %v", err)
+	}
+
+	// Changing an inherited setting makes it a repository override; untouched ones stay global
+	cfg.Settings.Theme = "high-contrast"
+	if err := cfg.Save(); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(string(data), "theme: high-contrast") {
+		t.Errorf("saved config missing the changed theme:\n%s", data)
+	}
+	if strings.Contains(string(data), "push_on_create") {
+		t.Errorf("saved config contains the inherited git settings:\n%s", data)
+	}
+}
+
+func TestValidateMultiplexer(t *testing.T) {
+	tests := []struct {
+		multiplexer string
+		wantErr     bool
+	}{
+		{multiplexer: ""},
+		{multiplexer: "tmux"},
+		{multiplexer: "zellij"},
+		{multiplexer: "screen", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		cfg := Config{Multiplexer: tt.multiplexer}
+		if err := cfg.validateMultiplexer(); (err != nil) != tt.wantErr {
+			t.Errorf("validateMultiplexer() with %q error = %v, wantErr %v", tt.multiplexer, err, tt.wantErr)
+		}
+	}
+}
+
+func TestUserState(t *testing.T) {
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	configPath := filepath.Join(t.TempDir(), configFileName)
+	if err := os.WriteFile(configPath, []byte("name: demo\ncolors:\n  demo-login: blue\npinned:\n  - demo-login\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Two lfg instances on the same repository
+	first, err := LoadFromPath(configPath)
+	if err != nil {
+		t.Fatalf("LoadFromPath() error = %v", err)
+	}
+	second, err := LoadFromPath(configPath)
+	if err != nil {
+		t.Fatalf("LoadFromPath() error = %v", err)
+	}
+	if first.WorktreeColor("demo-login") != "blue" || !first.IsPinned("demo-login") {
+		t.Errorf("colours and pins from the config file weren't loaded: colors %v, pinned %v", first.Colors, first.Pinned)
+	}
+
+	first.TogglePin("demo-signup")
+	if err := first.Save(); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+	second.SetWorktreeColor("demo-signup", "green")
+	if err := second.Save(); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+
+	data, err := os.ReadFile(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if strings.Contains(string(data), "colors") || strings.Contains(string(data), "pinned") {
+		t.Errorf("saved config still has colours or pins:\n%s", data)
+	}
+
+	saved, err := LoadFromPath(configPath)
+	if err != nil {
+		t.Fatalf("LoadFromPath() error = %v", err)
+	}
+	if saved.WorktreeColor("demo-login") != "blue" || saved.WorktreeColor("demo-signup") != "green" {
+		t.Errorf("colours = %v, want the moved one and the second instance's", saved.Colors)
+	}
+	if saved.PinIndex("demo-login") != 0 || saved.PinIndex("demo-signup") != 1 {
+		t.Errorf("pins = %v, want the moved one and the first instance's, in order", saved.Pinned)
+	}
+}
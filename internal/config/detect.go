@@ -0,0 +1,129 @@
+package config
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+)
+
+// LayoutTemplates are the layouts lfg ships for common kinds of project, picked with layout_template
+// or in the init wizard. The default is a code pane and a shell
+var LayoutTemplates = map[string][]LayoutRow{
+	"default": paneLayout("code", "", "shell", ""),
+	"rails":   paneLayout("code", "", "server", "bin/rails server", "test", "bin/rails test", "shell", ""),
+	"ruby":    paneLayout("code", "", "test", "bundle exec rake", "shell", ""),
+	"node":    paneLayout("code", "", "server", "npm run dev", "test", "npm test", "shell", ""),
+	"rust":    paneLayout("code", "", "test", "cargo test", "shell", ""),
+	"python":  paneLayout("code", "", "test", "pytest", "shell", ""),
+	"docker":  paneLayout("code", "", "server", "docker compose up", "shell", ""),
+}
+
+// LayoutTemplateNames returns the names of the layout templates, the default first
+func LayoutTemplateNames() []string {
+	names := []string{"default"}
+	for name := range LayoutTemplates {
+		if name != "default" {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names[1:])
+	return names
+}
+
+// layoutTemplate returns a copy of the named layout template, so it can be edited without changing the template
+func layoutTemplate(name string) []LayoutRow {
+	return append([]LayoutRow{}, LayoutTemplates[name]...)
+}
+
+// Project is the kind of project detected in a repository, with the layout proposed for it
+type Project struct {
+	Name     string // e.g. "Rails", empty when nothing was recognised
+	Marker   string // File the project was recognised by, e.g. "Gemfile"
+	Template string // Layout template the layout is based on
+	Layout   []LayoutRow
+}
+
+// DetectProject recognises the project in repoRoot by its files and proposes a layout of
+// code, server, test and shell panes for it, based on its layout template
+// Repositories that aren't recognised get the default layout
+func DetectProject(repoRoot string) Project {
+	exists := func(name string) bool {
+		_, err := os.Stat(filepath.Join(repoRoot, name))
+		return err == nil
+	}
+
+	switch {
+	case exists("Gemfile") && exists("bin/rails"):
+		project := Project{Name: "Rails", Marker: "Gemfile", Template: "rails", Layout: layoutTemplate("rails")}
+		if exists("bin/dev") {
+			project.Layout = paneLayout("code", "", "server", "bin/dev", "test", "bin/rails test", "shell", "")
+		}
+		return project
+	case exists("Gemfile"):
+		return Project{Name: "Ruby", Marker: "Gemfile", Template: "ruby", Layout: layoutTemplate("ruby")}
+	case exists("package.json"):
+		return Project{Name: "Node", Marker: "package.json", Template: "node", Layout: nodeLayout(repoRoot, exists)}
+	case exists("Cargo.toml"):
+		return Project{Name: "Rust", Marker: "Cargo.toml", Template: "rust", Layout: layoutTemplate("rust")}
+	}
+	for _, marker := range []string{"pyproject.toml", "requirements.txt", "setup.py"} {
+		if exists(marker) {
+			return Project{Name: "Python", Marker: marker, Template: "python", Layout: layoutTemplate("python")}
+		}
+	}
+	for _, compose := range []string{"compose.yaml", "compose.yml", "docker-compose.yml", "docker-compose.yaml"} {
+		if exists(compose) {
+			return Project{Name: "Docker Compose", Marker: compose, Template: "docker", Layout: layoutTemplate("docker")}
+		}
+	}
+	return Project{Template: "default", Layout: layoutTemplate("default")}
+}
+
+// nodeLayout runs the package's dev (or start) and test scripts with the package manager its lockfile belongs to
+func nodeLayout(repoRoot string, exists func(string) bool) []LayoutRow {
+	manager := "npm"
+	for _, lockfile := range []struct{ file, manager string }{
+		{"pnpm-lock.yaml", "pnpm"},
+		{"yarn.lock", "yarn"},
+		{"bun.lockb", "bun"},
+	} {
+		if exists(lockfile.file) {
+			manager = lockfile.manager
+			break
+		}
+	}
+
+	var pkg struct {
+		Scripts map[string]string `json:"scripts"`
+	}
+	if data, err := os.ReadFile(filepath.Join(repoRoot, "package.json")); err == nil {
+		json.Unmarshal(data, &pkg)
+	}
+
+	panes := []string{"code", ""}
+	if _, ok := pkg.Scripts["dev"]; ok {
+		panes = append(panes, "server", manager+" run dev")
+	} else if _, ok := pkg.Scripts["start"]; ok {
+		panes = append(panes, "server", manager+" run start")
+	}
+	if _, ok := pkg.Scripts["test"]; ok {
+		panes = append(panes, "test", manager+" test")
+	}
+	return paneLayout(append(panes, "shell", "")...)
+}
+
+// paneLayout stacks panes of equal height, given as name and command pairs, an empty command being a shell
+func paneLayout(namesAndCommands ...string) []LayoutRow {
+	count := len(namesAndCommands) / 2
+	var layout []LayoutRow
+	for i := 0; i < count; i++ {
+		row := LayoutRow{Height: fmt.Sprintf("%d%%", 100/count), Name: namesAndCommands[2*i]}
+		if command := namesAndCommands[2*i+1]; command != "" {
+			row.Command = &command
+		}
+		layout = append(layout, row)
+	}
+	return layout
+}
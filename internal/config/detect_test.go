@@ -0,0 +1,122 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestDetectProject(t *testing.T) {
+	tests := []struct {
+		name    string
+		files   map[string]string
+		project string
+		panes   string // name=command pairs, an empty command being a shell
+	}{
+		{
+			name:    "rails with bin/dev",
+			files:   map[string]string{"Gemfile": "", "bin/rails": "", "bin/dev": ""},
+			project: "Rails",
+			panes:   "code= server=bin/dev test=bin/rails test shell=",
+		},
+		{
+			name:    "node with pnpm",
+			files:   map[string]string{"package.json": `{"scripts": {"dev": "vite", "test": "vitest"}}`, "pnpm-lock.yaml": ""},
+			project: "Node",
+			panes:   "code= server=pnpm run dev test=pnpm test shell=",
+		},
+		{
+			name:    "node without scripts",
+			files:   map[string]string{"package.json": `{}`},
+			project: "Node",
+			panes:   "code= shell=",
+		},
+		{
+			name:    "rust",
+			files:   map[string]string{"Cargo.toml": ""},
+			project: "Rust",
+			panes:   "code= test=cargo test shell=",
+		},
+		{
+			name:    "python",
+			files:   map[string]string{"pyproject.toml": ""},
+			project: "Python",
+			panes:   "code= test=pytest shell=",
+		},
+		{
+			name:    "docker compose",
+			files:   map[string]string{"docker-compose.yml": ""},
+			project: "Docker Compose",
+			panes:   "code= server=docker compose up shell=",
+		},
+		{
+			name:  "unrecognised",
+			files: map[string]string{"README.md": ""},
+			panes: "code= shell=",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			root := t.TempDir()
+			for name, content := range tt.files {
+				path := filepath.Join(root, name)
+				if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+					t.Fatal(err)
+				}
+				if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+					t.Fatal(err)
+				}
+			}
+
+			project := DetectProject(root)
+			if project.Name != tt.project {
+				t.Errorf("DetectProject() name = %q, want %q", project.Name, tt.project)
+			}
+			var panes []string
+			for _, row := range project.Layout {
+				command := ""
+				if row.Command != nil {
+					command = *row.Command
+				}
+				panes = append(panes, row.Name+"="+command)
+			}
+			if got := strings.Join(panes, " "); got != tt.panes {
+				t.Errorf("DetectProject() panes = %q, want %q", got, tt.panes)
+			}
+		})
+	}
+}
+
+func TestLayoutTemplate(t *testing.T) {
+	tests := []struct {
+		name    string
+		config  string
+		panes   int
+		wantErr bool
+	}{
+		{name: "template", config: "name: demo\nlayout_template: node\n", panes: 4},
+		{name: "layout wins", config: "name: demo\nlayout_template: node\nlayout:\n  - height: 100%\n    name: code\n", panes: 1},
+		{name: "unknown template", config: "name: demo\nlayout_template: cobol\n", wantErr: true},
+		{name: "nested panes", config: "name: demo\nlayout:\n  - height: 100%\n    panes:\n      - name: code\n      - split: horizontal\n        panes:\n          - name: logs\n          - name: server\n", panes: 1},
+		{name: "unknown split", config: "name: demo\nlayout:\n  - height: 100%\n    panes:\n      - split: diagonal\n        panes:\n          - name: logs\n", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+			path := filepath.Join(t.TempDir(), configFileName)
+			if err := os.WriteFile(path, []byte(tt.config), 0644); err != nil {
+				t.Fatal(err)
+			}
+			cfg, err := LoadFromPath(path)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("LoadFromPath() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err == nil && len(cfg.GetLayout()) != tt.panes {
+				t.Errorf("GetLayout() has %d panes, want %d", len(cfg.GetLayout()), tt.panes)
+			}
+		})
+	}
+}
@@ -0,0 +1,156 @@
+package config
+
+import (
+	"sort"
+	"strings"
+	"unicode"
+)
+
+// TodoMatch is a todo matching a search, with how well it matched
+type TodoMatch struct {
+	Todo  *Todo
+	Score int
+}
+
+// Match scores, best first
+const (
+	scoreSubstring  = 100 // The query appears as typed
+	scoreAllWords   = 75  // Every word of the query appears, in any order
+	scoreSubsequent = 50  // The query's letters appear in order, less one per skipped letter
+)
+
+// ConfidentMargin is how far the best match must score above the next to be picked on its own
+const ConfidentMargin = 25
+
+// FindTodos fuzzy-matches todo descriptions against query, best matches first
+func (c *Config) FindTodos(query string) []TodoMatch {
+	var matches []TodoMatch
+	for i := range c.Todos {
+		if score, ok := FuzzyScore(query, c.Todos[i].Description); ok {
+			matches = append(matches, TodoMatch{Todo: &c.Todos[i], Score: score})
+		}
+	}
+	sort.SliceStable(matches, func(i, j int) bool {
+		return matches[i].Score > matches[j].Score
+	})
+	return matches
+}
+
+// ConfidentMatch returns the match to act on without asking, if there is a clear winner
+func ConfidentMatch(matches []TodoMatch) (*TodoMatch, bool) {
+	switch {
+	case len(matches) == 0:
+		return nil, false
+	case len(matches) == 1, matches[0].Score-matches[1].Score >= ConfidentMargin:
+		return &matches[0], true
+	}
+	return nil, false
+}
+
+// FuzzyScore scores how well text matches query, ignoring case
+func FuzzyScore(query, text string) (int, bool) {
+	query = strings.ToLower(strings.TrimSpace(query))
+	text = strings.ToLower(text)
+	if query == "" {
+		return 0, false
+	}
+
+	if strings.Contains(text, query) {
+		return scoreSubstring, true
+	}
+
+	allWords := true
+	for _, word := range strings.Fields(query) {
+		if !strings.Contains(text, word) {
+			allWords = false
+			break
+		}
+	}
+	if allWords {
+		return scoreAllWords, true
+	}
+
+	// Letters in order, e.g. "flkspc" for "flaky spec"
+	letters := []rune(strings.ReplaceAll(query, " ", ""))
+	skipped, qi := 0, 0
+	for _, r := range text {
+		if qi == len(letters) {
+			break
+		}
+		if r == letters[qi] {
+			qi++
+		} else if qi > 0 {
+			skipped++
+		}
+	}
+	if qi < len(letters) {
+		return 0, false
+	}
+	return max(scoreSubsequent-skipped, 1), true
+}
+
+// DuplicateRatio is how similar a new todo's description must be to an open one to be taken as a duplicate
+const DuplicateRatio = 0.85
+
+// FindDuplicate returns the open todo whose description is most similar to description, nil when none
+// is at least DuplicateRatio alike
+func (c *Config) FindDuplicate(description string) *Todo {
+	var best *Todo
+	bestRatio := DuplicateRatio
+	for i := range c.Todos {
+		if c.Todos[i].Status == TodoStatusDone {
+			continue
+		}
+		if ratio := Similarity(description, c.Todos[i].Description); ratio >= bestRatio {
+			best, bestRatio = &c.Todos[i], ratio
+		}
+	}
+	return best
+}
+
+// Similarity returns how alike two descriptions are, from 0 to 1, ignoring case, punctuation and word order
+// It's the edit distance between them relative to the longer one, as typed and with their words sorted
+func Similarity(a, b string) float64 {
+	wordsA, wordsB := similarityWords(a), similarityWords(b)
+	if len(wordsA) == 0 || len(wordsB) == 0 {
+		return 0
+	}
+	ratio := editRatio(strings.Join(wordsA, " "), strings.Join(wordsB, " "))
+	sort.Strings(wordsA)
+	sort.Strings(wordsB)
+	return max(ratio, editRatio(strings.Join(wordsA, " "), strings.Join(wordsB, " ")))
+}
+
+// similarityWords splits text into lowercase words, dropping punctuation
+func similarityWords(text string) []string {
+	return strings.FieldsFunc(strings.ToLower(text), func(r rune) bool {
+		return !unicode.IsLetter(r) && !unicode.IsDigit(r)
+	})
+}
+
+// editRatio is 1 less the Levenshtein distance between a and b over the length of the longer one
+func editRatio(a, b string) float64 {
+	ra, rb := []rune(a), []rune(b)
+	longest := max(len(ra), len(rb))
+	if longest == 0 {
+		return 1
+	}
+
+	prev := make([]int, len(rb)+1)
+	cur := make([]int, len(rb)+1)
+	for j := range prev {
+		prev[j] = j
+	}
+	for i := 1; i <= len(ra); i++ {
+		cur[0] = i
+		for j := 1; j <= len(rb); j++ {
+			cost := 1
+			if ra[i-1] == rb[j-1] {
+				cost = 0
+			}
+			cur[j] = min(prev[j]+1, cur[j-1]+1, prev[j-1]+cost)
+		}
+		prev, cur = cur, prev
+	}
+	return 1 - float64(prev[len(rb)])/float64(longest)
+}
@@ -0,0 +1,79 @@
+package config
+
+import (
+	"testing"
+)
+
+func TestFindTodos(t *testing.T) {
+	cfg := &Config{Todos: []Todo{
+		{Description: "Fix flaky login spec", Worktree: "myapp-fix-flaky-login-spec"},
+		{Description: "Add billing page", Worktree: "myapp-add-billing-page"},
+		{Description: "Login with SSO", Worktree: "myapp-login-with-sso"},
+	}}
+
+	tests := []struct {
+		name      string
+		query     string
+		matches   []string
+		confident string
+	}{
+		{name: "substring", query: "billing", matches: []string{"Add billing page"}, confident: "Add billing page"},
+		{name: "ignores case", query: "FLAKY", matches: []string{"Fix flaky login spec"}, confident: "Fix flaky login spec"},
+		{name: "words in any order", query: "spec flaky", matches: []string{"Fix flaky login spec"}, confident: "Fix flaky login spec"},
+		{name: "letters in order", query: "blpg", matches: []string{"Add billing page"}, confident: "Add billing page"},
+		{name: "ambiguous", query: "login", matches: []string{"Fix flaky login spec", "Login with SSO"}},
+		{name: "no match", query: "deploy"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			matches := cfg.FindTodos(tt.query)
+			if len(matches) != len(tt.matches) {
+				t.Fatalf("FindTodos(%q) returned %d matches, want %d", tt.query, len(matches), len(tt.matches))
+			}
+			for i, m := range matches {
+				if m.Todo.Description != tt.matches[i] {
+					t.Errorf("FindTodos(%q)[%d] = %q, want %q", tt.query, i, m.Todo.Description, tt.matches[i])
+				}
+			}
+
+			match, ok := ConfidentMatch(matches)
+			if ok != (tt.confident != "") {
+				t.Fatalf("ConfidentMatch() ok = %v, want %v", ok, tt.confident != "")
+			}
+			if ok && match.Todo.Description != tt.confident {
+				t.Errorf("ConfidentMatch() = %q, want %q", match.Todo.Description, tt.confident)
+			}
+		})
+	}
+}
+
+func TestFindDuplicate(t *testing.T) {
+	cfg := &Config{Todos: []Todo{
+		{Description: "Fix flaky login spec", Worktree: "myapp-fix-flaky-login-spec"},
+		{Description: "Add billing page", Status: TodoStatusDone},
+		{Description: "Login with SSO"},
+	}}
+
+	tests := []struct {
+		description string
+		expected    string
+	}{
+		{description: "Fix flaky login spec", expected: "Fix flaky login spec"},
+		{description: "fix flakey login spec!", expected: "Fix flaky login spec"},
+		{description: "login spec flaky fix", expected: "Fix flaky login spec"},
+		{description: "Login with SAML", expected: ""},
+		{description: "Add billing page", expected: ""}, // Done todos can be added again
+		{description: "Fix login", expected: ""},
+	}
+
+	for _, tt := range tests {
+		got := ""
+		if todo := cfg.FindDuplicate(tt.description); todo != nil {
+			got = todo.Description
+		}
+		if got != tt.expected {
+			t.Errorf("FindDuplicate(%q) = %q, want %q (similarity %.2f)", tt.description, got, tt.expected, Similarity(tt.description, cfg.Todos[0].Description))
+		}
+	}
+}
@@ -2,6 +2,7 @@ package config
 
 import (
 	"fmt"
+	"os"
 	"path/filepath"
 
 	tea "github.com/charmbracelet/bubbletea"
@@ -9,14 +10,142 @@ import (
 	"github.com/markcipolla/lfg/internal/github"
 )
 
-func runInitWizard(configPath, repoRoot string) (*Config, error) {
+// InitOptions answers the init wizard's questions, so a repository can be set up from scripts
+type InitOptions struct {
+	Name    string // Project name, defaults to the repository directory's name
+	Backend string // Where todos are kept: "local" (the default) or "github"
+	Project int    // GitHub project number, a new project is created when zero
+}
+
+// Init sets up lfg-config.yaml in the current repository: with the init wizard, its answers
+// pre-filled from opts, or without any prompts when interactive is false
+func Init(opts InitOptions, interactive bool) (*Config, error) {
+	if opts.Backend != "" && opts.Backend != "local" && opts.Backend != "github" {
+		return nil, fmt.Errorf("unknown backend %q, use local or github", opts.Backend)
+	}
+	if opts.Project != 0 && opts.Backend != "github" {
+		return nil, fmt.Errorf("a GitHub project needs the github backend")
+	}
+
+	repoRoot, err := getRepoRoot()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get repo root: %w", err)
+	}
+	configPath := filepath.Join(repoRoot, configFileName)
+	if _, err := os.Stat(configPath); err == nil {
+		return nil, fmt.Errorf("%s already exists", configPath)
+	}
+
+	if interactive {
+		return runInitWizard(configPath, repoRoot, opts)
+	}
+	return initConfig(configPath, repoRoot, opts)
+}
+
+// initConfig creates the config the wizard would when every question is answered with opts or its default
+func initConfig(configPath, repoRoot string, opts InitOptions) (*Config, error) {
+	name := opts.Name
+	if name == "" {
+		name = filepath.Base(repoRoot)
+	}
+
+	var backend *StorageBackend
+	if opts.Backend == "github" {
+		var err error
+		if backend, err = githubBackend(name, opts.Project); err != nil {
+			return nil, err
+		}
+	}
+
+	cfg := newConfig(configPath, name, DetectProject(repoRoot).Layout, backend)
+	if err := cfg.Save(); err != nil {
+		return nil, fmt.Errorf("failed to save config: %w", err)
+	}
+	return cfg, nil
+}
+
+// githubBackend stores todos in the repository's GitHub project with the given number, or in a new
+// project named after the repository when the number is zero
+func githubBackend(name string, number int) (*StorageBackend, error) {
+	if !github.IsAuthenticated() {
+		return nil, fmt.Errorf("not authenticated with GitHub, run: gh auth login --scopes project,repo")
+	}
+	ok, err := github.HasRequiredScopes()
+	if err != nil {
+		return nil, fmt.Errorf("failed to check GitHub scopes: %w", err)
+	}
+	if !ok {
+		return nil, fmt.Errorf("missing GitHub scopes, run: gh auth refresh --scopes project,repo")
+	}
+	repoInfo, err := github.GetRepoInfo()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get repository info: %w", err)
+	}
+
+	backend := &StorageBackend{Type: "github", Owner: repoInfo.Owner, Repo: repoInfo.Name, ProjectNumber: number}
+	if number == 0 {
+		project, err := github.CreateProject(repoInfo.Owner, repoInfo.Name, name)
+		if err != nil {
+			return nil, fmt.Errorf("failed to create GitHub project: %w", err)
+		}
+		backend.ProjectNumber = project.Number
+		return backend, nil
+	}
+
+	projects, err := github.ListProjects(repoInfo.Owner, repoInfo.Name)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list GitHub projects: %w", err)
+	}
+	for _, project := range projects {
+		if project.Number == number {
+			return backend, nil
+		}
+	}
+	return nil, fmt.Errorf("%s/%s has no GitHub project %d", repoInfo.Owner, repoInfo.Name, number)
+}
+
+// defaultConfig returns the config initConfig saves when every question is left at its default, unsaved
+func defaultConfig(configPath, repoRoot string) *Config {
+	return newConfig(configPath, filepath.Base(repoRoot), DetectProject(repoRoot).Layout, nil)
+}
+
+// newConfig returns a new repository's config, using the global config's layout and settings when there is one
+func newConfig(configPath, name string, layout []LayoutRow, backend *StorageBackend) *Config {
+	// Description pane is automatic (always top 10%), so layout only defines the remaining 90%
+	cfg := &Config{
+		Name:           name,
+		WorktreeNaming: "Add feature",
+		StorageBackend: backend,
+		Todos:          []Todo{},
+		Layout:         layout,
+		configPath:     configPath,
+	}
+
+	// A global config's layout and settings apply instead of the defaults
+	if global, err := loadGlobal(); err == nil && global != nil {
+		if len(global.ownLayout()) > 0 {
+			cfg.Layout = nil
+		}
+		cfg.applyGlobal(global)
+	}
+	return cfg
+}
+
+func runInitWizard(configPath, repoRoot string, opts InitOptions) (*Config, error) {
 	// Get default project name from directory
-	defaultName := filepath.Base(repoRoot)
+	name := opts.Name
+	if name == "" {
+		name = filepath.Base(repoRoot)
+	}
 
 	m := &initModel{
 		step:        stepProjectName,
-		projectName: defaultName,
+		projectName: name,
 		configPath:  configPath,
+		project:     DetectProject(repoRoot),
+	}
+	if opts.Backend == "github" {
+		m.storageChoice = 1
 	}
 
 	p := tea.NewProgram(m)
@@ -37,6 +166,7 @@ type initStep int
 
 const (
 	stepProjectName initStep = iota
+	stepLayout
 	stepStorageBackend
 	stepGitHubAuth
 	stepGitHubProjectSelect
@@ -48,6 +178,10 @@ type initModel struct {
 	step            initStep
 	projectName     string
 	storageChoice   int // 0 = Local, 1 = GitHub
+	project         Project // Detected project, whose layout can be edited before it's saved
+	layoutCursor    int
+	editingCommand  bool   // The selected pane's command is being typed into commandInput
+	commandInput    string
 	githubSetup     *githubSetupState
 	configPath      string
 	config          *Config
@@ -97,6 +231,9 @@ func (m *initModel) Init() tea.Cmd {
 func (m *initModel) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 	switch msg := msg.(type) {
 	case tea.KeyMsg:
+		if m.step == stepLayout {
+			return m.updateLayout(msg)
+		}
 		switch msg.String() {
 		case "ctrl+c", "esc":
 			m.cancelled = true
@@ -168,6 +305,8 @@ func (m *initModel) View() string {
 	switch m.step {
 	case stepProjectName:
 		return m.viewProjectName()
+	case stepLayout:
+		return m.viewLayout()
 	case stepStorageBackend:
 		return m.viewStorageBackend()
 	case stepGitHubAuth:
@@ -191,6 +330,110 @@ func (m *initModel) viewProjectName() string {
 	)
 }
 
+// updateLayout handles keys on the proposed layout, where pane commands can be edited or panes removed
+func (m *initModel) updateLayout(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	layout := m.project.Layout
+	if m.editingCommand {
+		switch msg.Type {
+		case tea.KeyEnter:
+			layout[m.layoutCursor].Command = nil
+			if m.commandInput != "" {
+				layout[m.layoutCursor].Command = stringPtr(m.commandInput)
+			}
+			m.editingCommand = false
+		case tea.KeyEsc:
+			m.editingCommand = false
+		case tea.KeyBackspace:
+			if len(m.commandInput) > 0 {
+				m.commandInput = m.commandInput[:len(m.commandInput)-1]
+			}
+		case tea.KeyRunes, tea.KeySpace:
+			m.commandInput += string(msg.Runes)
+		}
+		return m, nil
+	}
+
+	switch msg.String() {
+	case "ctrl+c", "esc":
+		m.cancelled = true
+		return m, tea.Quit
+	case "up", "k":
+		if m.layoutCursor > 0 {
+			m.layoutCursor--
+		}
+	case "down", "j":
+		if m.layoutCursor < len(layout)-1 {
+			m.layoutCursor++
+		}
+	case "e":
+		m.editingCommand = true
+		m.commandInput = ""
+		if command := layout[m.layoutCursor].Command; command != nil {
+			m.commandInput = *command
+		}
+	case "d":
+		if len(layout) > 1 {
+			m.project.Layout = append(layout[:m.layoutCursor], layout[m.layoutCursor+1:]...)
+			m.layoutCursor = min(m.layoutCursor, len(m.project.Layout)-1)
+			for i := range m.project.Layout {
+				m.project.Layout[i].Height = fmt.Sprintf("%d%%", 100/len(m.project.Layout))
+			}
+		}
+	case "t":
+		// Cycle through the shipped templates, starting over from the chosen one
+		names := LayoutTemplateNames()
+		next := names[0]
+		for i, name := range names {
+			if name == m.project.Template {
+				next = names[(i+1)%len(names)]
+			}
+		}
+		m.project.Template = next
+		m.project.Layout = layoutTemplate(next)
+		m.layoutCursor = 0
+	case "enter":
+		m.step = stepStorageBackend
+	}
+	return m, nil
+}
+
+func (m *initModel) viewLayout() string {
+	detected := "No project type detected, using the default panes:"
+	if m.project.Name != "" {
+		detected = fmt.Sprintf("Detected a %s project (%s), proposing these panes:", m.project.Name, m.project.Marker)
+	}
+
+	var rows string
+	for i, row := range m.project.Layout {
+		command := "(shell)"
+		if row.Command != nil {
+			command = *row.Command
+		}
+		if m.editingCommand && i == m.layoutCursor {
+			command = m.commandInput + "█"
+		}
+		line := fmt.Sprintf("%-10s %s", row.Name, command)
+		if i == m.layoutCursor {
+			rows += selectedStyle.Render("> "+line) + "\n"
+		} else {
+			rows += "  " + line + "\n"
+		}
+	}
+
+	help := "↑↓/jk: Navigate | e: Edit command | d: Remove pane | t: Next template | Enter: Accept | Esc: Cancel"
+	if m.editingCommand {
+		help = "Enter: Save command (empty for a shell) | Esc: Discard"
+	}
+	return fmt.Sprintf(
+		"%s\n\n%s\nTemplate: %s\n\n%s\n%s\n",
+		titleStyle.Render("Session Layout"),
+		detected,
+		selectedStyle.Render(m.project.Template),
+		rows,
+		helpStyle.Render(help),
+	)
+}
+
 func (m *initModel) viewStorageBackend() string {
 	options := []string{
 		"Local YAML (todos stored in lfg-config.yaml)",
@@ -297,7 +540,11 @@ func (m *initModel) viewComplete() string {
 func (m *initModel) handleEnter() (tea.Model, tea.Cmd) {
 	switch m.step {
 	case stepProjectName:
-		m.step = stepStorageBackend
+		m.step = stepLayout
+		// A global layout is used instead, so there's nothing to propose
+		if global, err := loadGlobal(); err == nil && global != nil && len(global.ownLayout()) > 0 {
+			m.step = stepStorageBackend
+		}
 	case stepStorageBackend:
 		if m.storageChoice == 1 {
 			m.step = stepGitHubAuth
@@ -483,30 +730,8 @@ func (m *initModel) createGitHubProject() tea.Msg {
 }
 
 func (m *initModel) completeSetup(backend *StorageBackend) (tea.Model, tea.Cmd) {
-	// Create default config with new layout format
-	// Description pane is automatic (always top 10%), so layout only defines the remaining 90%
-	m.config = &Config{
-		Name:           m.projectName,
-		WorktreeNaming: "Add feature",
-		StorageBackend: backend,
-		Todos:          []Todo{},
-		Layout: []LayoutRow{
-			{
-				Height: "33%",
-				Name:   "code",
-			},
-			{
-				Height: "34%",
-				Name:   "server",
-				Command: stringPtr("claude --dangerously-skip-permissions"),
-			},
-			{
-				Height: "33%",
-				Name:   "shell",
-			},
-		},
-		configPath: m.configPath,
-	}
+	// Create the config with the layout proposed for the project
+	m.config = newConfig(m.configPath, m.projectName, m.project.Layout, backend)
 
 	// Save config
 	if err := m.config.Save(); err != nil {
@@ -0,0 +1,95 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestInitConfig(t *testing.T) {
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+	repoRoot := filepath.Join(t.TempDir(), "shop")
+	if err := os.MkdirAll(repoRoot, 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(repoRoot, "Cargo.toml"), []byte("[package]\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	configPath := filepath.Join(repoRoot, configFileName)
+
+	tests := []struct {
+		name     string
+		opts     InitOptions
+		expected string
+	}{
+		{name: "defaults", expected: "shop"},
+		{name: "named", opts: InitOptions{Name: "storefront", Backend: "local"}, expected: "storefront"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			defer os.Remove(configPath)
+			if _, err := initConfig(configPath, repoRoot, tt.opts); err != nil {
+				t.Fatalf("initConfig() error = %v", err)
+			}
+
+			cfg, err := LoadFromPath(configPath)
+			if err != nil {
+				t.Fatalf("LoadFromPath() error = %v", err)
+			}
+			if cfg.Name != tt.expected {
+				t.Errorf("Name = %q, want %q", cfg.Name, tt.expected)
+			}
+			if cfg.StorageBackend != nil {
+				t.Errorf("StorageBackend = %+v, want local storage", cfg.StorageBackend)
+			}
+			if got, want := len(cfg.GetLayout()), len(LayoutTemplates["rust"]); got != want {
+				t.Errorf("GetLayout() has %d panes, want the %d of the rust template", got, want)
+			}
+		})
+	}
+}
+
+func TestInitOptions(t *testing.T) {
+	for _, opts := range []InitOptions{
+		{Backend: "jira"},
+		{Backend: "local", Project: 3},
+	} {
+		if _, err := Init(opts, false); err == nil {
+			t.Errorf("Init(%+v) succeeded, want an error", opts)
+		}
+	}
+}
+
+func TestDefaultConfig(t *testing.T) {
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+	repoRoot := filepath.Join(t.TempDir(), "shop")
+	if err := os.MkdirAll(repoRoot, 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(repoRoot, "Cargo.toml"), []byte("[package]\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	configPath := filepath.Join(repoRoot, configFileName)
+
+	cfg := defaultConfig(configPath, repoRoot)
+	if cfg.Name != "shop" || len(cfg.GetLayout()) != len(LayoutTemplates["rust"]) {
+		t.Errorf("defaultConfig() = %q with %d panes, want the wizard's defaults", cfg.Name, len(cfg.GetLayout()))
+	}
+	if _, err := os.Stat(configPath); !os.IsNotExist(err) {
+		t.Fatalf("defaultConfig() wrote %s", configPath)
+	}
+
+	// It's only written once something is changed and saved
+	cfg.AddTodo("Fix checkout", "")
+	if err := cfg.Save(); err != nil {
+		t.Fatal(err)
+	}
+	saved, err := LoadFromPath(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(saved.Todos) != 1 {
+		t.Errorf("saved config has %d todos, want 1", len(saved.Todos))
+	}
+}
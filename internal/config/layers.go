@@ -0,0 +1,151 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"reflect"
+
+	"gopkg.in/yaml.v3"
+)
+
+// Configs are layered, each overriding the one before:
+//  1. the global config, ~/.config/lfg/config.yaml
+//  2. the repository config, lfg-config.yaml in the repository root
+//  3. scoped configs, lfg-config.yaml in directories between the repository root and the current
+//     directory, e.g. packages/api/lfg-config.yaml in a monorepo, the closest one last
+
+// inheritableFields are the settings a repository config takes from the global config when it leaves them unset
+var inheritableFields = []string{"IDE", "Naming", "Templates", "Settings", "Git", "Setup", "Scratch", "Aging", "Review", "CheckCommand"}
+
+// layeredField is a config field set from another config file
+type layeredField struct {
+	applied reflect.Value // Copy of the value set, to tell whether it was changed since
+	own     reflect.Value // The value from this config's own file
+}
+
+// layer sets a field to a value from another config file, remembering this file's own value so Save
+// writes that back unless the layered value was changed
+func (c *Config) layer(name string, value reflect.Value) {
+	field := reflect.ValueOf(c).Elem().FieldByName(name)
+	if c.layers == nil {
+		c.layers = make(map[string]layeredField)
+	}
+	own := field
+	if existing, ok := c.layers[name]; ok {
+		own = existing.own
+	}
+	c.layers[name] = layeredField{applied: cloneValue(value), own: cloneValue(own)}
+	field.Set(cloneValue(value))
+}
+
+// cloneValue copies pointers and slices one level deep, so layered values can be changed independently
+func cloneValue(v reflect.Value) reflect.Value {
+	switch v.Kind() {
+	case reflect.Pointer:
+		if v.IsNil() {
+			return v
+		}
+		copied := reflect.New(v.Elem().Type())
+		copied.Elem().Set(v.Elem())
+		return copied
+	case reflect.Slice:
+		if v.IsNil() {
+			return v
+		}
+		return reflect.AppendSlice(reflect.MakeSlice(v.Type(), 0, v.Len()), v)
+	}
+	copied := reflect.New(v.Type()).Elem()
+	copied.Set(v)
+	return copied
+}
+
+// withoutLayers returns a copy of the config holding only what belongs in its own file
+func (c *Config) withoutLayers() *Config {
+	out := *c
+	ov := reflect.ValueOf(&out).Elem()
+	for name, layered := range c.layers {
+		field := ov.FieldByName(name)
+		if reflect.DeepEqual(field.Interface(), layered.applied.Interface()) {
+			field.Set(layered.own)
+		}
+	}
+	return &out
+}
+
+// applyGlobal fills the settings this config leaves unset from the global config
+// Inherited values are copies, so changing them here never touches the global config
+func (c *Config) applyGlobal(global *Config) {
+	c.global = global
+	cv, gv := reflect.ValueOf(c).Elem(), reflect.ValueOf(global).Elem()
+	for _, name := range inheritableFields {
+		if !cv.FieldByName(name).IsZero() || gv.FieldByName(name).IsZero() {
+			continue
+		}
+		c.layer(name, gv.FieldByName(name))
+	}
+}
+
+// applyScoped overrides the settings a scoped config sets, e.g. the layout for one package of a monorepo
+// A scoped layout replaces the repository's, or is added after it with extend_layout
+func (c *Config) applyScoped(scoped *Config) {
+	cv, sv := reflect.ValueOf(c).Elem(), reflect.ValueOf(scoped).Elem()
+	for _, name := range inheritableFields {
+		if sv.FieldByName(name).IsZero() {
+			continue
+		}
+		c.layer(name, sv.FieldByName(name))
+	}
+
+	layout := scoped.ownLayout()
+	if len(layout) == 0 {
+		return
+	}
+	if scoped.ExtendLayout {
+		layout = append(append([]LayoutRow{}, c.GetLayout()...), layout...)
+	}
+	c.layer("Layout", reflect.ValueOf(layout))
+	c.layer("Windows", reflect.Zero(cv.FieldByName("Windows").Type()))
+	c.layer("ExtendLayout", reflect.ValueOf(false))
+}
+
+// ScopedPaths returns the scoped configs that apply in dir: the lfg-config.yaml files in dir and its
+// parents below root, farthest from dir first
+func ScopedPaths(dir, root string) []string {
+	rel, err := filepath.Rel(root, dir)
+	if err != nil || rel == "." || !filepath.IsLocal(rel) {
+		return nil
+	}
+
+	var paths []string
+	for ; dir != root; dir = filepath.Dir(dir) {
+		candidate := filepath.Join(dir, configFileName)
+		if _, err := os.Stat(candidate); err == nil {
+			paths = append([]string{candidate}, paths...)
+		}
+	}
+	return paths
+}
+
+// loadScoped reads the scoped configs that apply in the current directory, below the worktree root
+func (c *Config) loadScoped(worktreeRoot string) error {
+	cwd, err := os.Getwd()
+	if err != nil {
+		return nil
+	}
+	for _, path := range ScopedPaths(cwd, worktreeRoot) {
+		data, err := os.ReadFile(path)
+		if err != nil {
+			return fmt.Errorf("failed to read scoped config: %w", err)
+		}
+		var scoped Config
+		if err := yaml.Unmarshal(data, &scoped); err != nil {
+			return fmt.Errorf("failed to parse scoped config %s: %w", path, err)
+		}
+		if len(scoped.Todos) > 0 {
+			fmt.Fprintf(os.Stderr, "Warning: ignoring the todos in %s, todos are kept in the repository root's %s\n", path, configFileName)
+		}
+		c.applyScoped(&scoped)
+	}
+	return nil
+}
@@ -0,0 +1,112 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+func TestScopedPaths(t *testing.T) {
+	root := t.TempDir()
+	api := filepath.Join(root, "packages", "api")
+	handlers := filepath.Join(api, "handlers")
+	if err := os.MkdirAll(handlers, 0755); err != nil {
+		t.Fatal(err)
+	}
+	for _, dir := range []string{root, filepath.Join(root, "packages"), handlers} {
+		if err := os.WriteFile(filepath.Join(dir, configFileName), []byte("name: demo\n"), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	tests := []struct {
+		name string
+		dir  string
+		want []string
+	}{
+		{name: "repository root", dir: root},
+		{name: "outside the repository", dir: filepath.Dir(root)},
+		{name: "nested package", dir: api, want: []string{filepath.Join(root, "packages", configFileName)}},
+		{
+			name: "closest last",
+			dir:  handlers,
+			want: []string{filepath.Join(root, "packages", configFileName), filepath.Join(handlers, configFileName)},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := ScopedPaths(tt.dir, root); !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ScopedPaths() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestLoadScoped(t *testing.T) {
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+	root := t.TempDir()
+	api := filepath.Join(root, "packages", "api")
+	if err := os.MkdirAll(api, 0755); err != nil {
+		t.Fatal(err)
+	}
+	files := []struct{ path, content string }{
+		{filepath.Join(root, configFileName), "name: demo\nsettings:\n  theme: light\nlayout:\n  - height: 100%\n    name: code\n"},
+		{filepath.Join(root, "packages", configFileName), "check_command: make test\n"},
+		{filepath.Join(api, configFileName), "settings:\n  theme: high-contrast\nextend_layout: true\nlayout:\n  - height: 50%\n    name: api\n"},
+	}
+	for _, f := range files {
+		if err := os.WriteFile(f.path, []byte(f.content), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	cfg, err := LoadFromPath(filepath.Join(root, configFileName))
+	if err != nil {
+		t.Fatalf("LoadFromPath() error = %v", err)
+	}
+	wd, _ := os.Getwd()
+	defer os.Chdir(wd)
+	if err := os.Chdir(api); err != nil {
+		t.Fatal(err)
+	}
+	if err := cfg.loadScoped(root); err != nil {
+		t.Fatalf("loadScoped() error = %v", err)
+	}
+
+	var panes []string
+	for _, row := range cfg.GetLayout() {
+		panes = append(panes, row.Name)
+	}
+	if strings.Join(panes, ",") != "code,api" {
+		t.Errorf("GetLayout() panes = %v, want [code api]", panes)
+	}
+	if cfg.Settings.Theme != "high-contrast" {
+		t.Errorf("Settings.Theme = %q, want high-contrast", cfg.Settings.Theme)
+	}
+	if cfg.CheckCommand != "make test" {
+		t.Errorf("CheckCommand = %q, want make test", cfg.CheckCommand)
+	}
+
+	// Saving keeps the scoped settings out of the repository config
+	cfg.AddTodo("Fix the API", "")
+	if err := cfg.Save(); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+	saved, err := os.ReadFile(filepath.Join(root, configFileName))
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, want := range []string{"theme: light", "name: code", "Fix the API"} {
+		if !strings.Contains(string(saved), want) {
+			t.Errorf("saved config missing %q:\n%s", want, saved)
+		}
+	}
+	for _, unwanted := range []string{"high-contrast", "make test", "name: api", "extend_layout"} {
+		if strings.Contains(string(saved), unwanted) {
+			t.Errorf("saved config contains scoped %q:\n%s", unwanted, saved)
+		}
+	}
+}
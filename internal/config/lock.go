@@ -0,0 +1,39 @@
+package config
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"time"
+)
+
+const (
+	lockWait  = 5 * time.Second  // How long Lock waits for another lfg to let go
+	lockStale = 30 * time.Second // Age after which a lock is taken to be left behind by an lfg that died
+)
+
+// Lock takes the lock file next to path, waiting while another lfg holds it, and returns the func that
+// releases it. Hold it around reading, changing and writing path so lfg instances running side by side
+// don't undo each other's changes
+func Lock(path string) (func(), error) {
+	lockPath := path + ".lock"
+	deadline := time.Now().Add(lockWait)
+	for {
+		file, err := os.OpenFile(lockPath, os.O_CREATE|os.O_EXCL|os.O_WRONLY, 0644)
+		if err == nil {
+			_ = file.Close()
+			return func() { _ = os.Remove(lockPath) }, nil
+		}
+		if !errors.Is(err, os.ErrExist) {
+			return nil, fmt.Errorf("failed to lock %s: %w", path, err)
+		}
+		if info, err := os.Stat(lockPath); err == nil && time.Since(info.ModTime()) > lockStale {
+			_ = os.Remove(lockPath)
+			continue
+		}
+		if time.Now().After(deadline) {
+			return nil, fmt.Errorf("failed to lock %s: %s is held by another lfg", path, lockPath)
+		}
+		time.Sleep(20 * time.Millisecond)
+	}
+}
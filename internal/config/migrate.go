@@ -0,0 +1,88 @@
+package config
+
+import (
+	"fmt"
+	"os"
+
+	"gopkg.in/yaml.v3"
+)
+
+// MigrateGlobalTodos moves the todos left in the global config, from before todos were kept per repository,
+// into this repository's config. Only the ones belongs picks are moved, e.g. those linked to one of its
+// worktrees; the rest stay behind for their own repositories to claim. It returns how many were moved and
+// how many are left in the global config
+func (c *Config) MigrateGlobalTodos(belongs func(Todo) bool) (int, int, error) {
+	if c.configPath == "" {
+		return 0, 0, nil
+	}
+	path, err := GlobalPath()
+	if err != nil {
+		return 0, 0, nil
+	}
+	data, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return 0, 0, nil
+	}
+	if err != nil {
+		return 0, 0, fmt.Errorf("failed to read global config: %w", err)
+	}
+
+	// Edit the parsed document rather than the Config, so the rest of the file and its comments survive
+	var doc yaml.Node
+	if err := yaml.Unmarshal(data, &doc); err != nil {
+		return 0, 0, fmt.Errorf("failed to parse global config %s: %w", path, err)
+	}
+	root, index := todosKey(&doc)
+	if index < 0 {
+		return 0, 0, nil
+	}
+
+	var kept []*yaml.Node
+	moved := 0
+	for _, node := range root.Content[index+1].Content {
+		var todo Todo
+		if err := node.Decode(&todo); err != nil || !belongs(todo) {
+			kept = append(kept, node)
+			continue
+		}
+		if c.findTodo(todo.Description) == nil {
+			c.Todos = append(c.Todos, todo)
+		}
+		moved++
+	}
+	if moved == 0 {
+		return 0, len(kept), nil
+	}
+
+	// Save the repository's copy first, so a failure leaves the todos in both places rather than neither
+	if err := c.Save(); err != nil {
+		return 0, len(kept) + moved, err
+	}
+	if len(kept) == 0 {
+		root.Content = append(root.Content[:index], root.Content[index+2:]...)
+	} else {
+		root.Content[index+1].Content = kept
+	}
+	out, err := yaml.Marshal(&doc)
+	if err != nil {
+		return moved, len(kept), fmt.Errorf("failed to marshal global config: %w", err)
+	}
+	if err := os.WriteFile(path, out, 0644); err != nil {
+		return moved, len(kept), fmt.Errorf("failed to write global config: %w", err)
+	}
+	return moved, len(kept), nil
+}
+
+// todosKey returns the top-level mapping of a parsed config and the index of its todos key, -1 without one
+func todosKey(doc *yaml.Node) (*yaml.Node, int) {
+	if doc.Kind != yaml.DocumentNode || len(doc.Content) == 0 || doc.Content[0].Kind != yaml.MappingNode {
+		return nil, -1
+	}
+	root := doc.Content[0]
+	for i := 0; i+1 < len(root.Content); i += 2 {
+		if root.Content[i].Value == "todos" && root.Content[i+1].Kind == yaml.SequenceNode {
+			return root, i
+		}
+	}
+	return root, -1
+}
@@ -0,0 +1,59 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestMigrateGlobalTodos(t *testing.T) {
+	home := t.TempDir()
+	t.Setenv("XDG_CONFIG_HOME", home)
+	globalPath := filepath.Join(home, "lfg", "config.yaml")
+	if err := os.MkdirAll(filepath.Dir(globalPath), 0755); err != nil {
+		t.Fatal(err)
+	}
+	global := "# shared settings\nide: code\ntodos:\n  - description: Fix login\n    worktree: myapp-login\n  - description: Other repo work\n    worktree: other-api\n  - description: Unlinked idea\n"
+	if err := os.WriteFile(globalPath, []byte(global), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	configPath := filepath.Join(t.TempDir(), configFileName)
+	if err := os.WriteFile(configPath, []byte("name: myapp\ntodos:\n  - description: Write docs\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	cfg, err := LoadFromPath(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(cfg.Todos) != 1 {
+		t.Fatalf("loaded todos = %+v, want only the repository's own", cfg.Todos)
+	}
+
+	belongs := func(todo Todo) bool { return strings.HasPrefix(todo.Worktree, "myapp-") }
+	moved, left, err := cfg.MigrateGlobalTodos(belongs)
+	if err != nil || moved != 1 || left != 2 {
+		t.Fatalf("MigrateGlobalTodos() = %d, %d, %v, want 1 moved and 2 left", moved, left, err)
+	}
+	if cfg.findTodo("Fix login") == nil || len(cfg.Todos) != 2 {
+		t.Errorf("todos = %+v, want Fix login moved in", cfg.Todos)
+	}
+
+	saved, err := LoadFromPath(configPath)
+	if err != nil || saved.findTodo("Fix login") == nil {
+		t.Errorf("saved config is missing the moved todo (err %v)", err)
+	}
+	data, err := os.ReadFile(globalPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if content := string(data); strings.Contains(content, "Fix login") || !strings.Contains(content, "Other repo work") || !strings.Contains(content, "Unlinked idea") || !strings.Contains(content, "# shared settings") {
+		t.Errorf("global config after migrating =\n%s\nwant the other repository's and the unlinked todos left, comments kept", content)
+	}
+
+	// Running again finds nothing left to move
+	if moved, left, err := cfg.MigrateGlobalTodos(belongs); err != nil || moved != 0 || left != 2 {
+		t.Errorf("second MigrateGlobalTodos() = %d, %d, %v, want nothing moved and 2 left", moved, left, err)
+	}
+}
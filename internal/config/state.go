@@ -0,0 +1,144 @@
+package config
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+)
+
+// userState is what one user sets up for themselves in a repository, the worktree colours and pins
+// It's kept in the data dir rather than the shared config, so teammates don't overwrite each other's
+type userState struct {
+	Colors map[string]string `json:"colors,omitempty"` // Accent colour by worktree name
+	Pinned []string          `json:"pinned,omitempty"` // Entries kept at the top of the list, in pin order
+}
+
+// stateChange is a change to the user state, applied in memory straight away and to the file on save
+type stateChange func(*userState)
+
+// setColor assigns a colour to a worktree, or clears it when color is ""
+func (s *userState) setColor(worktree, color string) {
+	if color == "" {
+		delete(s.Colors, worktree)
+		return
+	}
+	if s.Colors == nil {
+		s.Colors = make(map[string]string)
+	}
+	s.Colors[worktree] = color
+}
+
+// pinIndex returns the position of a pinned entry, or -1 if it isn't pinned
+func (s *userState) pinIndex(key string) int {
+	for i, pinned := range s.Pinned {
+		if pinned == key {
+			return i
+		}
+	}
+	return -1
+}
+
+// setPinned pins an entry below the existing pins, or unpins it
+func (s *userState) setPinned(key string, pinned bool) {
+	i := s.pinIndex(key)
+	switch {
+	case pinned && i < 0:
+		s.Pinned = append(s.Pinned, key)
+	case !pinned && i >= 0:
+		s.Pinned = append(s.Pinned[:i], s.Pinned[i+1:]...)
+	}
+}
+
+// renamePin keeps an entry's pin when its key changes
+func (s *userState) renamePin(from, to string) {
+	if i := s.pinIndex(from); i >= 0 {
+		s.Pinned[i] = to
+	}
+}
+
+// statePath returns where the user state of this config's repository is kept, "" for a config
+// that wasn't loaded from a file
+func (c *Config) statePath() string {
+	if c.configPath == "" {
+		return ""
+	}
+	dir, err := DataDir()
+	if err != nil {
+		return ""
+	}
+	sum := sha256.Sum256([]byte(c.configPath))
+	return filepath.Join(dir, "state", hex.EncodeToString(sum[:8])+".json")
+}
+
+func readState(path string) userState {
+	var state userState
+	if data, err := os.ReadFile(path); err == nil {
+		_ = json.Unmarshal(data, &state)
+	}
+	return state
+}
+
+// loadState reads the user state, moving colours and pins still set in the config file into it
+func (c *Config) loadState() {
+	legacy := userState{Colors: c.Colors, Pinned: c.Pinned}
+	state := readState(c.statePath())
+	c.Colors, c.Pinned = state.Colors, state.Pinned
+	for worktree, color := range legacy.Colors {
+		if c.WorktreeColor(worktree) == "" {
+			c.SetWorktreeColor(worktree, color)
+		}
+	}
+	for _, key := range legacy.Pinned {
+		if !c.IsPinned(key) {
+			c.TogglePin(key)
+		}
+	}
+}
+
+// changeState applies a change to the user state, which saveState applies again to the state on disk
+func (c *Config) changeState(change stateChange) {
+	state := userState{Colors: c.Colors, Pinned: c.Pinned}
+	change(&state)
+	c.Colors, c.Pinned = state.Colors, state.Pinned
+	c.stateChanges = append(c.stateChanges, change)
+}
+
+// saveState applies the changes made since the last save to the user state on disk
+// They're applied to what's on disk rather than written over it, so changes an lfg running alongside
+// saved in the meantime are kept
+func (c *Config) saveState() error {
+	path := c.statePath()
+	if path == "" || len(c.stateChanges) == 0 {
+		return nil
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return fmt.Errorf("failed to create state directory: %w", err)
+	}
+	unlock, err := Lock(path)
+	if err != nil {
+		return err
+	}
+	defer unlock()
+
+	state := readState(path)
+	for _, change := range c.stateChanges {
+		change(&state)
+	}
+	data, err := json.Marshal(state)
+	if err != nil {
+		return err
+	}
+	tmp := path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return fmt.Errorf("failed to write state: %w", err)
+	}
+	if err := os.Rename(tmp, path); err != nil {
+		return fmt.Errorf("failed to write state: %w", err)
+	}
+	c.Colors, c.Pinned = state.Colors, state.Pinned
+	c.stateChanges = nil
+	return nil
+}
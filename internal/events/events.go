@@ -0,0 +1,109 @@
+package events
+
+import (
+	"encoding/json"
+	"io"
+	"sync"
+	"time"
+)
+
+// Event types
+const (
+	WorktreeCreated = "worktree_created"
+	WorktreeDeleted = "worktree_deleted"
+	WorktreeRenamed = "worktree_renamed"
+	TodoCreated     = "todo_created"
+	SessionStarted  = "session_started"
+	TodoCompleted   = "todo_completed"
+)
+
+// Event is a machine-readable record of something lfg changed
+type Event struct {
+	Type     string    `json:"type"`
+	Time     time.Time `json:"time"`
+	Worktree string    `json:"worktree,omitempty"`
+	From     string    `json:"from,omitempty"` // Previous name of a renamed worktree
+	Path     string    `json:"path,omitempty"`
+	Branch   string    `json:"branch,omitempty"`
+	Session  string    `json:"session,omitempty"`
+	Todo     string    `json:"todo,omitempty"`
+}
+
+var (
+	mu          sync.Mutex
+	out         io.Writer
+	held        bool
+	pending     []Event
+	subscribers []func(Event)
+)
+
+// Enable writes events to w as newline-delimited JSON
+// Events are dropped until Enable is called
+func Enable(w io.Writer) {
+	mu.Lock()
+	defer mu.Unlock()
+	out = w
+}
+
+// Enabled reports whether events are being written
+func Enabled() bool {
+	mu.Lock()
+	defer mu.Unlock()
+	return out != nil
+}
+
+// Hold queues events instead of writing them, e.g. while a full-screen TUI owns stdout
+// The returned function writes the queued events and resumes normal output
+func Hold() func() {
+	mu.Lock()
+	held = true
+	mu.Unlock()
+
+	return func() {
+		mu.Lock()
+		defer mu.Unlock()
+		held = false
+		for _, e := range pending {
+			write(e)
+		}
+		pending = nil
+	}
+}
+
+// Subscribe calls fn with every event emitted from now on, whether or not events are being written
+func Subscribe(fn func(Event)) {
+	mu.Lock()
+	defer mu.Unlock()
+	subscribers = append(subscribers, fn)
+}
+
+// Emit records an event
+func Emit(e Event) {
+	mu.Lock()
+	if e.Time.IsZero() {
+		e.Time = time.Now().UTC()
+	}
+	notify := append([]func(Event){}, subscribers...)
+	switch {
+	case out == nil:
+	case held:
+		pending = append(pending, e)
+	default:
+		write(e)
+	}
+	mu.Unlock()
+
+	// Subscribers run unlocked so they can emit events themselves
+	for _, fn := range notify {
+		fn(e)
+	}
+}
+
+func write(e Event) {
+	// Events are best effort, a closed pipe shouldn't break the command that emitted them
+	data, err := json.Marshal(e)
+	if err != nil {
+		return
+	}
+	out.Write(append(data, '\n'))
+}
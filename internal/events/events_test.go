@@ -0,0 +1,68 @@
+package events
+
+import (
+	"bytes"
+	"encoding/json"
+	"strings"
+	"testing"
+)
+
+func TestEmit(t *testing.T) {
+	var buf bytes.Buffer
+	Enable(&buf)
+	defer Enable(nil)
+
+	Emit(Event{Type: WorktreeCreated, Worktree: "myapp-login", Branch: "myapp-login"})
+	Emit(Event{Type: SessionStarted, Session: "myapp/myapp-login"})
+
+	lines := strings.Split(strings.TrimSpace(buf.String()), "\n")
+	if len(lines) != 2 {
+		t.Fatalf("Expected 2 events, got %d: %q", len(lines), buf.String())
+	}
+
+	var e Event
+	if err := json.Unmarshal([]byte(lines[0]), &e); err != nil {
+		t.Fatalf("Event is not valid JSON: %v", err)
+	}
+	if e.Type != WorktreeCreated || e.Worktree != "myapp-login" {
+		t.Errorf("Unexpected event %+v", e)
+	}
+	if e.Time.IsZero() {
+		t.Error("Expected event time to be set")
+	}
+}
+
+func TestHold(t *testing.T) {
+	var buf bytes.Buffer
+	Enable(&buf)
+	defer Enable(nil)
+
+	release := Hold()
+	Emit(Event{Type: TodoCompleted, Todo: "Fix login"})
+	if buf.Len() != 0 {
+		t.Errorf("Expected no output while held, got %q", buf.String())
+	}
+
+	release()
+	if !strings.Contains(buf.String(), `"type":"todo_completed"`) {
+		t.Errorf("Expected held event to be written on release, got %q", buf.String())
+	}
+}
+
+func TestEmitDisabled(t *testing.T) {
+	Enable(nil)
+	// Must not panic without a writer
+	Emit(Event{Type: WorktreeDeleted})
+}
+
+func TestSubscribe(t *testing.T) {
+	Enable(nil)
+	var got []Event
+	Subscribe(func(e Event) { got = append(got, e) })
+	defer func() { subscribers = nil }()
+
+	Emit(Event{Type: SessionStarted, Worktree: "myapp-login"})
+	if len(got) != 1 || got[0].Worktree != "myapp-login" || got[0].Time.IsZero() {
+		t.Errorf("Subscriber got %+v, want the session event", got)
+	}
+}
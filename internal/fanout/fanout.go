@@ -0,0 +1,112 @@
+package fanout
+
+import (
+	"errors"
+	"fmt"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// Result is how a command went in one worktree
+type Result struct {
+	Worktree git.Worktree
+	Duration time.Duration
+	Output   string // Combined stdout and stderr
+	ExitCode int    // -1 when the command couldn't be started
+	Err      error
+}
+
+// Filter returns the worktrees whose name matches a glob pattern like "fix-*"
+func Filter(worktrees []git.Worktree, pattern string) ([]git.Worktree, error) {
+	if _, err := filepath.Match(pattern, ""); err != nil {
+		return nil, fmt.Errorf("invalid pattern %q: %w", pattern, err)
+	}
+	var matched []git.Worktree
+	for _, wt := range worktrees {
+		if ok, _ := filepath.Match(pattern, wt.Name()); ok {
+			matched = append(matched, wt)
+		} else if ok, _ := filepath.Match(pattern, wt.DisplayName()); ok {
+			matched = append(matched, wt)
+		}
+	}
+	return matched, nil
+}
+
+// Command returns the argv to run: a single argument is a shell snippet run with sh -c,
+// so pipes and && work, while several arguments are run as they are
+func Command(args []string) []string {
+	if len(args) == 1 {
+		return []string{"sh", "-c", args[0]}
+	}
+	return args
+}
+
+// Run runs the command in every worktree, one at a time or all at once with parallel
+// report is called with each result as it finishes, and the results are returned in worktree order
+func Run(worktrees []git.Worktree, args []string, parallel bool, report func(Result)) []Result {
+	argv := Command(args)
+	results := make([]Result, len(worktrees))
+
+	var mu sync.Mutex
+	var wg sync.WaitGroup
+	for i, wt := range worktrees {
+		run := func(i int, wt git.Worktree) {
+			result := runIn(wt, argv)
+			mu.Lock()
+			defer mu.Unlock()
+			results[i] = result
+			if report != nil {
+				report(result)
+			}
+		}
+		if !parallel {
+			run(i, wt)
+			continue
+		}
+		wg.Add(1)
+		go func(i int, wt git.Worktree) {
+			defer wg.Done()
+			run(i, wt)
+		}(i, wt)
+	}
+	wg.Wait()
+
+	return results
+}
+
+func runIn(wt git.Worktree, argv []string) Result {
+	cmd := exec.Command(argv[0], argv[1:]...)
+	cmd.Dir = wt.Path
+
+	start := time.Now()
+	output, err := runner.CombinedOutput(cmd)
+	result := Result{Worktree: wt, Duration: time.Since(start), Output: strings.TrimRight(string(output), "\n"), Err: err}
+
+	var exitErr *exec.ExitError
+	switch {
+	case err == nil:
+		result.ExitCode = 0
+	case errors.As(err, &exitErr):
+		result.ExitCode = exitErr.ExitCode()
+	default:
+		result.ExitCode = -1
+	}
+	return result
+}
+
+// Failed counts the worktrees where the command didn't succeed
+func Failed(results []Result) int {
+	failed := 0
+	for _, r := range results {
+		if r.Err != nil {
+			failed++
+		}
+	}
+	return failed
+}
@@ -0,0 +1,88 @@
+package fanout
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"reflect"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/git"
+)
+
+func TestFilter(t *testing.T) {
+	worktrees := []git.Worktree{
+		{Path: "/src/myapp"},
+		{Path: "/src/myapp-fix-login"},
+		{Path: "/src/myapp-fix-signup"},
+		{Path: "/src/myapp-add-billing"},
+	}
+
+	matched, err := Filter(worktrees, "*-fix-*")
+	if err != nil {
+		t.Fatalf("Filter() error = %v", err)
+	}
+	var names []string
+	for _, wt := range matched {
+		names = append(names, wt.Name())
+	}
+	if expected := []string{"myapp-fix-login", "myapp-fix-signup"}; !reflect.DeepEqual(names, expected) {
+		t.Errorf("Filter() = %v, want %v", names, expected)
+	}
+
+	if _, err := Filter(worktrees, "[fix"); err == nil {
+		t.Error("Filter() with an invalid pattern should fail")
+	}
+}
+
+func TestCommand(t *testing.T) {
+	tests := []struct {
+		args     []string
+		expected []string
+	}{
+		{args: []string{"git fetch && git status -s"}, expected: []string{"sh", "-c", "git fetch && git status -s"}},
+		{args: []string{"rg", "TODO"}, expected: []string{"rg", "TODO"}},
+	}
+
+	for _, tt := range tests {
+		if got := Command(tt.args); !reflect.DeepEqual(got, tt.expected) {
+			t.Errorf("Command(%v) = %v, want %v", tt.args, got, tt.expected)
+		}
+	}
+}
+
+func TestRun(t *testing.T) {
+	if _, err := exec.LookPath("sh"); err != nil {
+		t.Skip("sh not installed, skipping test")
+	}
+
+	var worktrees []git.Worktree
+	for _, name := range []string{"passing", "failing"} {
+		dir := filepath.Join(t.TempDir(), name)
+		if err := os.Mkdir(dir, 0755); err != nil {
+			t.Fatal(err)
+		}
+		worktrees = append(worktrees, git.Worktree{Path: dir})
+	}
+	if err := os.WriteFile(filepath.Join(worktrees[1].Path, "broken"), nil, 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	for _, parallel := range []bool{false, true} {
+		reported := 0
+		results := Run(worktrees, []string{"basename $(pwd); test ! -e broken || exit 3"}, parallel, func(Result) { reported++ })
+
+		if reported != 2 {
+			t.Errorf("Run(parallel=%v) reported %d results, want 2", parallel, reported)
+		}
+		if results[0].ExitCode != 0 || results[0].Output != "passing" {
+			t.Errorf("Run(parallel=%v)[0] = exit %d, output %q", parallel, results[0].ExitCode, results[0].Output)
+		}
+		if results[1].ExitCode != 3 || results[1].Output != "failing" {
+			t.Errorf("Run(parallel=%v)[1] = exit %d, output %q", parallel, results[1].ExitCode, results[1].Output)
+		}
+		if failed := Failed(results); failed != 1 {
+			t.Errorf("Failed() = %d, want 1", failed)
+		}
+	}
+}
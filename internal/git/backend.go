@@ -0,0 +1,58 @@
+package git
+
+import (
+	"fmt"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// Backend reads what every refresh needs from the repository: its worktrees and their changes
+// Picked with git.backend, see BackendExec and BackendNative
+type Backend interface {
+	ListWorktrees() ([]Worktree, error)
+	Status(path string) (string, error) // `git status --porcelain -b` output for the worktree at path
+}
+
+// currentBackend returns the backend set with git.backend, running git unless it's native
+func currentBackend() Backend {
+	if settings != nil && settings.Backend == BackendNative {
+		return nativeBackend{}
+	}
+	return execBackend{}
+}
+
+// execBackend runs git for everything, and is what the native backend falls back to
+type execBackend struct{}
+
+func (execBackend) ListWorktrees() ([]Worktree, error) {
+	output, err := runner.Output(Command("worktree", "list", "--porcelain"))
+	if err != nil {
+		return nil, fmt.Errorf("failed to list worktrees: %w", err)
+	}
+	worktrees := parseWorktrees(string(output))
+	for i := range worktrees {
+		worktrees[i].ID = worktreeID(worktrees[i].Path)
+	}
+	return worktrees, nil
+}
+
+func (execBackend) Status(path string) (string, error) {
+	output, err := runner.Output(Command("-C", path, "status", "--porcelain", "-b"))
+	if err != nil {
+		return "", err
+	}
+	return string(output), nil
+}
+
+// nativeBackend lists worktrees from the .git directory. Finding changes means reading the index and
+// walking the working tree, which git does far better, so status is still asked of git
+type nativeBackend struct {
+	execBackend
+}
+
+func (b nativeBackend) ListWorktrees() ([]Worktree, error) {
+	if worktrees, err := readWorktrees("."); err == nil {
+		return worktrees, nil
+	}
+	return b.execBackend.ListWorktrees()
+}
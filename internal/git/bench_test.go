@@ -0,0 +1,27 @@
+package git
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+)
+
+// porcelainListing returns `git worktree list --porcelain` output for n worktrees
+func porcelainListing(n int) string {
+	var b strings.Builder
+	b.WriteString("worktree /src/myapp\nHEAD 0123456789abcdef0123456789abcdef01234567\nbranch refs/heads/main\n\n")
+	for i := 1; i < n; i++ {
+		fmt.Fprintf(&b, "worktree /src/myapp-feature-%d\nHEAD 0123456789abcdef0123456789abcdef01234567\nbranch refs/heads/feature-%d\n\n", i, i)
+	}
+	return b.String()
+}
+
+func BenchmarkParseWorktrees(b *testing.B) {
+	output := porcelainListing(1000)
+	b.ReportAllocs()
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		worktrees := parseWorktrees(output)
+		assignDisplayNames(worktrees)
+	}
+}
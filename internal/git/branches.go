@@ -0,0 +1,181 @@
+package git
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// Branch is an existing local or remote branch that can be checked out into a new worktree
+type Branch struct {
+	Name   string // e.g. "fix-login", or "origin/fix-login" for remote branches
+	Remote bool
+}
+
+// LocalName returns the name of the local branch: the branch itself, or the one tracking a remote branch
+func (b Branch) LocalName() string {
+	if !b.Remote {
+		return b.Name
+	}
+	if _, name, ok := strings.Cut(b.Name, "/"); ok {
+		return name
+	}
+	return b.Name
+}
+
+// ListBranches returns the branches that could be checked out into a new worktree: local branches
+// not checked out anywhere, then remote branches that have no local branch yet
+func ListBranches() ([]Branch, error) {
+	output, err := runner.Output(Command("for-each-ref", "--format=%(refname)", "refs/heads", "refs/remotes"))
+	if err != nil {
+		return nil, fmt.Errorf("failed to list branches: %w", err)
+	}
+	worktrees, err := ListWorktrees()
+	if err != nil {
+		return nil, err
+	}
+	checkedOut := make(map[string]bool)
+	for _, wt := range worktrees {
+		checkedOut[wt.BranchName()] = true
+	}
+	return parseBranches(string(output), checkedOut), nil
+}
+
+// parseBranches parses `for-each-ref --format=%(refname)` output into the branches ListBranches returns
+func parseBranches(output string, checkedOut map[string]bool) []Branch {
+	local := make(map[string]bool)
+	var locals, remotes []Branch
+	for _, ref := range strings.Fields(output) {
+		if name, ok := strings.CutPrefix(ref, "refs/heads/"); ok {
+			local[name] = true
+			if !checkedOut[name] {
+				locals = append(locals, Branch{Name: name})
+			}
+		} else if name, ok := strings.CutPrefix(ref, "refs/remotes/"); ok && !strings.HasSuffix(name, "/HEAD") {
+			remotes = append(remotes, Branch{Name: name, Remote: true})
+		}
+	}
+
+	branches := locals
+	for _, branch := range remotes {
+		if !local[branch.LocalName()] {
+			branches = append(branches, branch)
+		}
+	}
+	return branches
+}
+
+// RemoteBranchInfo is a remote branch with its last commit, as shown in the remote branch picker
+type RemoteBranchInfo struct {
+	Branch
+	Author  string // Author of the last commit
+	Date    string // When the last commit was made, e.g. "3 days ago"
+	Subject string // Subject of the last commit
+}
+
+// remoteBranchFormat separates the fields with NUL so subjects and names can contain anything else
+const remoteBranchFormat = "--format=%(refname)%00%(authorname)%00%(committerdate:relative)%00%(subject)"
+
+// ListRemoteBranches fetches from every remote, then returns the remote branches that have no local
+// branch yet, most recently committed to first
+// A failed fetch (e.g. offline) only means the list may be out of date, so it isn't an error
+func ListRemoteBranches() ([]RemoteBranchInfo, error) {
+	if output, err := runner.CombinedOutput(Command("fetch", "--all", "--prune")); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to fetch: %s\n", strings.TrimSpace(string(output)))
+	}
+
+	output, err := runner.Output(Command("for-each-ref", "--sort=-committerdate", remoteBranchFormat, "refs/heads", "refs/remotes"))
+	if err != nil {
+		return nil, fmt.Errorf("failed to list remote branches: %w", err)
+	}
+	return parseRemoteBranches(string(output)), nil
+}
+
+// parseRemoteBranches parses ListRemoteBranches' for-each-ref output, skipping remote branches with a local branch
+func parseRemoteBranches(output string) []RemoteBranchInfo {
+	local := make(map[string]bool)
+	var remotes []RemoteBranchInfo
+	for _, line := range strings.Split(output, "\n") {
+		fields := strings.SplitN(line, "\x00", 4)
+		if len(fields) < 4 {
+			continue
+		}
+		if name, ok := strings.CutPrefix(fields[0], "refs/heads/"); ok {
+			local[name] = true
+		} else if name, ok := strings.CutPrefix(fields[0], "refs/remotes/"); ok && !strings.HasSuffix(name, "/HEAD") {
+			remotes = append(remotes, RemoteBranchInfo{
+				Branch:  Branch{Name: name, Remote: true},
+				Author:  fields[1],
+				Date:    fields[2],
+				Subject: fields[3],
+			})
+		}
+	}
+
+	var branches []RemoteBranchInfo
+	for _, branch := range remotes {
+		if !local[branch.LocalName()] {
+			branches = append(branches, branch)
+		}
+	}
+	return branches
+}
+
+// RemoteBranch finds a remote branch called name when there is no local branch of that name yet,
+// so a teammate's branch can be tracked instead of creating a new one. origin is preferred
+func RemoteBranch(name string) (Branch, bool) {
+	if BranchExists(name) {
+		return Branch{}, false
+	}
+	output, err := runner.Output(Command("for-each-ref", "--format=%(refname)", "refs/remotes"))
+	if err != nil {
+		return Branch{}, false
+	}
+	return findRemoteBranch(string(output), name)
+}
+
+// findRemoteBranch picks the remote branch called name from `for-each-ref --format=%(refname)` output
+func findRemoteBranch(output, name string) (Branch, bool) {
+	var found []Branch
+	for _, ref := range strings.Fields(output) {
+		remoteName, ok := strings.CutPrefix(ref, "refs/remotes/")
+		if !ok {
+			continue
+		}
+		branch := Branch{Name: remoteName, Remote: true}
+		if branch.LocalName() != name {
+			continue
+		}
+		if strings.HasPrefix(remoteName, "origin/") {
+			return branch, true
+		}
+		found = append(found, branch)
+	}
+	if len(found) == 0 {
+		return Branch{}, false
+	}
+	return found[0], true
+}
+
+// CreateWorktreeFromBranch creates a worktree directory called name with an existing branch checked out
+// Remote branches get a local branch tracking them
+func CreateWorktreeFromBranch(name string, branch Branch) error {
+	worktreePath, err := worktreePathFor(name)
+	if err != nil {
+		return err
+	}
+
+	cmd := Command("worktree", "add", worktreePath, branch.Name)
+	if branch.Remote {
+		cmd = Command("worktree", "add", "--track", "-b", branch.LocalName(), worktreePath, branch.Name)
+	}
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to create worktree: %s", strings.TrimSpace(string(output)))
+	}
+
+	events.Emit(events.Event{Type: events.WorktreeCreated, Worktree: name, Path: worktreePath, Branch: branch.LocalName()})
+	return nil
+}
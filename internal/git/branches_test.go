@@ -0,0 +1,106 @@
+package git
+
+import (
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+func TestParseBranches(t *testing.T) {
+	output := strings.Join([]string{
+		"refs/heads/feature",
+		"refs/heads/fix-login",
+		"refs/heads/main",
+		"refs/remotes/origin/HEAD",
+		"refs/remotes/origin/fix-login",
+		"refs/remotes/origin/main",
+		"refs/remotes/origin/team/new-api",
+	}, "\n")
+	checkedOut := map[string]bool{"main": true, "feature": true}
+
+	want := []Branch{
+		{Name: "fix-login"},
+		{Name: "origin/team/new-api", Remote: true},
+	}
+	if got := parseBranches(output, checkedOut); !reflect.DeepEqual(got, want) {
+		t.Errorf("parseBranches() = %+v, want %+v", got, want)
+	}
+	if got := want[1].LocalName(); got != "team/new-api" {
+		t.Errorf("LocalName() = %q, want team/new-api", got)
+	}
+}
+
+func TestParseRemoteBranches(t *testing.T) {
+	output := strings.Join([]string{
+		"refs/remotes/origin/team/new-api\x00Ana\x002 hours ago\x00Add the new API",
+		"refs/heads/fix-login\x00Me\x003 days ago\x00Fix login",
+		"refs/remotes/origin/HEAD\x00Bo\x005 days ago\x00Release",
+		"refs/remotes/origin/fix-login\x00Me\x003 days ago\x00Fix login",
+		"refs/remotes/origin/old-spike\x00Bo\x002 months ago\x00Try a cache: maybe",
+	}, "\n")
+
+	want := []RemoteBranchInfo{
+		{Branch: Branch{Name: "origin/team/new-api", Remote: true}, Author: "Ana", Date: "2 hours ago", Subject: "Add the new API"},
+		{Branch: Branch{Name: "origin/old-spike", Remote: true}, Author: "Bo", Date: "2 months ago", Subject: "Try a cache: maybe"},
+	}
+	if got := parseRemoteBranches(output); !reflect.DeepEqual(got, want) {
+		t.Errorf("parseRemoteBranches() = %+v, want %+v", got, want)
+	}
+}
+
+func TestFindRemoteBranch(t *testing.T) {
+	output := strings.Join([]string{
+		"refs/remotes/fork/team/new-api",
+		"refs/remotes/fork/only-on-fork",
+		"refs/remotes/origin/HEAD",
+		"refs/remotes/origin/team/new-api",
+	}, "\n")
+
+	tests := []struct {
+		name  string
+		want  Branch
+		found bool
+	}{
+		{name: "team/new-api", want: Branch{Name: "origin/team/new-api", Remote: true}, found: true},
+		{name: "only-on-fork", want: Branch{Name: "fork/only-on-fork", Remote: true}, found: true},
+		{name: "missing"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, found := findRemoteBranch(output, tt.name)
+			if found != tt.found || got != tt.want {
+				t.Errorf("findRemoteBranch(%q) = %+v, %v, want %+v, %v", tt.name, got, found, tt.want, tt.found)
+			}
+		})
+	}
+}
+
+func TestCreateWorktreeFromBranch(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	mainPath, _ := newTestRepo(t)
+	runGit(t, mainPath, "branch", "existing")
+
+	// The repository is its own origin, so a teammate's branch is one fetched and deleted locally
+	runGit(t, mainPath, "branch", "teammate")
+	runGit(t, mainPath, "remote", "add", "origin", mainPath)
+	runGit(t, mainPath, "fetch", "-q", "origin")
+	runGit(t, mainPath, "branch", "-q", "-D", "teammate")
+	chdir(t, mainPath)
+
+	if err := CreateWorktreeFromBranch("myapp-existing", Branch{Name: "existing"}); err != nil {
+		t.Fatalf("CreateWorktreeFromBranch() local error = %v", err)
+	}
+	path := filepath.Join(filepath.Dir(mainPath), "myapp-existing")
+	if got := strings.TrimSpace(runGit(t, path, "branch", "--show-current")); got != "existing" {
+		t.Errorf("local worktree is on %q, want existing", got)
+	}
+
+	if err := CreateWorktreeFromBranch("myapp-teammate", Branch{Name: "origin/teammate", Remote: true}); err != nil {
+		t.Fatalf("CreateWorktreeFromBranch() remote error = %v", err)
+	}
+	path = filepath.Join(filepath.Dir(mainPath), "myapp-teammate")
+	if got := strings.TrimSpace(runGit(t, path, "rev-parse", "--abbrev-ref", "@{upstream}")); got != "origin/teammate" {
+		t.Errorf("remote worktree tracks %q, want origin/teammate", got)
+	}
+}
@@ -0,0 +1,71 @@
+package git
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+// cacheName returns the file name a repository's cached data is kept under
+func cacheName(repo string) string {
+	sum := sha256.Sum256([]byte(repo))
+	return hex.EncodeToString(sum[:8]) + ".json"
+}
+
+// writeCacheFile writes v as JSON, writing then renaming so a concurrent lfg never reads half a file
+func writeCacheFile(path string, v any) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return fmt.Errorf("failed to create cache directory: %w", err)
+	}
+	data, err := json.Marshal(v)
+	if err != nil {
+		return err
+	}
+	tmp := path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return fmt.Errorf("failed to write cache: %w", err)
+	}
+	return os.Rename(tmp, path)
+}
+
+// worktreeCachePath returns where the last worktree listing of a repository is kept
+func worktreeCachePath(repo string) (string, error) {
+	dir, err := config.DataDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "cache", cacheName(repo)), nil
+}
+
+// CachedWorktrees returns the worktrees saved by the last SaveWorktreeCache for a repository, or nil
+// The listing may be out of date, it's for showing something straight away while ListWorktrees runs
+func CachedWorktrees(repo string) []Worktree {
+	path, err := worktreeCachePath(repo)
+	if err != nil {
+		return nil
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil
+	}
+	var worktrees []Worktree
+	if err := json.Unmarshal(data, &worktrees); err != nil || len(worktrees) == 0 {
+		return nil
+	}
+	assignDisplayNames(worktrees)
+	return worktrees
+}
+
+// SaveWorktreeCache saves a repository's worktree listing for the next CachedWorktrees
+func SaveWorktreeCache(repo string, worktrees []Worktree) error {
+	path, err := worktreeCachePath(repo)
+	if err != nil {
+		return err
+	}
+	return writeCacheFile(path, worktrees)
+}
@@ -0,0 +1,35 @@
+package git
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestWorktreeCache(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+
+	if got := CachedWorktrees("/src/myapp"); got != nil {
+		t.Fatalf("CachedWorktrees() before saving = %+v, want nil", got)
+	}
+
+	worktrees := []Worktree{
+		{Path: "/src/myapp", Branch: "refs/heads/main", Commit: "abc123"},
+		{Path: "/src/client-a/api", Branch: "refs/heads/api", Commit: "def456", ID: "api"},
+		{Path: "/src/client-b/api", Commit: "789abc", ID: "api1", Detached: true},
+	}
+	if err := SaveWorktreeCache("/src/myapp", worktrees); err != nil {
+		t.Fatalf("SaveWorktreeCache() error = %v", err)
+	}
+
+	got := CachedWorktrees("/src/myapp")
+	assignDisplayNames(worktrees)
+	if !reflect.DeepEqual(got, worktrees) {
+		t.Errorf("CachedWorktrees() = %+v, want %+v", got, worktrees)
+	}
+	if got[1].DisplayName() != "client-a/api" {
+		t.Errorf("cached worktree display name = %q, want client-a/api", got[1].DisplayName())
+	}
+	if other := CachedWorktrees("/src/other"); other != nil {
+		t.Errorf("CachedWorktrees() for another repo = %+v, want nil", other)
+	}
+}
@@ -0,0 +1,108 @@
+package git
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// checkCacheFile caches the last check_command result in the worktree's git directory
+const checkCacheFile = "lfg-check.json"
+
+// checkOutputLines is how much of a failing check's output is kept
+const checkOutputLines = 5
+
+// CheckResult is the outcome of the check command for one state of a worktree
+type CheckResult struct {
+	Passed      bool      `json:"passed"`
+	Fingerprint string    `json:"fingerprint"` // Worktree state the check ran against
+	Ran         time.Time `json:"ran"`
+	Output      string    `json:"output,omitempty"` // Last lines of output when the check failed
+}
+
+// Fingerprint identifies a worktree's contents from HEAD, the diff against it and its untracked files,
+// so it changes whenever a check could give a different result
+func Fingerprint(path string) (string, error) {
+	hash := sha1.New()
+	for _, args := range [][]string{
+		{"rev-parse", "HEAD"},
+		{"diff", "HEAD", "--binary"},
+		{"ls-files", "--others", "--exclude-standard"},
+	} {
+		cmd := Command(append([]string{"-C", path}, args...)...)
+		output, err := runner.Output(cmd)
+		if err != nil {
+			return "", fmt.Errorf("failed to fingerprint %s: %w", path, err)
+		}
+		hash.Write(output)
+
+		// Untracked files aren't in the diff, their size and modification time stand in for their contents
+		if args[0] == "ls-files" {
+			for _, file := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+				if info, err := os.Stat(filepath.Join(path, file)); err == nil && file != "" {
+					fmt.Fprintf(hash, "%s %d %d\n", file, info.Size(), info.ModTime().UnixNano())
+				}
+			}
+		}
+	}
+	return hex.EncodeToString(hash.Sum(nil)), nil
+}
+
+// CachedCheck returns the last check result for a worktree, or nil if it has never been checked
+func CachedCheck(path string) *CheckResult {
+	data, err := os.ReadFile(filepath.Join(worktreeGitDir(path), checkCacheFile))
+	if err != nil {
+		return nil
+	}
+	var result CheckResult
+	if err := json.Unmarshal(data, &result); err != nil {
+		return nil
+	}
+	return &result
+}
+
+// RunCheck runs the check command in a worktree unless the cached result is for its current state
+func RunCheck(command, path string) (*CheckResult, error) {
+	fingerprint, err := Fingerprint(path)
+	if err != nil {
+		return nil, err
+	}
+	if cached := CachedCheck(path); cached != nil && cached.Fingerprint == fingerprint {
+		return cached, nil
+	}
+
+	cmd := exec.Command("sh", "-c", command)
+	cmd.Dir = path
+	output, err := runner.CombinedOutput(cmd)
+	result := &CheckResult{Passed: err == nil, Ran: time.Now()}
+	if err != nil {
+		var exitErr *exec.ExitError
+		if !errors.As(err, &exitErr) {
+			return nil, fmt.Errorf("failed to run %s: %w", command, err)
+		}
+		lines := strings.Split(strings.TrimSpace(string(output)), "\n")
+		result.Output = strings.Join(lines[max(len(lines)-checkOutputLines, 0):], "\n")
+	}
+
+	// Fingerprint again, the check may have left build output behind that would otherwise trigger another run
+	if result.Fingerprint, err = Fingerprint(path); err != nil {
+		return nil, err
+	}
+	data, err := json.Marshal(result)
+	if err != nil {
+		return nil, err
+	}
+	if err := os.WriteFile(filepath.Join(worktreeGitDir(path), checkCacheFile), data, 0644); err != nil {
+		return nil, fmt.Errorf("failed to cache check result: %w", err)
+	}
+	return result, nil
+}
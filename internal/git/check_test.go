@@ -0,0 +1,48 @@
+package git
+
+import (
+	"os/exec"
+	"path/filepath"
+	"testing"
+)
+
+func TestRunCheck(t *testing.T) {
+	if _, err := exec.LookPath("sh"); err != nil {
+		t.Skip("sh not installed, skipping test")
+	}
+	t.Setenv("LFG_GIT_ARGS", "")
+	_, worktreePath := newTestRepo(t)
+
+	// Runs are counted outside the worktree, so counting doesn't change the fingerprint
+	runs := filepath.Join(t.TempDir(), "runs")
+	command := "echo run >> " + runs + "; grep -q hello README"
+
+	result, err := RunCheck(command, worktreePath)
+	if err != nil {
+		t.Fatalf("RunCheck() error = %v", err)
+	}
+	if !result.Passed {
+		t.Errorf("RunCheck() passed = false, want true")
+	}
+
+	// Nothing changed, so the cached result is used
+	if _, err := RunCheck(command, worktreePath); err != nil {
+		t.Fatalf("RunCheck() error = %v", err)
+	}
+	if got := readFile(t, runs); got != "run\n" {
+		t.Errorf("check ran %q, want once", got)
+	}
+
+	// Editing a file runs the check again
+	writeFile(t, filepath.Join(worktreePath, "README"), "goodbye\n")
+	result, err = RunCheck(command, worktreePath)
+	if err != nil {
+		t.Fatalf("RunCheck() error = %v", err)
+	}
+	if result.Passed {
+		t.Errorf("RunCheck() after breaking README passed = true, want false")
+	}
+	if cached := CachedCheck(worktreePath); cached == nil || cached.Passed || cached.Fingerprint != result.Fingerprint {
+		t.Errorf("CachedCheck() = %+v, want the failing result", cached)
+	}
+}
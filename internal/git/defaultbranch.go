@@ -0,0 +1,100 @@
+package git
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"sync"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// defaultBranch caches the detected default branch for the rest of the run
+var defaultBranch struct {
+	sync.Mutex
+	name string
+}
+
+// DefaultBranch returns the repository's default branch, e.g. "main", "master" or "develop"
+// It comes from git.default_branch in the config, then origin/HEAD, then whichever of main and master exists
+func DefaultBranch() string {
+	if settings != nil && settings.DefaultBranch != "" {
+		return settings.DefaultBranch
+	}
+
+	defaultBranch.Lock()
+	defer defaultBranch.Unlock()
+	if defaultBranch.name == "" {
+		defaultBranch.name = detectDefaultBranch()
+	}
+	return defaultBranch.name
+}
+
+func detectDefaultBranch() string {
+	if output, err := runner.Output(Command("symbolic-ref", "--short", "refs/remotes/origin/HEAD")); err == nil {
+		if branch, ok := strings.CutPrefix(strings.TrimSpace(string(output)), "origin/"); ok && branch != "" {
+			return branch
+		}
+	}
+	for _, branch := range []string{"main", "master"} {
+		for _, ref := range []string{"refs/remotes/origin/" + branch, "refs/heads/" + branch} {
+			if runner.Run(Command("show-ref", "--verify", "--quiet", ref)) == nil {
+				return branch
+			}
+		}
+	}
+	return "main"
+}
+
+// DefaultBaseRef returns the ref new work is compared against: origin's copy of the default branch
+// when there is one, since it's the most up to date, otherwise the local branch
+func DefaultBaseRef() string {
+	return BaseRef(DefaultBranch())
+}
+
+// BaseRef returns the ref to compare with or rebase onto a branch: origin's copy when there is one,
+// otherwise the local branch
+func BaseRef(branch string) string {
+	if runner.Run(Command("show-ref", "--verify", "--quiet", "refs/remotes/origin/"+branch)) == nil {
+		return "origin/" + branch
+	}
+	return branch
+}
+
+// Divergence is how far a worktree's HEAD has moved from the default branch (or another base)
+type Divergence struct {
+	Ahead  int // Commits on HEAD that the base doesn't have
+	Behind int // Commits on the base that HEAD doesn't have
+}
+
+// CompareToDefault counts the commits a worktree is ahead of and behind the default branch
+func CompareToDefault(path string) (Divergence, error) {
+	return CompareToBase(path, DefaultBranch())
+}
+
+// CompareToBase counts the commits a worktree is ahead of and behind a branch, e.g. its pull request's base
+func CompareToBase(path, branch string) (Divergence, error) {
+	cmd := Command("-C", path, "rev-list", "--left-right", "--count", BaseRef(branch)+"...HEAD")
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return Divergence{}, fmt.Errorf("failed to compare with %s: %w", branch, err)
+	}
+	return parseDivergence(string(output))
+}
+
+// parseDivergence parses `rev-list --left-right --count base...HEAD` output: "<behind>\t<ahead>"
+func parseDivergence(output string) (Divergence, error) {
+	fields := strings.Fields(output)
+	if len(fields) != 2 {
+		return Divergence{}, fmt.Errorf("unexpected rev-list output: %q", output)
+	}
+	behind, err := strconv.Atoi(fields[0])
+	if err != nil {
+		return Divergence{}, err
+	}
+	ahead, err := strconv.Atoi(fields[1])
+	if err != nil {
+		return Divergence{}, err
+	}
+	return Divergence{Ahead: ahead, Behind: behind}, nil
+}
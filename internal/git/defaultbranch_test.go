@@ -0,0 +1,42 @@
+package git
+
+import (
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+func TestParseDivergence(t *testing.T) {
+	tests := []struct {
+		name    string
+		output  string
+		want    Divergence
+		wantErr bool
+	}{
+		{name: "up to date", output: "0\t0\n", want: Divergence{}},
+		{name: "ahead and behind", output: "5\t2\n", want: Divergence{Ahead: 2, Behind: 5}},
+		{name: "empty", output: "", wantErr: true},
+		{name: "not a number", output: "x\t1\n", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := parseDivergence(tt.output)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("parseDivergence() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("parseDivergence() = %+v, want %+v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestDefaultBranchOverride(t *testing.T) {
+	t.Cleanup(func() { settings = nil })
+	Configure(&config.Git{DefaultBranch: "develop"})
+
+	if got := DefaultBranch(); got != "develop" {
+		t.Errorf("DefaultBranch() = %q, want develop", got)
+	}
+}
@@ -1,32 +1,87 @@
 package git
 
 import (
+	"bytes"
+	"crypto/sha1"
+	"encoding/hex"
 	"fmt"
 	"os"
 	"os/exec"
 	"path/filepath"
 	"strings"
+	"time"
 
 	"github.com/markcipolla/lfg/internal/config"
-	"github.com/markcipolla/lfg/internal/tmux"
+	"github.com/markcipolla/lfg/internal/runner"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/terminal"
 )
 
+// settings overrides the git executable, global args and environment, see Configure
+var settings *config.Git
+
+func init() {
+	config.GitCommand = Command
+}
+
+// Configure sets how git is invoked from the repository config
+func Configure(cfg *config.Git) {
+	settings = cfg
+}
+
+// Command returns a git command using the configured executable, global args and environment
+// LFG_GIT and LFG_GIT_ARGS (space-separated) take precedence over the config
+func Command(args ...string) *exec.Cmd {
+	executable := "git"
+	var globalArgs []string
+	if settings != nil {
+		if settings.Executable != "" {
+			executable = settings.Executable
+		}
+		globalArgs = settings.Args
+	}
+	if env := os.Getenv("LFG_GIT"); env != "" {
+		executable = env
+	}
+	if env := os.Getenv("LFG_GIT_ARGS"); env != "" {
+		globalArgs = strings.Fields(env)
+	}
+
+	cmd := exec.Command(executable, append(append([]string{}, globalArgs...), args...)...)
+	if settings != nil && len(settings.Env) > 0 {
+		cmd.Env = os.Environ()
+		for k, v := range settings.Env {
+			cmd.Env = append(cmd.Env, k+"="+v)
+		}
+	}
+	return cmd
+}
+
 type Worktree struct {
 	Path   string
 	Branch string
 	Commit string
+	ID     string // Administrative id under .git/worktrees, unique within the repo
+	Detached bool
+
+	displayName string
 }
 
 // ListWorktrees returns all git worktrees
 func ListWorktrees() ([]Worktree, error) {
-	cmd := exec.Command("git", "worktree", "list", "--porcelain")
-	output, err := cmd.Output()
+	worktrees, err := currentBackend().ListWorktrees()
 	if err != nil {
-		return nil, fmt.Errorf("failed to list worktrees: %w", err)
+		return nil, err
 	}
+	assignDisplayNames(worktrees)
+	return worktrees, nil
+}
 
+// parseWorktrees parses `git worktree list --porcelain` output
+func parseWorktrees(output string) []Worktree {
 	var worktrees []Worktree
-	lines := strings.Split(string(output), "\n")
+	lines := strings.Split(output, "\n")
 
 	var current Worktree
 	for _, line := range lines {
@@ -39,14 +94,76 @@ func ListWorktrees() ([]Worktree, error) {
 			current.Branch = strings.TrimPrefix(line, "branch ")
 		} else if strings.HasPrefix(line, "HEAD ") {
 			current.Commit = strings.TrimPrefix(line, "HEAD ")
+		} else if line == "detached" {
+			current.Detached = true
 		}
 	}
 
 	if current.Path != "" {
 		worktrees = append(worktrees, current)
 	}
+	return worktrees
+}
 
-	return worktrees, nil
+// assignDisplayNames labels each worktree with its directory name, prefixed by the
+// parent directory when another worktree shares the same basename
+func assignDisplayNames(worktrees []Worktree) {
+	counts := make(map[string]int)
+	for _, wt := range worktrees {
+		counts[GetWorktreeName(wt.Path)]++
+	}
+
+	for i := range worktrees {
+		base := GetWorktreeName(worktrees[i].Path)
+		if counts[base] > 1 {
+			worktrees[i].displayName = qualifiedName(worktrees[i].Path)
+		} else {
+			worktrees[i].displayName = base
+		}
+	}
+}
+
+// qualifiedName returns "parent/basename" for a worktree path
+func qualifiedName(path string) string {
+	return filepath.Base(filepath.Dir(path)) + "/" + filepath.Base(path)
+}
+
+// worktreeID returns git's administrative id for a worktree: the directory name under
+// .git/worktrees, which git keeps unique even when two worktree directories share a basename
+func worktreeID(path string) string {
+	// Linked worktrees have a ".git" file containing "gitdir: <main>/.git/worktrees/<id>"
+	data, err := os.ReadFile(filepath.Join(path, ".git"))
+	if err == nil {
+		content := strings.TrimSpace(string(data))
+		if strings.HasPrefix(content, "gitdir:") {
+			return filepath.Base(strings.TrimSpace(strings.TrimPrefix(content, "gitdir:")))
+		}
+	}
+
+	// Main worktree (".git" is a directory) or missing directory: use the directory name
+	return GetWorktreeName(path)
+}
+
+// Name returns the stable identifier used to link the worktree to todos and tmux sessions
+func (w Worktree) Name() string {
+	if w.ID != "" {
+		return w.ID
+	}
+	return GetWorktreeName(w.Path)
+}
+
+// DisplayName returns the name shown to users: the directory name, or "parent/dir"
+// when it would otherwise be ambiguous
+func (w Worktree) DisplayName() string {
+	if w.displayName != "" {
+		return w.displayName
+	}
+	return GetWorktreeName(w.Path)
+}
+
+// BranchName returns the short branch name checked out in the worktree
+func (w Worktree) BranchName() string {
+	return strings.TrimPrefix(w.Branch, "refs/heads/")
 }
 
 // GetWorktreeName extracts the worktree name from its path
@@ -54,20 +171,162 @@ func GetWorktreeName(path string) string {
 	return filepath.Base(path)
 }
 
-// GetWorktreePath returns the full path for a worktree by name
-func GetWorktreePath(name string) (string, error) {
+// GenerateWorktreeName creates a worktree name from project name and feature description
+// Format: [project-name]-[dasherized-feature-name]
+// Stop words are dropped and abbreviations applied per word when naming is configured
+func GenerateWorktreeName(projectName, description string, naming *config.Naming) string {
+	return TruncateName(fullWorktreeName(projectName, description, naming), MaxNameLength(naming))
+}
+
+// IsNameShortened reports whether GenerateWorktreeName had to shorten the name for a description
+func IsNameShortened(projectName, description string, naming *config.Naming) bool {
+	return len(fullWorktreeName(projectName, description, naming)) > MaxNameLength(naming)
+}
+
+// slugWord lowercases a word of a worktree name and removes the characters that can't be in one
+func slugWord(word string) string {
+	var cleaned strings.Builder
+	for _, r := range strings.ToLower(word) {
+		if (r >= 'a' && r <= 'z') || (r >= '0' && r <= '9') || r == '-' {
+			cleaned.WriteRune(r)
+		}
+	}
+	return strings.Trim(cleaned.String(), "-")
+}
+
+// fullWorktreeName is the name GenerateWorktreeName gives a description before it's shortened
+func fullWorktreeName(projectName, description string, naming *config.Naming) string {
+	stopWords := map[string]bool{}
+	abbreviations := map[string]string{}
+	if naming != nil {
+		for _, w := range naming.StopWords {
+			stopWords[strings.ToLower(w)] = true
+		}
+		for word, abbr := range naming.Abbreviations {
+			abbreviations[slugWord(word)] = slugWord(abbr)
+		}
+	}
+
+	var words, allWords []string
+	for _, word := range strings.Fields(description) {
+		word = slugWord(word)
+		if abbr, ok := abbreviations[word]; ok {
+			word = abbr
+		}
+		if word == "" {
+			continue
+		}
+		allWords = append(allWords, word)
+		if !stopWords[word] {
+			words = append(words, word)
+		}
+	}
+
+	// Don't drop everything if the description only contains stop words
+	if len(words) == 0 {
+		words = allWords
+	}
+
+	dasherized := strings.Join(words, "-")
+
+	// Remove consecutive dashes
+	for strings.Contains(dasherized, "--") {
+		dasherized = strings.ReplaceAll(dasherized, "--", "-")
+	}
+
+	// Trim dashes from start/end
+	dasherized = strings.Trim(dasherized, "-")
+
+	return projectName + "-" + dasherized
+}
+
+// DefaultMaxNameLength keeps generated names readable in tmux's status line and well under git's ref limits
+const DefaultMaxNameLength = 60
+
+// MaxNameLength returns the configured maximum worktree name length
+// It caps the worktree name only: the session name adds the project in front, e.g. "myapp/<name>"
+func MaxNameLength(naming *config.Naming) int {
+	if naming == nil || naming.MaxLength <= 0 {
+		return DefaultMaxNameLength
+	}
+	return naming.MaxLength
+}
+
+// TruncateName shortens name to at most max characters, replacing the end with a short hash
+// of the full name so long descriptions that share a prefix still get different names
+func TruncateName(name string, max int) string {
+	if len(name) <= max {
+		return name
+	}
+
+	sum := sha1.Sum([]byte(name))
+	suffix := hex.EncodeToString(sum[:])[:6]
+	if max <= len(suffix)+1 {
+		return suffix[:min(max, len(suffix))]
+	}
+	return strings.TrimRight(name[:max-len(suffix)-1], "-") + "-" + suffix
+}
+
+// ValidateBranchName checks that git accepts branch as a branch name
+func ValidateBranchName(branch string) error {
+	if err := runner.Run(Command("check-ref-format", "--branch", branch)); err != nil {
+		return fmt.Errorf("%q is not a valid branch name", branch)
+	}
+	return nil
+}
+
+// FindWorktree returns the worktree with the given name
+// Names are matched against the stable worktree id, then "parent/dir" or the full path,
+// then the directory name, which is an error if more than one worktree shares it
+func FindWorktree(name string) (*Worktree, error) {
 	worktrees, err := ListWorktrees()
 	if err != nil {
-		return "", err
+		return nil, err
 	}
 
-	for _, wt := range worktrees {
-		if GetWorktreeName(wt.Path) == name {
-			return wt.Path, nil
+	return findWorktree(worktrees, name)
+}
+
+func findWorktree(worktrees []Worktree, name string) (*Worktree, error) {
+	for i := range worktrees {
+		if worktrees[i].Name() == name {
+			return &worktrees[i], nil
+		}
+	}
+	for i := range worktrees {
+		if worktrees[i].Path == name || qualifiedName(worktrees[i].Path) == name {
+			return &worktrees[i], nil
+		}
+	}
+
+	var matches []*Worktree
+	for i := range worktrees {
+		if GetWorktreeName(worktrees[i].Path) == name {
+			matches = append(matches, &worktrees[i])
 		}
 	}
 
-	return "", fmt.Errorf("worktree %q not found", name)
+	switch len(matches) {
+	case 0:
+		return nil, fmt.Errorf("worktree %q not found", name)
+	case 1:
+		return matches[0], nil
+	}
+
+	candidates := make([]string, len(matches))
+	for i, wt := range matches {
+		candidates[i] = qualifiedName(wt.Path)
+	}
+	return nil, &AmbiguousError{Name: name, Candidates: candidates}
+}
+
+// GetWorktreePath returns the full path for a worktree by name
+func GetWorktreePath(name string) (string, error) {
+	wt, err := FindWorktree(name)
+	if err != nil {
+		return "", err
+	}
+	return wt.Path, nil
 }
 
 // GetCurrentWorktree returns the name of the current worktree, or empty string if not in a worktree
@@ -84,61 +343,299 @@ func GetCurrentWorktree() (string, error) {
 		return "", err
 	}
 
-	// Check if current directory is in any worktree
+	return worktreeContaining(worktrees, cwd), nil
+}
+
+// LastWorktree returns the worktree opened from lfg most recently before the current one, to go back to
+// it like cd -
+func LastWorktree() (string, error) {
+	mainPath, err := GetMainWorktreePath()
+	if err != nil {
+		return "", fmt.Errorf("failed to get main worktree: %w", err)
+	}
+	current, _ := GetCurrentWorktree()
+	name := NewMetaStore(mainPath).MostRecent(current)
+	if name == "" {
+		return "", fmt.Errorf("no other worktree has been opened from lfg yet")
+	}
+	return name, nil
+}
+
+// CurrentWorktreeIn returns the name of the worktree in a listing that contains the current directory,
+// or empty string if none does
+func CurrentWorktreeIn(worktrees []Worktree) string {
+	cwd, err := os.Getwd()
+	if err != nil {
+		return ""
+	}
+	return worktreeContaining(worktrees, cwd)
+}
+
+func worktreeContaining(worktrees []Worktree, dir string) string {
 	for _, wt := range worktrees {
-		// Check if cwd is the worktree path or a subdirectory of it
-		if cwd == wt.Path || strings.HasPrefix(cwd, wt.Path+string(filepath.Separator)) {
-			return GetWorktreeName(wt.Path), nil
+		// Check if dir is the worktree path or a subdirectory of it
+		if dir == wt.Path || strings.HasPrefix(dir, wt.Path+string(filepath.Separator)) {
+			return wt.Name()
 		}
 	}
-
-	return "", nil
+	return ""
 }
 
 // CreateWorktree creates a new git worktree in the parent directory of the repo root
 func CreateWorktree(name string) error {
+	return CreateWorktreeWithBranch(name, name)
+}
+
+// worktreePathFor returns where a new worktree called name goes: next to the repository
+func worktreePathFor(name string) (string, error) {
 	// Get the repository root
-	rootCmd := exec.Command("git", "rev-parse", "--show-toplevel")
-	rootOutput, err := rootCmd.Output()
+	rootCmd := Command("rev-parse", "--show-toplevel")
+	rootOutput, err := runner.Output(rootCmd)
 	if err != nil {
-		return fmt.Errorf("failed to get repo root: %w", err)
+		return "", fmt.Errorf("failed to get repo root: %w", err)
 	}
 	repoRoot := strings.TrimSpace(string(rootOutput))
 
-	// Get the parent directory
-	parentDir := filepath.Dir(repoRoot)
-
 	// Create worktree path in parent directory
-	worktreePath := filepath.Join(parentDir, name)
+	return filepath.Join(filepath.Dir(repoRoot), name), nil
+}
+
+// CreateWorktreeWithBranch creates a worktree directory called name on a new branch
+// The branch may differ from the directory, e.g. "feat/myapp-login" for "myapp-login"
+func CreateWorktreeWithBranch(name, branch string) error {
+	worktreePath, err := worktreePathFor(name)
+	if err != nil {
+		return err
+	}
 
 	// Create branch and worktree
-	cmd := exec.Command("git", "worktree", "add", "-b", name, worktreePath)
-	output, err := cmd.CombinedOutput()
+	cmd := Command("worktree", "add", "-b", branch, worktreePath)
+	output, err := runner.CombinedOutput(cmd)
 	if err != nil {
 		return fmt.Errorf("failed to create worktree: %s", string(output))
 	}
+
+	events.Emit(events.Event{Type: events.WorktreeCreated, Worktree: name, Path: worktreePath, Branch: branch})
 	return nil
 }
 
-// IsBranchMerged checks if a branch has been merged into the default branch
-func IsBranchMerged(branchName string) (bool, error) {
-	// Get the default branch
-	cmd := exec.Command("git", "symbolic-ref", "refs/remotes/origin/HEAD")
-	output, err := cmd.Output()
-	if err != nil {
-		// Fallback to master/main
-		cmd = exec.Command("git", "rev-parse", "--verify", "origin/main")
-		if cmd.Run() == nil {
-			output = []byte("refs/remotes/origin/main")
-		} else {
-			output = []byte("refs/remotes/origin/master")
+// SwitchBranch points an existing worktree at another branch, creating it off the default branch if it
+// doesn't exist yet
+// It refuses a worktree with uncommitted changes, which would otherwise be carried onto the new branch
+func SwitchBranch(path, branch string) error {
+	if err := EnsureNoOperation(path); err != nil {
+		return err
+	}
+
+	status, err := runner.Output(Command("-C", path, "status", "--porcelain"))
+	if err != nil {
+		return fmt.Errorf("failed to check worktree status: %w", err)
+	}
+	if len(bytes.TrimSpace(status)) > 0 {
+		return fmt.Errorf("%s has uncommitted changes, commit or stash them before switching branch", filepath.Base(path))
+	}
+
+	args := []string{"-C", path, "switch", branch}
+	if !BranchExists(branch) {
+		// Like a new worktree, the branch starts from the default branch rather than the old one
+		base := DefaultBranch()
+		if !BranchExists(base) {
+			base = DefaultBaseRef()
+		}
+		args = []string{"-C", path, "switch", "-c", branch, base}
+	}
+
+	cmd := Command(args...)
+	output, err := runner.CombinedOutput(cmd)
+	if err != nil {
+		return fmt.Errorf("failed to switch branch: %s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// CherryPick applies the last count commits of the worktree at srcPath onto the worktree at dstPath
+// Only commits dstPath doesn't have can be picked, so count can't reach back past where the two split
+// If a commit doesn't apply cleanly the cherry-pick is aborted, leaving dstPath untouched
+func CherryPick(srcPath, dstPath string, count int) error {
+	if count < 1 {
+		count = 1
+	}
+	if err := EnsureNoOperation(dstPath); err != nil {
+		return err
+	}
+
+	base, err := runner.Output(Command("-C", dstPath, "rev-parse", "HEAD"))
+	if err != nil {
+		return fmt.Errorf("failed to find the target's HEAD: %w", err)
+	}
+	cmd := Command("-C", srcPath, "rev-list", "--reverse", strings.TrimSpace(string(base))+"..HEAD")
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return fmt.Errorf("failed to list commits: %w", err)
+	}
+	commits := strings.Fields(string(output))
+	if len(commits) == 0 {
+		return fmt.Errorf("no commits to cherry-pick")
+	}
+	if count > len(commits) {
+		return fmt.Errorf("only the last %d commits aren't in the target, can't cherry-pick %d", len(commits), count)
+	}
+	commits = commits[len(commits)-count:]
+
+	cmd = Command(append([]string{"-C", dstPath, "cherry-pick"}, commits...)...)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		runner.Run(Command("-C", dstPath, "cherry-pick", "--abort"))
+		return fmt.Errorf("failed to cherry-pick: %s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// RebaseOnBase fetches a branch from origin and rebases the worktree onto it, e.g. when its pull request's
+// base has moved on. A rebase that hits conflicts is left stopped for them to be resolved
+// With autostash, uncommitted changes are stashed first and reapplied afterwards instead of stopping the rebase
+func RebaseOnBase(path, branch string, autostash bool) error {
+	if err := EnsureNoOperation(path); err != nil {
+		return err
+	}
+
+	// Without a remote, or offline, the copy already fetched (or the local branch) is used
+	runner.Run(Command("-C", path, "fetch", "origin", branch))
+
+	base := BaseRef(branch)
+	args := []string{"-C", path, "rebase", base}
+	if autostash {
+		args = append(args, "--autostash")
+	}
+	if output, err := runner.CombinedOutput(Command(args...)); err != nil {
+		if OperationInProgress(path) == IssueRebase {
+			return fmt.Errorf("rebase onto %s stopped with conflicts, resolve them in the worktree or abort it", base)
 		}
+		return fmt.Errorf("failed to rebase onto %s: %s", base, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// ApplyChanges applies the uncommitted changes (staged and unstaged) of the worktree at srcPath
+// to the worktree at dstPath, leaving srcPath as it was
+// Untracked files aren't included
+func ApplyChanges(srcPath, dstPath string) error {
+	if err := EnsureNoOperation(dstPath); err != nil {
+		return err
+	}
+
+	patch, err := runner.Output(Command("-C", srcPath, "diff", "--binary", "HEAD"))
+	if err != nil {
+		return fmt.Errorf("failed to diff worktree: %w", err)
+	}
+	if len(patch) == 0 {
+		return fmt.Errorf("no uncommitted changes to apply")
+	}
+
+	cmd := Command("-C", dstPath, "apply", "--3way")
+	cmd.Stdin = bytes.NewReader(patch)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to apply changes: %s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// MoveChanges moves the uncommitted changes (including untracked files) of the worktree at srcPath
+// to the worktree at dstPath via a stash, leaving srcPath clean
+// If the changes can't be applied they are restored to srcPath
+func MoveChanges(srcPath, dstPath string) error {
+	// Stashing mid-rebase or merge would take the half-resolved state with it
+	if err := EnsureNoOperation(srcPath); err != nil {
+		return err
+	}
+
+	status, err := runner.Output(Command("-C", srcPath, "status", "--porcelain"))
+	if err != nil {
+		return fmt.Errorf("failed to check worktree status: %w", err)
+	}
+	if len(bytes.TrimSpace(status)) == 0 {
+		return fmt.Errorf("no uncommitted changes to move")
 	}
-	defaultBranch := strings.TrimSpace(strings.TrimPrefix(string(output), "refs/remotes/"))
 
+	// The stash is shared between all worktrees of a repository
+	cmd := Command("-C", srcPath, "stash", "push", "--include-untracked", "-m", "lfg: move to "+filepath.Base(dstPath))
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to stash changes: %s", strings.TrimSpace(string(output)))
+	}
+
+	cmd = Command("-C", dstPath, "stash", "apply")
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		if restoreErr := runner.Run(Command("-C", srcPath, "stash", "pop")); restoreErr != nil {
+			return fmt.Errorf("failed to apply changes (they are kept in the stash): %s", strings.TrimSpace(string(output)))
+		}
+		return fmt.Errorf("failed to apply changes: %s", strings.TrimSpace(string(output)))
+	}
+
+	if err := runner.Run(Command("-C", srcPath, "stash", "drop")); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to drop stash: %v\n", err)
+	}
+	return nil
+}
+
+// commitTemplateFile is written to the worktree's git directory, so it isn't part of the checkout
+const commitTemplateFile = "lfg-commit-template"
+
+// DescribeBranch records a todo's description as the branch description (shown by tools such as
+// `git branch --edit-description` and used by `git request-pull`) and, when templates define one,
+// sets a commit message template for the worktree at path
+func DescribeBranch(path, branch, description string, templates *config.Templates) error {
+	cmd := Command("-C", path, "config", "branch."+branch+".description", description)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to set branch description: %s", strings.TrimSpace(string(output)))
+	}
+
+	template := templates.RenderCommitTemplate(description, branch)
+	if template == "" {
+		return nil
+	}
+	gitDir := worktreeGitDir(path)
+	if gitDir == "" {
+		return fmt.Errorf("failed to find git directory for %s", path)
+	}
+	templatePath := filepath.Join(gitDir, commitTemplateFile)
+	if err := os.WriteFile(templatePath, []byte(template), 0644); err != nil {
+		return fmt.Errorf("failed to write commit template: %w", err)
+	}
+
+	// commit.template is set in a file the repository config includes only while the branch is checked
+	// out, so other worktrees keep theirs without turning on git's per-worktree config extension
+	includePath := templatePath + ".config"
+	cmd = Command("config", "--file", includePath, "commit.template", templatePath)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to set commit template: %s", strings.TrimSpace(string(output)))
+	}
+	cmd = Command("-C", path, "config", "includeIf.onbranch:"+branch+".path", includePath)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to set commit template: %s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// PushBranch pushes a branch to origin and sets it as the upstream
+func PushBranch(branch string) error {
+	cmd := Command("push", "-u", "origin", branch)
+	output, err := runner.CombinedOutput(cmd)
+	if err != nil {
+		return fmt.Errorf("failed to push %s: %s", branch, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// BranchExists reports whether a local branch exists
+func BranchExists(branch string) bool {
+	cmd := Command("show-ref", "--verify", "--quiet", "refs/heads/"+branch)
+	return runner.Run(cmd) == nil
+}
+
+// IsBranchMerged checks if a branch has been merged into the default branch
+func IsBranchMerged(branchName string) (bool, error) {
 	// Check if branch is merged
-	cmd = exec.Command("git", "branch", "-r", "--merged", defaultBranch)
-	output, err = cmd.Output()
+	cmd := Command("branch", "-r", "--merged", DefaultBaseRef())
+	output, err := runner.Output(cmd)
 	if err != nil {
 		return false, err
 	}
@@ -157,19 +654,42 @@ func IsBranchMerged(branchName string) (bool, error) {
 
 // DeleteWorktree deletes a git worktree
 func DeleteWorktree(name string, deleteBranch bool) error {
+	return RemoveWorktree(name, RemoveOptions{DeleteBranch: deleteBranch})
+}
+
+// ForceDeleteWorktree deletes a git worktree even if it has uncommitted changes or untracked files
+func ForceDeleteWorktree(name string, deleteBranch bool) error {
+	return RemoveWorktree(name, RemoveOptions{Force: true, DeleteBranch: deleteBranch})
+}
+
+// RemoveOptions says what removing a worktree may override, each on its own
+type RemoveOptions struct {
+	Force        bool // Remove it even with uncommitted changes or untracked files
+	IgnoreLocked bool // Remove it even when it's locked with `git worktree lock`
+	DeleteBranch bool // Delete its branch too, whatever commits only it has
+}
+
+// RemoveWorktree deletes a git worktree, refusing to throw away changes or remove a locked worktree
+// unless opts allows it
+func RemoveWorktree(name string, opts RemoveOptions) error {
 	// Get the worktree path
-	worktreePath, err := GetWorktreePath(name)
+	wt, err := FindWorktree(name)
 	if err != nil {
 		// Worktree doesn't exist in git, just try to delete the branch
-		if deleteBranch {
-			cmd := exec.Command("git", "branch", "-D", name)
-			if err := cmd.Run(); err != nil {
+		if opts.DeleteBranch {
+			cmd := Command("branch", "-D", name)
+			if err := runner.Run(cmd); err != nil {
 				fmt.Fprintf(os.Stderr, "Warning: failed to delete branch %s\n", name)
 			}
 		}
 		return nil
 	}
 
+	// Removing a worktree mid-rebase loses the rebase state without cleaning it up
+	if err := EnsureNoOperation(wt.Path); err != nil {
+		return err
+	}
+
 	// Check if we're currently in the worktree being deleted
 	currentWorktree, err := GetCurrentWorktree()
 	if err == nil && currentWorktree == name {
@@ -183,19 +703,40 @@ func DeleteWorktree(name string, deleteBranch bool) error {
 		}
 	}
 
-	// Remove worktree using the full path
-	cmd := exec.Command("git", "worktree", "remove", worktreePath)
-	output, err := cmd.CombinedOutput()
+	// Remove worktree using the full path. git only removes a locked worktree when forced twice, which
+	// also throws away changes, so without Force the lock is lifted instead and put back on failure
+	args := []string{"worktree", "remove"}
+	if opts.Force {
+		args = append(args, "--force")
+	}
+	reason, locked := LockReason(wt.Path)
+	if locked && opts.IgnoreLocked {
+		if opts.Force {
+			args = append(args, "--force")
+		} else if output, err := runner.CombinedOutput(Command("worktree", "unlock", wt.Path)); err != nil {
+			return fmt.Errorf("failed to unlock worktree: %s", string(output))
+		}
+	}
+	output, err := runner.CombinedOutput(Command(append(args, wt.Path)...))
 	if err != nil {
+		if locked && opts.IgnoreLocked && !opts.Force {
+			_ = runner.Run(Command("worktree", "lock", "--reason", reason, wt.Path))
+		}
 		return fmt.Errorf("failed to remove worktree: %s", string(output))
 	}
 
-	// Delete branch if requested
-	if deleteBranch {
-		cmd = exec.Command("git", "branch", "-D", name)
-		if err := cmd.Run(); err != nil {
+	events.Emit(events.Event{Type: events.WorktreeDeleted, Worktree: name, Path: wt.Path, Branch: wt.BranchName()})
+
+	// Delete branch if requested (it may differ from the directory name)
+	if opts.DeleteBranch {
+		branch := wt.BranchName()
+		if branch == "" {
+			branch = name
+		}
+		cmd := Command("branch", "-D", branch)
+		if err := runner.Run(cmd); err != nil {
 			// Don't fail if branch deletion fails
-			fmt.Fprintf(os.Stderr, "Warning: failed to delete branch %s\n", name)
+			fmt.Fprintf(os.Stderr, "Warning: failed to delete branch %s\n", branch)
 		}
 	}
 
@@ -224,26 +765,68 @@ func GetMainWorktreePath() (string, error) {
 	return "", fmt.Errorf("no main worktree found")
 }
 
-// JumpToWorktree switches to a worktree by creating/attaching tmux session
+// Choices for opening a worktree whose rebase, merge or cherry-pick is unfinished
+const (
+	attachCancel = iota
+	attachShell  // Start the session with a single shell
+	attachFull   // Start the session with the full layout anyway
+)
+
+// confirmInterrupted asks how to open a worktree with unfinished work, opening just a shell when
+// there's no terminal to ask on
+var confirmInterrupted = func(name, trouble string) int {
+	answer, ok := terminal.Prompt(fmt.Sprintf("%s has a %s.\n"+
+		"Open just a [s]hell to fix it, the [f]ull layout anyway, or [c]ancel? [s] ", name, trouble))
+	if !ok {
+		return attachShell
+	}
+	switch answer {
+	case "", "s", "shell":
+		return attachShell
+	case "f", "full":
+		return attachFull
+	}
+	return attachCancel
+}
+
+// JumpToWorktree switches to a worktree by creating/attaching its tmux or zellij session
 func JumpToWorktree(name string, cfg *config.Config) error {
 	// Find worktree
-	worktrees, err := ListWorktrees()
+	wt, err := FindWorktree(name)
 	if err != nil {
 		return err
 	}
 
-	var targetPath string
-	for _, wt := range worktrees {
-		if GetWorktreeName(wt.Path) == name {
-			targetPath = wt.Path
-			break
+	// Pull request reviews get the reduced review layout
+	if _, ok := ReviewNumber(wt.Name()); ok {
+		cfg = cfg.ReviewProfile()
+	}
+
+	// Starting servers on a worktree mid-rebase or with conflicts only produces noise, so a new
+	// session can get just a shell to fix things in
+	multiplexer.AdoptLegacySessions(cfg.Name, wt.Name())
+	mux := multiplexer.Current()
+	if !mux.SessionExists(mux.SessionName(cfg.Name, wt.Name())) {
+		if trouble := InterruptedWork(wt.Path); trouble != "" {
+			switch confirmInterrupted(wt.DisplayName(), trouble) {
+			case attachShell:
+				cfg = cfg.ShellProfile()
+			case attachCancel:
+				return fmt.Errorf("didn't open %s: %s", wt.DisplayName(), trouble)
+			}
 		}
 	}
 
-	if targetPath == "" {
-		return fmt.Errorf("worktree '%s' not found", name)
+	// Recorded first, attaching only returns once the client detaches
+	if mainPath, err := GetMainWorktreePath(); err == nil {
+		if err := NewMetaStore(mainPath).SetLastAttached(wt.Name(), time.Now()); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: failed to record attaching to %s: %v\n", wt.DisplayName(), err)
+		}
+		if err := NewHistoryStore(mainPath).Visit(wt.Name()); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: failed to add %s to the history: %v\n", wt.DisplayName(), err)
+		}
 	}
 
-	// Create/attach tmux session
-	return tmux.CreateOrAttachSession(name, targetPath, cfg)
+	// Create/attach the session, keyed by the stable worktree id
+	return mux.CreateOrAttachSession(wt.Name(), wt.Path, cfg)
 }
@@ -1,9 +1,16 @@
 package git
 
 import (
+	"errors"
+	"fmt"
 	"os"
+	"os/exec"
 	"path/filepath"
+	"strings"
 	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
 )
 
 func TestGetWorktreeName(t *testing.T) {
@@ -39,6 +46,142 @@ func TestGetWorktreeName(t *testing.T) {
 	}
 }
 
+func TestWorktreeID(t *testing.T) {
+	tmpDir := t.TempDir()
+
+	// Main worktree: .git is a directory
+	mainPath := filepath.Join(tmpDir, "myapp")
+	if err := os.MkdirAll(filepath.Join(mainPath, ".git"), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	// Linked worktree whose directory name collides with another repo's, so git gave it a suffixed id
+	linkedPath := filepath.Join(tmpDir, "elsewhere", "fix-login")
+	if err := os.MkdirAll(linkedPath, 0755); err != nil {
+		t.Fatal(err)
+	}
+	gitFile := "gitdir: " + filepath.Join(mainPath, ".git", "worktrees", "fix-login1") + "\n"
+	if err := os.WriteFile(filepath.Join(linkedPath, ".git"), []byte(gitFile), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	if id := worktreeID(mainPath); id != "myapp" {
+		t.Errorf("worktreeID(main) = %q, want %q", id, "myapp")
+	}
+	if id := worktreeID(linkedPath); id != "fix-login1" {
+		t.Errorf("worktreeID(linked) = %q, want %q", id, "fix-login1")
+	}
+
+	wt := Worktree{Path: linkedPath, ID: "fix-login1"}
+	if wt.Name() != "fix-login1" {
+		t.Errorf("Name() = %q, want the worktree id", wt.Name())
+	}
+	if (Worktree{Path: linkedPath}).Name() != "fix-login" {
+		t.Error("Name() should fall back to the directory name without an id")
+	}
+}
+
+func TestFindWorktreeDuplicateBasenames(t *testing.T) {
+	worktrees := []Worktree{
+		{Path: "/code/myapp", ID: "myapp"},
+		{Path: "/code/client-a/api", ID: "api"},
+		{Path: "/code/client-b/api", ID: "api1"},
+		{Path: "/code/myapp-login", ID: "myapp-login"},
+	}
+	assignDisplayNames(worktrees)
+
+	displayNames := []string{"myapp", "client-a/api", "client-b/api", "myapp-login"}
+	for i, want := range displayNames {
+		if got := worktrees[i].DisplayName(); got != want {
+			t.Errorf("DisplayName(%q) = %q, want %q", worktrees[i].Path, got, want)
+		}
+	}
+
+	tests := []struct {
+		name     string
+		query    string
+		expected string
+		wantErr  bool
+	}{
+		{name: "unique basename", query: "myapp-login", expected: "/code/myapp-login"},
+		{name: "worktree id", query: "api1", expected: "/code/client-b/api"},
+		{name: "parent qualified", query: "client-a/api", expected: "/code/client-a/api"},
+		{name: "full path", query: "/code/client-b/api", expected: "/code/client-b/api"},
+		{name: "not found", query: "missing", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			wt, err := findWorktree(worktrees, tt.query)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("findWorktree(%q) expected error, got %q", tt.query, wt.Path)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("findWorktree(%q) unexpected error: %v", tt.query, err)
+			}
+			if wt.Path != tt.expected {
+				t.Errorf("findWorktree(%q) = %q, want %q", tt.query, wt.Path, tt.expected)
+			}
+		})
+	}
+
+	// Basename shared by two worktrees whose ids differ from it
+	ambiguous := []Worktree{
+		{Path: "/code/client-a/web", ID: "web1"},
+		{Path: "/code/client-b/web", ID: "web2"},
+	}
+	_, err := findWorktree(ambiguous, "web")
+	if err == nil {
+		t.Fatal("findWorktree(\"web\") expected an ambiguity error")
+	}
+	if !strings.Contains(err.Error(), "client-a/web") || !strings.Contains(err.Error(), "client-b/web") {
+		t.Errorf("ambiguity error should list candidates, got %q", err.Error())
+	}
+}
+
+func TestCommand(t *testing.T) {
+	defer Configure(nil)
+	t.Setenv("LFG_GIT", "")
+	t.Setenv("LFG_GIT_ARGS", "")
+
+	Configure(&config.Git{
+		Executable: "/opt/git/bin/git",
+		Args:       []string{"-c", "protocol.version=2"},
+		Env:        map[string]string{"GIT_SSH_COMMAND": "ssh -i ~/.ssh/deploy"},
+	})
+
+	cmd := Command("status")
+	if cmd.Path != "/opt/git/bin/git" {
+		t.Errorf("Expected configured executable, got %q", cmd.Path)
+	}
+	if got := strings.Join(cmd.Args[1:], " "); got != "-c protocol.version=2 status" {
+		t.Errorf("Expected global args before the command, got %q", got)
+	}
+	found := false
+	for _, env := range cmd.Env {
+		if env == "GIT_SSH_COMMAND=ssh -i ~/.ssh/deploy" {
+			found = true
+		}
+	}
+	if !found {
+		t.Error("Expected configured environment to be set")
+	}
+
+	// Environment variables take precedence over the config
+	t.Setenv("LFG_GIT", "/usr/local/bin/git")
+	t.Setenv("LFG_GIT_ARGS", "-c core.fsmonitor=false")
+	cmd = Command("status")
+	if cmd.Path != "/usr/local/bin/git" {
+		t.Errorf("Expected LFG_GIT to override the executable, got %q", cmd.Path)
+	}
+	if got := strings.Join(cmd.Args[1:], " "); got != "-c core.fsmonitor=false status" {
+		t.Errorf("Expected LFG_GIT_ARGS to override the global args, got %q", got)
+	}
+}
+
 func TestGetCurrentWorktree(t *testing.T) {
 	// This test is skipped if not in a git repository
 	_, err := os.Stat(".git")
@@ -64,3 +207,546 @@ func TestGetCurrentWorktree(t *testing.T) {
 		t.Logf("Current worktree: %q (expected %q or empty)", worktreeName, expectedName)
 	}
 }
+
+func TestGenerateWorktreeName(t *testing.T) {
+	tests := []struct {
+		name        string
+		project     string
+		description string
+		expected    string
+	}{
+		{
+			name:        "simple description",
+			project:     "myapp",
+			description: "Add login page",
+			expected:    "myapp-add-login-page",
+		},
+		{
+			name:        "special characters removed",
+			project:     "myapp",
+			description: "Fix bug #123 (urgent!)",
+			expected:    "myapp-fix-bug-123-urgent",
+		},
+		{
+			name:        "consecutive and trailing dashes collapsed",
+			project:     "myapp",
+			description: "  Refactor -- config  ",
+			expected:    "myapp-refactor-config",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			result := GenerateWorktreeName(tt.project, tt.description, nil)
+			if result != tt.expected {
+				t.Errorf("GenerateWorktreeName(%q, %q) = %q, want %q", tt.project, tt.description, result, tt.expected)
+			}
+		})
+	}
+}
+
+func TestGenerateWorktreeNameWithNaming(t *testing.T) {
+	naming := &config.Naming{
+		StopWords: []string{"the", "a", "Implement"},
+		Abbreviations: map[string]string{
+			"authentication": "auth",
+			"Configuration":  "config",
+			"kubernetes":     "K8s / cluster",
+		},
+	}
+
+	tests := []struct {
+		name        string
+		description string
+		expected    string
+	}{
+		{
+			name:        "stop words dropped",
+			description: "Implement the login page",
+			expected:    "myapp-login-page",
+		},
+		{
+			name:        "abbreviations applied",
+			description: "Fix authentication configuration",
+			expected:    "myapp-fix-auth-config",
+		},
+		{
+			name:        "abbreviations cleaned like the rest of the name",
+			description: "Deploy to Kubernetes",
+			expected:    "myapp-deploy-to-k8scluster",
+		},
+		{
+			name:        "only stop words keeps original words",
+			description: "The a",
+			expected:    "myapp-the-a",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			result := GenerateWorktreeName("myapp", tt.description, naming)
+			if result != tt.expected {
+				t.Errorf("GenerateWorktreeName(%q) = %q, want %q", tt.description, result, tt.expected)
+			}
+		})
+	}
+}
+
+// newTestRepo creates a repository with one commit and a linked worktree on branch "feature"
+func newTestRepo(t *testing.T) (mainPath, worktreePath string) {
+	t.Helper()
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git not installed, skipping test")
+	}
+
+	t.Setenv("GIT_AUTHOR_NAME", "lfg")
+	t.Setenv("GIT_AUTHOR_EMAIL", "lfg@example.com")
+	t.Setenv("GIT_COMMITTER_NAME", "lfg")
+	t.Setenv("GIT_COMMITTER_EMAIL", "lfg@example.com")
+
+	tmpDir := t.TempDir()
+	mainPath = filepath.Join(tmpDir, "myapp")
+	worktreePath = filepath.Join(tmpDir, "myapp-feature")
+
+	runGit(t, tmpDir, "init", "-q", mainPath)
+	writeFile(t, filepath.Join(mainPath, "README"), "hello\n")
+	runGit(t, mainPath, "add", "README")
+	runGit(t, mainPath, "commit", "-q", "-m", "initial")
+	runGit(t, mainPath, "worktree", "add", "-q", "-b", "feature", worktreePath)
+
+	return mainPath, worktreePath
+}
+
+func runGit(t *testing.T, dir string, args ...string) string {
+	t.Helper()
+	cmd := exec.Command("git", args...)
+	cmd.Dir = dir
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		t.Fatalf("git %s: %v\n%s", strings.Join(args, " "), err, output)
+	}
+	return string(output)
+}
+
+func writeFile(t *testing.T, path, content string) {
+	t.Helper()
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func readFile(t *testing.T, path string) string {
+	t.Helper()
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return string(data)
+}
+
+func TestCherryPick(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+
+	// Commit made in the wrong worktree
+	writeFile(t, filepath.Join(mainPath, "fix.txt"), "fix\n")
+	runGit(t, mainPath, "add", "fix.txt")
+	runGit(t, mainPath, "commit", "-q", "-m", "fix")
+
+	// Commits the target already has can't be picked again
+	if err := CherryPick(mainPath, worktreePath, 2); err == nil {
+		t.Error("CherryPick() of more commits than the target lacks error = nil")
+	}
+	if err := CherryPick(mainPath, worktreePath, 1); err != nil {
+		t.Fatalf("CherryPick() error = %v", err)
+	}
+
+	if got := readFile(t, filepath.Join(worktreePath, "fix.txt")); got != "fix\n" {
+		t.Errorf("fix.txt = %q, want %q", got, "fix\n")
+	}
+	if subject := strings.TrimSpace(runGit(t, worktreePath, "log", "-1", "--format=%s")); subject != "fix" {
+		t.Errorf("latest commit = %q, want %q", subject, "fix")
+	}
+}
+
+func TestRebaseOnBase(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+	base := strings.TrimSpace(runGit(t, mainPath, "branch", "--show-current"))
+
+	writeFile(t, filepath.Join(worktreePath, "feature.txt"), "feature\n")
+	runGit(t, worktreePath, "add", "feature.txt")
+	runGit(t, worktreePath, "commit", "-q", "-m", "feature")
+	for _, file := range []string{"one.txt", "two.txt"} {
+		writeFile(t, filepath.Join(mainPath, file), file+"\n")
+		runGit(t, mainPath, "add", file)
+		runGit(t, mainPath, "commit", "-q", "-m", file)
+	}
+
+	if d, err := CompareToBase(worktreePath, base); err != nil || d != (Divergence{Ahead: 1, Behind: 2}) {
+		t.Fatalf("CompareToBase() = %+v, %v, want 1 ahead and 2 behind", d, err)
+	}
+
+	// Uncommitted changes stop the rebase unless they're stashed around it
+	writeFile(t, filepath.Join(worktreePath, "README"), "changed\n")
+	if err := RebaseOnBase(worktreePath, base, false); err == nil {
+		t.Fatal("RebaseOnBase() with uncommitted changes should fail without autostash")
+	}
+
+	// There's no origin to fetch from, so it rebases onto the local branch
+	if err := RebaseOnBase(worktreePath, base, true); err != nil {
+		t.Fatalf("RebaseOnBase() error = %v", err)
+	}
+	if d, err := CompareToBase(worktreePath, base); err != nil || d != (Divergence{Ahead: 1}) {
+		t.Errorf("CompareToBase() after rebasing = %+v, %v, want 1 ahead", d, err)
+	}
+	if got := readFile(t, filepath.Join(worktreePath, "README")); got != "changed\n" {
+		t.Errorf("README after rebasing = %q, want the uncommitted change reapplied", got)
+	}
+}
+
+func TestApplyChanges(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+
+	if err := ApplyChanges(mainPath, worktreePath); err == nil {
+		t.Error("ApplyChanges() with a clean worktree should fail")
+	}
+
+	writeFile(t, filepath.Join(mainPath, "README"), "hello\nworld\n")
+
+	if err := ApplyChanges(mainPath, worktreePath); err != nil {
+		t.Fatalf("ApplyChanges() error = %v", err)
+	}
+
+	if got := readFile(t, filepath.Join(worktreePath, "README")); got != "hello\nworld\n" {
+		t.Errorf("README in target = %q, want the applied change", got)
+	}
+	if got := readFile(t, filepath.Join(mainPath, "README")); got != "hello\nworld\n" {
+		t.Errorf("README in source = %q, should be left as it was", got)
+	}
+}
+
+func TestMoveChanges(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+
+	if err := MoveChanges(mainPath, worktreePath); err == nil {
+		t.Error("MoveChanges() with a clean worktree should fail")
+	}
+
+	writeFile(t, filepath.Join(mainPath, "README"), "hello\nmess\n")
+	writeFile(t, filepath.Join(mainPath, "new.txt"), "untracked\n")
+
+	if err := MoveChanges(mainPath, worktreePath); err != nil {
+		t.Fatalf("MoveChanges() error = %v", err)
+	}
+
+	if got := readFile(t, filepath.Join(worktreePath, "README")); got != "hello\nmess\n" {
+		t.Errorf("README in target = %q, want the moved change", got)
+	}
+	if got := readFile(t, filepath.Join(worktreePath, "new.txt")); got != "untracked\n" {
+		t.Errorf("new.txt in target = %q, want the untracked file", got)
+	}
+	if status := runGit(t, mainPath, "status", "--porcelain"); status != "" {
+		t.Errorf("source worktree should be clean, got status %q", status)
+	}
+	if stash := runGit(t, mainPath, "stash", "list"); stash != "" {
+		t.Errorf("stash should be empty after the move, got %q", stash)
+	}
+}
+
+func TestSwitchBranch(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+	chdir(t, mainPath)
+	defaultBranch.name = ""
+
+	writeFile(t, filepath.Join(worktreePath, "README"), "feature\n")
+	runGit(t, worktreePath, "commit", "-q", "-am", "feature change")
+
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "wip\n")
+	if err := SwitchBranch(worktreePath, "other"); err == nil {
+		t.Fatal("SwitchBranch() with uncommitted changes should fail")
+	}
+	if got := strings.TrimSpace(runGit(t, worktreePath, "branch", "--show-current")); got != "feature" {
+		t.Errorf("branch after refusing = %q, want feature", got)
+	}
+
+	runGit(t, worktreePath, "clean", "-qf")
+	if err := SwitchBranch(worktreePath, "other"); err != nil {
+		t.Fatalf("SwitchBranch() error = %v", err)
+	}
+	if got := strings.TrimSpace(runGit(t, worktreePath, "branch", "--show-current")); got != "other" {
+		t.Errorf("branch after switching = %q, want other", got)
+	}
+	// The new branch starts from the default branch, not the feature it replaced
+	if got := readFile(t, filepath.Join(worktreePath, "README")); got != "hello\n" {
+		t.Errorf("README on the new branch = %q, want the default branch's", got)
+	}
+}
+
+func TestIsBranchMerged(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch args[1] {
+		case "symbolic-ref":
+			return []byte("refs/remotes/origin/main\n"), nil
+		case "branch":
+			return []byte("  origin/main\n  origin/myapp-login\n  origin/feat/myapp-profile\n"), nil
+		}
+		return nil, fmt.Errorf("unexpected command %v", args)
+	}}
+	defer runner.Set(fake)()
+
+	tests := []struct {
+		branch   string
+		expected bool
+	}{
+		{branch: "myapp-login", expected: true},
+		{branch: "feat/myapp-profile", expected: true},
+		{branch: "myapp-unmerged", expected: false},
+	}
+
+	for _, tt := range tests {
+		merged, err := IsBranchMerged(tt.branch)
+		if err != nil {
+			t.Fatalf("IsBranchMerged(%q) error = %v", tt.branch, err)
+		}
+		if merged != tt.expected {
+			t.Errorf("IsBranchMerged(%q) = %v, want %v", tt.branch, merged, tt.expected)
+		}
+	}
+
+	last := fake.Calls[len(fake.Calls)-1]
+	if strings.Join(last[1:], " ") != "branch -r --merged origin/main" {
+		t.Errorf("Expected merged branches to be listed against origin/main, got %v", last)
+	}
+}
+
+func TestGenerateWorktreeNameMaxLength(t *testing.T) {
+	long := "Refactor the billing reconciliation pipeline so that it retries failed webhook deliveries"
+	other := "Refactor the billing reconciliation pipeline so that it batches invoice exports nightly"
+
+	name := GenerateWorktreeName("myapp", long, nil)
+	if len(name) > DefaultMaxNameLength {
+		t.Errorf("GenerateWorktreeName() = %q (%d chars), want at most %d", name, len(name), DefaultMaxNameLength)
+	}
+	if !strings.HasPrefix(name, "myapp-refactor-the-billing") {
+		t.Errorf("Expected the readable prefix to be kept, got %q", name)
+	}
+	if name == GenerateWorktreeName("myapp", other, nil) {
+		t.Errorf("Expected descriptions sharing a prefix to get different names, both got %q", name)
+	}
+	if name != GenerateWorktreeName("myapp", long, nil) {
+		t.Error("Expected shortened names to be deterministic")
+	}
+
+	short := GenerateWorktreeName("myapp", long, &config.Naming{MaxLength: 20})
+	if len(short) > 20 {
+		t.Errorf("GenerateWorktreeName() with max_length 20 = %q (%d chars)", short, len(short))
+	}
+	if !IsNameShortened("myapp", long, &config.Naming{MaxLength: 20}) {
+		t.Error("IsNameShortened() = false for a name cut to max_length")
+	}
+
+	// A name exactly max_length long is kept as it is
+	exact := "Billing retries"
+	if name := GenerateWorktreeName("myapp", exact, &config.Naming{MaxLength: 21}); name != "myapp-billing-retries" {
+		t.Errorf("GenerateWorktreeName(%q) with max_length 21 = %q, want it unchanged", exact, name)
+	}
+	if IsNameShortened("myapp", exact, &config.Naming{MaxLength: 21}) {
+		t.Error("IsNameShortened() = true for a name exactly max_length long")
+	}
+}
+
+func TestTruncateName(t *testing.T) {
+	tests := []struct {
+		name   string
+		input  string
+		max    int
+		length int
+	}{
+		{name: "short enough", input: "myapp-login", max: 20, length: 11},
+		{name: "exact length", input: "myapp-login", max: 11, length: 11},
+		{name: "shortened", input: "myapp-fix-the-login-page", max: 16, length: 16},
+		{name: "tiny max", input: "myapp-login", max: 4, length: 4},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			result := TruncateName(tt.input, tt.max)
+			if len(result) != tt.length {
+				t.Errorf("TruncateName(%q, %d) = %q, want %d chars", tt.input, tt.max, result, tt.length)
+			}
+			if strings.Contains(result, "--") {
+				t.Errorf("TruncateName(%q, %d) = %q, should not contain a double dash", tt.input, tt.max, result)
+			}
+		})
+	}
+}
+
+func TestPushBranch(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		if strings.Join(args[1:], " ") != "push -u origin feat/myapp-login" {
+			return nil, fmt.Errorf("unexpected command %v", args)
+		}
+		return []byte("fatal: 'origin' does not appear to be a git repository\n"), fmt.Errorf("exit status 128")
+	}}
+	defer runner.Set(fake)()
+
+	err := PushBranch("feat/myapp-login")
+	if err == nil || !strings.Contains(err.Error(), "does not appear to be a git repository") {
+		t.Errorf("PushBranch() error = %v, want git's output", err)
+	}
+}
+
+func TestDescribeBranch(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+	templates := &config.Templates{CommitTemplate: "\n\n# {description} ({branch})\n"}
+
+	if err := DescribeBranch(worktreePath, "feature", "Fix flaky login spec", templates); err != nil {
+		t.Fatalf("DescribeBranch() error = %v", err)
+	}
+
+	if got := strings.TrimSpace(runGit(t, mainPath, "config", "branch.feature.description")); got != "Fix flaky login spec" {
+		t.Errorf("branch description = %q, want %q", got, "Fix flaky login spec")
+	}
+
+	templatePath := strings.TrimSpace(runGit(t, worktreePath, "config", "commit.template"))
+	if got := readFile(t, templatePath); got != "\n\n# Fix flaky login spec (feature)\n" {
+		t.Errorf("commit template = %q", got)
+	}
+
+	// The template only applies to the worktree it was written for
+	if out, err := exec.Command("git", "-C", mainPath, "config", "commit.template").Output(); err == nil {
+		t.Errorf("main worktree commit.template = %q, want unset", strings.TrimSpace(string(out)))
+	}
+	// without changing the repository format for other tools
+	if out, err := exec.Command("git", "-C", mainPath, "config", "extensions.worktreeConfig").Output(); err == nil {
+		t.Errorf("extensions.worktreeConfig = %q, want unset", strings.TrimSpace(string(out)))
+	}
+}
+
+func TestParseWorktrees(t *testing.T) {
+	output := "worktree /src/myapp\nHEAD abc123\nbranch refs/heads/main\n\nworktree /src/myapp-login\nHEAD def456\ndetached\n\n"
+
+	got := parseWorktrees(output)
+	want := []Worktree{
+		{Path: "/src/myapp", Commit: "abc123", Branch: "refs/heads/main"},
+		{Path: "/src/myapp-login", Commit: "def456", Detached: true},
+	}
+	if len(got) != len(want) {
+		t.Fatalf("parseWorktrees() = %+v, want %+v", got, want)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Errorf("parseWorktrees()[%d] = %+v, want %+v", i, got[i], want[i])
+		}
+	}
+}
+
+// chdir changes the working directory for the rest of the test
+func chdir(t *testing.T, dir string) {
+	t.Helper()
+	wd, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chdir(dir); err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { os.Chdir(wd) })
+}
+
+func TestForceDeleteWorktree(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	mainPath, worktreePath := newTestRepo(t)
+	chdir(t, mainPath)
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "work in progress\n")
+
+	if err := DeleteWorktree(worktreePath, false); err == nil {
+		t.Fatal("DeleteWorktree() removed a worktree with untracked files")
+	}
+	if err := ForceDeleteWorktree(worktreePath, true); err != nil {
+		t.Fatalf("ForceDeleteWorktree() error = %v", err)
+	}
+	if _, err := os.Stat(worktreePath); !os.IsNotExist(err) {
+		t.Errorf("worktree still exists at %s", worktreePath)
+	}
+	if output := runGit(t, mainPath, "branch", "--list", "feature"); strings.TrimSpace(output) != "" {
+		t.Errorf("branch feature still exists")
+	}
+}
+
+func TestRemoveLockedWorktree(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	mainPath, worktreePath := newTestRepo(t)
+	chdir(t, mainPath)
+	runGit(t, mainPath, "worktree", "lock", "--reason", "on a USB drive", worktreePath)
+
+	if reason, locked := LockReason(worktreePath); !locked || reason != "on a USB drive" {
+		t.Errorf("LockReason() = %q, %v, want the lock's reason", reason, locked)
+	}
+	if err := RemoveWorktree(worktreePath, RemoveOptions{}); err == nil {
+		t.Fatal("RemoveWorktree() removed a locked worktree")
+	}
+
+	// Ignoring the lock still keeps uncommitted work, and the lock
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "work in progress\n")
+	if err := RemoveWorktree(worktreePath, RemoveOptions{IgnoreLocked: true}); err == nil {
+		t.Fatal("RemoveWorktree() removed a worktree with untracked files without Force")
+	}
+	if _, locked := LockReason(worktreePath); !locked {
+		t.Error("RemoveWorktree() left the worktree unlocked after failing")
+	}
+
+	if err := RemoveWorktree(worktreePath, RemoveOptions{Force: true, IgnoreLocked: true}); err != nil {
+		t.Fatalf("RemoveWorktree() error = %v", err)
+	}
+	if _, err := os.Stat(worktreePath); !os.IsNotExist(err) {
+		t.Errorf("worktree still exists at %s", worktreePath)
+	}
+}
+
+func TestResolveWorktree(t *testing.T) {
+	worktrees := []Worktree{
+		{Path: "/code/myapp", ID: "myapp", Branch: "refs/heads/main"},
+		{Path: "/code/myapp-auth-tokens", ID: "myapp-auth-tokens", Branch: "refs/heads/feat/auth-tokens"},
+		{Path: "/code/myapp-billing", ID: "myapp-billing", Branch: "refs/heads/billing"},
+		{Path: "/code/myapp-billing-v2", ID: "myapp-billing-v2", Branch: "refs/heads/billing-v2"},
+	}
+
+	tests := []struct {
+		name      string
+		query     string
+		expected  string
+		ambiguous bool
+		wantErr   bool
+	}{
+		{name: "exact name", query: "myapp-billing", expected: "/code/myapp-billing"},
+		{name: "substring", query: "auth", expected: "/code/myapp-auth-tokens"},
+		{name: "letters in order", query: "atok", expected: "/code/myapp-auth-tokens"},
+		{name: "branch", query: "feat/auth", expected: "/code/myapp-auth-tokens"},
+		{name: "ambiguous", query: "billing", ambiguous: true},
+		{name: "no match", query: "zzz", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			wt, err := resolveWorktree(worktrees, tt.query)
+			var ambiguous *AmbiguousError
+			if got := errors.As(err, &ambiguous); got != tt.ambiguous {
+				t.Fatalf("resolveWorktree(%q) error = %v, want ambiguous %v", tt.query, err, tt.ambiguous)
+			}
+			if tt.ambiguous || tt.wantErr {
+				if err == nil {
+					t.Errorf("resolveWorktree(%q) expected error, got %q", tt.query, wt.Path)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("resolveWorktree(%q) unexpected error: %v", tt.query, err)
+			}
+			if wt.Path != tt.expected {
+				t.Errorf("resolveWorktree(%q) = %q, want %q", tt.query, wt.Path, tt.expected)
+			}
+		})
+	}
+}
@@ -0,0 +1,225 @@
+package git
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// Health issue kinds
+const (
+	IssueMissing    = "missing"
+	IssueDetached   = "detached"
+	IssueRebase     = "rebase"
+	IssueMerge      = "merge"
+	IssueCherryPick = "cherry-pick"
+	IssueConflicts  = "conflicts"
+	IssueSubmodules = "submodules"
+)
+
+// HealthIssue is a broken or unusual state of a worktree
+type HealthIssue struct {
+	Kind    string
+	Summary string // e.g. "rebase in progress"
+	Fix     string // How to fix it by hand
+	Remedy  string // What Remediate does, empty if it can't be fixed automatically
+}
+
+// CheckHealth returns the problems found in a worktree, or nil if it looks healthy
+func CheckHealth(wt Worktree) []HealthIssue {
+	if _, err := os.Stat(wt.Path); os.IsNotExist(err) {
+		return []HealthIssue{{
+			Kind:    IssueMissing,
+			Summary: "worktree directory is missing",
+			Fix:     "Restore the directory, or run `git worktree prune` to forget it",
+			Remedy:  "git worktree prune",
+		}}
+	}
+
+	var issues []HealthIssue
+
+	if op := OperationInProgress(wt.Path); op != "" {
+		issues = append(issues, HealthIssue{
+			Kind:    op,
+			Summary: op + " in progress",
+			Fix:     fmt.Sprintf("Finish it with `git %s --continue`, or abort it", op),
+			Remedy:  fmt.Sprintf("git %s --abort", op),
+		})
+	}
+
+	if wt.Detached {
+		issues = append(issues, HealthIssue{
+			Kind:    IssueDetached,
+			Summary: "HEAD is detached",
+			Fix:     "Create a branch for the current commit, or switch to an existing branch",
+			Remedy:  fmt.Sprintf("git switch -c %s", wt.Name()),
+		})
+	}
+
+	if files := conflictedFiles(wt.Path); len(files) > 0 {
+		issues = append(issues, HealthIssue{
+			Kind:    IssueConflicts,
+			Summary: fmt.Sprintf("%d conflicted file(s)", len(files)),
+			Fix:     "Resolve the conflicts in " + strings.Join(files, ", ") + " and `git add` them",
+		})
+	}
+
+	if brokenSubmodules(wt.Path) {
+		issues = append(issues, HealthIssue{
+			Kind:    IssueSubmodules,
+			Summary: "submodules are not initialized",
+			Fix:     "Run `git submodule update --init --recursive`",
+			Remedy:  "git submodule update --init --recursive",
+		})
+	}
+
+	return issues
+}
+
+// Remediate applies an issue's remedy to the worktree
+func Remediate(wt Worktree, issue HealthIssue) error {
+	var args []string
+	switch issue.Kind {
+	case IssueMissing:
+		args = []string{"worktree", "prune"}
+	case IssueRebase, IssueMerge, IssueCherryPick:
+		return AbortOperation(wt.Path)
+	case IssueDetached:
+		args = []string{"-C", wt.Path, "switch", "-c", wt.Name()}
+	case IssueSubmodules:
+		args = []string{"-C", wt.Path, "submodule", "update", "--init", "--recursive"}
+	default:
+		return fmt.Errorf("%s can't be fixed automatically", issue.Summary)
+	}
+
+	cmd := Command(args...)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to run git %s: %s", strings.Join(args, " "), strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// OperationInProgressError is returned when a worktree can't be changed because a rebase,
+// merge or cherry-pick hasn't been finished
+type OperationInProgressError struct {
+	Operation string
+	Path      string
+}
+
+func (e *OperationInProgressError) Error() string {
+	return fmt.Sprintf("%s in progress in %s, finish it or abort it with `git %s --abort` first", e.Operation, filepath.Base(e.Path), e.Operation)
+}
+
+// EnsureNoOperation returns an *OperationInProgressError if the worktree is mid-rebase, merge or cherry-pick
+func EnsureNoOperation(path string) error {
+	if op := OperationInProgress(path); op != "" {
+		return &OperationInProgressError{Operation: op, Path: path}
+	}
+	return nil
+}
+
+// AbortOperation aborts the rebase, merge or cherry-pick in progress in a worktree
+func AbortOperation(path string) error {
+	op := OperationInProgress(path)
+	if op == "" {
+		return nil
+	}
+
+	cmd := Command("-C", path, op, "--abort")
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to abort %s: %s", op, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// OperationInProgress returns "rebase", "merge" or "cherry-pick" if the worktree is in the
+// middle of one, or "" otherwise
+func OperationInProgress(path string) string {
+	gitDir := worktreeGitDir(path)
+	if gitDir == "" {
+		return ""
+	}
+
+	exists := func(name string) bool {
+		_, err := os.Stat(filepath.Join(gitDir, name))
+		return err == nil
+	}
+
+	switch {
+	case exists("rebase-merge") || exists("rebase-apply"):
+		return IssueRebase
+	case exists("MERGE_HEAD"):
+		return IssueMerge
+	case exists("CHERRY_PICK_HEAD"):
+		return IssueCherryPick
+	}
+	return ""
+}
+
+// worktreeGitDir returns the worktree's own git directory: .git for the main worktree,
+// .git/worktrees/<id> for linked ones
+func worktreeGitDir(path string) string {
+	if path == "" {
+		return ""
+	}
+	dotGit := filepath.Join(path, ".git")
+	info, err := os.Stat(dotGit)
+	if err != nil {
+		return ""
+	}
+	if info.IsDir() {
+		return dotGit
+	}
+
+	data, err := os.ReadFile(dotGit)
+	if err != nil {
+		return ""
+	}
+	gitDir := strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(string(data)), "gitdir:"))
+	if !filepath.IsAbs(gitDir) {
+		gitDir = filepath.Join(path, gitDir)
+	}
+	return gitDir
+}
+
+// InterruptedWork describes an unfinished rebase, merge or cherry-pick and any conflicted files in a
+// worktree, e.g. "rebase in progress, 2 conflicted file(s)", or returns "" when there's neither
+func InterruptedWork(path string) string {
+	var parts []string
+	if op := OperationInProgress(path); op != "" {
+		parts = append(parts, op+" in progress")
+	}
+	if files := conflictedFiles(path); len(files) > 0 {
+		parts = append(parts, fmt.Sprintf("%d conflicted file(s)", len(files)))
+	}
+	return strings.Join(parts, ", ")
+}
+
+func conflictedFiles(path string) []string {
+	output, err := runner.Output(Command("-C", path, "diff", "--name-only", "--diff-filter=U"))
+	if err != nil {
+		return nil
+	}
+	return strings.Fields(string(output))
+}
+
+// brokenSubmodules reports whether any submodule is uninitialized ("-") or conflicted ("U")
+func brokenSubmodules(path string) bool {
+	if _, err := os.Stat(filepath.Join(path, ".gitmodules")); err != nil {
+		return false
+	}
+
+	output, err := runner.Output(Command("-C", path, "submodule", "status"))
+	if err != nil {
+		return true
+	}
+	for _, line := range strings.Split(string(output), "\n") {
+		if strings.HasPrefix(line, "-") || strings.HasPrefix(line, "U") {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,123 @@
+package git
+
+import (
+	"errors"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func issueKinds(issues []HealthIssue) map[string]bool {
+	kinds := make(map[string]bool)
+	for _, issue := range issues {
+		kinds[issue.Kind] = true
+	}
+	return kinds
+}
+
+func TestCheckHealthMissing(t *testing.T) {
+	issues := CheckHealth(Worktree{Path: filepath.Join(t.TempDir(), "gone")})
+	if len(issues) != 1 || issues[0].Kind != IssueMissing {
+		t.Errorf("Expected a single missing issue, got %+v", issues)
+	}
+}
+
+func TestCheckHealthMergeConflict(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+
+	if issues := CheckHealth(Worktree{Path: worktreePath}); len(issues) != 0 {
+		t.Fatalf("Expected a fresh worktree to be healthy, got %+v", issues)
+	}
+
+	writeFile(t, filepath.Join(mainPath, "README"), "main\n")
+	runGit(t, mainPath, "commit", "-q", "-am", "main change")
+	writeFile(t, filepath.Join(worktreePath, "README"), "feature\n")
+	runGit(t, worktreePath, "commit", "-q", "-am", "feature change")
+
+	// Expected to fail with a conflict
+	mainBranch := strings.TrimSpace(runGit(t, mainPath, "branch", "--show-current"))
+	exec.Command("git", "-C", worktreePath, "merge", mainBranch).Run()
+
+	wt := Worktree{Path: worktreePath}
+	kinds := issueKinds(CheckHealth(wt))
+	if !kinds[IssueMerge] || !kinds[IssueConflicts] {
+		t.Fatalf("Expected merge and conflict issues, got %v", kinds)
+	}
+
+	if err := Remediate(wt, HealthIssue{Kind: IssueMerge}); err != nil {
+		t.Fatalf("Remediate(merge) error = %v", err)
+	}
+	if issues := CheckHealth(wt); len(issues) != 0 {
+		t.Errorf("Expected worktree to be healthy after aborting the merge, got %+v", issues)
+	}
+}
+
+func TestCheckHealthDetached(t *testing.T) {
+	_, worktreePath := newTestRepo(t)
+	runGit(t, worktreePath, "switch", "-q", "--detach")
+
+	wt := Worktree{Path: worktreePath, ID: "myapp-feature", Detached: true}
+	if !issueKinds(CheckHealth(wt))[IssueDetached] {
+		t.Fatal("Expected a detached HEAD issue")
+	}
+
+	if err := Remediate(wt, HealthIssue{Kind: IssueDetached}); err != nil {
+		t.Fatalf("Remediate(detached) error = %v", err)
+	}
+	if branch := runGit(t, worktreePath, "branch", "--show-current"); branch != "myapp-feature\n" {
+		t.Errorf("Expected a branch named after the worktree, got %q", branch)
+	}
+}
+
+func TestRemediateConflictsNotAutomatic(t *testing.T) {
+	if err := Remediate(Worktree{Path: t.TempDir()}, HealthIssue{Kind: IssueConflicts, Summary: "1 conflicted file(s)"}); err == nil {
+		t.Error("Expected conflicts to require manual resolution")
+	}
+}
+
+func TestOperationInProgressBlocksChanges(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+
+	writeFile(t, filepath.Join(mainPath, "README"), "main\n")
+	runGit(t, mainPath, "commit", "-q", "-am", "main change")
+	writeFile(t, filepath.Join(worktreePath, "README"), "feature\n")
+	runGit(t, worktreePath, "commit", "-q", "-am", "feature change")
+
+	// Expected to stop with a conflict
+	exec.Command("git", "-C", worktreePath, "rebase", strings.TrimSpace(runGit(t, mainPath, "branch", "--show-current"))).Run()
+	if op := OperationInProgress(worktreePath); op != IssueRebase {
+		t.Fatalf("OperationInProgress() = %q, want %q", op, IssueRebase)
+	}
+
+	err := SwitchBranch(worktreePath, "other")
+	var blocked *OperationInProgressError
+	if !errors.As(err, &blocked) || blocked.Operation != IssueRebase {
+		t.Fatalf("SwitchBranch() during a rebase = %v, want an OperationInProgressError", err)
+	}
+
+	if err := AbortOperation(worktreePath); err != nil {
+		t.Fatalf("AbortOperation() error = %v", err)
+	}
+	if err := EnsureNoOperation(worktreePath); err != nil {
+		t.Errorf("EnsureNoOperation() after abort = %v, want nil", err)
+	}
+}
+
+func TestInterruptedWork(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+	if trouble := InterruptedWork(worktreePath); trouble != "" {
+		t.Errorf("InterruptedWork() on a clean worktree = %q, want none", trouble)
+	}
+
+	writeFile(t, filepath.Join(mainPath, "README"), "main\n")
+	runGit(t, mainPath, "commit", "-q", "-am", "main change")
+	writeFile(t, filepath.Join(worktreePath, "README"), "feature\n")
+	runGit(t, worktreePath, "commit", "-q", "-am", "feature change")
+
+	// Expected to stop with a conflict
+	exec.Command("git", "-C", worktreePath, "rebase", strings.TrimSpace(runGit(t, mainPath, "branch", "--show-current"))).Run()
+	if trouble, want := InterruptedWork(worktreePath), "rebase in progress, 1 conflicted file(s)"; trouble != want {
+		t.Errorf("InterruptedWork() = %q, want %q", trouble, want)
+	}
+}
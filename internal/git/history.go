@@ -0,0 +1,107 @@
+package git
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+// maxHistory is how many attached worktrees the history keeps, dropping the oldest first
+const maxHistory = 50
+
+// AttachHistory is the worktrees whose sessions were opened from lfg, oldest first, and the one being
+// looked at, so they can be stepped back and forth through like a browser's history
+type AttachHistory struct {
+	Entries  []string `json:"entries,omitempty"`
+	Position int      `json:"position"`
+}
+
+// Current returns the worktree the history is at, "" when it's empty
+func (h AttachHistory) Current() string {
+	if h.Position < 0 || h.Position >= len(h.Entries) {
+		return ""
+	}
+	return h.Entries[h.Position]
+}
+
+// visit records opening a worktree: the entries after the current one are dropped, as a browser drops
+// the pages ahead of the one a link is followed from. Opening the current entry again changes nothing,
+// which is what stepping through the history does
+func (h *AttachHistory) visit(name string) {
+	if h.Current() == name {
+		return
+	}
+	if h.Position >= 0 && h.Position < len(h.Entries) {
+		h.Entries = h.Entries[:h.Position+1]
+	}
+	h.Entries = append(h.Entries, name)
+	if len(h.Entries) > maxHistory {
+		h.Entries = h.Entries[len(h.Entries)-maxHistory:]
+	}
+	h.Position = len(h.Entries) - 1
+}
+
+// step moves back (negative delta) or forward through the history, skipping worktrees that no longer
+// exist, and returns the worktree it moved to, false when there is none that way
+func (h *AttachHistory) step(delta int, exists func(string) bool) (string, bool) {
+	for i := h.Position + delta; i >= 0 && i < len(h.Entries); i += delta {
+		if exists(h.Entries[i]) {
+			h.Position = i
+			return h.Entries[i], true
+		}
+	}
+	return "", false
+}
+
+// HistoryStore keeps a repository's AttachHistory in the data dir, so it carries over between the
+// popups lfg is usually run in
+type HistoryStore struct {
+	path string
+}
+
+// NewHistoryStore returns the attach history of the repository whose main worktree is at repo
+func NewHistoryStore(repo string) *HistoryStore {
+	store := &HistoryStore{}
+	if dir, err := config.DataDir(); err == nil {
+		store.path = filepath.Join(dir, "history", cacheName(repo))
+	}
+	return store
+}
+
+// Load returns the recorded history, empty when there is none
+func (s *HistoryStore) Load() AttachHistory {
+	var history AttachHistory
+	if s.path == "" {
+		return history
+	}
+	if data, err := os.ReadFile(s.path); err == nil {
+		_ = json.Unmarshal(data, &history)
+	}
+	return history
+}
+
+// Visit records that a worktree's session was opened
+func (s *HistoryStore) Visit(name string) error {
+	if s.path == "" {
+		return nil
+	}
+	history := s.Load()
+	history.visit(name)
+	return writeCacheFile(s.path, history)
+}
+
+// Step moves back (negative delta) or forward through the history to the nearest worktree that still
+// exists and returns it, "" when there is none that way
+func (s *HistoryStore) Step(delta int, exists func(string) bool) (string, error) {
+	if s.path == "" {
+		return "", nil
+	}
+	history := s.Load()
+	name, ok := history.step(delta, exists)
+	if !ok {
+		return "", nil
+	}
+	return name, writeCacheFile(s.path, history)
+}
@@ -0,0 +1,49 @@
+package git
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestAttachHistory(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	store := NewHistoryStore("/src/myapp")
+	exists := func(name string) bool { return name != "gone" }
+
+	for _, name := range []string{"main", "login", "gone", "login", "review-42"} {
+		if err := store.Visit(name); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if got := store.Load(); strings.Join(got.Entries, ",") != "main,login,gone,login,review-42" || got.Current() != "review-42" {
+		t.Fatalf("history = %+v", got)
+	}
+
+	// Back skips worktrees that were deleted since
+	for _, want := range []string{"login", "login", "main", ""} {
+		got, err := store.Step(-1, exists)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if got != want {
+			t.Fatalf("Step(-1) = %q, want %q", got, want)
+		}
+	}
+
+	// Opening the entry stepped to keeps what's ahead, opening another drops it
+	if err := store.Visit("main"); err != nil {
+		t.Fatal(err)
+	}
+	if got, _ := store.Step(1, exists); got != "login" {
+		t.Fatalf("Step(1) = %q, want login", got)
+	}
+	if err := store.Visit("billing"); err != nil {
+		t.Fatal(err)
+	}
+	if got := store.Load(); strings.Join(got.Entries, ",") != "main,login,billing" {
+		t.Errorf("after visiting from the middle, entries = %v", got.Entries)
+	}
+	if got, _ := store.Step(1, exists); got != "" {
+		t.Errorf("Step(1) at the end = %q, want none", got)
+	}
+}
@@ -0,0 +1,161 @@
+package git
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+// WorktreeMeta is what lfg remembers about a worktree on this machine, as opposed to the todos and
+// settings shared through the config
+type WorktreeMeta struct {
+	LastAttached   time.Time `json:"last_attached,omitzero"`     // When its session was last opened from lfg
+	PullRequestURL string    `json:"pull_request_url,omitempty"` // Pull request opened for its branch
+	ChecksTrusted  bool      `json:"checks_trusted,omitempty"`   // check_command may run in it although it's a review worktree
+}
+
+// MetaStore keeps each worktree's WorktreeMeta of a repository in the data dir, keyed by worktree name
+type MetaStore struct {
+	path string
+}
+
+// NewMetaStore returns the metadata store of the repository whose main worktree is at repo
+func NewMetaStore(repo string) *MetaStore {
+	store := &MetaStore{}
+	if dir, err := config.DataDir(); err == nil {
+		store.path = filepath.Join(dir, "meta", cacheName(repo))
+	}
+	return store
+}
+
+func (s *MetaStore) load() map[string]WorktreeMeta {
+	entries := make(map[string]WorktreeMeta)
+	if data, err := os.ReadFile(s.path); err == nil {
+		_ = json.Unmarshal(data, &entries)
+	}
+	return entries
+}
+
+// change reads the entries, applies fn and writes them back, holding the store's lock throughout
+func (s *MetaStore) change(fn func(entries map[string]WorktreeMeta)) error {
+	if err := os.MkdirAll(filepath.Dir(s.path), 0755); err != nil {
+		return fmt.Errorf("failed to create cache directory: %w", err)
+	}
+	unlock, err := config.Lock(s.path)
+	if err != nil {
+		return err
+	}
+	defer unlock()
+
+	entries := s.load()
+	fn(entries)
+	return writeCacheFile(s.path, entries)
+}
+
+// Get returns the metadata of the worktree with the given name, empty when nothing was recorded
+func (s *MetaStore) Get(name string) WorktreeMeta {
+	if s.path == "" {
+		return WorktreeMeta{}
+	}
+	return s.load()[name]
+}
+
+// All returns the metadata recorded for each worktree, by name
+func (s *MetaStore) All() map[string]WorktreeMeta {
+	if s.path == "" {
+		return map[string]WorktreeMeta{}
+	}
+	return s.load()
+}
+
+// Update changes the metadata of the worktree with the given name and saves it
+func (s *MetaStore) Update(name string, change func(*WorktreeMeta)) error {
+	if s.path == "" {
+		return nil
+	}
+	return s.change(func(entries map[string]WorktreeMeta) {
+		meta := entries[name]
+		change(&meta)
+		if meta == (WorktreeMeta{}) {
+			delete(entries, name)
+		} else {
+			entries[name] = meta
+		}
+	})
+}
+
+// Forget drops everything recorded about deleted worktrees
+func (s *MetaStore) Forget(names ...string) error {
+	if s.path == "" {
+		return nil
+	}
+	if _, err := os.Stat(s.path); err != nil {
+		return nil
+	}
+	return s.change(func(entries map[string]WorktreeMeta) {
+		for _, name := range names {
+			delete(entries, name)
+		}
+	})
+}
+
+// Rename moves what was recorded about a worktree to its new name
+func (s *MetaStore) Rename(from, to string) error {
+	if s.path == "" {
+		return nil
+	}
+	return s.change(func(entries map[string]WorktreeMeta) {
+		if meta, ok := entries[from]; ok {
+			delete(entries, from)
+			entries[to] = meta
+		}
+	})
+}
+
+// LastAttached returns when the worktree's session was last opened from lfg, zero if never
+func (s *MetaStore) LastAttached(name string) time.Time {
+	return s.Get(name).LastAttached
+}
+
+// SetLastAttached records when the worktree's session was opened
+func (s *MetaStore) SetLastAttached(name string, t time.Time) error {
+	return s.Update(name, func(meta *WorktreeMeta) { meta.LastAttached = t })
+}
+
+// MostRecent returns the worktree whose session was opened from lfg last, leaving out skip (such as the
+// worktree lfg runs in), or "" when no other was opened
+func (s *MetaStore) MostRecent(skip string) string {
+	var recent string
+	var latest time.Time
+	for name, meta := range s.All() {
+		if name != skip && meta.LastAttached.After(latest) {
+			recent, latest = name, meta.LastAttached
+		}
+	}
+	return recent
+}
+
+// PullRequestURL returns the pull request recorded for the worktree's branch, empty if none
+func (s *MetaStore) PullRequestURL(name string) string {
+	return s.Get(name).PullRequestURL
+}
+
+// SetPullRequestURL records the pull request opened for the worktree's branch, empty to clear it
+func (s *MetaStore) SetPullRequestURL(name, url string) error {
+	return s.Update(name, func(meta *WorktreeMeta) { meta.PullRequestURL = url })
+}
+
+// ChecksTrusted reports whether check_command may run in a pull request review worktree, which holds
+// someone else's code and so is only checked once you've said so
+func (s *MetaStore) ChecksTrusted(name string) bool {
+	return s.Get(name).ChecksTrusted
+}
+
+// SetChecksTrusted allows or stops check_command runs in a review worktree
+func (s *MetaStore) SetChecksTrusted(name string, trusted bool) error {
+	return s.Update(name, func(meta *WorktreeMeta) { meta.ChecksTrusted = trusted })
+}
@@ -0,0 +1,61 @@
+package git
+
+import (
+	"testing"
+	"time"
+)
+
+func TestMetaStore(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+
+	store := NewMetaStore("/src/myapp")
+	if got := store.Get("api"); got != (WorktreeMeta{}) {
+		t.Fatalf("Get() before recording = %+v, want empty", got)
+	}
+
+	attached := time.Date(2025, 3, 1, 9, 30, 0, 0, time.UTC)
+	if err := store.SetLastAttached("api", attached); err != nil {
+		t.Fatalf("SetLastAttached() error = %v", err)
+	}
+	if err := store.SetPullRequestURL("api", "https://github.com/acme/myapp/pull/7"); err != nil {
+		t.Fatalf("SetPullRequestURL() error = %v", err)
+	}
+	if err := store.SetLastAttached("web", attached.Add(time.Hour)); err != nil {
+		t.Fatalf("SetLastAttached() error = %v", err)
+	}
+
+	// Another instance sees what was recorded
+	reopened := NewMetaStore("/src/myapp")
+	if got := reopened.LastAttached("api"); !got.Equal(attached) {
+		t.Errorf("LastAttached() = %v, want %v", got, attached)
+	}
+	if got := reopened.PullRequestURL("api"); got != "https://github.com/acme/myapp/pull/7" {
+		t.Errorf("PullRequestURL() = %q", got)
+	}
+	if got := len(reopened.All()); got != 2 {
+		t.Errorf("All() has %d worktrees, want 2", got)
+	}
+	if got := NewMetaStore("/src/other").Get("api"); got != (WorktreeMeta{}) {
+		t.Errorf("Get() for another repo = %+v, want empty", got)
+	}
+	if got := reopened.MostRecent(""); got != "web" {
+		t.Errorf("MostRecent() = %q, want web", got)
+	}
+	if got := reopened.MostRecent("web"); got != "api" {
+		t.Errorf("MostRecent() skipping web = %q, want api", got)
+	}
+
+	// Clearing every field drops the entry, as does forgetting the worktree
+	if err := reopened.SetPullRequestURL("web", ""); err != nil {
+		t.Fatal(err)
+	}
+	if err := reopened.Update("web", func(meta *WorktreeMeta) { meta.LastAttached = time.Time{} }); err != nil {
+		t.Fatal(err)
+	}
+	if err := reopened.Forget("api"); err != nil {
+		t.Fatalf("Forget() error = %v", err)
+	}
+	if got := store.All(); len(got) != 0 {
+		t.Errorf("All() after forgetting = %+v, want empty", got)
+	}
+}
@@ -0,0 +1,155 @@
+package git
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+)
+
+// Backends for reading the repository, set with git.backend
+const (
+	BackendExec   = "exec"   // Run git for everything, the default
+	BackendNative = "native" // Read worktree listings from the .git directory, running git when that isn't possible
+)
+
+// unbornCommit is what git reports as the commit of a branch with no commits yet
+const unbornCommit = "0000000000000000000000000000000000000000"
+
+// readWorktrees lists the worktrees of the repository containing dir by reading its .git directory
+// instead of running `git worktree list`, which adds up on every refresh in large repositories
+// The main worktree comes first, then the linked ones sorted by path, the order git lists them in
+// Layouts it doesn't handle, e.g. bare repositories or reftable refs, return an error so git can be asked instead
+func readWorktrees(dir string) ([]Worktree, error) {
+	if os.Getenv("GIT_DIR") != "" {
+		return nil, fmt.Errorf("GIT_DIR is set")
+	}
+	gitDir, err := findGitDir(dir)
+	if err != nil {
+		return nil, err
+	}
+	commonDir := gitDir
+	if data, err := os.ReadFile(filepath.Join(gitDir, "commondir")); err == nil {
+		commonDir = resolvePath(gitDir, strings.TrimSpace(string(data)))
+	}
+	if filepath.Base(commonDir) != ".git" {
+		return nil, fmt.Errorf("unsupported repository layout at %s", commonDir)
+	}
+
+	main := Worktree{Path: filepath.Dir(commonDir)}
+	if err := readHead(commonDir, commonDir, &main); err != nil {
+		return nil, err
+	}
+
+	var linked []Worktree
+	entries, err := os.ReadDir(filepath.Join(commonDir, "worktrees"))
+	if err != nil && !os.IsNotExist(err) {
+		return nil, err
+	}
+	for _, entry := range entries {
+		adminDir := filepath.Join(commonDir, "worktrees", entry.Name())
+		data, err := os.ReadFile(filepath.Join(adminDir, "gitdir"))
+		if err != nil {
+			continue
+		}
+		wt := Worktree{Path: filepath.Dir(resolvePath(adminDir, strings.TrimSpace(string(data))))}
+		if err := readHead(adminDir, commonDir, &wt); err != nil {
+			return nil, err
+		}
+		linked = append(linked, wt)
+	}
+	sort.Slice(linked, func(i, j int) bool { return linked[i].Path < linked[j].Path })
+
+	worktrees := append([]Worktree{main}, linked...)
+	for i := range worktrees {
+		worktrees[i].ID = worktreeID(worktrees[i].Path)
+	}
+	return worktrees, nil
+}
+
+// findGitDir returns the git directory of the worktree containing dir, following the .git file of
+// linked worktrees
+func findGitDir(dir string) (string, error) {
+	dir, err := filepath.Abs(dir)
+	if err != nil {
+		return "", err
+	}
+	for {
+		path := filepath.Join(dir, ".git")
+		info, err := os.Stat(path)
+		if err == nil && info.IsDir() {
+			return path, nil
+		}
+		if err == nil {
+			data, err := os.ReadFile(path)
+			if err != nil {
+				return "", err
+			}
+			target, ok := strings.CutPrefix(strings.TrimSpace(string(data)), "gitdir:")
+			if !ok {
+				return "", fmt.Errorf("unrecognised .git file at %s", path)
+			}
+			return resolvePath(dir, strings.TrimSpace(target)), nil
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return "", fmt.Errorf("not a git repository")
+		}
+		dir = parent
+	}
+}
+
+// readHead fills in a worktree's branch and commit from the HEAD in its git directory
+func readHead(gitDir, commonDir string, wt *Worktree) error {
+	data, err := os.ReadFile(filepath.Join(gitDir, "HEAD"))
+	if err != nil {
+		return err
+	}
+	head := strings.TrimSpace(string(data))
+	ref, ok := strings.CutPrefix(head, "ref: ")
+	if !ok {
+		wt.Commit = head
+		wt.Detached = true
+		return nil
+	}
+	if ref == "refs/heads/.invalid" {
+		return fmt.Errorf("reftable refs aren't supported")
+	}
+
+	wt.Branch = ref
+	wt.Commit, err = resolveRef(commonDir, ref)
+	return err
+}
+
+// resolveRef returns the commit a branch points at, from its loose ref file or packed-refs
+func resolveRef(commonDir, ref string) (string, error) {
+	if data, err := os.ReadFile(filepath.Join(commonDir, filepath.FromSlash(ref))); err == nil {
+		commit := strings.TrimSpace(string(data))
+		if strings.HasPrefix(commit, "ref: ") {
+			return "", fmt.Errorf("symbolic ref %s isn't supported", ref)
+		}
+		return commit, nil
+	}
+
+	data, err := os.ReadFile(filepath.Join(commonDir, "packed-refs"))
+	if err != nil && !os.IsNotExist(err) {
+		return "", err
+	}
+	for _, line := range strings.Split(string(data), "\n") {
+		if commit, name, ok := strings.Cut(line, " "); ok && name == ref && !strings.HasPrefix(line, "#") {
+			return commit, nil
+		}
+	}
+	// A branch with no commits yet
+	return unbornCommit, nil
+}
+
+// resolvePath resolves a path read from a file in dir, which git may have written relative to it
+func resolvePath(dir, path string) string {
+	if filepath.IsAbs(path) {
+		return filepath.Clean(path)
+	}
+	return filepath.Join(dir, path)
+}
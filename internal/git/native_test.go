@@ -0,0 +1,88 @@
+package git
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestReadWorktrees(t *testing.T) {
+	mainPath, worktreePath := newTestRepo(t)
+	detachedPath := filepath.Join(filepath.Dir(mainPath), "myapp-detached")
+	runGit(t, mainPath, "worktree", "add", "-q", "--detach", detachedPath)
+	subdir := filepath.Join(worktreePath, "app")
+	if err := os.Mkdir(subdir, 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	listed := func() []Worktree {
+		worktrees := parseWorktrees(runGit(t, mainPath, "worktree", "list", "--porcelain"))
+		for i := range worktrees {
+			worktrees[i].ID = worktreeID(worktrees[i].Path)
+		}
+		return worktrees
+	}
+
+	for _, dir := range []string{mainPath, subdir} {
+		got, err := readWorktrees(dir)
+		if err != nil {
+			t.Fatalf("readWorktrees(%s) error = %v", dir, err)
+		}
+		if expected := listed(); !reflect.DeepEqual(got, expected) {
+			t.Errorf("readWorktrees(%s) = %+v, want what git lists: %+v", dir, got, expected)
+		}
+	}
+
+	// Branches packed by gc are read from packed-refs
+	runGit(t, mainPath, "pack-refs", "--all")
+	if got, err := readWorktrees(mainPath); err != nil || !reflect.DeepEqual(got, listed()) {
+		t.Errorf("readWorktrees() with packed refs = %+v, %v, want %+v", got, err, listed())
+	}
+
+	bare := filepath.Join(t.TempDir(), "bare.git")
+	runGit(t, mainPath, "clone", "-q", "--bare", mainPath, bare)
+	if _, err := readWorktrees(bare); err == nil {
+		t.Error("readWorktrees() of a bare repository should fail so git is asked instead")
+	}
+}
+
+func TestBackends(t *testing.T) {
+	t.Cleanup(func() { settings = nil })
+	chdir(t, t.TempDir())
+
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch command := strings.Join(args, " "); {
+		case strings.Contains(command, "worktree list"):
+			return []byte(porcelainListing(2)), nil
+		case strings.Contains(command, "status --porcelain -b"):
+			return []byte("## main...origin/main [ahead 1]\n M app.go\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	for _, backend := range []string{BackendExec, BackendNative} {
+		Configure(&config.Git{Backend: backend})
+
+		// Outside a repository the native backend can't read .git, so it asks git like exec does
+		worktrees, err := ListWorktrees()
+		if err != nil || len(worktrees) != 2 || worktrees[1].Name() != "myapp-feature-1" {
+			t.Errorf("%s: ListWorktrees() = %+v, %v, want the two worktrees git lists", backend, worktrees, err)
+		}
+
+		status := readStatus(worktrees[1], "", time.Now())
+		if !status.Dirty || status.Unpushed != 1 {
+			t.Errorf("%s: readStatus() = %+v, want dirty and one commit to push", backend, status)
+		}
+		report, err := CheckSafety(worktrees[1])
+		if err != nil || report.Modified != 1 {
+			t.Errorf("%s: CheckSafety() = %+v, %v, want one modified file and the branch header skipped", backend, report, err)
+		}
+	}
+}
@@ -0,0 +1,122 @@
+package git
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// RenameWorktree moves a worktree's directory to newName next to it with `git worktree move`, and
+// renames its branch when the branch is named after the worktree, e.g. "feat/<name>"
+// Git's id for the worktree is renamed too, as it is the name todos and sessions are linked by.
+// It returns the worktree as it is after the rename
+func RenameWorktree(wt Worktree, newName string) (Worktree, error) {
+	oldName := wt.Name()
+	if err := ValidateWorktreeName(newName); err != nil {
+		return wt, err
+	}
+	newPath := filepath.Join(filepath.Dir(wt.Path), newName)
+	if _, err := os.Stat(newPath); err == nil {
+		return wt, fmt.Errorf("%s already exists", newPath)
+	}
+
+	branch := wt.BranchName()
+	newBranch := branch
+	if prefix, ok := strings.CutSuffix(branch, oldName); ok && branch != "" {
+		newBranch = prefix + newName
+		if BranchExists(newBranch) {
+			return wt, fmt.Errorf("branch %s already exists", newBranch)
+		}
+	}
+
+	// A rebase or merge in progress keeps state under the worktree's git directory, which is about to move
+	if err := EnsureNoOperation(wt.Path); err != nil {
+		return wt, err
+	}
+
+	// Moving the directory lfg runs in would leave it nowhere
+	if current, err := GetCurrentWorktree(); err == nil && current == oldName {
+		mainPath, err := GetMainWorktreePath()
+		if err != nil {
+			return wt, fmt.Errorf("failed to get main worktree: %w", err)
+		}
+		if err := os.Chdir(mainPath); err != nil {
+			return wt, fmt.Errorf("failed to change to main worktree: %w", err)
+		}
+	}
+
+	if output, err := runner.CombinedOutput(Command("worktree", "move", wt.Path, newPath)); err != nil {
+		return wt, fmt.Errorf("failed to move worktree: %s", strings.TrimSpace(string(output)))
+	}
+	if err := renameWorktreeID(newPath, newName); err != nil {
+		return wt, err
+	}
+
+	if newBranch != branch {
+		if output, err := runner.CombinedOutput(Command("branch", "-m", branch, newBranch)); err != nil {
+			return wt, fmt.Errorf("failed to rename branch %s: %s", branch, strings.TrimSpace(string(output)))
+		}
+	}
+
+	renamed := Worktree{Path: newPath, Branch: wt.Branch, Commit: wt.Commit, ID: worktreeID(newPath)}
+	if newBranch != branch {
+		renamed.Branch = "refs/heads/" + newBranch
+	}
+	events.Emit(events.Event{Type: events.WorktreeRenamed, Worktree: renamed.Name(), From: oldName, Path: newPath, Branch: renamed.BranchName()})
+	return renamed, nil
+}
+
+// ValidateWorktreeName checks a worktree name is usable as a directory name next to the others, so it
+// can't reach outside the worktrees directory
+func ValidateWorktreeName(name string) error {
+	switch {
+	case strings.TrimSpace(name) == "":
+		return fmt.Errorf("worktree name cannot be empty")
+	case strings.ContainsAny(name, `/\`), name == ".", name == "..":
+		return fmt.Errorf("%q is not a valid worktree name", name)
+	}
+	return nil
+}
+
+// renameWorktreeID renames git's administrative directory for a linked worktree under .git/worktrees
+// to name, and points the worktree's .git file at it. It is left alone when another worktree has that id
+func renameWorktreeID(path, name string) error {
+	dotGit := filepath.Join(path, ".git")
+	data, err := os.ReadFile(dotGit)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", dotGit, err)
+	}
+	gitDir := strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(string(data)), "gitdir:"))
+	relative := !filepath.IsAbs(gitDir)
+	if relative {
+		gitDir = filepath.Join(path, gitDir)
+	}
+	if filepath.Base(gitDir) == name {
+		return nil
+	}
+
+	renamed := filepath.Join(filepath.Dir(gitDir), name)
+	if _, err := os.Stat(renamed); err == nil {
+		return nil
+	}
+	if err := os.Rename(gitDir, renamed); err != nil {
+		return fmt.Errorf("failed to rename worktree id: %w", err)
+	}
+
+	link := renamed
+	if relative {
+		if rel, err := filepath.Rel(path, renamed); err == nil {
+			link = rel
+		}
+	}
+	if err := os.WriteFile(dotGit, []byte("gitdir: "+link+"\n"), 0644); err != nil {
+		// Put the id back so the worktree still works
+		os.Rename(renamed, gitDir)
+		return fmt.Errorf("failed to update %s: %w", dotGit, err)
+	}
+	return nil
+}
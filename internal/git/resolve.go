@@ -0,0 +1,75 @@
+package git
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+// AmbiguousError is returned when a name matches more than one worktree equally well
+type AmbiguousError struct {
+	Name       string
+	Candidates []string
+}
+
+func (e *AmbiguousError) Error() string {
+	return fmt.Sprintf("worktree %q is ambiguous, use one of: %s", e.Name, strings.Join(e.Candidates, ", "))
+}
+
+// ResolveWorktree finds a worktree like FindWorktree, falling back to fuzzy matching the query
+// against worktree names and branches when no worktree has that name
+func ResolveWorktree(query string) (*Worktree, error) {
+	worktrees, err := ListWorktrees()
+	if err != nil {
+		return nil, err
+	}
+
+	return resolveWorktree(worktrees, query)
+}
+
+func resolveWorktree(worktrees []Worktree, query string) (*Worktree, error) {
+	wt, err := findWorktree(worktrees, query)
+	var ambiguous *AmbiguousError
+	if err == nil || errors.As(err, &ambiguous) {
+		return wt, err
+	}
+
+	type match struct {
+		worktree *Worktree
+		score    int
+	}
+	var matches []match
+	for i := range worktrees {
+		best := 0
+		for _, text := range []string{worktrees[i].Name(), worktrees[i].BranchName()} {
+			if score, ok := config.FuzzyScore(query, text); ok {
+				best = max(best, score)
+			}
+		}
+		if best > 0 {
+			matches = append(matches, match{worktree: &worktrees[i], score: best})
+		}
+	}
+	sort.SliceStable(matches, func(i, j int) bool {
+		return matches[i].score > matches[j].score
+	})
+
+	switch {
+	case len(matches) == 0:
+		return nil, err
+	case len(matches) == 1, matches[0].score-matches[1].score >= config.ConfidentMargin:
+		return matches[0].worktree, nil
+	}
+
+	// Only the matches close to the best are worth offering
+	var candidates []string
+	for _, m := range matches {
+		if matches[0].score-m.score < config.ConfidentMargin {
+			candidates = append(candidates, m.worktree.Name())
+		}
+	}
+	return nil, &AmbiguousError{Name: query, Candidates: candidates}
+}
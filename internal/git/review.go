@@ -0,0 +1,60 @@
+package git
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// reviewPrefix starts the name of every worktree created to review a pull request
+const reviewPrefix = "review-"
+
+// ReviewName returns the worktree (and branch) name for reviewing a pull request
+func ReviewName(number int) string {
+	return reviewPrefix + strconv.Itoa(number)
+}
+
+// ReviewNumber returns the pull request a review worktree was created for, or false if it isn't one
+func ReviewNumber(name string) (int, bool) {
+	rest, ok := strings.CutPrefix(name, reviewPrefix)
+	if !ok {
+		return 0, false
+	}
+	number, err := strconv.Atoi(rest)
+	if err != nil || number <= 0 {
+		return 0, false
+	}
+	return number, true
+}
+
+// CreateReviewWorktree checks out a pull request's head into a review-<number> worktree
+// If the worktree already exists it is left as it is, so work in progress on a review isn't lost
+func CreateReviewWorktree(number int) (string, error) {
+	name := ReviewName(number)
+	if _, err := FindWorktree(name); err == nil {
+		return name, nil
+	}
+
+	// GitHub exposes every pull request head, including ones from forks, as refs/pull/<n>/head
+	cmd := Command("fetch", "origin", fmt.Sprintf("pull/%d/head", number))
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return "", fmt.Errorf("failed to fetch pull request #%d: %s", number, strings.TrimSpace(string(output)))
+	}
+
+	path, err := worktreePathFor(name)
+	if err != nil {
+		return "", err
+	}
+
+	// -B resets a branch left behind by an earlier review of the same pull request
+	cmd = Command("worktree", "add", "-B", name, path, "FETCH_HEAD")
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return "", fmt.Errorf("failed to create worktree: %s", string(output))
+	}
+
+	events.Emit(events.Event{Type: events.WorktreeCreated, Worktree: name, Path: path, Branch: name})
+	return name, nil
+}
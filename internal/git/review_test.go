@@ -0,0 +1,27 @@
+package git
+
+import (
+	"testing"
+)
+
+func TestReviewNumber(t *testing.T) {
+	tests := []struct {
+		name     string
+		expected int
+		ok       bool
+	}{
+		{name: ReviewName(42), expected: 42, ok: true},
+		{name: "review-0", ok: false},
+		{name: "review-login", ok: false},
+		{name: "myapp-review-42", ok: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			number, ok := ReviewNumber(tt.name)
+			if ok != tt.ok || number != tt.expected {
+				t.Errorf("ReviewNumber(%q) = %d, %v, want %d, %v", tt.name, number, ok, tt.expected, tt.ok)
+			}
+		})
+	}
+}
@@ -0,0 +1,100 @@
+package git
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// SafetyReport summarizes what deleting a worktree would throw away
+type SafetyReport struct {
+	Modified   int    // Tracked files with uncommitted changes
+	Untracked  int    // Untracked files, not counting ignored ones
+	Unpushed   int    // Commits not on the upstream, or on no other branch when there is no upstream
+	NoUpstream bool   // The branch has never been pushed
+	Stashes    int    // Stash entries made on the branch
+	Locked     bool   // Locked with `git worktree lock`, so git won't remove it
+	LockReason string // Why it was locked, if a reason was given
+}
+
+// Safe reports whether nothing would be lost by deleting the worktree and its branch
+func (r *SafetyReport) Safe() bool {
+	return r.Modified == 0 && r.Untracked == 0 && r.Unpushed == 0 && r.Stashes == 0
+}
+
+// Dirty reports whether the worktree has uncommitted changes or untracked files
+func (r *SafetyReport) Dirty() bool {
+	return r.Modified > 0 || r.Untracked > 0
+}
+
+// CheckSafety inspects a worktree for changes and commits that only exist there
+func CheckSafety(wt Worktree) (*SafetyReport, error) {
+	report := &SafetyReport{}
+	report.LockReason, report.Locked = LockReason(wt.Path)
+
+	output, err := currentBackend().Status(wt.Path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read status of %s: %w", wt.DisplayName(), err)
+	}
+	for _, line := range strings.Split(strings.TrimRight(output, "\n"), "\n") {
+		switch {
+		case line == "", strings.HasPrefix(line, "## "):
+		case strings.HasPrefix(line, "??"):
+			report.Untracked++
+		default:
+			report.Modified++
+		}
+	}
+
+	branch := wt.BranchName()
+	if branch == "" {
+		return report, nil
+	}
+
+	if output, err := runner.Output(Command("-C", wt.Path, "rev-list", "--count", "@{upstream}..HEAD")); err == nil {
+		report.Unpushed, _ = strconv.Atoi(strings.TrimSpace(string(output)))
+	} else {
+		// Without an upstream, count the commits no other branch or remote has
+		report.NoUpstream = true
+		cmd := Command("-C", wt.Path, "rev-list", "--count", "HEAD", "--not", "--exclude="+branch, "--branches", "--remotes")
+		if output, err := runner.Output(cmd); err == nil {
+			report.Unpushed, _ = strconv.Atoi(strings.TrimSpace(string(output)))
+		}
+	}
+
+	if output, err := runner.Output(Command("-C", wt.Path, "stash", "list", "--format=%gs")); err == nil {
+		report.Stashes = countStashes(string(output), branch)
+	}
+
+	return report, nil
+}
+
+// LockReason reports whether the worktree at path is locked with `git worktree lock`, and the reason
+// given for it
+func LockReason(path string) (string, bool) {
+	gitDir := worktreeGitDir(path)
+	if gitDir == "" {
+		return "", false
+	}
+	data, err := os.ReadFile(filepath.Join(gitDir, "locked"))
+	if err != nil {
+		return "", false
+	}
+	return strings.TrimSpace(string(data)), true
+}
+
+// countStashes counts stash entries made on a branch from `git stash list --format=%gs` output,
+// where each entry starts "WIP on <branch>:" or "On <branch>:"
+func countStashes(output, branch string) int {
+	count := 0
+	for _, line := range strings.Split(output, "\n") {
+		if strings.HasPrefix(line, "WIP on "+branch+":") || strings.HasPrefix(line, "On "+branch+":") {
+			count++
+		}
+	}
+	return count
+}
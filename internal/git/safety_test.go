@@ -0,0 +1,48 @@
+package git
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestCheckSafety(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	_, worktreePath := newTestRepo(t)
+	wt := Worktree{Path: worktreePath, Branch: "refs/heads/feature"}
+
+	report, err := CheckSafety(wt)
+	if err != nil {
+		t.Fatalf("CheckSafety() error = %v", err)
+	}
+	if !report.Safe() || !report.NoUpstream {
+		t.Errorf("CheckSafety() of a fresh worktree = %+v, want safe without an upstream", report)
+	}
+
+	writeFile(t, filepath.Join(worktreePath, "feature.go"), "package feature\n")
+	runGit(t, worktreePath, "add", "feature.go")
+	runGit(t, worktreePath, "commit", "-q", "-m", "add feature")
+	writeFile(t, filepath.Join(worktreePath, "README"), "changed\n")
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "todo\n")
+	writeFile(t, filepath.Join(worktreePath, "scratch.txt"), "stash me\n")
+	runGit(t, worktreePath, "stash", "push", "-q", "--include-untracked", "--", "scratch.txt")
+
+	report, err = CheckSafety(wt)
+	if err != nil {
+		t.Fatalf("CheckSafety() error = %v", err)
+	}
+	expected := SafetyReport{Modified: 1, Untracked: 1, Unpushed: 1, NoUpstream: true, Stashes: 1}
+	if *report != expected {
+		t.Errorf("CheckSafety() = %+v, want %+v", *report, expected)
+	}
+}
+
+func TestCountStashes(t *testing.T) {
+	output := "WIP on feature: 1a2b3c4 add feature\n" +
+		"On feature: lfg: move to myapp-other\n" +
+		"WIP on feature-two: 5d6e7f8 other\n" +
+		"On main: experiment\n"
+
+	if got := countStashes(output, "feature"); got != 2 {
+		t.Errorf("countStashes() = %d, want 2", got)
+	}
+}
@@ -0,0 +1,114 @@
+package git
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// scratchPrefix starts the name of every scratch worktree, followed by the date it was created
+const scratchPrefix = "scratch-"
+
+const scratchDateFormat = "2006-01-02"
+
+// DefaultScratchExpiryDays is how long scratch worktrees are kept before being offered for cleanup
+const DefaultScratchExpiryDays = 7
+
+// ScratchExpiryDays returns the configured scratch worktree lifetime in days
+func ScratchExpiryDays(scratch *config.Scratch) int {
+	if scratch == nil || scratch.ExpiryDays <= 0 {
+		return DefaultScratchExpiryDays
+	}
+	return scratch.ExpiryDays
+}
+
+// ScratchName returns the name for a new scratch worktree created on day now
+// Later scratch worktrees on the same day are numbered, e.g. "scratch-2024-05-01-2"
+func ScratchName(now time.Time, taken func(name string) bool) string {
+	name := scratchPrefix + now.Format(scratchDateFormat)
+	for n := 2; taken(name); n++ {
+		name = fmt.Sprintf("%s%s-%d", scratchPrefix, now.Format(scratchDateFormat), n)
+	}
+	return name
+}
+
+// ScratchDate reports when a scratch worktree was created, or false if name isn't a scratch worktree
+func ScratchDate(name string) (time.Time, bool) {
+	rest, ok := strings.CutPrefix(name, scratchPrefix)
+	if !ok || len(rest) < len(scratchDateFormat) {
+		return time.Time{}, false
+	}
+	date, err := time.ParseInLocation(scratchDateFormat, rest[:len(scratchDateFormat)], time.Local)
+	if err != nil {
+		return time.Time{}, false
+	}
+	if suffix := rest[len(scratchDateFormat):]; suffix != "" {
+		if n, err := strconv.Atoi(strings.TrimPrefix(suffix, "-")); err != nil || n < 2 || suffix[0] != '-' {
+			return time.Time{}, false
+		}
+	}
+	return date, true
+}
+
+// IsScratch reports whether a worktree is a scratch worktree
+func (w Worktree) IsScratch() bool {
+	_, ok := ScratchDate(w.Name())
+	return ok
+}
+
+// ScratchExpiry returns when a scratch worktree should be offered for cleanup, or false if it isn't one
+func (w Worktree) ScratchExpiry(days int) (time.Time, bool) {
+	date, ok := ScratchDate(w.Name())
+	if !ok {
+		return time.Time{}, false
+	}
+	return date.AddDate(0, 0, days), true
+}
+
+// ExpiredScratch returns the scratch worktrees created more than days ago
+func ExpiredScratch(worktrees []Worktree, days int, now time.Time) []Worktree {
+	var expired []Worktree
+	for _, wt := range worktrees {
+		if expiry, ok := wt.ScratchExpiry(days); ok && !now.Before(expiry) {
+			expired = append(expired, wt)
+		}
+	}
+	return expired
+}
+
+// CreateScratchWorktree creates a throwaway worktree named for today, branched off the default branch
+func CreateScratchWorktree(now time.Time) (string, error) {
+	worktrees, err := ListWorktrees()
+	if err != nil {
+		return "", err
+	}
+	name := ScratchName(now, func(name string) bool {
+		if _, err := findWorktree(worktrees, name); err == nil {
+			return true
+		}
+		return BranchExists(name)
+	})
+
+	path, err := worktreePathFor(name)
+	if err != nil {
+		return "", err
+	}
+
+	// Branch off the default branch rather than wherever the current directory is
+	base := DefaultBranch()
+	if !BranchExists(base) {
+		base = DefaultBaseRef()
+	}
+	cmd := Command("worktree", "add", "-b", name, path, base)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return "", fmt.Errorf("failed to create worktree: %s", string(output))
+	}
+
+	events.Emit(events.Event{Type: events.WorktreeCreated, Worktree: name, Path: path, Branch: name})
+	return name, nil
+}
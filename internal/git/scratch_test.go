@@ -0,0 +1,77 @@
+package git
+
+import (
+	"testing"
+	"time"
+)
+
+func TestScratchName(t *testing.T) {
+	now := time.Date(2024, 5, 1, 15, 4, 0, 0, time.Local)
+
+	tests := []struct {
+		name     string
+		taken    []string
+		expected string
+	}{
+		{name: "first of the day", expected: "scratch-2024-05-01"},
+		{name: "second of the day", taken: []string{"scratch-2024-05-01"}, expected: "scratch-2024-05-01-2"},
+		{name: "third of the day", taken: []string{"scratch-2024-05-01", "scratch-2024-05-01-2"}, expected: "scratch-2024-05-01-3"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			taken := func(name string) bool {
+				for _, n := range tt.taken {
+					if n == name {
+						return true
+					}
+				}
+				return false
+			}
+			if got := ScratchName(now, taken); got != tt.expected {
+				t.Errorf("ScratchName() = %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestScratchDate(t *testing.T) {
+	tests := []struct {
+		name    string
+		scratch bool
+	}{
+		{name: "scratch-2024-05-01", scratch: true},
+		{name: "scratch-2024-05-01-2", scratch: true},
+		{name: "scratch-2024-05-01-x", scratch: false},
+		{name: "scratch-2024-05-01x2", scratch: false},
+		{name: "scratch-pad", scratch: false},
+		{name: "myapp-scratch-2024-05-01", scratch: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			date, ok := ScratchDate(tt.name)
+			if ok != tt.scratch {
+				t.Fatalf("ScratchDate(%q) ok = %v, want %v", tt.name, ok, tt.scratch)
+			}
+			if ok && date.Format("2006-01-02") != "2024-05-01" {
+				t.Errorf("ScratchDate(%q) = %v, want 2024-05-01", tt.name, date)
+			}
+		})
+	}
+}
+
+func TestExpiredScratch(t *testing.T) {
+	worktrees := []Worktree{
+		{Path: "/src/myapp"},
+		{Path: "/src/scratch-2024-05-01"},
+		{Path: "/src/scratch-2024-05-06"},
+		{Path: "/src/myapp-login"},
+	}
+	now := time.Date(2024, 5, 8, 9, 0, 0, 0, time.Local)
+
+	expired := ExpiredScratch(worktrees, 7, now)
+	if len(expired) != 1 || expired[0].Name() != "scratch-2024-05-01" {
+		t.Errorf("ExpiredScratch() = %v, want only scratch-2024-05-01", expired)
+	}
+}
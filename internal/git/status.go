@@ -0,0 +1,172 @@
+package git
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/tmux"
+)
+
+// DefaultStatusTTL is how long a worktree's status is reused before git and tmux are asked again
+const DefaultStatusTTL = 30 * time.Second
+
+// WorktreeStatus is what lfg shows about a worktree at a glance
+type WorktreeStatus struct {
+	Dirty    bool      `json:"dirty"`    // Uncommitted changes or untracked files
+	Ahead    int       `json:"ahead"`    // Commits the default branch doesn't have
+	Behind   int       `json:"behind"`   // Commits on the default branch that the worktree doesn't have
+	Unpushed int       `json:"unpushed"` // Commits not pushed to the branch's upstream
+	Unpulled int       `json:"unpulled"` // Commits on the branch's upstream not pulled yet
+	Session  bool      `json:"session"`  // Its tmux session is running
+	Crashed  int       `json:"crashed"`  // Panes of its session whose command exited, kept open by remain_on_exit
+	Updated  time.Time `json:"updated"`
+}
+
+// StatusCache keeps worktree statuses on disk so the TUI and `lfg status` share them instead of
+// each running git again. Entries expire after TTL, and are dropped early when lfg creates or
+// deletes the worktree or starts its session (see InvalidateOnEvent)
+type StatusCache struct {
+	path string
+	TTL  time.Duration
+	now  func() time.Time
+}
+
+// NewStatusCache returns the status cache of the repository whose main worktree is at repo
+func NewStatusCache(repo string) *StatusCache {
+	cache := &StatusCache{TTL: DefaultStatusTTL, now: time.Now}
+	if dir, err := config.DataDir(); err == nil {
+		cache.path = filepath.Join(dir, "status", cacheName(repo))
+	}
+	return cache
+}
+
+func (c *StatusCache) load() map[string]WorktreeStatus {
+	entries := make(map[string]WorktreeStatus)
+	if data, err := os.ReadFile(c.path); err == nil {
+		_ = json.Unmarshal(data, &entries)
+	}
+	return entries
+}
+
+func (c *StatusCache) save(entries map[string]WorktreeStatus) error {
+	return writeCacheFile(c.path, entries)
+}
+
+// Statuses returns the status of each worktree by name, only asking git and tmux about those whose
+// cached status has expired. sessionName gives a worktree's tmux session, empty to skip the check
+func (c *StatusCache) Statuses(worktrees []Worktree, sessionName func(Worktree) string) map[string]WorktreeStatus {
+	entries := c.load()
+	now := c.now()
+	statuses := make(map[string]WorktreeStatus, len(worktrees))
+	changed := false
+	for _, wt := range worktrees {
+		name := wt.Name()
+		status, ok := entries[name]
+		if !ok || now.Sub(status.Updated) >= c.TTL {
+			status = readStatus(wt, sessionName(wt), now)
+			entries[name] = status
+			changed = true
+		}
+		statuses[name] = status
+	}
+	if changed && c.path != "" {
+		_ = c.save(entries)
+	}
+	return statuses
+}
+
+// Invalidate drops the cached status of the named worktrees, or of every worktree when none are named
+func (c *StatusCache) Invalidate(names ...string) error {
+	if c.path == "" {
+		return nil
+	}
+	if _, err := os.Stat(c.path); err != nil {
+		return nil
+	}
+	if len(names) == 0 {
+		return os.Remove(c.path)
+	}
+	entries := c.load()
+	for _, name := range names {
+		delete(entries, name)
+	}
+	return c.save(entries)
+}
+
+// readStatus asks git and the multiplexer about a worktree
+func readStatus(wt Worktree, sessionName string, now time.Time) WorktreeStatus {
+	status := WorktreeStatus{Updated: now}
+	if output, err := currentBackend().Status(wt.Path); err == nil {
+		status.Dirty, status.Unpushed, status.Unpulled = parseStatusPorcelain(output)
+	}
+	if d, err := CompareToDefault(wt.Path); err == nil {
+		status.Ahead, status.Behind = d.Ahead, d.Behind
+	}
+	if sessionName != "" {
+		status.Session = multiplexer.Current().SessionExists(sessionName)
+	}
+	if status.Session && multiplexer.IsTmux() {
+		if crashed, err := tmux.CrashedPanes(sessionName); err == nil {
+			status.Crashed = len(crashed)
+		}
+	}
+	return status
+}
+
+// parseStatusPorcelain reads `git status --porcelain -b` output: whether there are any changes, and the
+// commits ahead of and behind the upstream from the "## main...origin/main [ahead 1, behind 2]" header
+func parseStatusPorcelain(output string) (dirty bool, ahead, behind int) {
+	for _, line := range strings.Split(output, "\n") {
+		header, ok := strings.CutPrefix(line, "## ")
+		if !ok {
+			dirty = dirty || strings.TrimSpace(line) != ""
+			continue
+		}
+		_, counts, ok := strings.Cut(header, " [")
+		if !ok {
+			continue
+		}
+		for _, count := range strings.Split(strings.TrimSuffix(counts, "]"), ", ") {
+			if n, ok := strings.CutPrefix(count, "ahead "); ok {
+				ahead, _ = strconv.Atoi(n)
+			} else if n, ok := strings.CutPrefix(count, "behind "); ok {
+				behind, _ = strconv.Atoi(n)
+			}
+		}
+	}
+	return dirty, ahead, behind
+}
+
+// InvalidateOnEvent drops a worktree's cached status when lfg creates or deletes it or starts its session
+// Subscribe it to the event stream with events.Subscribe
+func InvalidateOnEvent(e events.Event) {
+	switch e.Type {
+	case events.WorktreeCreated, events.WorktreeDeleted, events.WorktreeRenamed, events.SessionStarted:
+	default:
+		return
+	}
+	if mainPath, err := GetMainWorktreePath(); err == nil {
+		_ = NewStatusCache(mainPath).Invalidate(e.Worktree)
+	}
+}
+
+// LastActivity returns when a worktree's HEAD last moved, by a commit, checkout, reset or pull, read
+// from its reflog without running git. It's zero when there's no reflog
+func LastActivity(wt Worktree) time.Time {
+	gitDir := worktreeGitDir(wt.Path)
+	if gitDir == "" {
+		return time.Time{}
+	}
+	info, err := os.Stat(filepath.Join(gitDir, "logs", "HEAD"))
+	if err != nil {
+		return time.Time{}
+	}
+	return info.ModTime()
+}
@@ -0,0 +1,100 @@
+package git
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+func TestStatusCache(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	mainPath, worktreePath := newTestRepo(t)
+	t.Cleanup(func() { settings = nil })
+	Configure(&config.Git{DefaultBranch: strings.TrimSpace(runGit(t, mainPath, "branch", "--show-current"))})
+	worktrees := []Worktree{{Path: worktreePath, Branch: "refs/heads/feature"}}
+	noSession := func(Worktree) string { return "" }
+
+	now := time.Date(2024, 5, 1, 9, 0, 0, 0, time.UTC)
+	cache := NewStatusCache(mainPath)
+	cache.now = func() time.Time { return now }
+
+	name := worktrees[0].Name()
+	if got := cache.Statuses(worktrees, noSession)[name]; got.Dirty {
+		t.Fatalf("Statuses() = %+v, want a clean worktree", got)
+	}
+
+	// Cached statuses are reused until they expire
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "todo\n")
+	if got := cache.Statuses(worktrees, noSession)[name]; got.Dirty {
+		t.Errorf("Statuses() within the TTL = %+v, want the cached clean status", got)
+	}
+	now = now.Add(DefaultStatusTTL)
+	if got := cache.Statuses(worktrees, noSession)[name]; !got.Dirty {
+		t.Errorf("Statuses() after the TTL = %+v, want dirty", got)
+	}
+
+	// Invalidating a worktree drops its status straight away
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "")
+	runGit(t, worktreePath, "add", "notes.txt")
+	runGit(t, worktreePath, "commit", "-q", "-m", "Add notes")
+	if err := cache.Invalidate(name); err != nil {
+		t.Fatalf("Invalidate() error = %v", err)
+	}
+	got := cache.Statuses(worktrees, noSession)[name]
+	if got.Dirty || got.Ahead != 1 {
+		t.Errorf("Statuses() after Invalidate() = %+v, want clean and 1 ahead", got)
+	}
+}
+
+func TestParseStatusPorcelain(t *testing.T) {
+	tests := []struct {
+		name   string
+		output string
+		dirty  bool
+		ahead  int
+		behind int
+	}{
+		{name: "clean, no upstream", output: "## feature\n"},
+		{name: "in sync", output: "## main...origin/main\n"},
+		{name: "ahead and behind", output: "## main...origin/main [ahead 2, behind 5]\n", ahead: 2, behind: 5},
+		{name: "behind only", output: "## main...origin/main [behind 1]\n", behind: 1},
+		{name: "upstream gone", output: "## feature...origin/feature [gone]\n"},
+		{name: "dirty", output: "## main...origin/main [ahead 1]\n M go.mod\n?? notes.txt\n", dirty: true, ahead: 1},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			dirty, ahead, behind := parseStatusPorcelain(tt.output)
+			if dirty != tt.dirty || ahead != tt.ahead || behind != tt.behind {
+				t.Errorf("parseStatusPorcelain() = %v, %d, %d, want %v, %d, %d", dirty, ahead, behind, tt.dirty, tt.ahead, tt.behind)
+			}
+		})
+	}
+}
+
+func TestLastActivity(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	mainPath, worktreePath := newTestRepo(t)
+
+	old := time.Now().Add(-time.Hour)
+	mainLog := filepath.Join(mainPath, ".git", "logs", "HEAD")
+	if err := os.Chtimes(mainLog, old, old); err != nil {
+		t.Fatal(err)
+	}
+
+	mainActivity := LastActivity(Worktree{Path: mainPath})
+	if mainActivity.Unix() != old.Unix() {
+		t.Errorf("LastActivity(main) = %v, want %v", mainActivity, old)
+	}
+	if worktreeActivity := LastActivity(Worktree{Path: worktreePath}); !worktreeActivity.After(mainActivity) {
+		t.Errorf("LastActivity(worktree) = %v, want after %v", worktreeActivity, mainActivity)
+	}
+	if activity := LastActivity(Worktree{Path: t.TempDir()}); !activity.IsZero() {
+		t.Errorf("LastActivity(not a worktree) = %v, want zero", activity)
+	}
+}
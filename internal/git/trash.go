@@ -0,0 +1,225 @@
+package git
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// trashInfoFile records where a trashed worktree came from, inside its trashed directory
+const trashInfoFile = ".lfg-trash.json"
+
+// TrashEntry is a worktree directory moved to the trash, which can be restored with its changes
+type TrashEntry struct {
+	ID      string    `json:"-"` // Directory name in the trash, <name>-<timestamp>
+	Name    string    `json:"name"`
+	Path    string    `json:"path"`   // Where the worktree was
+	Repo    string    `json:"repo"`   // Main worktree of the repository it belongs to
+	Branch  string    `json:"branch"` // Empty for detached worktrees
+	Commit  string    `json:"commit"`
+	Deleted time.Time `json:"deleted"`
+}
+
+// TrashDir returns where trashed worktrees are kept, ~/.local/share/lfg/trash unless XDG_DATA_HOME is set
+func TrashDir() (string, error) {
+	dir, err := config.DataDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "trash"), nil
+}
+
+// TrashWorktree moves a worktree's directory to the trash instead of deleting it, keeping its branch
+// Git forgets the worktree, and RestoreTrash brings it back with its uncommitted changes
+func TrashWorktree(name string) (*TrashEntry, error) {
+	wt, err := FindWorktree(name)
+	if err != nil {
+		return nil, err
+	}
+	if err := EnsureNoOperation(wt.Path); err != nil {
+		return nil, err
+	}
+	mainPath, err := GetMainWorktreePath()
+	if err != nil {
+		return nil, err
+	}
+
+	// Step out of the worktree before moving it away
+	if current, err := GetCurrentWorktree(); err == nil && current == name {
+		if err := os.Chdir(mainPath); err != nil {
+			return nil, fmt.Errorf("failed to change to main worktree: %w", err)
+		}
+	}
+
+	entry, err := moveToTrash(mainPath, *wt, time.Now())
+	if err != nil {
+		return nil, err
+	}
+	events.Emit(events.Event{Type: events.WorktreeDeleted, Worktree: name, Path: wt.Path, Branch: wt.BranchName()})
+	return entry, nil
+}
+
+func moveToTrash(mainPath string, wt Worktree, now time.Time) (*TrashEntry, error) {
+	trashDir, err := TrashDir()
+	if err != nil {
+		return nil, err
+	}
+	if err := os.MkdirAll(trashDir, 0755); err != nil {
+		return nil, fmt.Errorf("failed to create trash: %w", err)
+	}
+
+	entry := &TrashEntry{
+		ID:      fmt.Sprintf("%s-%s", wt.Name(), now.Format("20060102-150405")),
+		Name:    wt.Name(),
+		Path:    wt.Path,
+		Repo:    mainPath,
+		Branch:  wt.BranchName(),
+		Commit:  wt.Commit,
+		Deleted: now,
+	}
+	dest := filepath.Join(trashDir, entry.ID)
+	if err := moveDir(wt.Path, dest); err != nil {
+		return nil, fmt.Errorf("failed to move %s to the trash: %w", wt.DisplayName(), err)
+	}
+
+	data, err := json.MarshalIndent(entry, "", "  ")
+	if err != nil {
+		return nil, err
+	}
+	if err := os.WriteFile(filepath.Join(dest, trashInfoFile), data, 0644); err != nil {
+		return nil, fmt.Errorf("failed to record trashed worktree: %w", err)
+	}
+
+	// The directory is gone, so prune drops git's record of the worktree
+	if output, err := runner.CombinedOutput(Command("-C", mainPath, "worktree", "prune")); err != nil {
+		return nil, fmt.Errorf("failed to prune worktrees: %s", strings.TrimSpace(string(output)))
+	}
+	return entry, nil
+}
+
+// moveDir renames a directory, falling back to mv when the trash is on another filesystem
+func moveDir(src, dst string) error {
+	if err := os.Rename(src, dst); err == nil {
+		return nil
+	}
+	if output, err := runner.CombinedOutput(exec.Command("mv", src, dst)); err != nil {
+		return fmt.Errorf("%s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// ListTrash returns the trashed worktrees of every repository, most recently deleted first
+func ListTrash() ([]TrashEntry, error) {
+	trashDir, err := TrashDir()
+	if err != nil {
+		return nil, err
+	}
+	dirs, err := os.ReadDir(trashDir)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read trash: %w", err)
+	}
+
+	var entries []TrashEntry
+	for _, dir := range dirs {
+		data, err := os.ReadFile(filepath.Join(trashDir, dir.Name(), trashInfoFile))
+		if err != nil {
+			continue
+		}
+		var entry TrashEntry
+		if err := json.Unmarshal(data, &entry); err != nil {
+			continue
+		}
+		entry.ID = dir.Name()
+		entries = append(entries, entry)
+	}
+	sort.SliceStable(entries, func(i, j int) bool {
+		return entries[i].Deleted.After(entries[j].Deleted)
+	})
+	return entries, nil
+}
+
+// FindTrash looks up a trashed worktree by ID, or by worktree name for its most recent deletion
+func FindTrash(entries []TrashEntry, id string) (*TrashEntry, error) {
+	for i := range entries {
+		if entries[i].ID == id {
+			return &entries[i], nil
+		}
+	}
+	for i := range entries {
+		if entries[i].Name == id {
+			return &entries[i], nil
+		}
+	}
+	return nil, fmt.Errorf("nothing named %s in the trash", id)
+}
+
+// RestoreTrash puts a trashed worktree back where it was, on its branch, with its uncommitted changes
+// Changes that were staged come back unstaged
+func RestoreTrash(entry TrashEntry) error {
+	trashDir, err := TrashDir()
+	if err != nil {
+		return err
+	}
+	src := filepath.Join(trashDir, entry.ID)
+	if _, err := os.Stat(entry.Path); err == nil {
+		return fmt.Errorf("%s already exists, move it out of the way first", entry.Path)
+	}
+
+	args := []string{"-C", entry.Repo, "worktree", "add", "--no-checkout", entry.Path, entry.Branch}
+	if entry.Branch == "" {
+		args = []string{"-C", entry.Repo, "worktree", "add", "--no-checkout", "--detach", entry.Path, entry.Commit}
+	}
+	if output, err := runner.CombinedOutput(Command(args...)); err != nil {
+		return fmt.Errorf("failed to recreate worktree: %s", strings.TrimSpace(string(output)))
+	}
+
+	// Move the files back, keeping the new .git link in place of the stale one
+	files, err := os.ReadDir(src)
+	if err != nil {
+		return fmt.Errorf("failed to read trashed worktree: %w", err)
+	}
+	for _, file := range files {
+		if file.Name() == ".git" || file.Name() == trashInfoFile {
+			continue
+		}
+		if err := moveDir(filepath.Join(src, file.Name()), filepath.Join(entry.Path, file.Name())); err != nil {
+			return fmt.Errorf("failed to restore %s: %w", file.Name(), err)
+		}
+	}
+
+	// --no-checkout leaves the index empty, reading HEAD into it makes the changes show up as such
+	if output, err := runner.CombinedOutput(Command("-C", entry.Path, "reset", "-q")); err != nil {
+		return fmt.Errorf("failed to reset index: %s", strings.TrimSpace(string(output)))
+	}
+	return os.RemoveAll(src)
+}
+
+// EmptyTrash permanently deletes every trashed worktree and returns how many there were
+func EmptyTrash() (int, error) {
+	entries, err := ListTrash()
+	if err != nil {
+		return 0, err
+	}
+	trashDir, err := TrashDir()
+	if err != nil {
+		return 0, err
+	}
+	for _, entry := range entries {
+		if err := os.RemoveAll(filepath.Join(trashDir, entry.ID)); err != nil {
+			return 0, fmt.Errorf("failed to delete %s: %w", entry.ID, err)
+		}
+	}
+	return len(entries), nil
+}
@@ -0,0 +1,71 @@
+package git
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestTrashAndRestore(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	mainPath, worktreePath := newTestRepo(t)
+
+	writeFile(t, filepath.Join(worktreePath, "README"), "work in progress\n")
+	writeFile(t, filepath.Join(worktreePath, "notes.txt"), "todo\n")
+	wt := Worktree{Path: worktreePath, Branch: "refs/heads/feature"}
+
+	entry, err := moveToTrash(mainPath, wt, time.Date(2024, 5, 1, 9, 30, 0, 0, time.UTC))
+	if err != nil {
+		t.Fatalf("moveToTrash() error = %v", err)
+	}
+	if _, err := os.Stat(worktreePath); !os.IsNotExist(err) {
+		t.Errorf("worktree directory still exists after trashing")
+	}
+	if list := runGit(t, mainPath, "worktree", "list"); strings.Contains(list, worktreePath) {
+		t.Errorf("git still lists the trashed worktree:\n%s", list)
+	}
+
+	entries, err := ListTrash()
+	if err != nil {
+		t.Fatalf("ListTrash() error = %v", err)
+	}
+	if len(entries) != 1 || entries[0].ID != "myapp-feature-20240501-093000" || entries[0].Branch != "feature" {
+		t.Fatalf("ListTrash() = %+v, want the trashed worktree", entries)
+	}
+	if found, err := FindTrash(entries, "myapp-feature"); err != nil || found.ID != entry.ID {
+		t.Errorf("FindTrash() by name = %+v, %v", found, err)
+	}
+
+	if err := RestoreTrash(entries[0]); err != nil {
+		t.Fatalf("RestoreTrash() error = %v", err)
+	}
+	if got := readFile(t, filepath.Join(worktreePath, "README")); got != "work in progress\n" {
+		t.Errorf("restored README = %q", got)
+	}
+	status := runGit(t, worktreePath, "status", "--porcelain")
+	if status != " M README\n?? notes.txt\n" {
+		t.Errorf("restored worktree status = %q", status)
+	}
+	if entries, _ := ListTrash(); len(entries) != 0 {
+		t.Errorf("ListTrash() after restoring = %+v, want empty", entries)
+	}
+}
+
+func TestEmptyTrash(t *testing.T) {
+	t.Setenv("LFG_GIT_ARGS", "")
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	mainPath, worktreePath := newTestRepo(t)
+
+	if _, err := moveToTrash(mainPath, Worktree{Path: worktreePath, Branch: "refs/heads/feature"}, time.Now()); err != nil {
+		t.Fatalf("moveToTrash() error = %v", err)
+	}
+	if count, err := EmptyTrash(); err != nil || count != 1 {
+		t.Errorf("EmptyTrash() = %d, %v, want 1", count, err)
+	}
+	if entries, _ := ListTrash(); len(entries) != 0 {
+		t.Errorf("ListTrash() after emptying = %+v, want empty", entries)
+	}
+}
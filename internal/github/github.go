@@ -4,8 +4,12 @@ import (
 	"bytes"
 	"encoding/json"
 	"fmt"
+	"net/url"
 	"os/exec"
 	"strings"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/runner"
 )
 
 type Project struct {
@@ -27,21 +31,37 @@ type ProjectItem struct {
 	} `json:"content"`
 }
 
+type PullRequest struct {
+	Number      int    `json:"number"`
+	Title       string `json:"title"`
+	HeadRefName string `json:"headRefName"`
+	Author      struct {
+		Login string `json:"login"`
+	} `json:"author"`
+}
+
 type RepoInfo struct {
 	Owner string
 	Name  string
+	Host  string // Only known when parsed from the remote URL, e.g. "github.com" or a GitHub Enterprise host
+}
+
+// IsInstalled checks if the gh CLI is available
+func IsInstalled() bool {
+	_, err := exec.LookPath("gh")
+	return err == nil
 }
 
 // IsAuthenticated checks if gh CLI is authenticated
 func IsAuthenticated() bool {
 	cmd := exec.Command("gh", "auth", "status")
-	return cmd.Run() == nil
+	return runner.Run(cmd) == nil
 }
 
 // HasRequiredScopes checks if the token has project and repo scopes
 func HasRequiredScopes() (bool, error) {
 	cmd := exec.Command("gh", "auth", "status", "-t")
-	output, err := cmd.Output()
+	output, err := runner.Output(cmd)
 	if err != nil {
 		return false, nil
 	}
@@ -65,14 +85,18 @@ func Authenticate() error {
 	cmd.Stdin = nil
 	cmd.Stdout = nil
 	cmd.Stderr = nil
-	return cmd.Run()
+	return runner.Run(cmd)
 }
 
 // GetRepoInfo gets the current repository owner and name
+// It asks gh, and falls back to parsing origin's URL when gh isn't installed or can't tell
 func GetRepoInfo() (*RepoInfo, error) {
 	cmd := exec.Command("gh", "repo", "view", "--json", "owner,name")
-	output, err := cmd.Output()
+	output, err := runner.Output(cmd)
 	if err != nil {
+		if info, remoteErr := remoteRepoInfo(); remoteErr == nil {
+			return info, nil
+		}
 		return nil, fmt.Errorf("failed to get repo info: %w", err)
 	}
 
@@ -93,6 +117,178 @@ func GetRepoInfo() (*RepoInfo, error) {
 	}, nil
 }
 
+// remoteRepoInfo reads the owner and name from origin's URL, with insteadOf rewrites applied by git
+func remoteRepoInfo() (*RepoInfo, error) {
+	output, err := runner.Output(git.Command("remote", "get-url", "origin"))
+	if err != nil {
+		return nil, fmt.Errorf("failed to get origin URL: %w", err)
+	}
+	return ParseRemoteURL(strings.TrimSpace(string(output)))
+}
+
+// ParseRemoteURL reads the host, owner and repository name from a git remote URL
+// Handles scp-style SSH (git@github.com:owner/repo.git), ssh://, https:// and git:// URLs, on any host
+func ParseRemoteURL(remote string) (*RepoInfo, error) {
+	var host, path string
+	if u, err := url.Parse(remote); err == nil && u.Scheme != "" && u.Host != "" {
+		host, path = u.Hostname(), u.Path
+	} else if before, after, ok := strings.Cut(remote, ":"); ok && !strings.Contains(before, "/") {
+		// scp-style: [user@]host:owner/repo
+		host, path = before, after
+		if _, h, ok := strings.Cut(host, "@"); ok {
+			host = h
+		}
+	} else {
+		return nil, fmt.Errorf("unrecognised remote URL: %s", remote)
+	}
+
+	parts := strings.Split(strings.Trim(strings.TrimSuffix(strings.TrimSuffix(path, "/"), ".git"), "/"), "/")
+	if host == "" || len(parts) < 2 || parts[len(parts)-2] == "" || parts[len(parts)-1] == "" {
+		return nil, fmt.Errorf("unrecognised remote URL: %s", remote)
+	}
+	return &RepoInfo{
+		Owner: parts[len(parts)-2],
+		Name:  parts[len(parts)-1],
+		Host:  host,
+	}, nil
+}
+
+// GetPullRequestURL returns the URL of the pull request for a branch
+func GetPullRequestURL(branch string) (string, error) {
+	cmd := exec.Command("gh", "pr", "view", branch, "--json", "url", "--jq", ".url")
+	var stderr bytes.Buffer
+	cmd.Stderr = &stderr
+
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return "", fmt.Errorf("no pull request found for %s: %s", branch, strings.TrimSpace(stderr.String()))
+	}
+
+	return strings.TrimSpace(string(output)), nil
+}
+
+// CreatePullRequest opens a pull request for a pushed branch, run from the worktree at dir so gh picks
+// the repository and base from it, and returns the new pull request's URL
+func CreatePullRequest(dir, branch, title, body string) (string, error) {
+	cmd := exec.Command("gh", "pr", "create", "--head", branch, "--title", title, "--body", body)
+	cmd.Dir = dir
+	var stderr bytes.Buffer
+	cmd.Stderr = &stderr
+
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return "", fmt.Errorf("failed to create pull request: %s", strings.TrimSpace(stderr.String()))
+	}
+
+	// gh prints the URL last, after any notes about the push
+	lines := strings.Split(strings.TrimSpace(string(output)), "\n")
+	return strings.TrimSpace(lines[len(lines)-1]), nil
+}
+
+// GetPullRequestState returns the state of the pull request for a branch (or PR number): "OPEN", "MERGED" or "CLOSED"
+func GetPullRequestState(branch string) (string, error) {
+	cmd := exec.Command("gh", "pr", "view", branch, "--json", "state", "--jq", ".state")
+	var stderr bytes.Buffer
+	cmd.Stderr = &stderr
+
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return "", fmt.Errorf("no pull request found for %s: %s", branch, strings.TrimSpace(stderr.String()))
+	}
+
+	return strings.TrimSpace(string(output)), nil
+}
+
+// PullRequestStatus is a branch's pull request: its state, the branch it merges into, its review and its checks
+type PullRequestStatus struct {
+	Number         int           `json:"number"`
+	State          string        `json:"state"`          // "OPEN", "MERGED" or "CLOSED"
+	Branch         string        `json:"baseRefName"`    // e.g. "main"
+	IsDraft        bool          `json:"isDraft"`
+	ReviewDecision string        `json:"reviewDecision"` // "APPROVED", "CHANGES_REQUESTED", "REVIEW_REQUIRED", or "" without required reviews
+	Checks         []StatusCheck `json:"statusCheckRollup"`
+}
+
+// StatusCheck is a check run (with Status and Conclusion) or a commit status (with State) on a pull request
+type StatusCheck struct {
+	Status     string `json:"status"`     // "QUEUED", "IN_PROGRESS" or "COMPLETED"
+	Conclusion string `json:"conclusion"` // e.g. "SUCCESS", "FAILURE", "SKIPPED"
+	State      string `json:"state"`      // e.g. "SUCCESS", "FAILURE", "PENDING"
+}
+
+// CI states summarising a pull request's checks
+const (
+	CIPassing = "passing"
+	CIFailing = "failing"
+	CIPending = "pending"
+)
+
+// CI summarises the pull request's checks: failing if any failed, pending if any are still running,
+// passing otherwise, and "" without checks
+func (s *PullRequestStatus) CI() string {
+	if len(s.Checks) == 0 {
+		return ""
+	}
+	pending := false
+	for _, check := range s.Checks {
+		result := check.State
+		if check.Status != "" {
+			if check.Status != "COMPLETED" {
+				pending = true
+				continue
+			}
+			result = check.Conclusion
+		}
+		switch result {
+		case "FAILURE", "ERROR", "CANCELLED", "TIMED_OUT", "ACTION_REQUIRED", "STARTUP_FAILURE":
+			return CIFailing
+		case "PENDING", "EXPECTED":
+			pending = true
+		}
+	}
+	if pending {
+		return CIPending
+	}
+	return CIPassing
+}
+
+// GetPullRequestStatus returns the pull request for a branch with its review and checks
+func GetPullRequestStatus(branch string) (*PullRequestStatus, error) {
+	cmd := exec.Command("gh", "pr", "view", branch, "--json", "number,state,baseRefName,isDraft,reviewDecision,statusCheckRollup")
+	var stderr bytes.Buffer
+	cmd.Stderr = &stderr
+
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, fmt.Errorf("no pull request found for %s: %s", branch, strings.TrimSpace(stderr.String()))
+	}
+
+	var status PullRequestStatus
+	if err := json.Unmarshal(output, &status); err != nil {
+		return nil, fmt.Errorf("failed to parse pull request: %w", err)
+	}
+	return &status, nil
+}
+
+// ListPullRequests lists the repository's open pull requests, newest first
+func ListPullRequests() ([]PullRequest, error) {
+	cmd := exec.Command("gh", "pr", "list", "--state", "open", "--limit", "50", "--json", "number,title,headRefName,author")
+	var stderr bytes.Buffer
+	cmd.Stderr = &stderr
+
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list pull requests: %s", strings.TrimSpace(stderr.String()))
+	}
+
+	var prs []PullRequest
+	if err := json.Unmarshal(output, &prs); err != nil {
+		return nil, fmt.Errorf("failed to parse pull requests: %w", err)
+	}
+
+	return prs, nil
+}
+
 // ListProjects lists all GitHub Projects for a repository
 func ListProjects(owner, repo string) ([]Project, error) {
 	query := fmt.Sprintf(`
@@ -231,7 +427,7 @@ func runGraphQL(query string) ([]byte, error) {
 	var stderr bytes.Buffer
 	cmd.Stderr = &stderr
 
-	output, err := cmd.Output()
+	output, err := runner.Output(cmd)
 	if err != nil {
 		return nil, fmt.Errorf("GraphQL query failed: %s", stderr.String())
 	}
@@ -396,7 +592,60 @@ func ListProjectItems(owner, repo string, projectNumber int) ([]ProjectItem, err
 
 // CreateProjectItem creates a new item in a GitHub Project
 func CreateProjectItem(owner, repo string, projectNumber int, title string) (*ProjectItem, error) {
-	// First, get the project ID
+	projectID, err := findProjectID(owner, repo, projectNumber)
+	if err != nil {
+		return nil, err
+	}
+
+	// Create a draft issue in the project
+	mutation := fmt.Sprintf(`
+		mutation {
+			addProjectV2DraftIssue(input: {
+				projectId: "%s"
+				title: "%s"
+			}) {
+				projectItem {
+					id
+					content {
+						... on DraftIssue {
+							title
+						}
+					}
+				}
+			}
+		}
+	`, projectID, escapeString(title))
+
+	output, err := runGraphQL(mutation)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create project item: %w", err)
+	}
+
+	var createResult struct {
+		Data struct {
+			AddProjectV2DraftIssue struct {
+				ProjectItem struct {
+					ID      string `json:"id"`
+					Content struct {
+						Title string `json:"title"`
+					} `json:"content"`
+				} `json:"projectItem"`
+			} `json:"addProjectV2DraftIssue"`
+		} `json:"data"`
+	}
+
+	if err := json.Unmarshal(output, &createResult); err != nil {
+		return nil, fmt.Errorf("failed to parse project item creation: %w", err)
+	}
+
+	return &ProjectItem{
+		ID:    createResult.Data.AddProjectV2DraftIssue.ProjectItem.ID,
+		Title: createResult.Data.AddProjectV2DraftIssue.ProjectItem.Content.Title,
+	}, nil
+}
+
+// findProjectID returns the node ID of a repository's project
+func findProjectID(owner, repo string, projectNumber int) (string, error) {
 	projectQuery := fmt.Sprintf(`
 		query {
 			repository(owner: "%s", name: "%s") {
@@ -412,7 +661,7 @@ func CreateProjectItem(owner, repo string, projectNumber int, title string) (*Pr
 
 	output, err := runGraphQL(projectQuery)
 	if err != nil {
-		return nil, err
+		return "", err
 	}
 
 	var projectResult struct {
@@ -429,66 +678,99 @@ func CreateProjectItem(owner, repo string, projectNumber int, title string) (*Pr
 	}
 
 	if err := json.Unmarshal(output, &projectResult); err != nil {
-		return nil, fmt.Errorf("failed to parse projects: %w", err)
+		return "", fmt.Errorf("failed to parse projects: %w", err)
 	}
 
-	var projectID string
 	for _, project := range projectResult.Data.Repository.ProjectsV2.Nodes {
 		if project.Number == projectNumber {
-			projectID = project.ID
-			break
+			return project.ID, nil
 		}
 	}
+	return "", fmt.Errorf("project #%d not found", projectNumber)
+}
 
-	if projectID == "" {
-		return nil, fmt.Errorf("project #%d not found", projectNumber)
+// ArchiveProjectItem archives a project item, hiding it from the project's views without deleting it
+func ArchiveProjectItem(owner, repo string, projectNumber int, itemID string) error {
+	projectID, err := findProjectID(owner, repo, projectNumber)
+	if err != nil {
+		return err
 	}
 
-	// Create a draft issue in the project
 	mutation := fmt.Sprintf(`
 		mutation {
-			addProjectV2DraftIssue(input: {
+			archiveProjectV2Item(input: {
 				projectId: "%s"
-				title: "%s"
+				itemId: "%s"
 			}) {
-				projectItem {
+				item {
 					id
+				}
+			}
+		}
+	`, projectID, itemID)
+
+	if _, err := runGraphQL(mutation); err != nil {
+		return fmt.Errorf("failed to archive project item: %w", err)
+	}
+	return nil
+}
+
+// UpdateProjectItemTitle retitles the draft issue behind a project item
+func UpdateProjectItemTitle(itemID, title string) error {
+	query := fmt.Sprintf(`
+		query {
+			node(id: "%s") {
+				... on ProjectV2Item {
 					content {
 						... on DraftIssue {
-							title
+							id
 						}
 					}
 				}
 			}
 		}
-	`, projectID, escapeString(title))
+	`, itemID)
 
-	output, err = runGraphQL(mutation)
+	output, err := runGraphQL(query)
 	if err != nil {
-		return nil, fmt.Errorf("failed to create project item: %w", err)
+		return err
 	}
 
-	var createResult struct {
+	var itemResult struct {
 		Data struct {
-			AddProjectV2DraftIssue struct {
-				ProjectItem struct {
-					ID      string `json:"id"`
-					Content struct {
-						Title string `json:"title"`
-					} `json:"content"`
-				} `json:"projectItem"`
-			} `json:"addProjectV2DraftIssue"`
+			Node struct {
+				Content struct {
+					ID string `json:"id"`
+				} `json:"content"`
+			} `json:"node"`
 		} `json:"data"`
 	}
 
-	if err := json.Unmarshal(output, &createResult); err != nil {
-		return nil, fmt.Errorf("failed to parse project item creation: %w", err)
+	if err := json.Unmarshal(output, &itemResult); err != nil {
+		return fmt.Errorf("failed to parse project item: %w", err)
+	}
+	draftID := itemResult.Data.Node.Content.ID
+	if draftID == "" {
+		return fmt.Errorf("project item %s isn't a draft issue", itemID)
 	}
 
-	return &ProjectItem{
-		ID:    createResult.Data.AddProjectV2DraftIssue.ProjectItem.ID,
-		Title: createResult.Data.AddProjectV2DraftIssue.ProjectItem.Content.Title,
-	}, nil
+	mutation := fmt.Sprintf(`
+		mutation {
+			updateProjectV2DraftIssue(input: {
+				draftIssueId: "%s"
+				title: "%s"
+			}) {
+				draftIssue {
+					id
+				}
+			}
+		}
+	`, draftID, escapeString(title))
+
+	if _, err := runGraphQL(mutation); err != nil {
+		return fmt.Errorf("failed to update project item title: %w", err)
+	}
+	return nil
 }
 
 // UpdateProjectItemStatus updates the status of a project item
@@ -633,7 +915,7 @@ func GetIssueComments(owner, repo string, issueNumber int) ([]IssueComment, erro
 		fmt.Sprintf("/repos/%s/%s/issues/%d/comments", owner, repo, issueNumber),
 		"--jq", ".")
 
-	output, err := cmd.Output()
+	output, err := runner.Output(cmd)
 	if err != nil {
 		return nil, fmt.Errorf("failed to get issue comments: %w", err)
 	}
@@ -668,7 +950,7 @@ func CreateIssueComment(owner, repo string, issueNumber int, body string) error
 	var stderr bytes.Buffer
 	cmd.Stderr = &stderr
 
-	if err := cmd.Run(); err != nil {
+	if err := runner.Run(cmd); err != nil {
 		return fmt.Errorf("failed to create issue comment: %s", stderr.String())
 	}
 
@@ -1,7 +1,10 @@
 package github
 
 import (
+	"fmt"
 	"testing"
+
+	"github.com/markcipolla/lfg/internal/runner"
 )
 
 func TestEscapeString(t *testing.T) {
@@ -46,3 +49,89 @@ func TestEscapeString(t *testing.T) {
 		})
 	}
 }
+
+func TestListPullRequests(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		return []byte(`[{"number":42,"title":"Add login","headRefName":"feat/login","author":{"login":"octocat"}}]`), nil
+	}}
+	defer runner.Set(fake)()
+
+	prs, err := ListPullRequests()
+	if err != nil {
+		t.Fatalf("ListPullRequests() error = %v", err)
+	}
+	if len(prs) != 1 || prs[0].Number != 42 || prs[0].HeadRefName != "feat/login" || prs[0].Author.Login != "octocat" {
+		t.Errorf("ListPullRequests() = %+v", prs)
+	}
+}
+
+func TestParseRemoteURL(t *testing.T) {
+	tests := []struct {
+		remote  string
+		want    RepoInfo
+		wantErr bool
+	}{
+		{remote: "git@github.com:markcipolla/lfg.git", want: RepoInfo{Owner: "markcipolla", Name: "lfg", Host: "github.com"}},
+		{remote: "github.com:markcipolla/lfg", want: RepoInfo{Owner: "markcipolla", Name: "lfg", Host: "github.com"}},
+		{remote: "ssh://git@github.example.com:2222/team/app.git", want: RepoInfo{Owner: "team", Name: "app", Host: "github.example.com"}},
+		{remote: "https://github.com/markcipolla/lfg", want: RepoInfo{Owner: "markcipolla", Name: "lfg", Host: "github.com"}},
+		{remote: "https://user@github.example.com/team/app.git/", want: RepoInfo{Owner: "team", Name: "app", Host: "github.example.com"}},
+		{remote: "git://github.com/markcipolla/lfg.git", want: RepoInfo{Owner: "markcipolla", Name: "lfg", Host: "github.com"}},
+		{remote: "/srv/git/lfg.git", wantErr: true},
+		{remote: "https://github.com/lfg", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.remote, func(t *testing.T) {
+			got, err := ParseRemoteURL(tt.remote)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ParseRemoteURL() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err == nil && *got != tt.want {
+				t.Errorf("ParseRemoteURL() = %+v, want %+v", *got, tt.want)
+			}
+		})
+	}
+}
+
+func TestGetRepoInfoWithoutGh(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		if args[0] == "gh" {
+			return nil, fmt.Errorf("exec: \"gh\": executable file not found in $PATH")
+		}
+		return []byte("git@github.com:markcipolla/lfg.git\n"), nil
+	}}
+	defer runner.Set(fake)()
+
+	info, err := GetRepoInfo()
+	if err != nil {
+		t.Fatalf("GetRepoInfo() error = %v", err)
+	}
+	if info.Owner != "markcipolla" || info.Name != "lfg" {
+		t.Errorf("GetRepoInfo() = %+v", info)
+	}
+}
+
+func TestPullRequestCI(t *testing.T) {
+	tests := []struct {
+		name     string
+		checks   []StatusCheck
+		expected string
+	}{
+		{name: "no checks", expected: ""},
+		{name: "all passed", checks: []StatusCheck{{Status: "COMPLETED", Conclusion: "SUCCESS"}, {State: "SUCCESS"}, {Status: "COMPLETED", Conclusion: "SKIPPED"}}, expected: CIPassing},
+		{name: "run in progress", checks: []StatusCheck{{Status: "COMPLETED", Conclusion: "SUCCESS"}, {Status: "IN_PROGRESS"}}, expected: CIPending},
+		{name: "status pending", checks: []StatusCheck{{State: "PENDING"}}, expected: CIPending},
+		{name: "failure wins over pending", checks: []StatusCheck{{Status: "QUEUED"}, {Status: "COMPLETED", Conclusion: "FAILURE"}}, expected: CIFailing},
+		{name: "status error", checks: []StatusCheck{{State: "ERROR"}}, expected: CIFailing},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			pr := &PullRequestStatus{State: "OPEN", Checks: tt.checks}
+			if got := pr.CI(); got != tt.expected {
+				t.Errorf("CI() = %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
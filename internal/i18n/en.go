@@ -0,0 +1,301 @@
+package i18n
+
+// english is the built-in catalog, and the fallback for messages a translation leaves out
+// Translations use the same keys, keeping the same %s and %d placeholders in the same order
+var english = map[string]string{
+	// Main list
+	"list.title":     "LFG - Git Worktrees",
+	"keys.new":       "new",
+	"keys.delete":    "delete",
+	"keys.repurpose": "repurpose",
+	"keys.rename":    "rename",
+	"keys.pick":      "pick into...",
+	"keys.refresh":   "refresh",
+	"keys.settings":  "settings",
+	"keys.health":    "health",
+	"keys.ide":       "open in IDE",
+	"keys.colour":    "colour",
+	"keys.notes":     "edit notes",
+	"keys.commit":    "commit",
+	"keys.config":    "edit config",
+	"keys.review":    "review a PR",
+	"keys.teammate":  "teammate's branch",
+	"keys.pin":       "pin",
+	"keys.session":   "session info",
+	"keys.restart":   "restart crashed",
+	"keys.output":    "output",
+	"keys.reconcile": "add new panes",
+	"keys.copy":      "copy path/branch/PR",
+	"keys.mark":      "mark for bulk",
+	"keys.open_pr":   "push and open PR",
+	"keys.blocker":   "blocked by...",
+	"keys.history":   "back/forward",
+	"keys.archive":   "archive/archived",
+	"keys.trust":     "check this review",
+
+	// Create dialog
+	"create.title":            "Create New Worktree",
+	"create.type":             "Type: ",
+	"create.description":      "Feature Description:",
+	"create.preview_name":     "Worktree will be created as: %s",
+	"create.shortened":        " (shortened to %d characters)",
+	"create.preview_branch":   "Branch: %s",
+	"create.preview_session":  "Tmux session: %s",
+	"create.preview_push":     "Push to: %s",
+	"create.preview_todo":     "Todo: %s",
+	"create.name":             "Worktree Name:",
+	"create.help":             "Tab: Edit name | Ctrl+P: Push %s | Ctrl+B: Existing branch | Ctrl+E: Editor | Enter: Create | Esc: Cancel",
+	"create.help_types":       "↑↓: Type | %s",
+	"create.track_prompt":     "Branch '%s' already exists on the remote. Check out %s and track it?",
+	"create.track_help":       "Y: Track it | N: Back",
+	"create.duplicate_prompt": "This looks like the open todo '%s'. Jump to it instead?",
+	"create.duplicate_help":   "J: Jump to it | C: Create anyway | Esc: Back",
+	"create.selected":         "Selected %s",
+	"create.not_listed":       "%s isn't in the list",
+
+	// Branch picker
+	"branches.title":    "Check Out a Branch",
+	"branches.new":      "New branch",
+	"branches.remote":   "%s (remote)",
+	"branches.tracking": "%s (tracking %s)",
+	"branches.existing": "%s (existing)",
+	"branches.help":     "↑↓: Select | Enter: Use for the new worktree | Esc: Back",
+	"branches.none":     "No other branches to check out",
+
+	// Repurpose dialog
+	"repurpose.title":   "Repurpose Worktree",
+	"repurpose.prompt":  "Reuse '%s' for:",
+	"repurpose.preview": "Worktree will switch to branch: %s",
+	"repurpose.help":    "Enter: Switch | Esc: Cancel",
+	"repurpose.done":    "Switched %s to %s",
+
+	// Rename dialog
+	"rename.title":  "Rename Worktree",
+	"rename.prompt": "New name for '%s', its branch and tmux session:",
+	"rename.help":   "Enter: Rename | Esc: Cancel",
+	"rename.done":   "Renamed %s to %s",
+
+	// Blocker picker
+	"blocker.title":     "Blocked By",
+	"blocker.prompt":    "'%s' waits on:",
+	"blocker.none":      "Nothing, it isn't blocked",
+	"blocker.help":      "↑↓: Select | Enter: Set | Esc: Cancel",
+	"blocker.set":       "%s is blocked by %s",
+	"blocker.cleared":   "%s is no longer blocked",
+	"blocker.unblocked": "⛓ Now unblocked: %s",
+
+	// Archived todos
+	"archive.title":       "Archived Todos",
+	"archive.empty":       "Nothing archived yet. Archive done todos with A",
+	"archive.completed":   "(done %s)",
+	"archive.help":        "↑↓: Select | u/Enter: Restore | Esc: Close",
+	"archive.archived":    "Archived %s",
+	"archive.restored":    "Restored %s",
+	"archive.only_todos":  "Only todos can be archived",
+	"archive.checked_out": "Delete the worktree first, its todo is archived with it gone",
+
+	// Pull request dialog
+	"openpr.title":    "Open Pull Request",
+	"openpr.prompt":   "Title for the pull request from '%s', pushed first:",
+	"openpr.help":     "Enter: Push and open | Esc: Cancel",
+	"openpr.pushing":  "Pushing %s and opening a pull request...",
+	"openpr.existing": "%s already has a pull request: %s",
+	"openpr.opened":   "Opened a pull request for %s: %s",
+
+	// Cherry-pick / apply dialog
+	"transfer.title":         "Cherry-pick / Apply Patch",
+	"transfer.prompt":        "Copy %s from '%s' into:",
+	"transfer.uncommitted":   "uncommitted changes",
+	"transfer.commits.one":   "the last commit",
+	"transfer.commits.other": "the last %d commits",
+	"transfer.help":          "↑↓: Target | 1-9: Cherry-pick last N commits | 0: Apply uncommitted changes | Enter: Copy | Esc: Cancel",
+	"transfer.picked.one":    "Cherry-picked %d commit into %s",
+	"transfer.picked.other":  "Cherry-picked %d commits into %s",
+	"transfer.applied":       "Applied uncommitted changes to %s",
+
+	// Rebase onto a pull request's base with uncommitted changes
+	"rebase.title":   "Rebase onto %s",
+	"rebase.prompt":  "'%s' has uncommitted changes. With autostash they're stashed before the rebase and reapplied after it, otherwise the rebase won't start.",
+	"rebase.help":    "Tab: Autostash %s | Enter: Rebase | Esc: Cancel",
+	"rebase.running": "Rebasing %s onto %s...",
+	"rebase.done":    "Rebased %s onto %s",
+	"rebase.needed":  "needs rebase onto %s, B: rebase",
+
+	// Unfinished rebase/merge
+	"blocked.title":   "%s In Progress",
+	"blocked.prompt":  "A %s is in progress in '%s', so it can't be changed until it is finished or aborted.\nAborting discards the %s's progress.",
+	"blocked.help":    "A: Abort the %s | Esc: Cancel",
+	"blocked.aborted": "Aborted %s, try again",
+
+	// Delete confirmation
+	"delete.todo.title":      "Delete Todo",
+	"delete.todo.prompt":     "Are you sure you want to delete todo '%s'?",
+	"delete.worktree.title":  "Delete Worktree",
+	"delete.worktree.prompt": "Are you sure you want to delete worktree '%s'?",
+	"delete.dirty.title":     "Discard Changes",
+	"delete.dirty.prompt":    "'%s' has %s. Throw them away with it?",
+	"delete.locked.title":    "Locked Worktree",
+	"delete.locked.prompt":   "'%s' is locked. Remove it anyway?",
+	"delete.locked.reason":   "'%s' is locked: %s. Remove it anyway?",
+	"delete.branch.title":    "Delete Branch",
+	"delete.branch.prompt":   "Its branch '%s' has %s. Delete the branch too?",
+	"delete.branch.help":     "Y: Delete it too | K: Keep the branch | N: Cancel",
+	"confirm.help":           "Y: Yes | N: No",
+	"delete.trashed":         "Moved %s to the trash, restore it with: lfg trash restore %s",
+
+	// Safety report in the delete confirmation
+	"safety.checking":        "Checking for unsaved work...",
+	"safety.failed":          "Couldn't check for unsaved work: %s",
+	"safety.modified.one":    "%d modified file",
+	"safety.modified.other":  "%d modified files",
+	"safety.untracked.one":   "%d untracked file",
+	"safety.untracked.other": "%d untracked files",
+	"safety.unpushed.one":    "%d unpushed commit",
+	"safety.unpushed.other":  "%d unpushed commits",
+	"safety.orphaned.one":    "%d commit on no other branch (never pushed)",
+	"safety.orphaned.other":  "%d commits on no other branch (never pushed)",
+	"safety.unchecked":       "commits that couldn't be checked for being pushed",
+	"safety.stashes.one":     "%d stash entry made on this branch",
+	"safety.stashes.other":   "%d stash entries made on this branch",
+	"safety.pr_open":         "Pull request is still open",
+	"safety.clean":           "Clean, pushed and nothing stashed",
+	"safety.pr_closed":       "Pull request %s",
+
+	// Cleanup prompt
+	"cleanup.title":      "Clean Up Worktrees",
+	"cleanup.prompt":     "These worktrees are no longer needed:",
+	"cleanup.confirm":    "Delete them and their branches?",
+	"cleanup.help":       "Y: Yes | N: Keep them for now",
+	"cleanup.done.one":   "Cleaned up %d worktree",
+	"cleanup.done.other": "Cleaned up %d worktrees",
+
+	// Bulk action prompt
+	"bulk.title":               "Marked Items",
+	"bulk.confirm":             "Go ahead?",
+	"bulk.help":                "Y: Yes | N: No",
+	"bulk.help_unpushed":       "Y: Yes | B: Delete branches with unpushed commits too (%s) | N: No",
+	"bulk.branch_kept":         "(%s, branch kept)",
+	"bulk.branch_deleted":      "(%s, branch deleted)",
+	"bulk.skipped":             "Left alone:",
+	"bulk.dirty":               "uncommitted changes",
+	"bulk.locked":              "locked",
+	"bulk.prompt.delete":       "These will be deleted:",
+	"bulk.prompt.done":         "These will be marked done:",
+	"bulk.prompt.prune":        "These will be pruned:",
+	"bulk.unmerged":            "couldn't check if it's merged: %s",
+	"bulk.range":               "Marking a range, move to its other end and press V again",
+	"bulk.unmarked":            "Nothing marked",
+	"bulk.marked":              "%d marked | d: Delete | D: Mark done | P: Prune merged | Esc: Clear",
+	"bulk.cleared":             "Cleared marks",
+	"bulk.none.delete":         "None of the marked items can be deleted",
+	"bulk.none.done":           "None of the marked items can be marked done",
+	"bulk.none.prune":          "None of the marked items can be pruned",
+	"bulk.result.delete.one":   "%d item deleted",
+	"bulk.result.delete.other": "%d items deleted",
+	"bulk.result.done.one":     "%d item marked done",
+	"bulk.result.done.other":   "%d items marked done",
+	"bulk.result.prune.one":    "%d item pruned",
+	"bulk.result.prune.other":  "%d items pruned",
+
+	// Health screen
+	"health.title":  "Worktree Health: %s",
+	"health.remedy": "   Press %d to run: %s",
+	"health.help":   "1-9: Fix | Esc: Close",
+	"health.ran":    "Ran %s",
+
+	// check_command in review worktrees
+	"check.trusted":    "check_command will run in %s",
+	"check.untrusted":  "check_command won't run in %s any more",
+	"check.not_review": "Only pull request review worktrees need allowing, check_command runs in the rest",
+
+	// Pull request badges in the list
+	"pr.number":   "PR #%d",
+	"pr.merged":   "PR #%d merged",
+	"pr.closed":   "PR #%d closed",
+	"pr.draft":    "draft",
+	"pr.approved": "approved",
+	"pr.changes":  "changes requested",
+	"pr.review":   "review required",
+
+	// Output preview
+	"preview.title":     "Output: %s",
+	"preview.help":      "  (tab: next pane, o: close)",
+	"preview.no_output": "No output yet",
+
+	// Review picker
+	"reviews.title":    "Review a Pull Request",
+	"reviews.help":     "↑↓: Select | Enter: Check out in review-<number> | Esc: Cancel",
+	"reviews.none":     "No open pull requests",
+	"reviews.fetching": "Fetching open pull requests...",
+
+	// Remote branch picker
+	"remote.title":      "Check Out a Teammate's Branch",
+	"remote.filter":     "Filter: ",
+	"remote.no_matches": "No branches match",
+	"remote.help":       "Type to filter | ↑↓: Select | Enter: Track in a new worktree | Esc: Clear filter / Cancel",
+	"remote.none":       "No remote branches without a worktree",
+	"remote.fetching":   "Fetching remote branches...",
+
+	// Session info
+	"session.title":              "Session: %s",
+	"session.failed":             "Failed to start",
+	"session.unnamed":            "(unnamed)",
+	"session.exited":             " (exited)",
+	"session.crashed":            " (crashed, exit status %d)",
+	"session.windows":            "Windows",
+	"session.layout_window":      " (worktree panes)",
+	"session.panes":              "Panes",
+	"session.environment":        "Environment",
+	"session.no_env":             "  Nothing set for this session",
+	"session.help":               "↑/↓: Select window | n: Rename | K/J: Move up/down | x: Restart crashed | r: Reload | Esc: Close",
+	"session.help_retry":         "f: Retry failed | %s",
+	"session.rename_help":        "Enter: Rename window | Esc: Cancel",
+	"session.renamed":            "Renamed window %s to %s",
+	"session.moved":              "Moved window",
+	"session.still_failed.one":   "%d pane still failed to start",
+	"session.still_failed.other": "%d panes still failed to start",
+	"session.all_started":        "All panes started",
+	"session.no_crashed":         "No crashed panes in %s",
+	"session.restarted.one":      "Restarted %d pane in %s",
+	"session.restarted.other":    "Restarted %d panes in %s",
+
+	// Settings screen
+	"settings.title":         "Settings",
+	"settings.help":          "↑↓: Select | Enter/Space: Change | Esc: Close",
+	"settings.theme":         "Theme",
+	"settings.sort_order":    "Sort order",
+	"settings.group":         "Group by",
+	"settings.confirm":       "Confirm deletes",
+	"settings.auto_refresh":  "Auto-refresh",
+	"settings.hide_done":     "Hide done",
+	"settings.hide_blocked":  "Hide blocked",
+	"settings.archive_after": "Archive done todos",
+	"settings.trash":         "Trash dirty worktrees",
+	"settings.save_sessions": "Save killed sessions",
+	"settings.mouse":         "Mouse scrolling and clicks",
+
+	// Status line
+	"status.working":     "Working...",
+	"commit.done":        "Committed in %s",
+	"push.done":          "Pushed %s to origin",
+	"copy.done":          "Copied %s to clipboard",
+	"pin.added":          "Pinned %s",
+	"pin.removed":        "Unpinned %s",
+	"colour.set":         "Coloured %s %s",
+	"colour.cleared":     "Cleared colour for %s",
+	"notes.saved":        "Saved notes for %s",
+	"notes.only_todos":   "Only todos have notes",
+	"config.reloaded":    "Reloaded %s",
+	"history.start":      "Nothing further back in the history",
+	"history.end":        "Nothing further forward in the history",
+	"mirror.failed":      "GitHub mirror failed, will retry: %s",
+	"reconcile.complete": "Session already has every pane in the layout",
+	"reconcile.added":    "Added %s",
+
+	// Setup summary
+	"setup.title":    "Setup: %s",
+	"setup.help":     "Enter: Close",
+	"setup.rerun":    "r: Re-run failed | %s",
+	"setup.running":  "Re-running failed steps...",
+	"setup.starting": "Setting up %s...",
+}
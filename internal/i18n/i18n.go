@@ -0,0 +1,112 @@
+// Package i18n looks up the TUI's help text, prompts and confirmations in a message catalog
+//
+// English is built in. Translations are YAML files of message keys, read from
+// ~/.config/lfg/locales/<language>.yaml (or under $XDG_CONFIG_HOME), so they can be added without
+// rebuilding lfg. Messages missing from a translation fall back to English.
+package i18n
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+
+	"gopkg.in/yaml.v3"
+)
+
+var (
+	mu       sync.RWMutex
+	language = "en"
+	messages = map[string]string{}
+)
+
+// Language picks the language to use: the configured one, else the one from LC_ALL, LC_MESSAGES or
+// LANG, e.g. "de" for "de_DE.UTF-8"
+func Language(configured string) string {
+	if configured != "" {
+		return normalize(configured)
+	}
+	for _, env := range []string{"LC_ALL", "LC_MESSAGES", "LANG"} {
+		if value := os.Getenv(env); value != "" {
+			return normalize(value)
+		}
+	}
+	return "en"
+}
+
+// normalize reduces a locale like "pt_BR.UTF-8" to its language, "pt"
+func normalize(locale string) string {
+	locale = strings.ToLower(locale)
+	if i := strings.IndexAny(locale, "_.@-"); i >= 0 {
+		locale = locale[:i]
+	}
+	if locale == "" || locale == "c" || locale == "posix" {
+		return "en"
+	}
+	return locale
+}
+
+// LocalesDir returns where translations are read from
+func LocalesDir() (string, error) {
+	configHome := os.Getenv("XDG_CONFIG_HOME")
+	if configHome == "" {
+		home, err := os.UserHomeDir()
+		if err != nil {
+			return "", fmt.Errorf("failed to find home directory: %w", err)
+		}
+		configHome = filepath.Join(home, ".config")
+	}
+	return filepath.Join(configHome, "lfg", "locales"), nil
+}
+
+// SetLanguage switches to a language, loading its translation file when it isn't English
+// A missing translation isn't an error, English is used instead
+func SetLanguage(lang string) error {
+	translated := map[string]string{}
+	if lang != "en" {
+		dir, err := LocalesDir()
+		if err != nil {
+			return err
+		}
+		data, err := os.ReadFile(filepath.Join(dir, lang+".yaml"))
+		if err != nil && !os.IsNotExist(err) {
+			return fmt.Errorf("failed to read %s translation: %w", lang, err)
+		}
+		if err := yaml.Unmarshal(data, &translated); err != nil {
+			return fmt.Errorf("failed to parse %s translation: %w", lang, err)
+		}
+	}
+
+	mu.Lock()
+	defer mu.Unlock()
+	language = lang
+	messages = translated
+	return nil
+}
+
+// T returns the message for key in the current language, formatted with args when there are any
+func T(key string, args ...any) string {
+	mu.RLock()
+	message, ok := messages[key]
+	mu.RUnlock()
+	if !ok {
+		message, ok = english[key]
+	}
+	if !ok {
+		return key
+	}
+	if len(args) == 0 {
+		return message
+	}
+	return fmt.Sprintf(message, args...)
+}
+
+// N returns the singular (key.one) or plural (key.other) message for a count, which is the first arg
+func N(key string, n int, args ...any) string {
+	form := ".other"
+	if n == 1 {
+		form = ".one"
+	}
+	return T(key+form, append([]any{n}, args...)...)
+}
@@ -0,0 +1,75 @@
+package i18n
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestLanguage(t *testing.T) {
+	tests := []struct {
+		name       string
+		configured string
+		lang       string
+		want       string
+	}{
+		{name: "configured wins", configured: "fr", lang: "de_DE.UTF-8", want: "fr"},
+		{name: "from LANG", lang: "pt_BR.UTF-8", want: "pt"},
+		{name: "C locale", lang: "C", want: "en"},
+		{name: "nothing set", want: "en"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Setenv("LC_ALL", "")
+			t.Setenv("LC_MESSAGES", "")
+			t.Setenv("LANG", tt.lang)
+			if got := Language(tt.configured); got != tt.want {
+				t.Errorf("Language(%q) = %q, want %q", tt.configured, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestTranslation(t *testing.T) {
+	dir := t.TempDir()
+	t.Setenv("XDG_CONFIG_HOME", dir)
+	locales := filepath.Join(dir, "lfg", "locales")
+	if err := os.MkdirAll(locales, 0755); err != nil {
+		t.Fatal(err)
+	}
+	translation := "settings.title: Einstellungen\nsafety.modified.other: \"%d geänderte Dateien\"\n"
+	if err := os.WriteFile(filepath.Join(locales, "de.yaml"), []byte(translation), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := SetLanguage("de"); err != nil {
+		t.Fatalf("SetLanguage() error = %v", err)
+	}
+	defer SetLanguage("en")
+
+	if got := T("settings.title"); got != "Einstellungen" {
+		t.Errorf("T() = %q, want the translation", got)
+	}
+	if got := N("safety.modified", 3); got != "3 geänderte Dateien" {
+		t.Errorf("N() = %q, want the translated plural", got)
+	}
+	// Messages the translation leaves out are in English
+	if got := N("safety.modified", 1); got != "1 modified file" {
+		t.Errorf("N() = %q, want the English fallback", got)
+	}
+	if err := SetLanguage("fr"); err != nil {
+		t.Errorf("SetLanguage() without a translation error = %v, want English", err)
+	}
+}
+
+func TestCatalogPlurals(t *testing.T) {
+	for key := range english {
+		if base, ok := strings.CutSuffix(key, ".one"); ok {
+			if _, ok := english[base+".other"]; !ok {
+				t.Errorf("%s has no .other form", key)
+			}
+		}
+	}
+}
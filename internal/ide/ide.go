@@ -0,0 +1,171 @@
+package ide
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+const (
+	TypeVSCode          = "vscode"
+	TypeVSCodeWorkspace = "vscode-workspace"
+	TypeJetBrains       = "jetbrains"
+	TypeZed             = "zed"
+	TypeTerminal        = "terminal" // An editor running in the terminal, e.g. nvim or helix
+)
+
+// Open opens a worktree path in the IDE configured for the repository
+// Defaults to VS Code when no ide section is configured
+// Terminal editors take over the terminal until they exit
+func Open(path string, cfg *config.Config) error {
+	if cmd := TerminalCommand(path, cfg); cmd != nil {
+		cmd.Stdin = os.Stdin
+		cmd.Stdout = os.Stdout
+		cmd.Stderr = os.Stderr
+		if err := cmd.Run(); err != nil {
+			return fmt.Errorf("failed to run %s: %w", cmd.Args[0], err)
+		}
+		return nil
+	}
+
+	ideCfg := ideConfig(cfg)
+	switch ideCfg.Type {
+	case "", TypeVSCode:
+		return launch(commandOrDefault(ideCfg.Command, "code"), path)
+
+	case TypeVSCodeWorkspace:
+		if ideCfg.WorkspaceFile == "" {
+			return fmt.Errorf("ide type %q requires workspace_file", ideCfg.Type)
+		}
+
+		// Workspace files are resolved relative to the repo root (where the config lives)
+		workspaceFile := ideCfg.WorkspaceFile
+		if !filepath.IsAbs(workspaceFile) {
+			workspaceFile = filepath.Join(filepath.Dir(cfg.GetConfigPath()), workspaceFile)
+		}
+
+		if err := AddToWorkspace(workspaceFile, path); err != nil {
+			return err
+		}
+		return launch(commandOrDefault(ideCfg.Command, "code"), workspaceFile)
+
+	case TypeJetBrains:
+		// JetBrains Toolbox generates shell scripts per IDE (idea, goland, webstorm, ...)
+		return launch(commandOrDefault(ideCfg.Command, "idea"), path)
+
+	case TypeZed:
+		return launch(commandOrDefault(ideCfg.Command, "zed"), path)
+	}
+
+	return fmt.Errorf("unknown ide type %q", ideCfg.Type)
+}
+
+// TerminalCommand returns the command that opens a worktree path in the repository's terminal editor,
+// run from the worktree, or nil when the IDE isn't one. The command defaults to $VISUAL or $EDITOR,
+// falling back to vi, and may include arguments, e.g. "nvim -c Telescope"
+func TerminalCommand(path string, cfg *config.Config) *exec.Cmd {
+	ideCfg := ideConfig(cfg)
+	if ideCfg.Type != TypeTerminal {
+		return nil
+	}
+	cmd := EditorCommand(ideCfg.Command, ".")
+	cmd.Dir = path
+	return cmd
+}
+
+// EditorCommand returns the command that opens path in command, e.g. "code --wait", or when command
+// is "" in $VISUAL or $EDITOR, falling back to vi
+func EditorCommand(command, path string) *exec.Cmd {
+	for _, env := range []string{"VISUAL", "EDITOR"} {
+		if command == "" {
+			command = os.Getenv(env)
+		}
+	}
+	args := strings.Fields(command)
+	if len(args) == 0 {
+		args = []string{"vi"}
+	}
+	return exec.Command(args[0], append(args[1:], path)...)
+}
+
+// ideConfig returns the repository's ide section, VS Code when there's none
+func ideConfig(cfg *config.Config) config.IDE {
+	if cfg.IDE != nil {
+		return *cfg.IDE
+	}
+	return config.IDE{Type: TypeVSCode}
+}
+
+// AddToWorkspace adds a folder to a VS Code .code-workspace file, creating the file if needed
+func AddToWorkspace(workspaceFile, path string) error {
+	workspace := map[string]interface{}{}
+
+	data, err := os.ReadFile(workspaceFile)
+	if err == nil {
+		if err := json.Unmarshal(data, &workspace); err != nil {
+			return fmt.Errorf("failed to parse workspace file: %w", err)
+		}
+	} else if !os.IsNotExist(err) {
+		return fmt.Errorf("failed to read workspace file: %w", err)
+	}
+
+	folders, _ := workspace["folders"].([]interface{})
+	if hasFolder(folders, path) {
+		return nil
+	}
+
+	workspace["folders"] = append(folders, map[string]interface{}{
+		"name": filepath.Base(path),
+		"path": path,
+	})
+
+	data, err = json.MarshalIndent(workspace, "", "\t")
+	if err != nil {
+		return fmt.Errorf("failed to marshal workspace file: %w", err)
+	}
+
+	if err := os.WriteFile(workspaceFile, data, 0644); err != nil {
+		return fmt.Errorf("failed to write workspace file: %w", err)
+	}
+
+	return nil
+}
+
+// hasFolder checks if a workspace folders list already contains a path
+func hasFolder(folders []interface{}, path string) bool {
+	for _, f := range folders {
+		folder, ok := f.(map[string]interface{})
+		if !ok {
+			continue
+		}
+		if p, ok := folder["path"].(string); ok && filepath.Clean(p) == filepath.Clean(path) {
+			return true
+		}
+	}
+	return false
+}
+
+func commandOrDefault(command, fallback string) string {
+	if command != "" {
+		return command
+	}
+	return fallback
+}
+
+func launch(command, target string) error {
+	if _, err := exec.LookPath(command); err != nil {
+		return fmt.Errorf("%s is not installed or not on PATH", command)
+	}
+
+	cmd := exec.Command(command, target)
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to run %s: %s", command, string(output))
+	}
+	return nil
+}
@@ -0,0 +1,144 @@
+package ide
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+func TestAddToWorkspace(t *testing.T) {
+	tmpDir := t.TempDir()
+	workspaceFile := filepath.Join(tmpDir, "project.code-workspace")
+
+	// Creates the file when missing
+	if err := AddToWorkspace(workspaceFile, "/src/project-feature"); err != nil {
+		t.Fatalf("AddToWorkspace() error = %v", err)
+	}
+
+	// Adding the same folder twice should not duplicate it
+	if err := AddToWorkspace(workspaceFile, "/src/project-feature/"); err != nil {
+		t.Fatalf("AddToWorkspace() error = %v", err)
+	}
+
+	if err := AddToWorkspace(workspaceFile, "/src/project-other"); err != nil {
+		t.Fatalf("AddToWorkspace() error = %v", err)
+	}
+
+	data, err := os.ReadFile(workspaceFile)
+	if err != nil {
+		t.Fatalf("Failed to read workspace file: %v", err)
+	}
+
+	var workspace struct {
+		Folders []struct {
+			Name string `json:"name"`
+			Path string `json:"path"`
+		} `json:"folders"`
+	}
+	if err := json.Unmarshal(data, &workspace); err != nil {
+		t.Fatalf("Failed to parse workspace file: %v", err)
+	}
+
+	if len(workspace.Folders) != 2 {
+		t.Fatalf("Expected 2 folders, got %d", len(workspace.Folders))
+	}
+	if workspace.Folders[0].Name != "project-feature" {
+		t.Errorf("Expected folder name 'project-feature', got %q", workspace.Folders[0].Name)
+	}
+}
+
+func TestAddToWorkspacePreservesSettings(t *testing.T) {
+	tmpDir := t.TempDir()
+	workspaceFile := filepath.Join(tmpDir, "project.code-workspace")
+
+	existing := `{"folders": [], "settings": {"editor.tabSize": 2}}`
+	if err := os.WriteFile(workspaceFile, []byte(existing), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := AddToWorkspace(workspaceFile, "/src/project-feature"); err != nil {
+		t.Fatalf("AddToWorkspace() error = %v", err)
+	}
+
+	data, err := os.ReadFile(workspaceFile)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	var workspace map[string]interface{}
+	if err := json.Unmarshal(data, &workspace); err != nil {
+		t.Fatalf("Failed to parse workspace file: %v", err)
+	}
+	if _, ok := workspace["settings"]; !ok {
+		t.Error("Expected existing settings to be preserved")
+	}
+}
+
+func TestEditorCommand(t *testing.T) {
+	tests := []struct {
+		name     string
+		command  string
+		visual   string
+		editor   string
+		expected string
+	}{
+		{name: "command first", command: "hx", visual: "nvim", expected: "hx /tmp/notes.md"},
+		{name: "visual first", visual: "nvim", editor: "nano", expected: "nvim /tmp/notes.md"},
+		{name: "editor with args", editor: "code --wait", expected: "code --wait /tmp/notes.md"},
+		{name: "fallback", expected: "vi /tmp/notes.md"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Setenv("VISUAL", tt.visual)
+			t.Setenv("EDITOR", tt.editor)
+			if got := strings.Join(EditorCommand(tt.command, "/tmp/notes.md").Args, " "); got != tt.expected {
+				t.Errorf("EditorCommand() = %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestTerminalCommand(t *testing.T) {
+	tests := []struct {
+		name     string
+		ide      *config.IDE
+		editor   string
+		expected []string
+	}{
+		{name: "no ide section", ide: nil, expected: nil},
+		{name: "gui editor", ide: &config.IDE{Type: TypeZed}, expected: nil},
+		{name: "configured command", ide: &config.IDE{Type: TypeTerminal, Command: "nvim -c Telescope"}, editor: "vim", expected: []string{"nvim", "-c", "Telescope", "."}},
+		{name: "editor from the environment", ide: &config.IDE{Type: TypeTerminal}, editor: "hx", expected: []string{"hx", "."}},
+		{name: "fallback", ide: &config.IDE{Type: TypeTerminal}, expected: []string{"vi", "."}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Setenv("VISUAL", "")
+			t.Setenv("EDITOR", tt.editor)
+
+			cmd := TerminalCommand("/src/myapp-login", &config.Config{IDE: tt.ide})
+			if tt.expected == nil {
+				if cmd != nil {
+					t.Errorf("TerminalCommand() = %v, want nil", cmd.Args)
+				}
+				return
+			}
+			if cmd == nil {
+				t.Fatalf("TerminalCommand() = nil, want %v", tt.expected)
+			}
+			if !reflect.DeepEqual(cmd.Args, tt.expected) {
+				t.Errorf("TerminalCommand() args = %v, want %v", cmd.Args, tt.expected)
+			}
+			if cmd.Dir != "/src/myapp-login" {
+				t.Errorf("TerminalCommand() dir = %q, want the worktree", cmd.Dir)
+			}
+		})
+	}
+}
@@ -0,0 +1,149 @@
+// Package mirror copies local todos to a GitHub Project for the "hybrid" storage backend
+// The config stays the source of truth: changes only ever flow to the project, and a todo that fails
+// to mirror is tried again the next time
+package mirror
+
+import (
+	"errors"
+	"fmt"
+	"slices"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/github"
+)
+
+// Change is what mirroring did for a todo, recorded on it with Apply
+type Change struct {
+	Description string
+	Item        string // Project item ID
+	Created     bool   // The item was made for the todo, which had none to find it by
+	State       string // Status the item was set to, empty when setting it failed
+	Title       string // Title the item was given, empty when it wasn't changed
+	Archived    bool   // The item of a removed todo was archived
+}
+
+// Status returns the project status a todo is mirrored as
+func Status(todo config.Todo) string {
+	switch {
+	case todo.Status == config.TodoStatusDone:
+		return "Done"
+	case todo.Worktree != "", todo.Status == config.TodoStatusInReview:
+		return "In Progress"
+	}
+	return "Todo"
+}
+
+// needsMirror reports whether a todo has changed since it was last mirrored
+// Todos that were done before they were ever mirrored are left out of the project
+func needsMirror(todo config.Todo) bool {
+	if todo.GitHubItem == "" {
+		return todo.Status != config.TodoStatusDone
+	}
+	return todo.GitHubState != Status(todo) || todo.GitHubTitle != todo.Description
+}
+
+// Pending reports whether any todo is waiting to be mirrored, or any removed todo's item to be archived
+func Pending(cfg *config.Config) bool {
+	if len(cfg.GitHubRemoved) > 0 {
+		return true
+	}
+	for _, todo := range cfg.Todos {
+		if needsMirror(todo) {
+			return true
+		}
+	}
+	return false
+}
+
+// Sync creates project items for the todos without one, updates the items whose todo changed status or
+// description, and archives the items of removed todos. It works from copies of the todos and removed
+// items so it can run in the background, returning the changes it made for Apply to record, along with
+// an error for each todo it couldn't mirror
+func Sync(backend *config.StorageBackend, todos []config.Todo, removed []string) ([]Change, error) {
+	var changes []Change
+	var errs []error
+	for _, todo := range todos {
+		if !needsMirror(todo) {
+			continue
+		}
+
+		change := Change{Description: todo.Description, Item: todo.GitHubItem}
+		if change.Item == "" {
+			created, err := github.CreateProjectItem(backend.Owner, backend.Repo, backend.ProjectNumber, todo.Description)
+			if err != nil {
+				errs = append(errs, fmt.Errorf("%s: %w", todo.Description, err))
+				continue
+			}
+			change.Item, change.Created, change.Title = created.ID, true, todo.Description
+		} else if todo.GitHubTitle != todo.Description {
+			if err := github.UpdateProjectItemTitle(change.Item, todo.Description); err != nil {
+				errs = append(errs, fmt.Errorf("%s: %w", todo.Description, err))
+			} else {
+				change.Title = todo.Description
+			}
+		}
+
+		status := Status(todo)
+		if todo.GitHubState == status {
+			change.State = status
+		} else if err := github.UpdateProjectItemStatus(backend.Owner, backend.Repo, backend.ProjectNumber, change.Item, status); err != nil {
+			errs = append(errs, fmt.Errorf("%s: %w", todo.Description, err))
+		} else {
+			change.State = status
+		}
+		changes = append(changes, change)
+	}
+
+	for _, item := range removed {
+		if err := github.ArchiveProjectItem(backend.Owner, backend.Repo, backend.ProjectNumber, item); err != nil {
+			errs = append(errs, fmt.Errorf("removed todo's item %s: %w", item, err))
+			continue
+		}
+		changes = append(changes, Change{Item: item, Archived: true})
+	}
+	return changes, errors.Join(errs...)
+}
+
+// Apply records mirrored changes on the todos they were made for, found by their project item, reporting
+// whether any were recorded. An item created for a todo that has since been removed is left to be archived
+func Apply(cfg *config.Config, changes []Change) bool {
+	applied := false
+	for _, change := range changes {
+		if change.Archived {
+			cfg.GitHubRemoved = slices.DeleteFunc(cfg.GitHubRemoved, func(item string) bool { return item == change.Item })
+			applied = true
+			continue
+		}
+
+		todo := findTodo(cfg, change)
+		if todo == nil {
+			if change.Created {
+				cfg.GitHubRemoved = append(cfg.GitHubRemoved, change.Item)
+				applied = true
+			}
+			continue
+		}
+		todo.GitHubItem = change.Item
+		todo.GitHubState = change.State
+		if change.Title != "" {
+			todo.GitHubTitle = change.Title
+		}
+		applied = true
+	}
+	return applied
+}
+
+// findTodo returns the todo a change was made for: the one with its item, or for a new item the one
+// without an item that had the description it was created with
+func findTodo(cfg *config.Config, change Change) *config.Todo {
+	for i := range cfg.Todos {
+		todo := &cfg.Todos[i]
+		if change.Created && todo.GitHubItem == "" && todo.Description == change.Description {
+			return todo
+		}
+		if !change.Created && todo.GitHubItem == change.Item {
+			return todo
+		}
+	}
+	return nil
+}
@@ -0,0 +1,131 @@
+package mirror
+
+import (
+	"errors"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// project answers the project queries, the draft issue mutation and the item's draft issue query
+const project = `{"data":{"repository":{"projectsV2":{"nodes":[{"id":"P1","number":1,"fields":{"nodes":[{"id":"F1","name":"Status","options":[{"id":"O1","name":"Todo"},{"id":"O2","name":"In Progress"},{"id":"O3","name":"Done"}]}]}}]}},"addProjectV2DraftIssue":{"projectItem":{"id":"NEW","content":{"title":"new"}}},"node":{"content":{"id":"D1"}}}}`
+
+func TestSync(t *testing.T) {
+	backend := &config.StorageBackend{Type: "hybrid", Owner: "acme", Repo: "shop", ProjectNumber: 1}
+	todos := []config.Todo{
+		{Description: "new todo", Status: config.TodoStatusPending},
+		{Description: "done before mirroring", Status: config.TodoStatusDone},
+		{Description: "started", Status: config.TodoStatusPending, Worktree: "started", GitHubItem: "I2", GitHubState: "Todo", GitHubTitle: "started"},
+		{Description: "unchanged", Status: config.TodoStatusDone, GitHubItem: "I3", GitHubState: "Done", GitHubTitle: "unchanged"},
+		{Description: "renamed", Status: config.TodoStatusPending, GitHubItem: "I4", GitHubState: "Todo", GitHubTitle: "old name"},
+	}
+	removed := []string{"I5"}
+
+	created := Change{Description: "new todo", Item: "NEW", Created: true, State: "Todo", Title: "new todo"}
+	started := Change{Description: "started", Item: "I2", State: "In Progress"}
+	renamed := Change{Description: "renamed", Item: "I4", State: "Todo", Title: "renamed"}
+	archived := Change{Item: "I5", Archived: true}
+
+	tests := []struct {
+		name     string
+		failing  string // A mutation that fails
+		expected []Change
+		wantErr  bool
+	}{
+		{
+			name:     "mirrors changed todos",
+			expected: []Change{created, started, renamed, archived},
+		},
+		{
+			name:    "status update fails",
+			failing: "updateProjectV2ItemFieldValue",
+			expected: []Change{
+				{Description: "new todo", Item: "NEW", Created: true, Title: "new todo"},
+				{Description: "started", Item: "I2"},
+				renamed,
+				archived,
+			},
+			wantErr: true,
+		},
+		{
+			name:     "creation fails",
+			failing:  "addProjectV2DraftIssue",
+			expected: []Change{started, renamed, archived},
+			wantErr:  true,
+		},
+		{
+			name:     "title update fails",
+			failing:  "updateProjectV2DraftIssue",
+			expected: []Change{created, started, {Description: "renamed", Item: "I4", State: "Todo"}, archived},
+			wantErr:  true,
+		},
+		{
+			name:     "archiving fails",
+			failing:  "archiveProjectV2Item",
+			expected: []Change{created, started, renamed},
+			wantErr:  true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				if tt.failing != "" && strings.Contains(strings.Join(args, " "), tt.failing) {
+					return nil, errors.New("exit status 1")
+				}
+				return []byte(project), nil
+			}}
+			defer runner.Set(fake)()
+
+			changes, err := Sync(backend, todos, removed)
+			if (err != nil) != tt.wantErr {
+				t.Errorf("Sync() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if !reflect.DeepEqual(changes, tt.expected) {
+				t.Errorf("Sync() = %+v, want %+v", changes, tt.expected)
+			}
+		})
+	}
+}
+
+func TestApply(t *testing.T) {
+	cfg := &config.Config{
+		Todos: []config.Todo{
+			{Description: "new todo", Status: config.TodoStatusPending},
+			{Description: "renamed", Status: config.TodoStatusPending, Worktree: "renamed", GitHubItem: "I9", GitHubState: "Todo", GitHubTitle: "old name"},
+		},
+		GitHubRemoved: []string{"I5"},
+	}
+
+	changes := []Change{
+		{Description: "new todo", Item: "NEW", Created: true, State: "Todo", Title: "new todo"},
+		{Description: "old name", Item: "I9", State: "In Progress", Title: "renamed"}, // Renamed while mirroring
+		{Description: "other", Item: "I2", State: "In Progress"},                      // Made for a todo since removed
+		{Description: "gone", Item: "NEW2", Created: true, State: "Todo"},             // Created for a todo removed meanwhile
+		{Item: "I5", Archived: true},
+	}
+	if !Apply(cfg, changes) {
+		t.Fatal("Apply() = false, want true")
+	}
+	if todo := cfg.Todos[0]; todo.GitHubItem != "NEW" || todo.GitHubState != "Todo" || todo.GitHubTitle != "new todo" {
+		t.Errorf("Apply() recorded %q/%q/%q, want NEW/Todo/new todo", todo.GitHubItem, todo.GitHubState, todo.GitHubTitle)
+	}
+	if todo := cfg.Todos[1]; todo.GitHubItem != "I9" || todo.GitHubState != "In Progress" || todo.GitHubTitle != "renamed" {
+		t.Errorf("Apply() recorded %q/%q/%q for the renamed todo, want I9/In Progress/renamed", todo.GitHubItem, todo.GitHubState, todo.GitHubTitle)
+	}
+	if !reflect.DeepEqual(cfg.GitHubRemoved, []string{"NEW2"}) {
+		t.Errorf("GitHubRemoved = %v, want only the item created for the removed todo", cfg.GitHubRemoved)
+	}
+
+	cfg.GitHubRemoved = nil
+	if Pending(cfg) {
+		t.Error("Pending() = true after mirroring every todo")
+	}
+	cfg.Todos[1].Description = "renamed again"
+	if !Pending(cfg) {
+		t.Error("Pending() = false after a description changed")
+	}
+}
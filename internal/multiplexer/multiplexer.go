@@ -0,0 +1,98 @@
+// Package multiplexer picks what worktree sessions run in, tmux or zellij, from the config's multiplexer
+// Starting, finding and killing sessions goes through it. The session tools that inspect or rearrange
+// panes, such as the preview, crash restarts and window overrides, only work with tmux
+package multiplexer
+
+import (
+	"fmt"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/tmux"
+	"github.com/markcipolla/lfg/internal/zellij"
+)
+
+// Multiplexer starts, finds and kills the sessions worktrees are opened in
+type Multiplexer interface {
+	Name() string
+	IsInstalled() bool
+	SessionName(project, worktreeName string) string
+	SessionExists(name string) bool
+	CreateOrAttachSession(name, path string, cfg *config.Config) error
+	KillSession(name string) error
+}
+
+// current is the multiplexer set with Configure, tmux until then
+var current Multiplexer = Tmux{}
+
+// Configure picks the multiplexer named in the config, tmux when it names none
+func Configure(name string) {
+	switch name {
+	case config.MultiplexerZellij:
+		current = Zellij{}
+	default:
+		current = Tmux{}
+	}
+}
+
+// Current returns the multiplexer sessions run in
+func Current() Multiplexer {
+	return current
+}
+
+// IsTmux reports whether sessions run in tmux, for the features only tmux supports
+func IsTmux() bool {
+	_, ok := current.(Tmux)
+	return ok
+}
+
+// RequireTmux returns an error naming feature when sessions don't run in tmux, nil when they do
+func RequireTmux(feature string) error {
+	if IsTmux() {
+		return nil
+	}
+	return fmt.Errorf("%s only works with tmux, sessions run in %s (set multiplexer: tmux to use it)", feature, current.Name())
+}
+
+// AdoptLegacySessions picks up the tmux sessions an older lfg started under the worktrees' bare names,
+// see tmux.AdoptLegacySessions. Zellij sessions were always namespaced
+func AdoptLegacySessions(project string, worktreeNames ...string) {
+	if IsTmux() {
+		tmux.AdoptLegacySessions(project, worktreeNames...)
+	}
+}
+
+// Tmux runs sessions in tmux
+type Tmux struct{}
+
+func (Tmux) Name() string      { return "tmux" }
+func (Tmux) IsInstalled() bool { return tmux.IsInstalled() }
+
+func (Tmux) SessionName(project, worktreeName string) string {
+	return tmux.SessionName(project, worktreeName)
+}
+
+func (Tmux) SessionExists(name string) bool { return tmux.SessionExists(name) }
+
+func (Tmux) CreateOrAttachSession(name, path string, cfg *config.Config) error {
+	return tmux.CreateOrAttachSession(name, path, cfg)
+}
+
+func (Tmux) KillSession(name string) error { return tmux.KillSession(name) }
+
+// Zellij runs sessions in zellij
+type Zellij struct{}
+
+func (Zellij) Name() string      { return "zellij" }
+func (Zellij) IsInstalled() bool { return zellij.IsInstalled() }
+
+func (Zellij) SessionName(project, worktreeName string) string {
+	return zellij.SessionName(project, worktreeName)
+}
+
+func (Zellij) SessionExists(name string) bool { return zellij.SessionExists(name) }
+
+func (Zellij) CreateOrAttachSession(name, path string, cfg *config.Config) error {
+	return zellij.CreateOrAttachSession(name, path, cfg)
+}
+
+func (Zellij) KillSession(name string) error { return zellij.KillSession(name) }
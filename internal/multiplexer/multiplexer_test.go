@@ -0,0 +1,30 @@
+package multiplexer
+
+import "testing"
+
+func TestConfigure(t *testing.T) {
+	defer Configure("")
+
+	tests := []struct {
+		name     string
+		expected string
+		tmux     bool
+	}{
+		{name: "", expected: "tmux", tmux: true},
+		{name: "tmux", expected: "tmux", tmux: true},
+		{name: "zellij", expected: "zellij"},
+	}
+
+	for _, tt := range tests {
+		Configure(tt.name)
+		if got := Current().Name(); got != tt.expected {
+			t.Errorf("Configure(%q) picked %s, want %s", tt.name, got, tt.expected)
+		}
+		if IsTmux() != tt.tmux {
+			t.Errorf("Configure(%q): IsTmux() = %v, want %v", tt.name, IsTmux(), tt.tmux)
+		}
+		if err := RequireTmux("The preview"); (err == nil) != tt.tmux {
+			t.Errorf("Configure(%q): RequireTmux() = %v, want an error only without tmux", tt.name, err)
+		}
+	}
+}
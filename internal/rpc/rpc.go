@@ -0,0 +1,237 @@
+package rpc
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/worktree"
+)
+
+// JSON-RPC 2.0 error codes
+const (
+	codeParseError     = -32700
+	codeInvalidRequest = -32600
+	codeMethodNotFound = -32601
+	codeInvalidParams  = -32602
+	codeServerError    = -32000
+)
+
+// Request is a JSON-RPC 2.0 request, one per line on stdin
+type Request struct {
+	JSONRPC string          `json:"jsonrpc"`
+	ID      json.RawMessage `json:"id,omitempty"`
+	Method  string          `json:"method"`
+	Params  json.RawMessage `json:"params,omitempty"`
+}
+
+// Response is a JSON-RPC 2.0 response, one per line on stdout
+type Response struct {
+	JSONRPC string          `json:"jsonrpc"`
+	ID      json.RawMessage `json:"id"`
+	Result  interface{}     `json:"result,omitempty"`
+	Error   *Error          `json:"error,omitempty"`
+}
+
+// Error is a JSON-RPC 2.0 error object
+type Error struct {
+	Code    int    `json:"code"`
+	Message string `json:"message"`
+}
+
+// WorktreeInfo describes a worktree and its linked todo
+type WorktreeInfo struct {
+	Name   string       `json:"name"`
+	Path   string       `json:"path"`
+	Branch string       `json:"branch"`
+	Commit string       `json:"commit"`
+	Todo   *config.Todo `json:"todo,omitempty"`
+}
+
+// Server answers JSON-RPC requests against the repository config
+type Server struct {
+	configPath string
+}
+
+// NewServer creates a server for the config at configPath
+// The config is re-read for every request so changes made by other lfg instances are picked up
+func NewServer(configPath string) *Server {
+	return &Server{configPath: configPath}
+}
+
+// Serve reads newline-delimited requests from r and writes responses to w until r is closed
+func (s *Server) Serve(r io.Reader, w io.Writer) error {
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(make([]byte, 0, 64*1024), 1024*1024)
+	encoder := json.NewEncoder(w)
+
+	for scanner.Scan() {
+		line := scanner.Bytes()
+		if len(line) == 0 {
+			continue
+		}
+
+		resp := s.handleLine(line)
+		if resp == nil {
+			// Notifications don't get a response
+			continue
+		}
+
+		if err := encoder.Encode(resp); err != nil {
+			return fmt.Errorf("failed to write response: %w", err)
+		}
+	}
+
+	return scanner.Err()
+}
+
+func (s *Server) handleLine(line []byte) *Response {
+	var req Request
+	if err := json.Unmarshal(line, &req); err != nil {
+		return errorResponse(nil, codeParseError, fmt.Sprintf("parse error: %v", err))
+	}
+
+	if req.Method == "" {
+		return errorResponse(req.ID, codeInvalidRequest, "missing method")
+	}
+
+	result, rpcErr := s.dispatch(req.Method, req.Params)
+	if len(req.ID) == 0 {
+		return nil
+	}
+	if rpcErr != nil {
+		return &Response{JSONRPC: "2.0", ID: req.ID, Error: rpcErr}
+	}
+	return &Response{JSONRPC: "2.0", ID: req.ID, Result: result}
+}
+
+func (s *Server) dispatch(method string, params json.RawMessage) (interface{}, *Error) {
+	switch method {
+	case "worktrees.list":
+		return s.listWorktrees()
+	case "worktrees.create":
+		var p struct {
+			Description string `json:"description"`
+		}
+		if err := decodeParams(params, &p); err != nil || p.Description == "" {
+			return nil, &Error{Code: codeInvalidParams, Message: "description is required"}
+		}
+		return s.createWorktree(p.Description)
+	case "worktrees.switch":
+		var p struct {
+			Name string `json:"name"`
+		}
+		if err := decodeParams(params, &p); err != nil || p.Name == "" {
+			return nil, &Error{Code: codeInvalidParams, Message: "name is required"}
+		}
+		return s.switchWorktree(p.Name)
+	case "todos.list":
+		cfg, err := s.loadConfig()
+		if err != nil {
+			return nil, err
+		}
+		return cfg.Todos, nil
+	}
+
+	return nil, &Error{Code: codeMethodNotFound, Message: fmt.Sprintf("method %q not found", method)}
+}
+
+func (s *Server) listWorktrees() (interface{}, *Error) {
+	cfg, rpcErr := s.loadConfig()
+	if rpcErr != nil {
+		return nil, rpcErr
+	}
+
+	worktrees, err := git.ListWorktrees()
+	if err != nil {
+		return nil, serverError(err)
+	}
+
+	infos := make([]WorktreeInfo, 0, len(worktrees))
+	for _, wt := range worktrees {
+		name := wt.Name()
+		infos = append(infos, WorktreeInfo{
+			Name:   name,
+			Path:   wt.Path,
+			Branch: wt.Branch,
+			Commit: wt.Commit,
+			Todo:   cfg.GetTodoForWorktree(name),
+		})
+	}
+	return infos, nil
+}
+
+func (s *Server) createWorktree(description string) (interface{}, *Error) {
+	cfg, rpcErr := s.loadConfig()
+	if rpcErr != nil {
+		return nil, rpcErr
+	}
+
+	name := git.GenerateWorktreeName(cfg.Name, description, cfg.Naming)
+	path, err := worktree.Create(cfg, worktree.CreateOptions{Name: name, Description: description})
+	if err != nil {
+		return nil, serverError(err)
+	}
+
+	return WorktreeInfo{
+		Name: name,
+		Path: path,
+		Todo: cfg.GetTodoForWorktree(name),
+	}, nil
+}
+
+func (s *Server) switchWorktree(name string) (interface{}, *Error) {
+	cfg, rpcErr := s.loadConfig()
+	if rpcErr != nil {
+		return nil, rpcErr
+	}
+
+	// Attaching would take over the editor's terminal, so only switching tmux clients is supported
+	if err := multiplexer.RequireTmux("worktrees.switch"); err != nil {
+		return nil, serverError(err)
+	}
+	if os.Getenv("TMUX") == "" {
+		return nil, &Error{Code: codeServerError, Message: "worktrees.switch requires running inside tmux"}
+	}
+
+	if err := git.JumpToWorktree(name, cfg); err != nil {
+		return nil, serverError(err)
+	}
+	return map[string]string{"name": name}, nil
+}
+
+func (s *Server) loadConfig() (*config.Config, *Error) {
+	cfg, err := config.LoadFromPath(s.configPath)
+	if err != nil {
+		return nil, serverError(err)
+	}
+	worktree.Configure(cfg)
+	return cfg, nil
+}
+
+func decodeParams(params json.RawMessage, v interface{}) error {
+	if len(params) == 0 {
+		return nil
+	}
+	return json.Unmarshal(params, v)
+}
+
+func serverError(err error) *Error {
+	return &Error{Code: codeServerError, Message: err.Error()}
+}
+
+func errorResponse(id json.RawMessage, code int, message string) *Response {
+	if id == nil {
+		id = json.RawMessage("null")
+	}
+	return &Response{
+		JSONRPC: "2.0",
+		ID:      id,
+		Error:   &Error{Code: code, Message: message},
+	}
+}
@@ -0,0 +1,107 @@
+package rpc
+
+import (
+	"bytes"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func newTestServer(t *testing.T) *Server {
+	t.Helper()
+
+	configPath := filepath.Join(t.TempDir(), "lfg-config.yaml")
+	content := `name: myapp
+worktree_naming: Add feature
+todos:
+  - description: Add login page
+    status: pending
+    worktree: myapp-add-login-page
+`
+	if err := os.WriteFile(configPath, []byte(content), 0644); err != nil {
+		t.Fatal(err)
+	}
+	return NewServer(configPath)
+}
+
+func serve(t *testing.T, s *Server, input string) []Response {
+	t.Helper()
+
+	var out bytes.Buffer
+	if err := s.Serve(strings.NewReader(input), &out); err != nil {
+		t.Fatalf("Serve() error = %v", err)
+	}
+
+	var responses []Response
+	decoder := json.NewDecoder(&out)
+	for decoder.More() {
+		var resp Response
+		if err := decoder.Decode(&resp); err != nil {
+			t.Fatalf("Failed to decode response: %v", err)
+		}
+		responses = append(responses, resp)
+	}
+	return responses
+}
+
+func TestServeErrors(t *testing.T) {
+	tests := []struct {
+		name     string
+		input    string
+		wantCode int
+	}{
+		{
+			name:     "invalid JSON",
+			input:    "{not json}\n",
+			wantCode: codeParseError,
+		},
+		{
+			name:     "unknown method",
+			input:    `{"jsonrpc":"2.0","id":1,"method":"nope"}` + "\n",
+			wantCode: codeMethodNotFound,
+		},
+		{
+			name:     "missing params",
+			input:    `{"jsonrpc":"2.0","id":1,"method":"worktrees.create"}` + "\n",
+			wantCode: codeInvalidParams,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			responses := serve(t, newTestServer(t), tt.input)
+			if len(responses) != 1 {
+				t.Fatalf("Expected 1 response, got %d", len(responses))
+			}
+			if responses[0].Error == nil {
+				t.Fatalf("Expected error response, got %+v", responses[0])
+			}
+			if responses[0].Error.Code != tt.wantCode {
+				t.Errorf("Expected error code %d, got %d", tt.wantCode, responses[0].Error.Code)
+			}
+		})
+	}
+}
+
+func TestServeTodosList(t *testing.T) {
+	input := `{"jsonrpc":"2.0","id":7,"method":"todos.list"}` + "\n" +
+		// Notifications (no id) must not produce a response
+		`{"jsonrpc":"2.0","method":"todos.list"}` + "\n"
+
+	responses := serve(t, newTestServer(t), input)
+	if len(responses) != 1 {
+		t.Fatalf("Expected 1 response, got %d", len(responses))
+	}
+
+	resp := responses[0]
+	if string(resp.ID) != "7" {
+		t.Errorf("Expected id 7, got %s", resp.ID)
+	}
+
+	todos, ok := resp.Result.([]interface{})
+	if !ok || len(todos) != 1 {
+		t.Fatalf("Expected 1 todo, got %#v", resp.Result)
+	}
+}
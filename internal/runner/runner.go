@@ -0,0 +1,195 @@
+package runner
+
+import (
+	"bytes"
+	"fmt"
+	"io"
+	"os/exec"
+	"strings"
+	"sync"
+	"time"
+)
+
+// maxRecordedOutput caps how much of each command's output is kept in the transcript
+const maxRecordedOutput = 4096
+
+// Runner executes external commands (git, tmux, gh)
+// Tests can swap it with Set to fake command output
+type Runner interface {
+	Run(cmd *exec.Cmd) error
+	Output(cmd *exec.Cmd) ([]byte, error)
+	CombinedOutput(cmd *exec.Cmd) ([]byte, error)
+}
+
+// Entry is one executed command in the transcript
+type Entry struct {
+	Args     []string
+	Dir      string
+	Start    time.Time
+	Duration time.Duration
+	Output   string
+	Err      error
+}
+
+// Recorder runs commands and keeps a transcript of them
+type Recorder struct {
+	mu      sync.Mutex
+	entries []Entry
+}
+
+var (
+	recorder        = &Recorder{}
+	current  Runner = Exec{}
+)
+
+// EnableTranscript records the commands run from now on, for --debug-transcript
+// The transcript keeps growing while lfg runs, so it's off unless asked for
+func EnableTranscript() {
+	current = recorder
+}
+
+// Set replaces the runner used by Run, Output and CombinedOutput and returns a function restoring the previous one
+func Set(r Runner) func() {
+	previous := current
+	current = r
+	return func() { current = previous }
+}
+
+// Run runs cmd like cmd.Run
+func Run(cmd *exec.Cmd) error {
+	return current.Run(cmd)
+}
+
+// Output runs cmd like cmd.Output
+func Output(cmd *exec.Cmd) ([]byte, error) {
+	return current.Output(cmd)
+}
+
+// CombinedOutput runs cmd like cmd.CombinedOutput
+func CombinedOutput(cmd *exec.Cmd) ([]byte, error) {
+	return current.CombinedOutput(cmd)
+}
+
+// Transcript returns the commands recorded since EnableTranscript
+func Transcript() []Entry {
+	return recorder.Entries()
+}
+
+// WriteTranscript writes the transcript in a human-readable form
+func WriteTranscript(w io.Writer) {
+	for _, e := range recorder.Entries() {
+		status := "ok"
+		if e.Err != nil {
+			status = e.Err.Error()
+		}
+		fmt.Fprintf(w, "$ %s  (%s, %s)\n", strings.Join(e.Args, " "), e.Duration.Round(time.Millisecond), status)
+		if e.Dir != "" {
+			fmt.Fprintf(w, "  in %s\n", e.Dir)
+		}
+		if output := strings.TrimRight(e.Output, "\n"); output != "" {
+			fmt.Fprintf(w, "  %s\n", strings.ReplaceAll(output, "\n", "\n  "))
+		}
+	}
+}
+
+// Exec runs commands without recording them
+type Exec struct{}
+
+// Run runs cmd like cmd.Run
+func (Exec) Run(cmd *exec.Cmd) error { return cmd.Run() }
+
+// Output runs cmd like cmd.Output
+func (Exec) Output(cmd *exec.Cmd) ([]byte, error) { return cmd.Output() }
+
+// CombinedOutput runs cmd like cmd.CombinedOutput
+func (Exec) CombinedOutput(cmd *exec.Cmd) ([]byte, error) { return cmd.CombinedOutput() }
+
+// Run runs cmd and records it
+// Output the caller doesn't collect is recorded too, unless cmd is attached to the terminal
+func (r *Recorder) Run(cmd *exec.Cmd) error {
+	var buf bytes.Buffer
+	if cmd.Stdout == nil && cmd.Stderr == nil {
+		cmd.Stdout = &buf
+		cmd.Stderr = &buf
+	}
+
+	start := time.Now()
+	err := cmd.Run()
+	r.record(cmd, start, buf.Bytes(), err)
+	return err
+}
+
+// Output runs cmd, records it and returns its stdout
+func (r *Recorder) Output(cmd *exec.Cmd) ([]byte, error) {
+	start := time.Now()
+	output, err := cmd.Output()
+	r.record(cmd, start, output, err)
+	return output, err
+}
+
+// CombinedOutput runs cmd, records it and returns its stdout and stderr
+func (r *Recorder) CombinedOutput(cmd *exec.Cmd) ([]byte, error) {
+	start := time.Now()
+	output, err := cmd.CombinedOutput()
+	r.record(cmd, start, output, err)
+	return output, err
+}
+
+// Entries returns a copy of the recorded commands
+func (r *Recorder) Entries() []Entry {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return append([]Entry(nil), r.entries...)
+}
+
+func (r *Recorder) record(cmd *exec.Cmd, start time.Time, output []byte, err error) {
+	if len(output) > maxRecordedOutput {
+		output = append(output[:maxRecordedOutput:maxRecordedOutput], "..."...)
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.entries = append(r.entries, Entry{
+		Args:     cmd.Args,
+		Dir:      cmd.Dir,
+		Start:    start,
+		Duration: time.Since(start),
+		Output:   string(output),
+		Err:      err,
+	})
+}
+
+// Fake is a Runner for tests that answers commands from Handler instead of running them
+type Fake struct {
+	Handler func(args []string) ([]byte, error)
+
+	mu    sync.Mutex
+	Calls [][]string
+}
+
+func (f *Fake) call(cmd *exec.Cmd) ([]byte, error) {
+	f.mu.Lock()
+	f.Calls = append(f.Calls, cmd.Args)
+	f.mu.Unlock()
+
+	if f.Handler == nil {
+		return nil, nil
+	}
+	return f.Handler(cmd.Args)
+}
+
+// Run records the call and discards the output
+func (f *Fake) Run(cmd *exec.Cmd) error {
+	_, err := f.call(cmd)
+	return err
+}
+
+// Output records the call and returns the handler's output
+func (f *Fake) Output(cmd *exec.Cmd) ([]byte, error) {
+	return f.call(cmd)
+}
+
+// CombinedOutput records the call and returns the handler's output
+func (f *Fake) CombinedOutput(cmd *exec.Cmd) ([]byte, error) {
+	return f.call(cmd)
+}
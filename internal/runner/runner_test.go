@@ -0,0 +1,92 @@
+package runner
+
+import (
+	"bytes"
+	"errors"
+	"os/exec"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestRecorder(t *testing.T) {
+	if _, err := exec.LookPath("echo"); err != nil {
+		t.Skip("echo not available, skipping test")
+	}
+
+	r := &Recorder{}
+	output, err := r.Output(exec.Command("echo", "hello"))
+	if err != nil {
+		t.Fatalf("Output() error = %v", err)
+	}
+	if string(output) != "hello\n" {
+		t.Errorf("Output() = %q, want %q", output, "hello\n")
+	}
+
+	// Output not collected by the caller is still recorded
+	if err := r.Run(exec.Command("echo", "quiet")); err != nil {
+		t.Fatalf("Run() error = %v", err)
+	}
+
+	entries := r.Entries()
+	if len(entries) != 2 {
+		t.Fatalf("Expected 2 entries, got %d", len(entries))
+	}
+	if strings.Join(entries[0].Args, " ") != "echo hello" {
+		t.Errorf("Expected args to be recorded, got %v", entries[0].Args)
+	}
+	if entries[1].Output != "quiet\n" {
+		t.Errorf("Expected Run output to be recorded, got %q", entries[1].Output)
+	}
+}
+
+func TestFake(t *testing.T) {
+	fake := &Fake{Handler: func(args []string) ([]byte, error) {
+		if args[1] == "fail" {
+			return nil, errors.New("boom")
+		}
+		return []byte("faked"), nil
+	}}
+	restore := Set(fake)
+	defer restore()
+
+	output, err := Output(exec.Command("git", "status"))
+	if err != nil || string(output) != "faked" {
+		t.Errorf("Output() = %q, %v, want faked output", output, err)
+	}
+	if err := Run(exec.Command("git", "fail")); err == nil {
+		t.Error("Expected the handler's error")
+	}
+	if len(fake.Calls) != 2 || fake.Calls[1][1] != "fail" {
+		t.Errorf("Expected calls to be recorded, got %v", fake.Calls)
+	}
+}
+
+func TestWriteTranscript(t *testing.T) {
+	before := len(Transcript())
+	recorder.record(exec.Command("git", "status"), time.Now(), []byte("clean\n"), nil)
+	if len(Transcript()) != before+1 {
+		t.Fatal("Expected the entry to be added to the transcript")
+	}
+
+	var buf bytes.Buffer
+	WriteTranscript(&buf)
+	if !strings.Contains(buf.String(), "$ git status") || !strings.Contains(buf.String(), "  clean") {
+		t.Errorf("Unexpected transcript:\n%s", buf.String())
+	}
+}
+
+func TestTranscriptOff(t *testing.T) {
+	if _, err := exec.LookPath("echo"); err != nil {
+		t.Skip("echo not available, skipping test")
+	}
+
+	// Nothing is kept until --debug-transcript enables it
+	before := len(Transcript())
+	if _, err := Output(exec.Command("echo", "hello")); err != nil {
+		t.Fatalf("Output() error = %v", err)
+	}
+	if len(Transcript()) != before {
+		t.Error("Expected commands not to be recorded without EnableTranscript")
+	}
+}
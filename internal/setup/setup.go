@@ -0,0 +1,132 @@
+package setup
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// Step is one thing to do in a new worktree: copy a file from the main worktree, or run a command
+type Step struct {
+	Copy    string // Path relative to the worktree root
+	Command string // Run with sh -c in the new worktree
+}
+
+// Label describes the step in summaries, e.g. "copy .env" or "bundle install"
+func (s Step) Label() string {
+	if s.Copy != "" {
+		return "copy " + s.Copy
+	}
+	return s.Command
+}
+
+// Result is how a step went
+type Result struct {
+	Step     Step
+	Duration time.Duration
+	Output   string // Combined output of a command, for failures
+	Err      error
+}
+
+// Failed returns the steps that failed, to run again
+func Failed(results []Result) []Step {
+	var failed []Step
+	for _, r := range results {
+		if r.Err != nil {
+			failed = append(failed, r.Step)
+		}
+	}
+	return failed
+}
+
+// Steps returns the configured setup steps: file copies first, then commands
+func Steps(cfg *config.Setup) []Step {
+	if cfg == nil {
+		return nil
+	}
+	var steps []Step
+	for _, path := range cfg.Copy {
+		steps = append(steps, Step{Copy: path})
+	}
+	for _, command := range cfg.Run {
+		steps = append(steps, Step{Command: command})
+	}
+	return steps
+}
+
+// Run runs the steps in order for the worktree at path, copying files from mainPath
+// Every step runs even if an earlier one failed, so the summary shows everything that needs attention
+func Run(steps []Step, mainPath, path string) []Result {
+	results := make([]Result, 0, len(steps))
+	for _, step := range steps {
+		start := time.Now()
+		output, err := step.run(mainPath, path)
+		results = append(results, Result{Step: step, Duration: time.Since(start), Output: output, Err: err})
+	}
+	return results
+}
+
+func (s Step) run(mainPath, path string) (string, error) {
+	if s.Copy != "" {
+		return "", copyFile(filepath.Join(mainPath, s.Copy), filepath.Join(path, s.Copy))
+	}
+
+	cmd := exec.Command("sh", "-c", s.Command)
+	cmd.Dir = path
+	output, err := runner.CombinedOutput(cmd)
+	if err != nil {
+		return strings.TrimSpace(string(output)), fmt.Errorf("%s failed: %w", s.Command, err)
+	}
+	return "", nil
+}
+
+// copyFile copies src to dst, keeping its permissions and creating dst's directory if needed
+func copyFile(src, dst string) error {
+	in, err := os.Open(src)
+	if err != nil {
+		return fmt.Errorf("failed to copy %s: %w", filepath.Base(src), err)
+	}
+	defer in.Close()
+
+	info, err := in.Stat()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(dst), 0755); err != nil {
+		return err
+	}
+
+	out, err := os.OpenFile(dst, os.O_WRONLY|os.O_CREATE|os.O_TRUNC, info.Mode().Perm())
+	if err != nil {
+		return fmt.Errorf("failed to copy %s: %w", filepath.Base(src), err)
+	}
+	if _, err := io.Copy(out, in); err != nil {
+		out.Close()
+		return fmt.Errorf("failed to copy %s: %w", filepath.Base(src), err)
+	}
+	return out.Close()
+}
+
+// Summary renders results as one line per step, e.g. "✓ copy .env" or "✗ bin/setup (12s)"
+func Summary(results []Result) []string {
+	lines := make([]string, 0, len(results))
+	for _, r := range results {
+		mark := "✓"
+		if r.Err != nil {
+			mark = "✗"
+		}
+		line := fmt.Sprintf("%s %s", mark, r.Step.Label())
+		if r.Step.Command != "" {
+			line += fmt.Sprintf(" (%s)", r.Duration.Round(time.Second))
+		}
+		lines = append(lines, line)
+	}
+	return lines
+}
@@ -0,0 +1,55 @@
+package setup
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"reflect"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+func TestRun(t *testing.T) {
+	if _, err := exec.LookPath("sh"); err != nil {
+		t.Skip("sh not installed, skipping test")
+	}
+
+	mainPath := t.TempDir()
+	path := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(mainPath, "config"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(mainPath, "config", "master.key"), []byte("secret"), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	steps := Steps(&config.Setup{
+		Copy: []string{"config/master.key", ".env"},
+		Run:  []string{"echo ok > installed", "echo broken; exit 1"},
+	})
+	results := Run(steps, mainPath, path)
+
+	if got := Summary(results); len(got) != 4 || got[0] != "✓ copy config/master.key" || got[1] != "✗ copy .env" {
+		t.Errorf("Summary() = %v", got)
+	}
+
+	data, err := os.ReadFile(filepath.Join(path, "config", "master.key"))
+	if err != nil || string(data) != "secret" {
+		t.Errorf("copied master.key = %q, %v", data, err)
+	}
+	if info, err := os.Stat(filepath.Join(path, "config", "master.key")); err == nil && info.Mode().Perm() != 0600 {
+		t.Errorf("copied master.key mode = %v, want 0600", info.Mode().Perm())
+	}
+	if _, err := os.Stat(filepath.Join(path, "installed")); err != nil {
+		t.Errorf("command didn't run in the worktree: %v", err)
+	}
+	if results[3].Output != "broken" {
+		t.Errorf("failed command output = %q, want %q", results[3].Output, "broken")
+	}
+
+	expected := []Step{{Copy: ".env"}, {Command: "echo broken; exit 1"}}
+	if failed := Failed(results); !reflect.DeepEqual(failed, expected) {
+		t.Errorf("Failed() = %v, want %v", failed, expected)
+	}
+}
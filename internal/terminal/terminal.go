@@ -0,0 +1,60 @@
+package terminal
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+)
+
+// Prompt asks question on stderr and returns the line typed in answer, trimmed and lowercased
+// ok is false without asking when stdin isn't a terminal, so callers pick a default instead of blocking
+func Prompt(question string) (answer string, ok bool) {
+	if stat, err := os.Stdin.Stat(); err != nil || stat.Mode()&os.ModeCharDevice == 0 {
+		return "", false
+	}
+	fmt.Fprint(os.Stderr, question)
+	line, _ := bufio.NewReader(os.Stdin).ReadString('\n')
+	return strings.ToLower(strings.TrimSpace(line)), true
+}
+
+// SupportsHyperlinks reports whether the terminal is known to render OSC 8 hyperlinks
+// Set LFG_HYPERLINKS=1 or LFG_HYPERLINKS=0 to override detection
+func SupportsHyperlinks() bool {
+	if v := os.Getenv("LFG_HYPERLINKS"); v != "" {
+		return v == "1" || v == "true"
+	}
+
+	switch os.Getenv("TERM_PROGRAM") {
+	case "iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper":
+		return true
+	}
+
+	// Windows Terminal
+	if os.Getenv("WT_SESSION") != "" {
+		return true
+	}
+
+	// GNOME Terminal, Tilix and other VTE terminals since 0.50
+	if vte, err := strconv.Atoi(os.Getenv("VTE_VERSION")); err == nil && vte >= 5000 {
+		return true
+	}
+
+	term := os.Getenv("TERM")
+	for _, t := range []string{"kitty", "alacritty", "foot", "wezterm", "ghostty"} {
+		if strings.Contains(term, t) {
+			return true
+		}
+	}
+
+	return false
+}
+
+// Hyperlink wraps text in an OSC 8 hyperlink to url when the terminal supports it
+func Hyperlink(url, text string) string {
+	if url == "" || !SupportsHyperlinks() {
+		return text
+	}
+	return "\x1b]8;;" + url + "\x1b\\" + text + "\x1b]8;;\x1b\\"
+}
@@ -0,0 +1,83 @@
+package terminal
+
+import (
+	"os"
+	"testing"
+)
+
+func TestHyperlink(t *testing.T) {
+	tests := []struct {
+		name       string
+		hyperlinks string
+		url        string
+		text       string
+		expected   string
+	}{
+		{
+			name:       "supported",
+			hyperlinks: "1",
+			url:        "https://github.com/owner/repo/issues/12",
+			text:       "#12",
+			expected:   "\x1b]8;;https://github.com/owner/repo/issues/12\x1b\\#12\x1b]8;;\x1b\\",
+		},
+		{
+			name:       "unsupported",
+			hyperlinks: "0",
+			url:        "https://github.com/owner/repo/issues/12",
+			text:       "#12",
+			expected:   "#12",
+		},
+		{
+			name:       "empty url",
+			hyperlinks: "1",
+			url:        "",
+			text:       "#12",
+			expected:   "#12",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Setenv("LFG_HYPERLINKS", tt.hyperlinks)
+			result := Hyperlink(tt.url, tt.text)
+			if result != tt.expected {
+				t.Errorf("Hyperlink(%q, %q) = %q, want %q", tt.url, tt.text, result, tt.expected)
+			}
+		})
+	}
+}
+
+func TestSupportsHyperlinksDetection(t *testing.T) {
+	t.Setenv("LFG_HYPERLINKS", "")
+	t.Setenv("TERM_PROGRAM", "")
+	t.Setenv("WT_SESSION", "")
+	t.Setenv("VTE_VERSION", "")
+	t.Setenv("TERM", "xterm-kitty")
+
+	if !SupportsHyperlinks() {
+		t.Error("Expected kitty to support hyperlinks")
+	}
+
+	t.Setenv("TERM", "dumb")
+	if SupportsHyperlinks() {
+		t.Error("Expected dumb terminal not to support hyperlinks")
+	}
+}
+
+func TestPromptWithoutTerminal(t *testing.T) {
+	stdin, err := os.CreateTemp(t.TempDir(), "stdin")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer stdin.Close()
+	stdin.WriteString("yes\n")
+	stdin.Seek(0, 0)
+
+	saved := os.Stdin
+	os.Stdin = stdin
+	defer func() { os.Stdin = saved }()
+
+	if answer, ok := Prompt("Continue? "); ok || answer != "" {
+		t.Errorf("Prompt() with stdin from a file = %q, %v, want no answer so the caller's default applies", answer, ok)
+	}
+}
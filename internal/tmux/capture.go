@@ -0,0 +1,49 @@
+package tmux
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// PaneNames lists the layout names of a session's tagged panes, in pane order
+func PaneNames(sessionName string) ([]string, error) {
+	_, order, err := taggedPanes(sessionName)
+	return order, err
+}
+
+// CapturePane returns the last lines of output in a session's pane, given by its layout name
+// An empty name captures the window's active pane
+func CapturePane(sessionName, pane string, lines int) (string, error) {
+	target := layoutWindow(sessionName)
+	if pane != "" {
+		running, _, err := taggedPanes(sessionName)
+		if err != nil {
+			return "", err
+		}
+		id, ok := running[pane]
+		if !ok {
+			return "", fmt.Errorf("no pane named %s in %s", pane, sessionName)
+		}
+		target = id
+	}
+
+	// Include some scrollback, since the visible screen is often mostly blank below the last output
+	cmd := Command("capture-pane", "-p", "-t", target, "-S", "-"+strconv.Itoa(lines))
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return "", fmt.Errorf("failed to capture pane: %w", err)
+	}
+	return lastLines(string(output), lines), nil
+}
+
+// lastLines returns up to n lines from the end of output, ignoring trailing blank lines
+func lastLines(output string, n int) string {
+	all := strings.Split(strings.TrimRight(output, " \n"), "\n")
+	for i, line := range all {
+		all[i] = strings.TrimRight(line, " ")
+	}
+	return strings.Join(all[max(len(all)-n, 0):], "\n")
+}
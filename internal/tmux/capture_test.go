@@ -0,0 +1,58 @@
+package tmux
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestLastLines(t *testing.T) {
+	tests := []struct {
+		name     string
+		output   string
+		n        int
+		expected string
+	}{
+		{name: "fewer lines than asked for", output: "one\ntwo\n", n: 5, expected: "one\ntwo"},
+		{name: "keeps the end", output: "one\ntwo\nthree\nfour\n", n: 2, expected: "three\nfour"},
+		{name: "ignores blank screen below the output", output: "one\ntwo   \n\n\n   \n\n", n: 2, expected: "one\ntwo"},
+		{name: "empty pane", output: "\n\n", n: 3, expected: ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := lastLines(tt.output, tt.n); got != tt.expected {
+				t.Errorf("lastLines() = %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestCapturePane(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch args[1] {
+		case "list-panes":
+			return []byte("%0\tagent\n%1\tserver\n"), nil
+		case "capture-pane":
+			return []byte("Listening on :3000\npanic: runtime error\n\n\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	output, err := CapturePane("myapp/login", "server", 30)
+	if err != nil {
+		t.Fatalf("CapturePane() error = %v", err)
+	}
+	if output != "Listening on :3000\npanic: runtime error" {
+		t.Errorf("CapturePane() = %q", output)
+	}
+	if got := strings.Join(fake.Calls[len(fake.Calls)-1], " "); got != "tmux capture-pane -p -t %1 -S -30" {
+		t.Errorf("CapturePane() ran %q", got)
+	}
+
+	if _, err := CapturePane("myapp/login", "tests", 30); err == nil {
+		t.Error("CapturePane() of a pane that isn't running should fail")
+	}
+}
@@ -0,0 +1,99 @@
+package tmux
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// runPaneCommand types a layout pane's command into its shell. With remain, the command replaces the
+// shell and the pane stays open once it exits, marked dead, so a crashed server shows up instead of
+// quietly leaving a prompt behind or closing the pane
+// The command is run by sh -c rather than exec'd itself, so the rest of e.g. `npm install && npm run dev`
+// still runs
+func runPaneCommand(pane, command string, remain bool) error {
+	if remain {
+		runner.Run(Command("set-option", "-p", "-t", pane, "remain-on-exit", "on"))
+		command = "exec sh -c " + shellQuote(command)
+	}
+	return runner.Run(Command("send-keys", "-t", pane, command, "Enter"))
+}
+
+// shellQuote quotes s as a single shell word
+func shellQuote(s string) string {
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
+
+// deadPaneFormat is the list-panes format parsed by CrashedPanes and RestartCrashed
+const deadPaneFormat = "#{pane_id}\t#{pane_dead}\t#{" + paneOption + "}"
+
+// deadPanes maps the IDs of a session's dead panes to their layout names
+func deadPanes(sessionName string) (map[string]string, []string, error) {
+	cmd := Command("list-panes", "-s", "-t", exact(sessionName), "-F", deadPaneFormat)
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to list panes: %w", err)
+	}
+	dead := make(map[string]string)
+	var order []string
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		fields := strings.Split(line, "\t")
+		if len(fields) != 3 || fields[1] != "1" {
+			continue
+		}
+		dead[fields[0]] = fields[2]
+		order = append(order, fields[0])
+	}
+	return dead, order, nil
+}
+
+// CrashedPanes returns the layout names of a session's panes whose command has exited and that were
+// kept open by remain_on_exit, "" for unnamed ones
+func CrashedPanes(sessionName string) ([]string, error) {
+	dead, order, err := deadPanes(sessionName)
+	if err != nil {
+		return nil, err
+	}
+	names := make([]string, 0, len(order))
+	for _, id := range order {
+		names = append(names, dead[id])
+	}
+	return names, nil
+}
+
+// RestartCrashed restarts the dead panes of a worktree's session in the worktree, running their layout
+// command again, and returns their names
+func RestartCrashed(worktreeName, path string, cfg *config.Config) ([]string, error) {
+	sessionName := SessionName(cfg.Name, worktreeName)
+	if err := checkManaged(sessionName, worktreeName); err != nil {
+		return nil, err
+	}
+	dead, order, err := deadPanes(sessionName)
+	if err != nil {
+		return nil, err
+	}
+	panes := make(map[string]layoutPane)
+	for _, pane := range layoutPanes(cfg) {
+		panes[pane.name] = pane
+	}
+
+	var restarted []string
+	for _, id := range order {
+		name := dead[id]
+		if err := runner.Run(Command(withShell("respawn-pane", "-k", "-t", id, "-c", path)...)); err != nil {
+			return restarted, fmt.Errorf("failed to restart pane %s: %w", name, err)
+		}
+		if name == agentPaneName {
+			err = setupAgentPane(id, worktreeName, path, cfg)
+		} else if pane, ok := panes[name]; ok && pane.command != nil && *pane.command != "" {
+			err = runPaneCommand(id, *pane.command, pane.remain)
+		}
+		if err != nil {
+			return restarted, fmt.Errorf("failed to run command in pane %s: %w", name, err)
+		}
+		restarted = append(restarted, name)
+	}
+	return restarted, nil
+}
@@ -0,0 +1,102 @@
+package tmux
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestRunPaneCommand(t *testing.T) {
+	tests := []struct {
+		name     string
+		command  string
+		remain   bool
+		expected []string
+	}{
+		{name: "typed into the shell", expected: []string{"tmux send-keys -t %3 bin/dev Enter"}},
+		{name: "remain on exit", remain: true, expected: []string{
+			"tmux set-option -p -t %3 remain-on-exit on",
+			"tmux send-keys -t %3 exec sh -c 'bin/dev' Enter",
+		}},
+		{name: "compound command", command: "npm install && npm run dev -- --name 'my app'", remain: true, expected: []string{
+			"tmux set-option -p -t %3 remain-on-exit on",
+			`tmux send-keys -t %3 exec sh -c 'npm install && npm run dev -- --name '\''my app'\''' Enter`,
+		}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{}
+			defer runner.Set(fake)()
+
+			command := tt.command
+			if command == "" {
+				command = "bin/dev"
+			}
+			if err := runPaneCommand("%3", command, tt.remain); err != nil {
+				t.Fatalf("runPaneCommand() error = %v", err)
+			}
+			var calls []string
+			for _, call := range fake.Calls {
+				calls = append(calls, strings.Join(call, " "))
+			}
+			if !reflect.DeepEqual(calls, tt.expected) {
+				t.Errorf("runPaneCommand() ran %q, want %q", calls, tt.expected)
+			}
+		})
+	}
+}
+
+func TestRestartCrashed(t *testing.T) {
+	server := "bin/rails server"
+	cfg := &config.Config{Name: "myapp", Layout: []config.LayoutRow{
+		{Height: "50%", Name: "server", Command: &server, RemainOnExit: true},
+		{Height: "50%", Name: "shell"},
+	}}
+
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch args[1] {
+		case "show-options":
+			return []byte("myapp-login\n"), nil
+		case "list-panes":
+			return []byte("%0\t0\tagent\n%1\t1\tserver\n%2\t0\tshell\n%5\t1\t\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	crashed, err := CrashedPanes("myapp/myapp-login")
+	if err != nil {
+		t.Fatalf("CrashedPanes() error = %v", err)
+	}
+	if !reflect.DeepEqual(crashed, []string{"server", ""}) {
+		t.Errorf("CrashedPanes() = %q, want the server and the unnamed pane", crashed)
+	}
+
+	fake.Calls = nil
+	restarted, err := RestartCrashed("myapp-login", "/src/myapp-login", cfg)
+	if err != nil {
+		t.Fatalf("RestartCrashed() error = %v", err)
+	}
+	if !reflect.DeepEqual(restarted, []string{"server", ""}) {
+		t.Errorf("RestartCrashed() = %q, want the server and the unnamed pane", restarted)
+	}
+
+	var calls []string
+	for _, call := range fake.Calls {
+		if call[1] == "respawn-pane" || call[1] == "send-keys" {
+			calls = append(calls, strings.Join(call, " "))
+		}
+	}
+	expected := []string{
+		"tmux respawn-pane -k -t %1 -c /src/myapp-login",
+		"tmux send-keys -t %1 exec bin/rails server Enter",
+		"tmux respawn-pane -k -t %5 -c /src/myapp-login",
+	}
+	if !reflect.DeepEqual(calls, expected) {
+		t.Errorf("RestartCrashed() ran %q, want %q", calls, expected)
+	}
+}
@@ -0,0 +1,135 @@
+package tmux
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// failuresOption records the panes that failed to start in a session, so they can be shown and retried later
+const failuresOption = "@lfg-failures"
+
+// PaneFailure is a pane that couldn't be created, or whose command couldn't be started
+type PaneFailure struct {
+	Pane string // Layout name, empty for unnamed panes
+	Err  string
+}
+
+// rowFailures records every pane of a layout row as failed with err
+func rowFailures(row config.LayoutRow, err error) []PaneFailure {
+	if len(row.Panes) == 0 {
+		return []PaneFailure{{Pane: row.Name, Err: err.Error()}}
+	}
+	var failures []PaneFailure
+	for _, pane := range row.Panes {
+		failures = append(failures, paneFailures(pane, err)...)
+	}
+	return failures
+}
+
+// formatFailures encodes failures as one "pane\terror" line each
+func formatFailures(failures []PaneFailure) string {
+	lines := make([]string, len(failures))
+	for i, failure := range failures {
+		lines[i] = failure.Pane + "\t" + strings.Join(strings.Fields(failure.Err), " ")
+	}
+	return strings.Join(lines, "\n")
+}
+
+// parseFailures decodes the output of formatFailures
+func parseFailures(output string) []PaneFailure {
+	var failures []PaneFailure
+	for _, line := range strings.Split(strings.TrimSpace(output), "\n") {
+		pane, err, ok := strings.Cut(line, "\t")
+		if !ok {
+			continue
+		}
+		failures = append(failures, PaneFailure{Pane: pane, Err: err})
+	}
+	return failures
+}
+
+// recordFailures stores a session's failed panes, clearing the record when there are none
+func recordFailures(sessionName string, failures []PaneFailure) error {
+	cmd := Command("set-option", "-u", "-t", exact(sessionName)+":", failuresOption)
+	if len(failures) > 0 {
+		cmd = Command("set-option", "-t", exact(sessionName)+":", failuresOption, formatFailures(failures))
+	}
+	if err := runner.Run(cmd); err != nil {
+		return fmt.Errorf("failed to record failed panes: %w", err)
+	}
+	return nil
+}
+
+// Failures lists the panes that failed to start when a session was created or last retried
+func Failures(sessionName string) ([]PaneFailure, error) {
+	cmd := Command("show-options", "-q", "-v", "-t", exact(sessionName)+":", failuresOption)
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read failed panes: %w", err)
+	}
+	return parseFailures(string(output)), nil
+}
+
+// RetryFailed retries the panes that failed to start in a worktree's session
+// Panes that were never created are added as Reconcile would, and panes whose command didn't start
+// have it sent again. Whatever still fails is recorded and returned, so it can be retried again
+func RetryFailed(worktreeName, path string, cfg *config.Config) ([]PaneFailure, error) {
+	sessionName := SessionName(cfg.Name, worktreeName)
+	if err := checkManaged(sessionName, worktreeName); err != nil {
+		return nil, err
+	}
+	failures, err := Failures(sessionName)
+	if err != nil || len(failures) == 0 {
+		return nil, err
+	}
+
+	result, err := Reconcile(worktreeName, path, cfg, false)
+	if err != nil {
+		return failures, err
+	}
+	added := make(map[string]bool)
+	for _, name := range result.Added {
+		added[name] = true
+	}
+
+	running, _, err := taggedPanes(sessionName)
+	if err != nil {
+		return failures, err
+	}
+	commands := make(map[string]*string)
+	remain := make(map[string]bool)
+	for _, pane := range layoutPanes(cfg) {
+		commands[pane.name] = pane.command
+		remain[pane.name] = pane.remain
+	}
+
+	var remaining []PaneFailure
+	for _, failure := range failures {
+		if added[failure.Pane] {
+			continue
+		}
+		id, ok := running[failure.Pane]
+		if !ok {
+			// Unnamed panes can't be found again, and panes removed from the layout are no longer wanted
+			if _, configured := commands[failure.Pane]; failure.Pane == "" || configured {
+				remaining = append(remaining, failure)
+			}
+			continue
+		}
+
+		var err error
+		if failure.Pane == agentPaneName {
+			err = setupAgentPane(id, worktreeName, path, cfg)
+		} else if command := commands[failure.Pane]; command != nil && *command != "" {
+			err = runPaneCommand(id, *command, remain[failure.Pane])
+		}
+		if err != nil {
+			remaining = append(remaining, PaneFailure{Pane: failure.Pane, Err: err.Error()})
+		}
+	}
+
+	return remaining, recordFailures(sessionName, remaining)
+}
@@ -0,0 +1,98 @@
+package tmux
+
+import (
+	"fmt"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestFormatFailures(t *testing.T) {
+	failures := []PaneFailure{
+		{Pane: "server", Err: "exit status 1\n(output: no space left)"},
+		{Pane: "", Err: "failed to create row 2"},
+	}
+	expected := []PaneFailure{
+		{Pane: "server", Err: "exit status 1 (output: no space left)"},
+		{Pane: "", Err: "failed to create row 2"},
+	}
+
+	if got := parseFailures(formatFailures(failures)); !reflect.DeepEqual(got, expected) {
+		t.Errorf("parseFailures(formatFailures()) = %+v, want %+v", got, expected)
+	}
+	if got := parseFailures(""); got != nil {
+		t.Errorf("parseFailures(\"\") = %+v, want nil", got)
+	}
+}
+
+func TestRetryFailed(t *testing.T) {
+	goTest := "go test ./..."
+	cfg := &config.Config{
+		Name: "myapp",
+		Layout: []config.LayoutRow{
+			{Height: "50%", Name: "server"},
+			{Height: "50%", Panes: []config.Pane{{Name: "shell"}, {Name: "tests", Command: &goTest}}},
+		},
+	}
+
+	tests := []struct {
+		name      string
+		sendKeys  error
+		remaining []PaneFailure
+	}{
+		{name: "all panes start", remaining: nil},
+		{name: "command still fails", sendKeys: fmt.Errorf("exit status 1"), remaining: []PaneFailure{{Pane: "tests", Err: "exit status 1"}}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				switch args[1] {
+				case "has-session", "set-option":
+					return nil, nil
+				case "show-options":
+					if args[len(args)-1] == managedOption {
+						return []byte("myapp-login\n"), nil
+					}
+					return []byte("tests\texit status 1\nshell\tfailed to create horizontal pane 1 in row 1\n"), nil
+				case "list-panes":
+					return []byte("%0\tagent\n%1\tserver\n%2\ttests\n"), nil
+				case "split-window":
+					return []byte("%3\n"), nil
+				case "send-keys":
+					return nil, tt.sendKeys
+				}
+				return nil, fmt.Errorf("unexpected command %v", args)
+			}}
+			defer runner.Set(fake)()
+
+			remaining, err := RetryFailed("myapp-login", "/tmp/myapp-login", cfg)
+			if err != nil {
+				t.Fatalf("RetryFailed() error = %v", err)
+			}
+			if !reflect.DeepEqual(remaining, tt.remaining) {
+				t.Errorf("RetryFailed() = %+v, want %+v", remaining, tt.remaining)
+			}
+
+			var calls []string
+			for _, call := range fake.Calls {
+				calls = append(calls, strings.Join(call, " "))
+			}
+			ran := strings.Join(calls, "\n")
+			for _, want := range []string{
+				"tmux set-option -p -t %3 @lfg-pane shell",
+				"tmux send-keys -t %2 go test ./... Enter",
+			} {
+				if !strings.Contains(ran, want) {
+					t.Errorf("RetryFailed() didn't run %q, ran:\n%s", want, ran)
+				}
+			}
+			if cleared := strings.Contains(ran, "set-option -u -t =myapp/myapp-login: @lfg-failures"); cleared != (tt.remaining == nil) {
+				t.Errorf("RetryFailed() cleared failures = %v, want %v", cleared, tt.remaining == nil)
+			}
+		})
+	}
+}
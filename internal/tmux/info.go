@@ -0,0 +1,101 @@
+package tmux
+
+import (
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// PaneInfo describes what is running in one pane of a session
+type PaneInfo struct {
+	Window  string // Window index and name, e.g. "0:myapp-login"
+	Index   int
+	Name    string // Layout name lfg gave the pane, if any
+	PID     int
+	Command string // Current foreground command
+	Dead    bool   // The pane's process has exited
+	Status  int    // Its exit status once dead
+}
+
+// SessionInfo describes a running session, for troubleshooting
+type SessionInfo struct {
+	Name     string
+	Windows  []WindowInfo
+	Panes    []PaneInfo
+	Env      []string // Session environment as KEY=value, sorted
+	Failures []PaneFailure
+}
+
+// paneFormat is the list-panes format parsed by parsePanes
+const paneFormat = "#{window_index}:#{window_name}\t#{pane_index}\t#{" + paneOption + "}\t#{pane_pid}\t#{pane_current_command}\t#{pane_dead}\t#{pane_dead_status}"
+
+// GetSessionInfo lists a session's panes, their processes, the session environment and any panes that failed to start
+func GetSessionInfo(sessionName string) (*SessionInfo, error) {
+	if !SessionExists(sessionName) {
+		return nil, fmt.Errorf("no tmux session named %s", sessionName)
+	}
+
+	cmd := Command("list-panes", "-s", "-t", exact(sessionName), "-F", paneFormat)
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list panes: %w", err)
+	}
+	info := &SessionInfo{Name: sessionName, Panes: parsePanes(string(output))}
+
+	if info.Windows, err = ListWindows(sessionName); err != nil {
+		return nil, err
+	}
+
+	cmd = Command("show-environment", "-t", exact(sessionName))
+	output, err = runner.Output(cmd)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read session environment: %w", err)
+	}
+	info.Env = parseEnvironment(string(output))
+
+	if info.Failures, err = Failures(sessionName); err != nil {
+		return nil, err
+	}
+
+	return info, nil
+}
+
+// parsePanes parses list-panes output in paneFormat
+func parsePanes(output string) []PaneInfo {
+	var panes []PaneInfo
+	for _, line := range strings.Split(strings.TrimSpace(output), "\n") {
+		fields := strings.Split(line, "\t")
+		if len(fields) != 7 {
+			continue
+		}
+		index, _ := strconv.Atoi(fields[1])
+		pid, _ := strconv.Atoi(fields[3])
+		status, _ := strconv.Atoi(fields[6])
+		panes = append(panes, PaneInfo{
+			Window:  fields[0],
+			Index:   index,
+			Name:    fields[2],
+			PID:     pid,
+			Command: fields[4],
+			Dead:    fields[5] == "1",
+			Status:  status,
+		})
+	}
+	return panes
+}
+
+// parseEnvironment parses show-environment output, skipping variables removed from the session ("-KEY")
+func parseEnvironment(output string) []string {
+	var env []string
+	for _, line := range strings.Split(strings.TrimSpace(output), "\n") {
+		if line == "" || strings.HasPrefix(line, "-") {
+			continue
+		}
+		env = append(env, line)
+	}
+	sort.Strings(env)
+	return env
+}
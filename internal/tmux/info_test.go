@@ -0,0 +1,32 @@
+package tmux
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestParsePanes(t *testing.T) {
+	output := "0:myapp-login\t0\tagent\t4100\tclaude\t0\t\n" +
+		"0:myapp-login\t1\tserver\t4101\tnode\t1\t1\n" +
+		"0:myapp-login\t2\t\t4102\tzsh\t1\t0\n" +
+		"garbage\n"
+
+	expected := []PaneInfo{
+		{Window: "0:myapp-login", Index: 0, Name: "agent", PID: 4100, Command: "claude"},
+		{Window: "0:myapp-login", Index: 1, Name: "server", PID: 4101, Command: "node", Dead: true, Status: 1},
+		{Window: "0:myapp-login", Index: 2, PID: 4102, Command: "zsh", Dead: true},
+	}
+
+	if got := parsePanes(output); !reflect.DeepEqual(got, expected) {
+		t.Errorf("parsePanes() = %+v, want %+v", got, expected)
+	}
+}
+
+func TestParseEnvironment(t *testing.T) {
+	output := "PORT=3001\n-SSH_AUTH_SOCK\nDATABASE_URL=postgres://localhost/myapp_login\n"
+	expected := []string{"DATABASE_URL=postgres://localhost/myapp_login", "PORT=3001"}
+
+	if got := parseEnvironment(output); !reflect.DeepEqual(got, expected) {
+		t.Errorf("parseEnvironment() = %v, want %v", got, expected)
+	}
+}
@@ -0,0 +1,102 @@
+package tmux
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// splitPanes divides a pane into panes side by side (horizontal) or stacked, sized by their width or
+// height, then splits those further or runs their commands
+// It returns how many panes the target became, including any that were split further
+func splitPanes(target string, panes []config.Pane, horizontal bool, path string) (int, []PaneFailure) {
+	direction := "-v"
+	if horizontal {
+		direction = "-h"
+	}
+	sizes := paneSizes(panes, horizontal)
+
+	// Each split leaves the current pane its own size and gives the new pane below or to the right
+	// the space of all the panes still to come
+	var failures []PaneFailure
+	targets := []string{target}
+	remaining := 0
+	for _, size := range sizes {
+		remaining += size
+	}
+	for i := 1; i < len(panes); i++ {
+		rest := 0
+		for _, size := range sizes[i:] {
+			rest += size
+		}
+		percent := min(max(rest*100/remaining, 1), 99)
+
+		cmd := Command(withShell("split-window", "-t", targets[i-1], direction, "-p", strconv.Itoa(percent), "-c", path, "-P", "-F", "#{pane_id}")...)
+		output, err := runner.Output(cmd)
+		if err != nil {
+			err = fmt.Errorf("failed to create pane %d of %s: %w", i, target, err)
+			for _, pane := range panes[i:] {
+				failures = append(failures, paneFailures(pane, err)...)
+			}
+			break
+		}
+		targets = append(targets, strings.TrimSpace(string(output)))
+		remaining = rest
+	}
+
+	count := len(targets)
+	for i, id := range targets {
+		pane := panes[i]
+		if len(pane.Panes) > 0 {
+			created, nested := splitPanes(id, pane.Panes, pane.Split == config.SplitHorizontal, path)
+			count += created - 1
+			failures = append(failures, nested...)
+			continue
+		}
+
+		tagPane(id, pane.Name)
+		if pane.Command != nil && *pane.Command != "" {
+			if err := runPaneCommand(id, *pane.Command, pane.RemainOnExit); err != nil {
+				failures = append(failures, PaneFailure{Pane: pane.Name, Err: err.Error()})
+			}
+		}
+	}
+	return count, failures
+}
+
+// paneSizes returns each pane's share of the space being split, as a percentage
+// Panes without a width (or height) share whatever the others leave equally
+func paneSizes(panes []config.Pane, horizontal bool) []int {
+	sizes := make([]int, len(panes))
+	used, unsized := 0, 0
+	for i, pane := range panes {
+		size := pane.Height
+		if horizontal {
+			size = pane.Width
+		}
+		sizes[i] = parsePercentage(size)
+		if sizes[i] <= 0 {
+			unsized++
+		}
+		used += max(sizes[i], 0)
+	}
+
+	for i := range sizes {
+		if sizes[i] <= 0 {
+			sizes[i] = max((100-used)/unsized, 1)
+		}
+	}
+	return sizes
+}
+
+// paneFailures records every pane that runs a command within a pane that couldn't be created
+func paneFailures(pane config.Pane, err error) []PaneFailure {
+	var failures []PaneFailure
+	for _, leaf := range pane.Leaves() {
+		failures = append(failures, PaneFailure{Pane: leaf.Name, Err: err.Error()})
+	}
+	return failures
+}
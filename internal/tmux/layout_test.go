@@ -0,0 +1,120 @@
+package tmux
+
+import (
+	"fmt"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestPaneSizes(t *testing.T) {
+	tests := []struct {
+		name       string
+		panes      []config.Pane
+		horizontal bool
+		expected   []int
+	}{
+		{name: "equal", panes: []config.Pane{{}, {}, {}, {}}, horizontal: true, expected: []int{25, 25, 25, 25}},
+		{name: "widths", panes: []config.Pane{{Width: "60%"}, {Width: "40%"}}, horizontal: true, expected: []int{60, 40}},
+		{name: "rest shared", panes: []config.Pane{{Width: "50%"}, {}, {}}, horizontal: true, expected: []int{50, 25, 25}},
+		{name: "heights when stacked", panes: []config.Pane{{Width: "10%", Height: "70%"}, {Height: "30%"}}, expected: []int{70, 30}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := paneSizes(tt.panes, tt.horizontal); !reflect.DeepEqual(got, tt.expected) {
+				t.Errorf("paneSizes() = %v, want %v", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestSplitPanes(t *testing.T) {
+	server := "bin/dev"
+	logs := "tail -f log/development.log"
+	// An editor on the left, with logs above the server on the right
+	panes := []config.Pane{
+		{Name: "editor", Width: "60%"},
+		{Split: config.SplitVertical, Panes: []config.Pane{
+			{Name: "logs", Height: "30%", Command: &logs},
+			{Name: "server", Command: &server},
+		}},
+	}
+
+	tests := []struct {
+		name     string
+		failAt   int // split-window call that fails, 0 for none
+		count    int
+		calls    []string
+		failures []PaneFailure
+	}{
+		{
+			name:  "all panes",
+			count: 3,
+			calls: []string{
+				"tmux split-window -t =s:0.1 -h -p 40 -c /tmp/wt -P -F #{pane_id}",
+				"tmux set-option -p -t =s:0.1 @lfg-pane editor",
+				"tmux split-window -t %5 -v -p 70 -c /tmp/wt -P -F #{pane_id}",
+				"tmux set-option -p -t %5 @lfg-pane logs",
+				"tmux send-keys -t %5 tail -f log/development.log Enter",
+				"tmux set-option -p -t %6 @lfg-pane server",
+				"tmux send-keys -t %6 bin/dev Enter",
+			},
+		},
+		{
+			name:     "nested split fails",
+			failAt:   2,
+			count:    2,
+			failures: []PaneFailure{{Pane: "server", Err: "failed to create pane 1 of %5: no space for new pane"}},
+		},
+		{
+			name:   "row split fails",
+			failAt: 1,
+			count:  1,
+			failures: []PaneFailure{
+				{Pane: "logs", Err: "failed to create pane 1 of =s:0.1: no space for new pane"},
+				{Pane: "server", Err: "failed to create pane 1 of =s:0.1: no space for new pane"},
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			splits := 0
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				switch args[1] {
+				case "split-window":
+					splits++
+					if splits == tt.failAt {
+						return nil, fmt.Errorf("no space for new pane")
+					}
+					return []byte(fmt.Sprintf("%%%d\n", 4+splits)), nil
+				case "set-option", "send-keys":
+					return nil, nil
+				}
+				return nil, fmt.Errorf("unexpected command %v", args)
+			}}
+			defer runner.Set(fake)()
+
+			count, failures := splitPanes("=s:0.1", panes, true, "/tmp/wt")
+			if count != tt.count {
+				t.Errorf("splitPanes() count = %d, want %d", count, tt.count)
+			}
+			if !reflect.DeepEqual(failures, tt.failures) {
+				t.Errorf("splitPanes() failures = %+v, want %+v", failures, tt.failures)
+			}
+			if tt.calls != nil {
+				var calls []string
+				for _, call := range fake.Calls {
+					calls = append(calls, strings.Join(call, " "))
+				}
+				if !reflect.DeepEqual(calls, tt.calls) {
+					t.Errorf("splitPanes() ran:\n%s\nwant:\n%s", strings.Join(calls, "\n"), strings.Join(tt.calls, "\n"))
+				}
+			}
+		})
+	}
+}
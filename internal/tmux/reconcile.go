@@ -0,0 +1,136 @@
+package tmux
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// paneOption tags the panes lfg creates with their layout name, so sessions can be reconciled later
+const paneOption = "@lfg-pane"
+
+// agentPaneName tags the agent pane, which is always there and isn't part of the configured layout
+const agentPaneName = "agent"
+
+// layoutPane is a named pane from the configured layout
+type layoutPane struct {
+	name    string
+	command *string
+	remain  bool // remain_on_exit
+}
+
+// layoutPanes flattens the layout into its named panes, in the order they are created
+// Unnamed panes can't be matched against a running session, so they are left out
+func layoutPanes(cfg *config.Config) []layoutPane {
+	var panes []layoutPane
+	for _, row := range cfg.GetLayout() {
+		if len(row.Panes) == 0 {
+			if row.Name != "" {
+				panes = append(panes, layoutPane{name: row.Name, command: row.Command, remain: row.RemainOnExit})
+			}
+			continue
+		}
+		for _, pane := range (config.Pane{Panes: row.Panes}).Leaves() {
+			if pane.Name != "" {
+				panes = append(panes, layoutPane{name: pane.Name, command: pane.Command, remain: pane.RemainOnExit})
+			}
+		}
+	}
+	return panes
+}
+
+// tagPane records the layout name of a pane, ignoring tmux versions without pane options
+func tagPane(target, name string) {
+	if name == "" {
+		return
+	}
+	runner.Run(Command("set-option", "-p", "-t", target, paneOption, name))
+}
+
+// taggedPanes maps the layout names of a session's tagged panes to their pane IDs
+// The names are also returned in pane order
+func taggedPanes(sessionName string) (map[string]string, []string, error) {
+	cmd := Command("list-panes", "-t", layoutWindow(sessionName), "-F", "#{pane_id}\t#{"+paneOption+"}")
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to list panes: %w", err)
+	}
+
+	running := make(map[string]string)
+	var order []string
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		id, name, _ := strings.Cut(line, "\t")
+		if name == "" {
+			continue
+		}
+		running[name] = id
+		order = append(order, name)
+	}
+	return running, order, nil
+}
+
+// ReconcileResult lists the panes Reconcile added and removed
+type ReconcileResult struct {
+	Added   []string
+	Removed []string
+}
+
+// Reconcile brings a running session in line with the configured layout without restarting it
+// Panes added to the config are created (running their command) below the existing ones
+// With prune, panes lfg created that are no longer in the config are closed
+func Reconcile(worktreeName, path string, cfg *config.Config, prune bool) (*ReconcileResult, error) {
+	sessionName := SessionName(cfg.Name, worktreeName)
+	if err := checkManaged(sessionName, worktreeName); err != nil {
+		return nil, err
+	}
+
+	running, order, err := taggedPanes(sessionName)
+	if err != nil {
+		return nil, err
+	}
+	if len(running) == 0 {
+		return nil, fmt.Errorf("session %s was started by an older lfg and can't be reconciled, restart it instead", sessionName)
+	}
+
+	lastPane := running[order[len(order)-1]]
+	result := &ReconcileResult{}
+	configured := make(map[string]bool)
+	for _, pane := range layoutPanes(cfg) {
+		configured[pane.name] = true
+		if _, ok := running[pane.name]; ok {
+			continue
+		}
+
+		cmd := Command(withShell("split-window", "-t", lastPane, "-v", "-c", path, "-P", "-F", "#{pane_id}")...)
+		output, err := runner.Output(cmd)
+		if err != nil {
+			return result, fmt.Errorf("failed to create pane %s: %w", pane.name, err)
+		}
+		lastPane = strings.TrimSpace(string(output))
+		tagPane(lastPane, pane.name)
+
+		if pane.command != nil && *pane.command != "" {
+			if err := runPaneCommand(lastPane, *pane.command, pane.remain); err != nil {
+				return result, fmt.Errorf("failed to run command in pane %s: %w", pane.name, err)
+			}
+		}
+		result.Added = append(result.Added, pane.name)
+	}
+
+	if prune {
+		for _, name := range order {
+			if name == agentPaneName || configured[name] {
+				continue
+			}
+			cmd := Command("kill-pane", "-t", running[name])
+			if err := runner.Run(cmd); err != nil {
+				return result, fmt.Errorf("failed to close pane %s: %w", name, err)
+			}
+			result.Removed = append(result.Removed, name)
+		}
+	}
+
+	return result, nil
+}
@@ -0,0 +1,94 @@
+package tmux
+
+import (
+	"fmt"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestReconcile(t *testing.T) {
+	goTest := "go test ./..."
+	cfg := &config.Config{
+		Name: "myapp",
+		Layout: []config.LayoutRow{
+			{Height: "50%", Name: "server"},
+			{Height: "50%", Panes: []config.Pane{{Name: "shell"}, {Name: "tests", Command: &goTest}}},
+		},
+	}
+
+	tests := []struct {
+		name     string
+		prune    bool
+		expected ReconcileResult
+	}{
+		{name: "adds missing panes", prune: false, expected: ReconcileResult{Added: []string{"tests"}}},
+		{name: "prunes removed panes", prune: true, expected: ReconcileResult{Added: []string{"tests"}, Removed: []string{"logs"}}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				switch args[1] {
+				case "has-session", "set-option", "send-keys", "kill-pane":
+					return nil, nil
+				case "show-options":
+					return []byte("myapp-login\n"), nil
+				case "list-panes":
+					return []byte("%0\tagent\n%1\tserver\n%2\tshell\n%3\tlogs\n%4\t\n"), nil
+				case "split-window":
+					return []byte("%5\n"), nil
+				}
+				return nil, fmt.Errorf("unexpected command %v", args)
+			}}
+			defer runner.Set(fake)()
+
+			result, err := Reconcile("myapp-login", "/tmp/myapp-login", cfg, tt.prune)
+			if err != nil {
+				t.Fatalf("Reconcile() error = %v", err)
+			}
+			if !reflect.DeepEqual(*result, tt.expected) {
+				t.Errorf("Reconcile() = %+v, want %+v", *result, tt.expected)
+			}
+
+			var calls []string
+			for _, call := range fake.Calls {
+				calls = append(calls, strings.Join(call, " "))
+			}
+			ran := strings.Join(calls, "\n")
+			for _, want := range []string{
+				"tmux split-window -t %3 -v -c /tmp/myapp-login -P -F #{pane_id}",
+				"tmux set-option -p -t %5 @lfg-pane tests",
+				"tmux send-keys -t %5 go test ./... Enter",
+			} {
+				if !strings.Contains(ran, want) {
+					t.Errorf("Reconcile() didn't run %q, ran:\n%s", want, ran)
+				}
+			}
+			if pruned := strings.Contains(ran, "kill-pane"); pruned != tt.prune {
+				t.Errorf("Reconcile() closed panes = %v, want %v", pruned, tt.prune)
+			}
+		})
+	}
+}
+
+func TestReconcileUntaggedSession(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch args[1] {
+		case "list-panes":
+			return []byte("%0\t\n%1\t\n"), nil
+		case "show-options":
+			return []byte("myapp-login\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	cfg := &config.Config{Name: "myapp", Layout: []config.LayoutRow{{Height: "100%", Name: "server"}}}
+	if _, err := Reconcile("myapp-login", "/tmp/myapp-login", cfg, false); err == nil {
+		t.Error("Reconcile() of a session without tagged panes should fail")
+	}
+}
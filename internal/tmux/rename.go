@@ -0,0 +1,53 @@
+package tmux
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// RenameSession follows a worktree rename: its session and the separate ones started alongside it are
+// renamed, and so is the window with its panes when that still has the old worktree name. Windows
+// remembered for the session move along with it. Sessions lfg didn't start are left alone
+func RenameSession(oldSession, newSession, oldWorktree, newWorktree string) error {
+	if from, err := windowOverridesPath(oldSession); err == nil {
+		if to, err := windowOverridesPath(newSession); err == nil {
+			os.Rename(from, to) // Fails when no windows were remembered, which is fine
+		}
+	}
+
+	renamed := false
+	for _, session := range sessionGroup(oldSession) {
+		if !IsManaged(session) {
+			continue
+		}
+		target := newSession + strings.TrimPrefix(session, oldSession)
+		if output, err := runner.CombinedOutput(Command("rename-session", "-t", exact(session), target)); err != nil {
+			return fmt.Errorf("failed to rename tmux session %s: %s", session, strings.TrimSpace(string(output)))
+		}
+		if err := markManaged(target, newWorktree); err != nil {
+			return err
+		}
+		renamed = renamed || session == oldSession
+	}
+	if !renamed {
+		return nil
+	}
+
+	windows, err := ListWindows(newSession)
+	if err != nil {
+		return err
+	}
+	for _, window := range windows {
+		if window.Layout && window.Name == oldWorktree {
+			if err := RenameWindow(newSession, window.Index, newWorktree); err != nil {
+				return err
+			}
+		}
+	}
+	runner.Run(Command("set-option", "-w", "-t", layoutWindow(newSession), windowOption, newWorktree))
+	setTerminalTitle(newSession, newWorktree)
+	return nil
+}
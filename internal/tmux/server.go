@@ -0,0 +1,69 @@
+package tmux
+
+import (
+	"os"
+	"os/exec"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// settings picks the tmux server and pane shell, set from the config with Configure
+var settings *config.Tmux
+
+// Configure sets the tmux server and pane shell lfg uses
+func Configure(cfg *config.Tmux) {
+	settings = cfg
+}
+
+// Command returns a tmux command on the configured server
+// LFG_TMUX_SOCKET (a socket name, or a path when it contains a "/") takes precedence over the config.
+// Without either, tmux uses the server of the session lfg runs in, or the default one
+func Command(args ...string) *exec.Cmd {
+	var global []string
+	if settings != nil {
+		switch {
+		case settings.SocketPath != "":
+			global = []string{"-S", settings.SocketPath}
+		case settings.SocketName != "":
+			global = []string{"-L", settings.SocketName}
+		}
+	}
+	if env := os.Getenv("LFG_TMUX_SOCKET"); env != "" {
+		global = []string{"-L", env}
+		if strings.Contains(env, "/") {
+			global = []string{"-S", env}
+		}
+	}
+	return exec.Command("tmux", append(global, args...)...)
+}
+
+// withShell appends the configured pane shell to a command that starts a pane, so it runs in place of
+// tmux's default-command, e.g. one that isn't a shell lfg can type commands into
+func withShell(args ...string) []string {
+	if settings != nil && settings.Shell != "" {
+		return append(args, settings.Shell)
+	}
+	return args
+}
+
+// layoutWindow targets the session's window with the worktree's panes
+// It is always the lowest-numbered window, whatever the server's base-index
+func layoutWindow(sessionName string) string {
+	return exact(sessionName) + ":^"
+}
+
+// paneBase returns the index of a new window's only pane, which is the server's pane-base-index
+func paneBase(window string) int {
+	output, err := runner.Output(Command("display-message", "-p", "-t", window, "#{pane_index}"))
+	if err != nil {
+		return 0
+	}
+	base, err := strconv.Atoi(strings.TrimSpace(string(output)))
+	if err != nil {
+		return 0
+	}
+	return base
+}
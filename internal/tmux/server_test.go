@@ -0,0 +1,77 @@
+package tmux
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestCommand(t *testing.T) {
+	defer Configure(nil)
+	t.Setenv("LFG_TMUX_SOCKET", "")
+
+	tests := []struct {
+		name     string
+		settings *config.Tmux
+		env      string
+		expected []string
+	}{
+		{name: "default server", expected: []string{"tmux", "has-session"}},
+		{name: "socket name", settings: &config.Tmux{SocketName: "work"}, expected: []string{"tmux", "-L", "work", "has-session"}},
+		{name: "socket path", settings: &config.Tmux{SocketName: "work", SocketPath: "/tmp/work.sock"}, expected: []string{"tmux", "-S", "/tmp/work.sock", "has-session"}},
+		{name: "environment", settings: &config.Tmux{SocketName: "work"}, env: "/tmp/other.sock", expected: []string{"tmux", "-S", "/tmp/other.sock", "has-session"}},
+		{name: "environment name", env: "lfg-test", expected: []string{"tmux", "-L", "lfg-test", "has-session"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			Configure(tt.settings)
+			t.Setenv("LFG_TMUX_SOCKET", tt.env)
+			if got := Command("has-session").Args; !reflect.DeepEqual(got, tt.expected) {
+				t.Errorf("Command() args = %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestCreatePaneLayoutBaseIndex(t *testing.T) {
+	defer Configure(nil)
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	t.Setenv("TMUX", "")
+	Configure(&config.Tmux{Shell: "bash"})
+
+	// A server with base-index 1 and pane-base-index 1
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		if args[1] == "display-message" {
+			return []byte("1\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	server := "bin/dev"
+	cfg := &config.Config{Layout: []config.LayoutRow{{Height: "100%", Name: "server", Command: &server}}}
+	if err := createPaneLayout("myapp/login", "myapp-login", "/tmp/wt", cfg); err != nil {
+		t.Fatalf("createPaneLayout() error = %v", err)
+	}
+
+	ran := make([]string, len(fake.Calls))
+	for i, call := range fake.Calls {
+		ran[i] = strings.Join(call, " ")
+	}
+	calls := strings.Join(ran, "\n")
+	for _, want := range []string{
+		"tmux display-message -p -t =myapp/login:^ #{pane_index}",
+		"tmux split-window -t =myapp/login:^.1 -v -p 55 -c /tmp/wt bash",
+		"tmux set-option -p -t =myapp/login:^.2 @lfg-pane server",
+		"tmux send-keys -t =myapp/login:^.2 bin/dev Enter",
+		"tmux select-pane -t =myapp/login:^.1",
+	} {
+		if !strings.Contains(calls, want) {
+			t.Errorf("createPaneLayout() didn't run %q, ran:\n%s", want, calls)
+		}
+	}
+}
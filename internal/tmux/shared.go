@@ -0,0 +1,126 @@
+package tmux
+
+import (
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/runner"
+	"github.com/markcipolla/lfg/internal/terminal"
+)
+
+// DetachOthers detaches the other terminals attached to a worktree's session when attaching to it,
+// instead of asking, set by `lfg -d`
+var DetachOthers bool
+
+// Choices for a session other terminals are already attached to
+const (
+	sharedCancel = iota
+	sharedAttach // Attach alongside them, everyone sees the same windows
+	sharedDetach // Detach them first
+	sharedNew    // Start a separate session with a numbered suffix
+)
+
+// sharedSeparator comes before the number of a separate session, it can't be in a worktree name as git
+// doesn't allow it in branch names, so myapp/login~2 is never the session of a worktree named login~2
+const sharedSeparator = "~"
+
+// resolveShared asks what to do about a session other terminals are attached to, sharing it when
+// there's no terminal to ask on
+var resolveShared = func(sessionName string, clients int) int {
+	if DetachOthers {
+		return sharedDetach
+	}
+	answer, ok := terminal.Prompt(fmt.Sprintf("tmux session %s is already attached in %d other terminal(s).\n"+
+		"[s]hare it, [d]etach the others, open a [n]ew session, or [c]ancel? [s] ", sessionName, clients))
+	if !ok {
+		return sharedAttach
+	}
+	switch answer {
+	case "", "s", "share":
+		return sharedAttach
+	case "d", "detach":
+		return sharedDetach
+	case "n", "new":
+		return sharedNew
+	}
+	return sharedCancel
+}
+
+// otherClients returns the terminals attached to a session, apart from the one lfg runs in
+func otherClients(sessionName string) []string {
+	output, err := runner.Output(Command("list-clients", "-t", exact(sessionName), "-F", "#{client_tty}"))
+	if err != nil {
+		return nil
+	}
+	own := ""
+	if InsideTmux() {
+		if out, err := runner.Output(Command("display-message", "-p", "#{client_tty}")); err == nil {
+			own = strings.TrimSpace(string(out))
+		}
+	}
+
+	var clients []string
+	for _, tty := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		if tty != "" && tty != own {
+			clients = append(clients, tty)
+		}
+	}
+	return clients
+}
+
+// shareSession settles what happens to the other terminals attached to an existing session before
+// attaching to it. It returns the session to attach to: the session itself, or a free suffixed name
+// for a separate session to create
+func shareSession(sessionName string) (string, error) {
+	clients := otherClients(sessionName)
+	if len(clients) == 0 {
+		return sessionName, nil
+	}
+
+	switch resolveShared(sessionName, len(clients)) {
+	case sharedAttach:
+		return sessionName, nil
+	case sharedDetach:
+		for _, tty := range clients {
+			if err := runner.Run(Command("detach-client", "-t", tty)); err != nil {
+				fmt.Fprintf(os.Stderr, "Warning: failed to detach %s: %v\n", tty, err)
+			}
+		}
+		return sessionName, nil
+	case sharedNew:
+		for n := 2; ; n++ {
+			if name := fmt.Sprintf("%s%s%d", sessionName, sharedSeparator, n); !SessionExists(name) {
+				return name, nil
+			}
+		}
+	}
+	return "", fmt.Errorf("didn't attach to tmux session %s, it's attached in another terminal", sessionName)
+}
+
+// sessionGroup returns a worktree's running sessions: its session and the separate ones started alongside
+// it when it was attached in another terminal
+func sessionGroup(sessionName string) []string {
+	sessions, err := ListSessions()
+	if err != nil {
+		return nil
+	}
+	var group []string
+	for _, session := range sessions {
+		if session == sessionName || isSeparateSession(session, sessionName) {
+			group = append(group, session)
+		}
+	}
+	return group
+}
+
+// isSeparateSession reports whether a session was started alongside sessionName, named with a number after it
+func isSeparateSession(session, sessionName string) bool {
+	n, ok := strings.CutPrefix(session, sessionName+sharedSeparator)
+	if !ok || n == "" {
+		return false
+	}
+	_, err := strconv.Atoi(n)
+	return err == nil
+}
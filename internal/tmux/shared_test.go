@@ -0,0 +1,73 @@
+package tmux
+
+import (
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestShareSession(t *testing.T) {
+	tests := []struct {
+		name         string
+		clients      string
+		choice       int
+		expected     string
+		wantErr      bool
+		wantDetached []string
+	}{
+		{name: "no other clients", clients: "/dev/pts/2\n", choice: sharedCancel, expected: "myapp/login"},
+		{name: "share", clients: "/dev/pts/1\n/dev/pts/2\n", choice: sharedAttach, expected: "myapp/login"},
+		{name: "detach others", clients: "/dev/pts/1\n/dev/pts/3\n", choice: sharedDetach, expected: "myapp/login", wantDetached: []string{"/dev/pts/1", "/dev/pts/3"}},
+		{name: "new session", clients: "/dev/pts/1\n", choice: sharedNew, expected: "myapp/login~3"},
+		{name: "cancel", clients: "/dev/pts/1\n", choice: sharedCancel, wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			// lfg itself runs in the client on /dev/pts/2
+			t.Setenv("TMUX", "/tmp/tmux-1000/default,123,0")
+			var detached []string
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				switch args[1] {
+				case "list-clients":
+					return []byte(tt.clients), nil
+				case "display-message":
+					return []byte("/dev/pts/2\n"), nil
+				case "detach-client":
+					detached = append(detached, args[3])
+				case "has-session":
+					// myapp/login~2 is taken by an earlier separate session
+					if args[3] != "=myapp/login~2" {
+						return nil, errors.New("can't find session")
+					}
+				}
+				return nil, nil
+			}}
+			defer runner.Set(fake)()
+
+			asked := false
+			original := resolveShared
+			resolveShared = func(sessionName string, clients int) int {
+				asked = true
+				return tt.choice
+			}
+			defer func() { resolveShared = original }()
+
+			got, err := shareSession("myapp/login")
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("shareSession() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.expected {
+				t.Errorf("shareSession() = %q, want %q", got, tt.expected)
+			}
+			if wantAsked := tt.clients != "/dev/pts/2\n"; asked != wantAsked {
+				t.Errorf("asked = %v, want %v", asked, wantAsked)
+			}
+			if strings.Join(detached, " ") != strings.Join(tt.wantDetached, " ") {
+				t.Errorf("detached %v, want %v", detached, tt.wantDetached)
+			}
+		})
+	}
+}
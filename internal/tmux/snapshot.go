@@ -0,0 +1,253 @@
+package tmux
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// SavedPane is a pane as it was when its session was saved
+// What ran in it isn't kept, as command lines can hold secrets; the layout's command for its name is run
+// again when it's restored
+type SavedPane struct {
+	Name string `json:"name,omitempty"` // Layout name lfg gave the pane
+	Path string `json:"path"`           // Working directory
+}
+
+// SavedWindow is a window as it was when its session was saved
+type SavedWindow struct {
+	Name   string      `json:"name"`
+	Layout string      `json:"layout"` // tmux layout string, applied again once the panes are recreated
+	Panes  []SavedPane `json:"panes"`
+}
+
+// SavedSession is a session saved as it was killed, so RestoreSession can bring it back
+type SavedSession struct {
+	Worktree string        `json:"worktree"`
+	Path     string        `json:"path"` // Worktree directory, where panes are moved to when restored elsewhere
+	Saved    time.Time     `json:"saved"`
+	Windows  []SavedWindow `json:"windows"`
+}
+
+// SaveOnKill saves sessions before KillSession kills them, set from settings.save_sessions
+var SaveOnKill bool
+
+// savedPaneFormat is the list-panes format parsed by parseSavedWindows
+const savedPaneFormat = "#{window_index}\t#{window_name}\t#{window_layout}\t#{" + paneOption + "}\t#{pane_current_path}"
+
+// savedSessionPath returns where a session is saved, ~/.local/share/lfg/sessions unless XDG_DATA_HOME is set
+func savedSessionPath(sessionName string) (string, error) {
+	dir, err := config.DataDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "sessions", url.PathEscape(sessionName)+".json"), nil
+}
+
+// SaveSession records the windows of a session lfg started, with each pane's layout name and working
+// directory, so it can be restored after being killed. Sessions lfg didn't start are skipped
+func SaveSession(sessionName string) error {
+	worktreeName := managedWorktree(sessionName)
+	if worktreeName == "" {
+		return nil
+	}
+
+	cmd := Command("list-panes", "-s", "-t", exact(sessionName), "-F", savedPaneFormat)
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return fmt.Errorf("failed to list panes of %s: %w", sessionName, err)
+	}
+	path, err := runner.Output(Command("display-message", "-p", "-t", exact(sessionName)+":", "#{session_path}"))
+	if err != nil {
+		return fmt.Errorf("failed to read the directory of %s: %w", sessionName, err)
+	}
+
+	saved := SavedSession{
+		Worktree: worktreeName,
+		Path:     strings.TrimSpace(string(path)),
+		Saved:    time.Now(),
+		Windows:  parseSavedWindows(string(output)),
+	}
+	data, err := json.MarshalIndent(saved, "", "  ")
+	if err != nil {
+		return err
+	}
+
+	file, err := savedSessionPath(sessionName)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(file), 0755); err != nil {
+		return fmt.Errorf("failed to save session: %w", err)
+	}
+	if err := os.WriteFile(file, data, 0644); err != nil {
+		return fmt.Errorf("failed to save session: %w", err)
+	}
+	return nil
+}
+
+// parseSavedWindows parses list-panes output in savedPaneFormat into windows, in order
+func parseSavedWindows(output string) []SavedWindow {
+	var windows []SavedWindow
+	index := ""
+	for _, line := range strings.Split(strings.TrimSpace(output), "\n") {
+		fields := strings.Split(line, "\t")
+		if len(fields) != 5 {
+			continue
+		}
+		if len(windows) == 0 || fields[0] != index {
+			index = fields[0]
+			windows = append(windows, SavedWindow{Name: fields[1], Layout: fields[2]})
+		}
+		window := &windows[len(windows)-1]
+		window.Panes = append(window.Panes, SavedPane{Name: fields[3], Path: fields[4]})
+	}
+	return windows
+}
+
+// LoadSavedSession reads a session saved by SaveSession
+func LoadSavedSession(sessionName string) (*SavedSession, error) {
+	path, err := savedSessionPath(sessionName)
+	if err != nil {
+		return nil, err
+	}
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return nil, fmt.Errorf("no saved session for %s", sessionName)
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read saved session: %w", err)
+	}
+
+	var saved SavedSession
+	if err := json.Unmarshal(data, &saved); err != nil {
+		return nil, fmt.Errorf("failed to parse saved session %s: %w", path, err)
+	}
+	return &saved, nil
+}
+
+// RestoreSession recreates the session saved for worktree from as the session of the worktree at path,
+// usually the same one brought back, with its windows and panes laid out as they were, each in its
+// working directory, then attaches to it. Panes run their layout command again, and directories inside
+// the saved worktree are moved to path. Panes that can't be recreated are recorded like those of a new
+// session, and the saved copy is removed
+func RestoreSession(from, worktreeName, path string, cfg *config.Config) error {
+	sessionName := SessionName(cfg.Name, worktreeName)
+	if SessionExists(sessionName) {
+		return fmt.Errorf("tmux session %s is already running", sessionName)
+	}
+	savedName := SessionName(cfg.Name, from)
+	saved, err := LoadSavedSession(savedName)
+	if err != nil {
+		return err
+	}
+	if len(saved.Windows) == 0 {
+		return fmt.Errorf("saved session for %s has no panes", from)
+	}
+
+	if err := startSession(sessionName, worktreeName, path, cfg); err != nil {
+		return err
+	}
+
+	panes := make(map[string]layoutPane)
+	for _, pane := range layoutPanes(cfg) {
+		panes[pane.name] = pane
+	}
+	var failures []PaneFailure
+	for i, window := range saved.Windows {
+		for j := range window.Panes {
+			window.Panes[j].Path = movedPath(window.Panes[j].Path, saved.Path, path)
+		}
+		failures = append(failures, restoreWindow(sessionName, i == 0, window, worktreeName, path, panes, cfg)...)
+	}
+	if err := recordFailures(sessionName, failures); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+
+	if file, err := savedSessionPath(savedName); err == nil {
+		os.Remove(file)
+	}
+	return SwitchOrAttach(sessionName)
+}
+
+// movedPath moves a saved pane's directory from inside the saved worktree to the one restored into, and
+// puts panes whose directory is gone in the worktree
+func movedPath(dir, savedRoot, root string) string {
+	if savedRoot != "" && savedRoot != root {
+		if rel, err := filepath.Rel(savedRoot, dir); err == nil && rel != ".." && !strings.HasPrefix(rel, "../") {
+			dir = filepath.Join(root, rel)
+		}
+	}
+	if _, err := os.Stat(dir); err != nil {
+		return root
+	}
+	return dir
+}
+
+// restoreWindow recreates a saved window, using the session's first window for the first one, and runs
+// the layout's command in each pane it names
+func restoreWindow(sessionName string, first bool, window SavedWindow, worktreeName, worktreePath string, layout map[string]layoutPane, cfg *config.Config) []PaneFailure {
+	// The session's first window starts in the worktree, so its first pane is restarted in its own directory
+	cmd := Command(withShell("new-window", "-t", exact(sessionName)+":", "-n", window.Name, "-c", window.Panes[0].Path, "-P", "-F", "#{pane_id}")...)
+	if first {
+		cmd = Command("display-message", "-p", "-t", layoutWindow(sessionName), "#{pane_id}")
+	}
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return savedFailures(window.Panes, fmt.Errorf("failed to create window %s: %w", window.Name, err))
+	}
+	ids := []string{strings.TrimSpace(string(output))}
+	if first && window.Panes[0].Path != worktreePath {
+		runner.Run(Command(withShell("respawn-pane", "-k", "-t", ids[0], "-c", window.Panes[0].Path)...))
+	}
+
+	var failures []PaneFailure
+	for i, pane := range window.Panes[1:] {
+		cmd := Command(withShell("split-window", "-t", ids[i], "-c", pane.Path, "-P", "-F", "#{pane_id}")...)
+		output, err := runner.Output(cmd)
+		if err != nil {
+			failures = savedFailures(window.Panes[i+1:], fmt.Errorf("failed to create pane %d of window %s: %w", i+1, window.Name, err))
+			break
+		}
+		ids = append(ids, strings.TrimSpace(string(output)))
+		// Even the panes out so there's always room for the next split
+		runner.Run(Command("select-layout", "-t", ids[0], "tiled"))
+	}
+
+	// The layout only fits when every pane was recreated
+	if len(ids) == len(window.Panes) && window.Layout != "" {
+		runner.Run(Command("select-layout", "-t", ids[0], window.Layout))
+	}
+
+	for i, id := range ids {
+		pane := window.Panes[i]
+		tagPane(id, pane.Name)
+		var err error
+		if pane.Name == agentPaneName {
+			err = setupAgentPane(id, worktreeName, worktreePath, cfg)
+		} else if configured, ok := layout[pane.Name]; ok && configured.command != nil && *configured.command != "" {
+			err = runPaneCommand(id, *configured.command, configured.remain)
+		}
+		if err != nil {
+			failures = append(failures, PaneFailure{Pane: pane.Name, Err: err.Error()})
+		}
+	}
+	return failures
+}
+
+// savedFailures records saved panes that couldn't be recreated as failed with err
+func savedFailures(panes []SavedPane, err error) []PaneFailure {
+	var failures []PaneFailure
+	for _, pane := range panes {
+		failures = append(failures, PaneFailure{Pane: pane.Name, Err: err.Error()})
+	}
+	return failures
+}
@@ -0,0 +1,116 @@
+package tmux
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestParseSavedWindows(t *testing.T) {
+	output := "0\tmyapp-login\tc1a2,80x24,0,0\tserver\t/src/myapp-login\n" +
+		"0\tmyapp-login\tc1a2,80x24,0,0\tshell\t/src/myapp-login/app\n" +
+		"2\tnotes\tb3f4,80x24,0,0\t\t/tmp\n"
+
+	expected := []SavedWindow{
+		{Name: "myapp-login", Layout: "c1a2,80x24,0,0", Panes: []SavedPane{
+			{Name: "server", Path: "/src/myapp-login"},
+			{Name: "shell", Path: "/src/myapp-login/app"},
+		}},
+		{Name: "notes", Layout: "b3f4,80x24,0,0", Panes: []SavedPane{{Path: "/tmp"}}},
+	}
+
+	if got := parseSavedWindows(output); !reflect.DeepEqual(got, expected) {
+		t.Errorf("parseSavedWindows() = %+v, want %+v", got, expected)
+	}
+}
+
+func TestSaveAndRestoreSession(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	t.Setenv("TMUX", "/tmp/tmux-1000/default,1,0")
+	server := "bin/dev"
+	cfg := &config.Config{Name: "myapp", Layout: []config.LayoutRow{{Name: "server", Command: &server}}}
+	saved := "/src/myapp-login"
+	path := t.TempDir()
+	if err := os.Mkdir(filepath.Join(path, "app"), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	running := true
+	paneID := 0
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch args[1] {
+		case "has-session":
+			if !running {
+				return nil, fmt.Errorf("can't find session")
+			}
+			return nil, nil
+		case "show-options":
+			return []byte("myapp-login\n"), nil
+		case "list-sessions":
+			return []byte("myapp/myapp-login\n"), nil
+		case "list-panes":
+			return []byte("0\tmyapp-login\tc1a2,80x24,0,0\tserver\t" + saved + "\n" +
+				"0\tmyapp-login\tc1a2,80x24,0,0\tshell\t" + saved + "/app\n"), nil
+		case "display-message":
+			if args[len(args)-1] == "#{session_path}" {
+				return []byte(saved + "\n"), nil
+			}
+			paneID++
+			return []byte(fmt.Sprintf("%%%d\n", paneID)), nil
+		case "split-window":
+			paneID++
+			return []byte(fmt.Sprintf("%%%d\n", paneID)), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	SaveOnKill = true
+	defer func() { SaveOnKill = false }()
+	if err := KillSession("myapp/myapp-login"); err != nil {
+		t.Fatalf("KillSession() error = %v", err)
+	}
+
+	if err := RestoreSession("myapp-login", "myapp-login", path, cfg); err == nil {
+		t.Error("RestoreSession() restored over a running session")
+	}
+
+	// Restored into another worktree, as the saved one is gone
+	running = false
+	fake.Calls = nil
+	if err := RestoreSession("myapp-login", "myapp-login-2", path, cfg); err != nil {
+		t.Fatalf("RestoreSession() error = %v", err)
+	}
+
+	var calls []string
+	for _, call := range fake.Calls {
+		calls = append(calls, strings.Join(call, " "))
+	}
+	ran := strings.Join(calls, "\n")
+	for _, want := range []string{
+		"tmux new-session -d -s myapp/myapp-login-2 -c " + path,
+		"tmux split-window -t %1 -c " + path + "/app -P -F #{pane_id}",
+		"tmux select-layout -t %1 c1a2,80x24,0,0",
+		"tmux set-option -p -t %1 @lfg-pane server",
+		"tmux send-keys -t %1 bin/dev Enter",
+		"tmux set-option -p -t %2 @lfg-pane shell",
+		"tmux switch-client -t =myapp/myapp-login-2",
+	} {
+		if !strings.Contains(ran, want) {
+			t.Errorf("RestoreSession() didn't run %q, ran:\n%s", want, ran)
+		}
+	}
+	if strings.Contains(ran, "send-keys -t %2") {
+		t.Errorf("RestoreSession() ran a command in a pane without one in the layout, ran:\n%s", ran)
+	}
+
+	if _, err := LoadSavedSession("myapp/myapp-login"); err == nil {
+		t.Error("LoadSavedSession() still finds the session after it was restored")
+	}
+}
@@ -1,13 +1,18 @@
 package tmux
 
 import (
+	"errors"
 	"fmt"
 	"os"
 	"os/exec"
+	"path/filepath"
 	"strconv"
 	"strings"
 
 	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+	"github.com/markcipolla/lfg/internal/terminal"
+	"github.com/markcipolla/lfg/internal/events"
 )
 
 // IsInstalled checks if tmux is available
@@ -16,10 +21,78 @@ func IsInstalled() bool {
 	return err == nil
 }
 
+// exact targets a session by its exact name
+// Without the "=" prefix tmux falls back to prefix and pattern matching, so "fix" would match "fix-login"
+// Option commands take a pane target, so they need a trailing ":" for the name to be read as a session
+func exact(sessionName string) string {
+	return "=" + sessionName
+}
+
 // SessionExists checks if a tmux session exists
 func SessionExists(name string) bool {
-	cmd := exec.Command("tmux", "has-session", "-t", name)
-	return cmd.Run() == nil
+	cmd := Command("has-session", "-t", exact(name))
+	return runner.Run(cmd) == nil
+}
+
+// managedOption marks the sessions lfg created, so a session that merely shares the name is left alone
+const managedOption = "@lfg-managed"
+
+// IsManaged reports whether a session was created by lfg
+func IsManaged(name string) bool {
+	return managedWorktree(name) != ""
+}
+
+// managedWorktree returns the worktree lfg created a session for, "" for sessions it didn't create
+func managedWorktree(name string) string {
+	cmd := Command("show-options", "-q", "-v", "-t", exact(name)+":", managedOption)
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// markManaged tags a session as created by lfg for the given worktree
+func markManaged(sessionName, worktreeName string) error {
+	cmd := Command("set-option", "-t", exact(sessionName)+":", managedOption, worktreeName)
+	if err := runner.Run(cmd); err != nil {
+		return fmt.Errorf("failed to tag session %s: %w", sessionName, err)
+	}
+	return nil
+}
+
+// checkManaged returns an error unless the session exists and was created by lfg
+func checkManaged(sessionName, worktreeName string) error {
+	if !SessionExists(sessionName) {
+		return fmt.Errorf("no tmux session for %s", worktreeName)
+	}
+	if !IsManaged(sessionName) {
+		return fmt.Errorf("tmux session %s wasn't started by lfg, leaving it alone", sessionName)
+	}
+	return nil
+}
+
+// Collision choices for a session that has the worktree's name but wasn't started by lfg
+const (
+	collisionCancel = iota
+	collisionAttach // Attach once, without touching the session
+	collisionAdopt  // Tag the session as lfg's, e.g. one started by an older lfg
+)
+
+// resolveCollision asks what to do about a session lfg didn't start, cancelling when there's no terminal to ask on
+var resolveCollision = func(sessionName string) int {
+	answer, ok := terminal.Prompt(fmt.Sprintf("A tmux session named %s already exists but wasn't started by lfg.\n"+
+		"[a]ttach to it as is, [t]ake it over as this worktree's session, or [c]ancel? ", sessionName))
+	if !ok {
+		return collisionCancel
+	}
+	switch answer {
+	case "a", "attach":
+		return collisionAttach
+	case "t", "take":
+		return collisionAdopt
+	}
+	return collisionCancel
 }
 
 // CreateOrAttachSession creates a new tmux session or attaches to existing one
@@ -28,25 +101,100 @@ func CreateOrAttachSession(name, path string, cfg *config.Config) error {
 		return fmt.Errorf("tmux is not installed")
 	}
 
-	// Sanitize session name - tmux doesn't allow dots in session names
-	sessionName := sanitizeSessionName(name)
+	// Namespace and sanitize session name - tmux doesn't allow dots in session names
+	sessionName := SessionName(cfg.Name, name)
+
+	if SessionExists(sessionName) && !IsManaged(sessionName) {
+		switch resolveCollision(sessionName) {
+		case collisionAttach:
+			return SwitchOrAttach(sessionName)
+		case collisionAdopt:
+			if err := markManaged(sessionName, name); err != nil {
+				return err
+			}
+		default:
+			return fmt.Errorf("tmux session %s already exists and wasn't started by lfg, rename or close it first", sessionName)
+		}
+	}
 
-	// If session exists, ensure windows exist and attach
+	// If session exists, ensure windows exist and attach, unless another terminal is using it and a
+	// separate session is wanted
 	if SessionExists(sessionName) {
+		target, err := shareSession(sessionName)
+		if err != nil {
+			return err
+		}
+		if target != sessionName {
+			return createSession(target, name, path, cfg)
+		}
 		if err := ensureWindows(sessionName, name, path, cfg); err != nil {
 			fmt.Fprintf(os.Stderr, "Warning: failed to ensure windows: %v\n", err)
 		}
-		return attachSession(sessionName)
+		setTerminalTitle(sessionName, name)
+		SetStatusColor(sessionName, cfg.WorktreeColor(name))
+		return SwitchOrAttach(sessionName)
 	}
 
 	// Create new session (pass both sanitized session name and original worktree name)
 	return createSession(sessionName, name, path, cfg)
 }
 
+// SessionName returns the tmux session name for a worktree
+// Sessions are namespaced by project so worktrees with the same name in different
+// repositories don't end up sharing a session
+func SessionName(project, worktreeName string) string {
+	if project == "" {
+		return SanitizeSessionName(worktreeName)
+	}
+	return SanitizeSessionName(project + "/" + worktreeName)
+}
+
+// AdoptLegacySessions renames the sessions an older lfg started under a worktree's bare name, before
+// sessions were namespaced by project, to the worktree's session name, so they're found, attached and
+// killed like the ones started since. A session that only shares the bare name, neither tagged by lfg for
+// the worktree nor started in a directory of that name, is left alone
+func AdoptLegacySessions(project string, worktreeNames ...string) {
+	if project == "" {
+		return
+	}
+	sessions, err := ListSessions()
+	if err != nil {
+		return
+	}
+	running := make(map[string]bool, len(sessions))
+	for _, session := range sessions {
+		running[session] = true
+	}
+	for _, name := range worktreeNames {
+		legacy, sessionName := SanitizeSessionName(name), SessionName(project, name)
+		if !running[legacy] || running[sessionName] || !isLegacySession(legacy, name) {
+			continue
+		}
+		if err := runner.Run(Command("rename-session", "-t", exact(legacy), sessionName)); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: failed to rename tmux session %s to %s: %v\n", legacy, sessionName, err)
+			continue
+		}
+		if err := markManaged(sessionName, name); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
+	}
+}
+
+// isLegacySession reports whether the session with a worktree's bare name is the one lfg started for it:
+// tagged for it, or from before sessions were tagged, started in the worktree's directory
+func isLegacySession(legacy, worktreeName string) bool {
+	if tagged := managedWorktree(legacy); tagged != "" {
+		return tagged == worktreeName
+	}
+	output, err := runner.Output(Command("display-message", "-p", "-t", exact(legacy)+":", "#{session_path}"))
+	return err == nil && filepath.Base(strings.TrimSpace(string(output))) == worktreeName
+}
+
 // SanitizeSessionName converts characters that tmux doesn't allow in session names
 func SanitizeSessionName(name string) string {
-	// Replace dots with underscores (tmux converts dots to underscores)
-	return strings.ReplaceAll(name, ".", "_")
+	// Replace dots and colons with underscores (tmux converts them to underscores)
+	name = strings.ReplaceAll(name, ".", "_")
+	return strings.ReplaceAll(name, ":", "_")
 }
 
 // sanitizeSessionName is a wrapper for backward compatibility
@@ -56,20 +204,21 @@ func sanitizeSessionName(name string) string {
 
 // ensureWindows checks if the session has the correct pane layout and recreates if needed
 func ensureWindows(sessionName, worktreeName, path string, cfg *config.Config) error {
-	// Check if a window with the worktree name exists
-	cmd := exec.Command("tmux", "list-windows", "-t", sessionName, "-F", "#{window_name}")
-	output, err := cmd.Output()
+	// Check if a window with the worktree name exists, or the one lfg tagged, which may have been renamed
+	cmd := Command("list-windows", "-t", exact(sessionName), "-F", "#{window_name}\t#{"+windowOption+"}")
+	output, err := runner.Output(cmd)
 	if err != nil {
 		return fmt.Errorf("failed to list windows: %w", err)
 	}
 
 	hasWorktreeWindow := false
-	lines := strings.Split(strings.TrimSpace(string(output)), "\n")
-	for _, line := range lines {
-		if line == worktreeName {
+	var lines []string
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		name, tag, _ := strings.Cut(line, "\t")
+		if name == worktreeName || tag != "" {
 			hasWorktreeWindow = true
-			break
 		}
+		lines = append(lines, name)
 	}
 
 	// If worktree window doesn't exist, create the pane layout
@@ -77,14 +226,14 @@ func ensureWindows(sessionName, worktreeName, path string, cfg *config.Config) e
 		// Kill all windows first
 		for _, line := range lines {
 			if line != "" {
-				cmd = exec.Command("tmux", "kill-window", "-t", fmt.Sprintf("%s:%s", sessionName, line))
-				cmd.Run() // Ignore errors
+				cmd = Command("kill-window", "-t", fmt.Sprintf("%s:%s", exact(sessionName), line))
+				runner.Run(cmd) // Ignore errors
 			}
 		}
 
 		// Create new window with pane layout, named with the worktree name
-		cmd = exec.Command("tmux", "new-window", "-t", sessionName, "-n", worktreeName, "-c", path)
-		if err := cmd.Run(); err != nil {
+		cmd = Command(withShell("new-window", "-t", exact(sessionName)+":", "-n", worktreeName, "-c", path)...)
+		if err := runner.Run(cmd); err != nil {
 			return fmt.Errorf("failed to create worktree window: %w", err)
 		}
 
@@ -96,36 +245,77 @@ func ensureWindows(sessionName, worktreeName, path string, cfg *config.Config) e
 }
 
 func createSession(sessionName, worktreeName, path string, cfg *config.Config) error {
+	if err := startSession(sessionName, worktreeName, path, cfg); err != nil {
+		return err
+	}
+	return createPaneLayout(sessionName, worktreeName, path, cfg)
+}
+
+// startSession creates a detached session with a single window, named after the worktree, and a single pane
+func startSession(sessionName, worktreeName, path string, cfg *config.Config) error {
 	// Verify path exists
 	if _, err := os.Stat(path); os.IsNotExist(err) {
 		return fmt.Errorf("path does not exist: %s", path)
 	}
 
 	// Create initial session (detached) with a single window
-	cmd := exec.Command("tmux", "new-session", "-d", "-s", sessionName, "-c", path)
-	output, err := cmd.CombinedOutput()
+	cmd := Command(withShell("new-session", "-d", "-s", sessionName, "-c", path)...)
+	output, err := runner.CombinedOutput(cmd)
 	if err != nil {
 		return fmt.Errorf("failed to create session: %s (output: %s)", err, string(output))
 	}
+	events.Emit(events.Event{Type: events.SessionStarted, Worktree: worktreeName, Path: path, Session: sessionName})
+
+	if err := markManaged(sessionName, worktreeName); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
 
 	// Rename the window to show the worktree name
-	cmd = exec.Command("tmux", "rename-window", "-t", fmt.Sprintf("%s:0", sessionName), worktreeName)
-	if err := cmd.Run(); err != nil {
+	cmd = Command("rename-window", "-t", layoutWindow(sessionName), worktreeName)
+	if err := runner.Run(cmd); err != nil {
 		return fmt.Errorf("failed to rename window: %w", err)
 	}
+	runner.Run(Command("set-option", "-w", "-t", layoutWindow(sessionName), windowOption, worktreeName))
 
 	// Enable mouse mode for this session
-	cmd = exec.Command("tmux", "set-option", "-t", sessionName, "mouse", "on")
-	if err := cmd.Run(); err != nil {
+	cmd = Command("set-option", "-t", exact(sessionName)+":", "mouse", "on")
+	if err := runner.Run(cmd); err != nil {
 		fmt.Fprintf(os.Stderr, "Warning: failed to enable mouse mode: %v\n", err)
 	}
 
-	return createPaneLayout(sessionName, worktreeName, path, cfg)
+	setTerminalTitle(sessionName, worktreeName)
+	SetStatusColor(sessionName, cfg.WorktreeColor(worktreeName))
+	return nil
+}
+
+// setTerminalTitle has tmux set the outer terminal's title to the worktree name while attached
+func setTerminalTitle(sessionName, worktreeName string) {
+	cmd := Command("set-option", "-t", exact(sessionName)+":", "set-titles", "on")
+	if err := runner.Run(cmd); err != nil {
+		return
+	}
+	runner.Run(Command("set-option", "-t", exact(sessionName)+":", "set-titles-string", "lfg: "+worktreeName))
+}
+
+// SetStatusColor colours the session's status bar so sessions are easy to tell apart
+// An empty color restores the default status style
+func SetStatusColor(sessionName, color string) error {
+	if color == "" {
+		cmd := Command("set-option", "-u", "-t", exact(sessionName)+":", "status-style")
+		return runner.Run(cmd)
+	}
+	cmd := Command("set-option", "-t", exact(sessionName)+":", "status-style", "bg="+color+",fg=black")
+	return runner.Run(cmd)
 }
 
 func createPaneLayout(sessionName, worktreeName, path string, cfg *config.Config) error {
-	// Use session and window index (window 0) as target to avoid issues with dots in window names
-	target := fmt.Sprintf("%s:0", sessionName)
+	// Target the window by position rather than name to avoid issues with dots in window names
+	// Panes are numbered from the server's pane-base-index, so pane N of the layout is base+N
+	target := layoutWindow(sessionName)
+	base := paneBase(target)
+	pane := func(index int) string {
+		return fmt.Sprintf("%s.%d", target, base+index)
+	}
 
 	// Get layout (handles backward compatibility with old Windows format)
 	layout := cfg.GetLayout()
@@ -133,12 +323,15 @@ func createPaneLayout(sessionName, worktreeName, path string, cfg *config.Config
 		return fmt.Errorf("no layout defined in config")
 	}
 
+	// Panes that fail to start don't stop the session, they are recorded so they can be retried
+	var failures []PaneFailure
+
 	// Step 1: Create agent pane (always 45% of screen)
 	// Split pane 0: top 45% for agent, bottom 55% for user panes
-	paneTarget := fmt.Sprintf("%s.0", target)
+	paneTarget := pane(0)
 	fmt.Fprintf(os.Stderr, "DEBUG: Creating agent pane - target=%s, paneTarget=%s\n", target, paneTarget)
-	cmd := exec.Command("tmux", "split-window", "-t", paneTarget, "-v", "-p", "55", "-c", path)
-	output, err := cmd.CombinedOutput()
+	cmd := Command(withShell("split-window", "-t", paneTarget, "-v", "-p", "55", "-c", path)...)
+	output, err := runner.CombinedOutput(cmd)
 	if err != nil {
 		return fmt.Errorf("failed to create agent pane: %w (output: %s)", err, string(output))
 	}
@@ -148,9 +341,10 @@ func createPaneLayout(sessionName, worktreeName, path string, cfg *config.Config
 	// - Pane 1: work area (bottom 55%)
 
 	// Setup agent pane
-	agentPane := fmt.Sprintf("%s.0", target)
+	agentPane := pane(0)
+	tagPane(agentPane, agentPaneName)
 	if err := setupAgentPane(agentPane, worktreeName, path, cfg); err != nil {
-		fmt.Fprintf(os.Stderr, "Warning: failed to setup agent pane: %v\n", err)
+		failures = append(failures, PaneFailure{Pane: agentPaneName, Err: err.Error()})
 	}
 
 	// Step 2: Build work panes in the bottom area according to layout
@@ -171,6 +365,7 @@ func createPaneLayout(sessionName, worktreeName, path string, cfg *config.Config
 	remainingPercent := 100
 
 	// Step 1: Create all vertical rows first
+	rows := len(layout)
 	for rowIdx := 1; rowIdx < len(layout); rowIdx++ {
 		// Calculate the sum of all remaining rows' heights
 		remainingHeight := 0
@@ -183,12 +378,17 @@ func createPaneLayout(sessionName, worktreeName, path string, cfg *config.Config
 		splitPercent := (remainingHeight * 100) / remainingPercent
 
 		// Split vertically to create this row (always split the bottom pane)
-		splitTarget := fmt.Sprintf("%s.%d", target, paneIndex)
+		splitTarget := pane(paneIndex)
 		fmt.Fprintf(os.Stderr, "DEBUG: Creating row %d - splitTarget=%s, paneIndex=%d, splitPercent=%d, remainingPercent=%d, remainingHeight=%d\n",
 			rowIdx, splitTarget, paneIndex, splitPercent, remainingPercent, remainingHeight)
-		cmd := exec.Command("tmux", "split-window", "-t", splitTarget, "-v", "-p", fmt.Sprintf("%d", splitPercent), "-c", path)
-		if err := cmd.Run(); err != nil {
-			return fmt.Errorf("failed to create row %d: %w", rowIdx, err)
+		cmd := Command(withShell("split-window", "-t", splitTarget, "-v", "-p", fmt.Sprintf("%d", splitPercent), "-c", path)...)
+		if err := runner.Run(cmd); err != nil {
+			// Later rows can't be created either, so the session ends after the last row that was
+			for _, row := range layout[rowIdx:] {
+				failures = append(failures, rowFailures(row, fmt.Errorf("failed to create row %d: %w", rowIdx, err))...)
+			}
+			rows = rowIdx
+			break
 		}
 
 		// Update remaining percentage (subtract the row we just created's height)
@@ -204,60 +404,46 @@ func createPaneLayout(sessionName, worktreeName, path string, cfg *config.Config
 
 	// Step 3: Handle horizontal splits and commands for each row
 	paneIndex = 1 // Reset to first user pane (pane 1, after agent)
-	for rowIdx, row := range layout {
+	for _, row := range layout[:rows] {
 		if len(row.Panes) > 0 {
-			// Multi-pane row: split horizontally within this row
-			rowStartPane := paneIndex
-
-			// Create all horizontal splits by splitting the leftmost pane each time
-			for paneIdx := 1; paneIdx < len(row.Panes); paneIdx++ {
-				// Calculate percentage: new pane gets (remaining-1)/remaining of current pane's size
-				remainingPanes := len(row.Panes) - paneIdx + 1
-				hSplitPercent := (100 * (remainingPanes - 1)) / remainingPanes
-
-				// Always split the first pane of this row (rowStartPane)
-				splitTarget := fmt.Sprintf("%s.%d", target, rowStartPane)
-				cmd := exec.Command("tmux", "split-window", "-t", splitTarget, "-h", "-p", fmt.Sprintf("%d", hSplitPercent), "-c", path)
-				if err := cmd.Run(); err != nil {
-					return fmt.Errorf("failed to create horizontal pane %d in row %d: %w", paneIdx, rowIdx, err)
-				}
-			}
-
-			// After all splits, run commands on each pane
-			for paneIdx, pane := range row.Panes {
-				if pane.Command != nil && *pane.Command != "" {
-					paneTarget := fmt.Sprintf("%s.%d", target, rowStartPane+paneIdx)
-					cmd := exec.Command("tmux", "send-keys", "-t", paneTarget, *pane.Command, "Enter")
-					if err := cmd.Run(); err != nil {
-						fmt.Fprintf(os.Stderr, "Warning: failed to run command in pane %s: %v\n", pane.Name, err)
-					}
-				}
-			}
+			// Multi-pane row: split horizontally within this row, and each pane further as configured
+			// New panes follow the one they were split from, so the row's panes stay contiguous
+			created, splitFailures := splitPanes(pane(paneIndex), row.Panes, true, path)
+			failures = append(failures, splitFailures...)
 
 			// Move to next row's starting pane
-			paneIndex += len(row.Panes)
+			paneIndex += created
 		} else {
 			// Single-pane row
+			paneTarget := pane(paneIndex)
+			tagPane(paneTarget, row.Name)
 			if row.Command != nil && *row.Command != "" {
 				// Run command if specified
-				paneTarget := fmt.Sprintf("%s.%d", target, paneIndex)
-				cmd := exec.Command("tmux", "send-keys", "-t", paneTarget, *row.Command, "Enter")
-				if err := cmd.Run(); err != nil {
-					fmt.Fprintf(os.Stderr, "Warning: failed to run command in pane %s: %v\n", row.Name, err)
+				if err := runPaneCommand(paneTarget, *row.Command, row.RemainOnExit); err != nil {
+					failures = append(failures, PaneFailure{Pane: row.Name, Err: err.Error()})
 				}
 			}
 			paneIndex++
 		}
 	}
 
+	if err := recordFailures(sessionName, failures); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+
 	// Select the agent pane (pane 0)
-	cmd = exec.Command("tmux", "select-pane", "-t", fmt.Sprintf("%s.0", target))
-	if err := cmd.Run(); err != nil {
+	cmd = Command("select-pane", "-t", pane(0))
+	if err := runner.Run(cmd); err != nil {
 		fmt.Fprintf(os.Stderr, "Warning: failed to select agent pane: %v\n", err)
 	}
 
+	// Windows renamed or added from lfg for this worktree come back with it
+	if err := applyWindowOverrides(sessionName, worktreeName, path); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+
 	// Attach to session
-	return attachSession(sessionName)
+	return SwitchOrAttach(sessionName)
 }
 
 func setupDescriptionPane(pane, worktreeName string, cfg *config.Config) error {
@@ -273,9 +459,9 @@ func setupDescriptionPane(pane, worktreeName string, cfg *config.Config) error {
 	configPath := cfg.GetConfigPath()
 
 	// Launch the viewer TUI in the pane using lfg --view with config path
-	cmd := exec.Command("tmux", "send-keys", "-t", pane,
+	cmd := Command("send-keys", "-t", pane,
 		fmt.Sprintf("%s --view --config %s %s", lfgPath, configPath, worktreeName), "Enter")
-	return cmd.Run()
+	return runner.Run(cmd)
 }
 
 func setupAgentPane(pane, worktreeName, path string, cfg *config.Config) error {
@@ -292,41 +478,72 @@ func setupAgentPane(pane, worktreeName, path string, cfg *config.Config) error {
 
 	// Launch the agent wrapper in the pane
 	// The wrapper will handle conversation capture and posting to GitHub
-	cmd := exec.Command("tmux", "send-keys", "-t", pane,
+	cmd := Command("send-keys", "-t", pane,
 		fmt.Sprintf("%s --agent --config %s %s", lfgPath, configPath, worktreeName), "Enter")
-	return cmd.Run()
+	return runner.Run(cmd)
 }
 
-func attachSession(name string) error {
-	// Check if we're already in a tmux session
-	if os.Getenv("TMUX") != "" {
-		// Switch to the session
-		cmd := exec.Command("tmux", "switch-client", "-t", name)
-		return cmd.Run()
+// InsideTmux reports whether lfg is running inside a tmux client
+func InsideTmux() bool {
+	return os.Getenv("TMUX") != ""
+}
+
+// SwitchOrAttach shows a session: from inside tmux the current client switches to it, since attaching
+// would nest sessions, otherwise the terminal attaches to it
+func SwitchOrAttach(name string) error {
+	if InsideTmux() {
+		cmd := Command("switch-client", "-t", exact(name))
+		if output, err := runner.CombinedOutput(cmd); err != nil {
+			return fmt.Errorf("failed to switch to session %s: %s", name, strings.TrimSpace(string(output)))
+		}
+		return nil
 	}
 
 	// Attach to session (replace current process)
-	cmd := exec.Command("tmux", "attach-session", "-t", name)
+	cmd := Command("attach-session", "-t", exact(name))
 	cmd.Stdin = os.Stdin
 	cmd.Stdout = os.Stdout
 	cmd.Stderr = os.Stderr
-	return cmd.Run()
+	return runner.Run(cmd)
+}
+
+// SendCommand types a shell command into the active pane of a session and runs it
+func SendCommand(sessionName, command string) error {
+	return runner.Run(Command("send-keys", "-t", exact(sessionName)+":", command, "Enter"))
 }
 
-// KillSession kills a tmux session
+// KillSession kills a tmux session lfg created along with the separate ones started alongside it, saving it
+// first for `lfg restore` when SaveOnKill is set. A session that only shares the name is left running and
+// reported as an error
 func KillSession(name string) error {
-	if !SessionExists(name) {
-		return nil
+	var errs []error
+	for _, session := range sessionGroup(name) {
+		if err := killSession(session, session == name); err != nil {
+			errs = append(errs, err)
+		}
+	}
+	return errors.Join(errs...)
+}
+
+// killSession kills one session lfg created, saving it first when save is set
+func killSession(name string, save bool) error {
+	if !IsManaged(name) {
+		return fmt.Errorf("tmux session %s wasn't started by lfg, leaving it running", name)
+	}
+	if save && SaveOnKill {
+		if err := SaveSession(name); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
 	}
 
-	cmd := exec.Command("tmux", "kill-session", "-t", name)
-	return cmd.Run()
+	cmd := Command("kill-session", "-t", exact(name))
+	return runner.Run(cmd)
 }
 
 // ListSessions returns all active tmux sessions
 func ListSessions() ([]string, error) {
-	cmd := exec.Command("tmux", "list-sessions", "-F", "#{session_name}")
-	output, err := cmd.Output()
+	cmd := Command("list-sessions", "-F", "#{session_name}")
+	output, err := runner.Output(cmd)
 	if err != nil {
 		// If no sessions exist, tmux returns an error
 		if strings.Contains(err.Error(), "no server running") {
@@ -1,7 +1,11 @@
 package tmux
 
 import (
+	"fmt"
+	"strings"
 	"testing"
+
+	"github.com/markcipolla/lfg/internal/runner"
 )
 
 func TestSanitizeSessionName(t *testing.T) {
@@ -42,9 +46,240 @@ func TestSanitizeSessionName(t *testing.T) {
 	}
 }
 
+func TestSessionName(t *testing.T) {
+	tests := []struct {
+		name     string
+		project  string
+		worktree string
+		expected string
+	}{
+		{
+			name:     "namespaced by project",
+			project:  "myapp",
+			worktree: "fix-login",
+			expected: "myapp/fix-login",
+		},
+		{
+			name:     "same worktree in another project",
+			project:  "otherapp",
+			worktree: "fix-login",
+			expected: "otherapp/fix-login",
+		},
+		{
+			name:     "dots sanitized",
+			project:  "household.email",
+			worktree: "household.email-homepage",
+			expected: "household_email/household_email-homepage",
+		},
+		{
+			name:     "no project",
+			project:  "",
+			worktree: "fix-login",
+			expected: "fix-login",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			result := SessionName(tt.project, tt.worktree)
+			if result != tt.expected {
+				t.Errorf("SessionName(%q, %q) = %q, want %q", tt.project, tt.worktree, result, tt.expected)
+			}
+		})
+	}
+}
+
 func TestIsInstalled(t *testing.T) {
 	// This test checks if tmux command is available
 	result := IsInstalled()
 	// We don't assert true/false as it depends on system
 	t.Logf("tmux installed: %v", result)
 }
+
+func TestSetStatusColor(t *testing.T) {
+	tests := []struct {
+		color    string
+		expected string
+	}{
+		{color: "blue", expected: "tmux set-option -t =myapp/login: status-style bg=blue,fg=black"},
+		{color: "", expected: "tmux set-option -u -t =myapp/login: status-style"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.color, func(t *testing.T) {
+			fake := &runner.Fake{}
+			defer runner.Set(fake)()
+
+			if err := SetStatusColor("myapp/login", tt.color); err != nil {
+				t.Fatalf("SetStatusColor() error = %v", err)
+			}
+			if len(fake.Calls) != 1 {
+				t.Fatalf("SetStatusColor() ran %d commands, want 1", len(fake.Calls))
+			}
+			if got := strings.Join(fake.Calls[0], " "); got != tt.expected {
+				t.Errorf("SetStatusColor() ran %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestKillSession(t *testing.T) {
+	tests := []struct {
+		name    string
+		managed string
+		killed  []string
+		wantErr bool
+	}{
+		// myapp/login-2 is the session of another worktree, login-2
+		{name: "session started by lfg", managed: "login\n", killed: []string{"=myapp/login", "=myapp/login~2"}},
+		{name: "session that only shares the name", managed: "", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				switch args[1] {
+				case "list-sessions":
+					return []byte("myapp/login\nmyapp/login-2\nmyapp/login~2\n"), nil
+				case "show-options":
+					return []byte(tt.managed), nil
+				}
+				return nil, nil
+			}}
+			defer runner.Set(fake)()
+
+			err := KillSession("myapp/login")
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("KillSession() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			var killed []string
+			for _, call := range fake.Calls {
+				if call[1] == "kill-session" {
+					killed = append(killed, call[3])
+				}
+			}
+			if strings.Join(killed, " ") != strings.Join(tt.killed, " ") {
+				t.Errorf("KillSession() killed %v, want %v", killed, tt.killed)
+			}
+		})
+	}
+}
+
+func TestAdoptLegacySessions(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		switch args[1] {
+		case "list-sessions":
+			return []byte("myapp-login\nmyapp-search\nmyapp/myapp-billing\nmyapp-billing\n"), nil
+		case "show-options":
+			return nil, nil
+		case "display-message":
+			// myapp-search is someone's own session that happens to share the name
+			if strings.Contains(strings.Join(args, " "), "=myapp-login:") {
+				return []byte("/src/myapp-login\n"), nil
+			}
+			return []byte("/home/me\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	AdoptLegacySessions("myapp", "myapp-login", "myapp-search", "myapp-billing")
+
+	var renamed []string
+	for _, call := range fake.Calls {
+		if call[1] == "rename-session" {
+			renamed = append(renamed, strings.Join(call[1:], " "))
+		}
+	}
+	if got := strings.Join(renamed, "\n"); got != "rename-session -t =myapp-login myapp/myapp-login" {
+		t.Errorf("AdoptLegacySessions() renamed:\n%s\nwant only myapp-login", got)
+	}
+}
+
+func TestSessionExistsSharedPrefix(t *testing.T) {
+	// Emulates tmux target matching: exact with "=", otherwise falling back to a prefix match
+	running := []string{"myapp/fix-login", "myapp/fix"}
+	match := func(sessions []string, target string) bool {
+		for _, session := range sessions {
+			if name, ok := strings.CutPrefix(target, "="); ok {
+				if session == name {
+					return true
+				}
+			} else if strings.HasPrefix(session, target) {
+				return true
+			}
+		}
+		return false
+	}
+
+	tests := []struct {
+		name     string
+		sessions []string
+		session  string
+		expected bool
+	}{
+		{name: "exact name", sessions: running, session: "myapp/fix", expected: true},
+		{name: "longer name", sessions: running, session: "myapp/fix-login", expected: true},
+		{name: "prefix of a running session", sessions: running[:1], session: "myapp/fix", expected: false},
+		{name: "prefix of the project", sessions: running, session: "myapp/fi", expected: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				if !match(tt.sessions, args[3]) {
+					return nil, fmt.Errorf("can't find session: %s", args[3])
+				}
+				return nil, nil
+			}}
+			defer runner.Set(fake)()
+
+			if got := SessionExists(tt.session); got != tt.expected {
+				t.Errorf("SessionExists(%q) = %v, want %v", tt.session, got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestSwitchOrAttach(t *testing.T) {
+	tests := []struct {
+		name     string
+		tmux     string
+		expected string
+	}{
+		{name: "inside tmux", tmux: "/tmp/tmux-1000/default,123,0", expected: "tmux switch-client -t =myapp/login"},
+		{name: "outside tmux", tmux: "", expected: "tmux attach-session -t =myapp/login"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Setenv("TMUX", tt.tmux)
+			fake := &runner.Fake{}
+			defer runner.Set(fake)()
+
+			if err := SwitchOrAttach("myapp/login"); err != nil {
+				t.Fatalf("SwitchOrAttach() error = %v", err)
+			}
+			if len(fake.Calls) != 1 {
+				t.Fatalf("SwitchOrAttach() ran %d commands, want 1", len(fake.Calls))
+			}
+			if got := strings.Join(fake.Calls[0], " "); got != tt.expected {
+				t.Errorf("SwitchOrAttach() ran %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestSendCommand(t *testing.T) {
+	fake := &runner.Fake{}
+	defer runner.Set(fake)()
+
+	if err := SendCommand("myapp", "cd '/src/myapp' && tmux kill-session"); err != nil {
+		t.Fatalf("SendCommand() error = %v", err)
+	}
+	// Exact, so a session named e.g. "myapp-login" isn't picked by prefix
+	want := "tmux send-keys -t =myapp: cd '/src/myapp' && tmux kill-session Enter"
+	if len(fake.Calls) != 1 || strings.Join(fake.Calls[0], " ") != want {
+		t.Errorf("SendCommand() ran %v, want %q", fake.Calls, want)
+	}
+}
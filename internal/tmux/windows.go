@@ -0,0 +1,215 @@
+package tmux
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// windowOption tags the window lfg lays a worktree's panes out in, so it's still found once renamed
+const windowOption = "@lfg-window"
+
+// WindowInfo describes one window of a session
+type WindowInfo struct {
+	Index  int
+	Name   string
+	Layout bool   // The window lfg laid the worktree's panes out in, always the first one
+	Path   string // Working directory of the window's active pane
+}
+
+// WindowOverride is a window of a worktree's session as it was renamed or reordered from lfg, so the
+// next session for the worktree gets it again
+type WindowOverride struct {
+	Name   string `json:"name"`
+	Path   string `json:"path,omitempty"`   // Working directory, the worktree when empty
+	Layout bool   `json:"layout,omitempty"` // The layout window, only renamed
+}
+
+// windowFormat is the list-windows format parsed by parseWindows
+const windowFormat = "#{window_index}\t#{window_name}\t#{" + windowOption + "}\t#{pane_current_path}"
+
+// ListWindows returns a session's windows in order
+// Sessions started before lfg tagged its window have their first window treated as the layout one
+func ListWindows(sessionName string) ([]WindowInfo, error) {
+	cmd := Command("list-windows", "-t", exact(sessionName), "-F", windowFormat)
+	output, err := runner.Output(cmd)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list windows: %w", err)
+	}
+	return parseWindows(string(output)), nil
+}
+
+// parseWindows parses list-windows output in windowFormat
+func parseWindows(output string) []WindowInfo {
+	var windows []WindowInfo
+	tagged := false
+	for _, line := range strings.Split(strings.TrimSpace(output), "\n") {
+		fields := strings.Split(line, "\t")
+		if len(fields) != 4 {
+			continue
+		}
+		index, err := strconv.Atoi(fields[0])
+		if err != nil {
+			continue
+		}
+		layout := fields[2] != ""
+		tagged = tagged || layout
+		windows = append(windows, WindowInfo{Index: index, Name: fields[1], Layout: layout, Path: fields[3]})
+	}
+	if !tagged && len(windows) > 0 {
+		windows[0].Layout = true
+	}
+	return windows
+}
+
+// RenameWindow renames a session's window
+func RenameWindow(sessionName string, index int, name string) error {
+	if strings.TrimSpace(name) == "" {
+		return fmt.Errorf("window name cannot be empty")
+	}
+	cmd := Command("rename-window", "-t", fmt.Sprintf("%s:%d", exact(sessionName), index), name)
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return fmt.Errorf("failed to rename window: %s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// MoveWindow swaps a session's window with the one before (delta -1) or after it (delta 1), returning
+// its new index. The layout window stays first, capturing output and adding panes look for it there
+func MoveWindow(sessionName string, index, delta int) (int, error) {
+	windows, err := ListWindows(sessionName)
+	if err != nil {
+		return index, err
+	}
+	from := -1
+	for i, window := range windows {
+		if window.Index == index {
+			from = i
+		}
+	}
+	to := from + delta
+	if from < 0 || to < 0 || to >= len(windows) {
+		return index, nil
+	}
+	if windows[from].Layout || windows[to].Layout {
+		return index, fmt.Errorf("the window with the worktree's panes stays first")
+	}
+
+	cmd := Command("swap-window", "-d",
+		"-s", fmt.Sprintf("%s:%d", exact(sessionName), windows[from].Index),
+		"-t", fmt.Sprintf("%s:%d", exact(sessionName), windows[to].Index))
+	if output, err := runner.CombinedOutput(cmd); err != nil {
+		return index, fmt.Errorf("failed to move window: %s", strings.TrimSpace(string(output)))
+	}
+	return windows[to].Index, nil
+}
+
+// windowOverridesPath returns where a session's window overrides are kept, next to saved sessions
+func windowOverridesPath(sessionName string) (string, error) {
+	dir, err := config.DataDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "windows", url.PathEscape(sessionName)+".json"), nil
+}
+
+// SaveWindowOverrides records a session's windows, their names and order, for the worktree's next session
+func SaveWindowOverrides(sessionName, worktreePath string) error {
+	windows, err := ListWindows(sessionName)
+	if err != nil {
+		return err
+	}
+	overrides := make([]WindowOverride, 0, len(windows))
+	for _, window := range windows {
+		override := WindowOverride{Name: window.Name, Layout: window.Layout}
+		if !window.Layout && filepath.Clean(window.Path) != filepath.Clean(worktreePath) {
+			override.Path = window.Path
+		}
+		overrides = append(overrides, override)
+	}
+
+	data, err := json.MarshalIndent(overrides, "", "  ")
+	if err != nil {
+		return err
+	}
+	path, err := windowOverridesPath(sessionName)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return fmt.Errorf("failed to save windows: %w", err)
+	}
+	if err := os.WriteFile(path, data, 0644); err != nil {
+		return fmt.Errorf("failed to save windows: %w", err)
+	}
+	return nil
+}
+
+// LoadWindowOverrides reads the windows saved by SaveWindowOverrides, nil when there are none
+func LoadWindowOverrides(sessionName string) ([]WindowOverride, error) {
+	path, err := windowOverridesPath(sessionName)
+	if err != nil {
+		return nil, err
+	}
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read saved windows: %w", err)
+	}
+	var overrides []WindowOverride
+	if err := json.Unmarshal(data, &overrides); err != nil {
+		return nil, fmt.Errorf("failed to parse saved windows %s: %w", path, err)
+	}
+	return overrides, nil
+}
+
+// ForgetWindowOverrides drops a deleted worktree's saved windows
+func ForgetWindowOverrides(sessionName string) {
+	if path, err := windowOverridesPath(sessionName); err == nil {
+		os.Remove(path)
+	}
+}
+
+// applyWindowOverrides renames a new session's layout window and adds the other saved windows after it,
+// in order, then selects the layout window again
+func applyWindowOverrides(sessionName, worktreeName, path string) error {
+	overrides, err := LoadWindowOverrides(sessionName)
+	if err != nil || len(overrides) == 0 {
+		return err
+	}
+
+	layout := layoutWindow(sessionName)
+	for _, override := range overrides {
+		if override.Layout {
+			if override.Name != "" && override.Name != worktreeName {
+				if err := runner.Run(Command("rename-window", "-t", layout, override.Name)); err != nil {
+					return fmt.Errorf("failed to rename window: %w", err)
+				}
+			}
+			continue
+		}
+
+		dir := override.Path
+		if dir == "" {
+			dir = path
+		}
+		if _, err := os.Stat(dir); err != nil {
+			dir = path
+		}
+		cmd := Command(withShell("new-window", "-d", "-t", exact(sessionName)+":", "-n", override.Name, "-c", dir)...)
+		if err := runner.Run(cmd); err != nil {
+			return fmt.Errorf("failed to create window %s: %w", override.Name, err)
+		}
+	}
+	return runner.Run(Command("select-window", "-t", layout))
+}
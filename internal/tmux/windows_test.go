@@ -0,0 +1,139 @@
+package tmux
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestParseWindows(t *testing.T) {
+	tests := []struct {
+		name     string
+		output   string
+		expected []WindowInfo
+	}{
+		{
+			name:   "tagged layout window",
+			output: "0\tlogin\tmyapp-login\t/src/myapp-login\n1\tlogs\t\t/src/myapp-login/log\n",
+			expected: []WindowInfo{
+				{Index: 0, Name: "login", Layout: true, Path: "/src/myapp-login"},
+				{Index: 1, Name: "logs", Path: "/src/myapp-login/log"},
+			},
+		},
+		{
+			name:   "session started before windows were tagged",
+			output: "1\tmyapp-login\t\t/src/myapp-login\n2\tzsh\t\t/tmp\n",
+			expected: []WindowInfo{
+				{Index: 1, Name: "myapp-login", Layout: true, Path: "/src/myapp-login"},
+				{Index: 2, Name: "zsh", Path: "/tmp"},
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := parseWindows(tt.output); !reflect.DeepEqual(got, tt.expected) {
+				t.Errorf("parseWindows() = %+v, want %+v", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestMoveWindow(t *testing.T) {
+	windows := "0\tmyapp-login\tmyapp-login\t/src/myapp-login\n1\tlogs\t\t/src/myapp-login\n3\tconsole\t\t/src/myapp-login\n"
+
+	tests := []struct {
+		name     string
+		index    int
+		delta    int
+		expected int
+		wantErr  bool
+		wantSwap string
+	}{
+		{name: "down", index: 1, delta: 1, expected: 3, wantSwap: "tmux swap-window -d -s =myapp/login:1 -t =myapp/login:3"},
+		{name: "up", index: 3, delta: -1, expected: 1, wantSwap: "tmux swap-window -d -s =myapp/login:3 -t =myapp/login:1"},
+		{name: "past the end", index: 3, delta: 1, expected: 3},
+		{name: "before the layout window", index: 1, delta: -1, expected: 1, wantErr: true},
+		{name: "layout window", index: 0, delta: 1, expected: 0, wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+				if args[1] == "list-windows" {
+					return []byte(windows), nil
+				}
+				return nil, nil
+			}}
+			defer runner.Set(fake)()
+
+			got, err := MoveWindow("myapp/login", tt.index, tt.delta)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("MoveWindow() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.expected {
+				t.Errorf("MoveWindow() = %d, want %d", got, tt.expected)
+			}
+			swap := ""
+			for _, call := range fake.Calls {
+				if call[1] == "swap-window" {
+					swap = strings.Join(call, " ")
+				}
+			}
+			if swap != tt.wantSwap {
+				t.Errorf("MoveWindow() ran %q, want %q", swap, tt.wantSwap)
+			}
+		})
+	}
+}
+
+func TestWindowOverrides(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	path := t.TempDir()
+
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		if args[1] == "list-windows" {
+			return []byte("0\tlogin\tmyapp-login\t" + path + "\n1\tconsole\t\t" + path + "\n2\tlogs\t\t/gone\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	if err := SaveWindowOverrides("myapp/login", path); err != nil {
+		t.Fatalf("SaveWindowOverrides() error = %v", err)
+	}
+	overrides, err := LoadWindowOverrides("myapp/login")
+	if err != nil {
+		t.Fatalf("LoadWindowOverrides() error = %v", err)
+	}
+	expected := []WindowOverride{{Name: "login", Layout: true}, {Name: "console"}, {Name: "logs", Path: "/gone"}}
+	if !reflect.DeepEqual(overrides, expected) {
+		t.Fatalf("LoadWindowOverrides() = %+v, want %+v", overrides, expected)
+	}
+
+	// A new session gets the windows back, in order, in the worktree when their directory is gone
+	fake.Calls = nil
+	if err := applyWindowOverrides("myapp/login", "myapp-login", path); err != nil {
+		t.Fatalf("applyWindowOverrides() error = %v", err)
+	}
+	var calls []string
+	for _, call := range fake.Calls {
+		calls = append(calls, strings.Join(call, " "))
+	}
+	expectedCalls := []string{
+		"tmux rename-window -t =myapp/login:^ login",
+		"tmux new-window -d -t =myapp/login: -n console -c " + path,
+		"tmux new-window -d -t =myapp/login: -n logs -c " + path,
+		"tmux select-window -t =myapp/login:^",
+	}
+	if !reflect.DeepEqual(calls, expectedCalls) {
+		t.Errorf("applyWindowOverrides() ran %q, want %q", calls, expectedCalls)
+	}
+
+	ForgetWindowOverrides("myapp/login")
+	if overrides, _ := LoadWindowOverrides("myapp/login"); overrides != nil {
+		t.Errorf("LoadWindowOverrides() after forgetting = %+v, want nil", overrides)
+	}
+}
@@ -0,0 +1,39 @@
+package tui
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+// ageBadge renders how many days a pending todo has been open, e.g. "14d", in the warning colour once
+// it reaches aging.warn_days and red once its work is stale. Todos opened today get no badge
+func ageBadge(todo *config.Todo, aging *config.Aging, now time.Time) string {
+	if todo == nil {
+		return ""
+	}
+	days := todo.Age(now)
+	if days < 1 {
+		return ""
+	}
+
+	label := fmt.Sprintf("%dd", days)
+	warn, stale := aging.Thresholds()
+	switch {
+	case days >= stale:
+		return errorStyle.Render(label)
+	case days >= warn:
+		return warningStyle.Render(label)
+	}
+	return label
+}
+
+// isStale reports whether the item's todo has been pending for aging.stale_days or more
+func (i worktreeItem) isStale() bool {
+	if i.todo == nil {
+		return false
+	}
+	_, stale := i.aging.Thresholds()
+	return i.todo.Age(time.Now()) >= stale
+}
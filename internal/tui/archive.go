@@ -0,0 +1,105 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// archiveView is the list of archived todos, opened with a
+type archiveView struct {
+	cursor int // Index into config.Archived
+}
+
+// checkedOutIn reports whether a worktree is one of worktrees, whose todos aren't archived
+func checkedOutIn(worktrees []git.Worktree) func(string) bool {
+	names := make(map[string]bool, len(worktrees))
+	for _, wt := range worktrees {
+		names[wt.Name()] = true
+	}
+	return func(name string) bool { return names[name] }
+}
+
+// archiveSelected moves the selected todo to the archive once it's done and its worktree is gone
+func (m *model) archiveSelected() tea.Cmd {
+	todo := m.selectedTodo()
+	if todo == nil {
+		return m.setStatus(i18n.T("archive.only_todos"))
+	}
+	if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+		return m.setStatus(i18n.T("archive.checked_out"))
+	}
+	description := todo.Description
+	if err := m.config.ArchiveTodo(description, checkedOutIn(m.worktrees)); err != nil {
+		m.err = err
+		return nil
+	}
+	if err := m.config.Save(); err != nil {
+		m.err = fmt.Errorf("failed to save config: %w", err)
+		return nil
+	}
+	return tea.Batch(m.setStatus(i18n.T("archive.archived", description)), m.refresh())
+}
+
+// updateArchive handles keys in the archived todos list
+func (m *model) updateArchive(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	archived := m.config.Archived
+	switch msg.String() {
+	case "esc", "q", "a":
+		m.archive = nil
+	case "up", "k":
+		if len(archived) > 0 {
+			m.archive.cursor = (m.archive.cursor + len(archived) - 1) % len(archived)
+		}
+	case "down", "j":
+		if len(archived) > 0 {
+			m.archive.cursor = (m.archive.cursor + 1) % len(archived)
+		}
+	case "u", "enter":
+		if len(archived) == 0 {
+			return m, nil
+		}
+		description := archived[m.archive.cursor].Description
+		if err := m.config.RestoreTodo(description); err != nil {
+			m.err = err
+			return m, nil
+		}
+		if err := m.config.Save(); err != nil {
+			m.err = fmt.Errorf("failed to save config: %w", err)
+			return m, nil
+		}
+		m.archive.cursor = min(m.archive.cursor, max(len(m.config.Archived)-1, 0))
+		return m, tea.Batch(m.setStatus(i18n.T("archive.restored", description)), m.refresh())
+	}
+	return m, nil
+}
+
+func (m *model) viewArchive() string {
+	var rows strings.Builder
+	if len(m.config.Archived) == 0 {
+		rows.WriteString(helpStyle.Render(i18n.T("archive.empty")) + "\n")
+	}
+	for i, todo := range m.config.Archived {
+		line := todo.Description
+		if !todo.Completed.IsZero() {
+			line += helpStyle.Render(" " + i18n.T("archive.completed", todo.Completed.Format("2 Jan 2006")))
+		}
+		if i == m.archive.cursor {
+			rows.WriteString(highlightStyle.Bold(true).Render("> ") + line)
+		} else {
+			rows.WriteString("  " + line)
+		}
+		rows.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("archive.title")),
+		rows.String(),
+		helpStyle.Render(i18n.T("archive.help")),
+	)
+}
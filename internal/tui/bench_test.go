@@ -0,0 +1,69 @@
+package tui
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/charmbracelet/bubbles/list"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+)
+
+// benchItems returns list items for n worktrees, every tenth linked to a todo
+func benchItems(cfg *config.Config, n int) []list.Item {
+	for i := 0; i < n; i += 10 {
+		cfg.Todos = append(cfg.Todos, config.Todo{
+			Description: fmt.Sprintf("Implement feature %d", i),
+			Status:      config.TodoStatusPending,
+			Worktree:    fmt.Sprintf("myapp-feature-%d", i),
+		})
+	}
+
+	items := make([]list.Item, 0, n)
+	for i := 0; i < n; i++ {
+		name := fmt.Sprintf("myapp-feature-%d", i)
+		items = append(items, worktreeItem{
+			worktree:     git.Worktree{Path: "/src/" + name, Branch: "refs/heads/feature-" + fmt.Sprint(i), ID: name},
+			todo:         cfg.GetTodoForWorktree(name),
+			isCheckedOut: true,
+			status:       git.WorktreeStatus{Dirty: i%2 == 0, Ahead: i % 3, Behind: i % 5},
+		})
+	}
+	return items
+}
+
+func BenchmarkListView(b *testing.B) {
+	cfg := &config.Config{Name: "myapp"}
+	l := list.New(arrangeItems(cfg, View{}, "myapp-feature-0", benchItems(cfg, 1000)), newDelegate("default"), 120, 40)
+	b.ReportAllocs()
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		_ = l.View()
+	}
+}
+
+func BenchmarkFilter(b *testing.B) {
+	cfg := &config.Config{Name: "myapp"}
+	items := benchItems(cfg, 1000)
+	targets := make([]string, len(items))
+	for i, item := range items {
+		targets[i] = item.FilterValue()
+	}
+	b.ReportAllocs()
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		_ = list.DefaultFilter("feat 42", targets)
+	}
+}
+
+// BenchmarkArrangeItems covers rebuilding the list on every refresh
+func BenchmarkArrangeItems(b *testing.B) {
+	cfg := &config.Config{Name: "myapp", Pinned: []string{"myapp-feature-500"}}
+	items := benchItems(cfg, 1000)
+	b.ReportAllocs()
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		_ = arrangeItems(cfg, View{}, "myapp-feature-0", items)
+	}
+}
@@ -0,0 +1,126 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// blockerPick is the todo whose blocker is being chosen with L
+type blockerPick struct {
+	todo   string // Description of the todo that waits
+	cursor int    // Index into blockerChoices(), 0 being "not blocked"
+}
+
+// startBlockerPick offers the other open todos as the selected todo's blocker
+func (m *model) startBlockerPick() tea.Cmd {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || item.todo == nil || item.todo.Status == config.TodoStatusDone {
+		return nil
+	}
+	m.pickingBlocker = &blockerPick{todo: item.todo.Description}
+	for i, choice := range m.blockerChoices() {
+		if choice == item.todo.BlockedBy {
+			m.pickingBlocker.cursor = i
+		}
+	}
+	return nil
+}
+
+// blockerChoices lists what the todo can wait on: nothing, then the other open todos
+func (m *model) blockerChoices() []string {
+	choices := []string{""}
+	for _, todo := range m.config.Todos {
+		if todo.Description != m.pickingBlocker.todo && todo.Status != config.TodoStatusDone {
+			choices = append(choices, todo.Description)
+		}
+	}
+	return choices
+}
+
+// updateBlockerPick handles keys in the blocker picker
+func (m *model) updateBlockerPick(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	choices := m.blockerChoices()
+	switch msg.String() {
+	case "esc", "q":
+		m.pickingBlocker = nil
+	case "up", "k":
+		m.pickingBlocker.cursor = (m.pickingBlocker.cursor + len(choices) - 1) % len(choices)
+	case "down", "j":
+		m.pickingBlocker.cursor = (m.pickingBlocker.cursor + 1) % len(choices)
+	case "enter":
+		todo, blocker := m.pickingBlocker.todo, choices[m.pickingBlocker.cursor]
+		m.pickingBlocker = nil
+		if err := m.config.SetBlockedBy(todo, blocker); err != nil {
+			m.err = err
+			return m, nil
+		}
+		if err := m.config.Save(); err != nil {
+			m.err = fmt.Errorf("failed to save config: %w", err)
+			return m, nil
+		}
+		m.waiting = blockedTodos(m.config)
+		status := i18n.T("blocker.cleared", todo)
+		if blocker != "" {
+			status = i18n.T("blocker.set", todo, blocker)
+		}
+		return m, tea.Batch(m.setStatus(status), m.refresh())
+	}
+	return m, nil
+}
+
+func (m *model) viewBlockerPick() string {
+	var rows strings.Builder
+	for i, choice := range m.blockerChoices() {
+		line := choice
+		if choice == "" {
+			line = i18n.T("blocker.none")
+		}
+		if i == m.pickingBlocker.cursor {
+			rows.WriteString(highlightStyle.Bold(true).Render("> " + line))
+		} else {
+			rows.WriteString("  " + line)
+		}
+		rows.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("blocker.title")),
+		i18n.T("blocker.prompt", m.pickingBlocker.todo),
+		rows.String(),
+		helpStyle.Render(i18n.T("blocker.help")),
+	)
+}
+
+// blockedTodos returns the descriptions of the todos waiting on an open todo
+func blockedTodos(cfg *config.Config) map[string]bool {
+	blocked := make(map[string]bool)
+	for i := range cfg.Todos {
+		if cfg.Blocker(&cfg.Todos[i]) != nil {
+			blocked[cfg.Todos[i].Description] = true
+		}
+	}
+	return blocked
+}
+
+// notifyUnblocked says which todos stopped waiting since the last refresh, because their blocker was
+// finished or removed
+func (m *model) notifyUnblocked() {
+	blocked := blockedTodos(m.config)
+	var unblocked []string
+	for i := range m.config.Todos {
+		todo := m.config.Todos[i]
+		if m.waiting[todo.Description] && !blocked[todo.Description] && todo.Status != config.TodoStatusDone {
+			unblocked = append(unblocked, todo.Description)
+		}
+	}
+	m.waiting = blocked
+	if len(unblocked) > 0 {
+		m.keepStatus(i18n.T("blocker.unblocked", strings.Join(unblocked, ", ")))
+	}
+}
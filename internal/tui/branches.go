@@ -0,0 +1,101 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// openBranchPicker lists the branches that can be checked out into the new worktree
+func (m *model) openBranchPicker() tea.Cmd {
+	branches, err := git.ListBranches()
+	if err != nil {
+		m.err = err
+		return nil
+	}
+	if len(branches) == 0 {
+		return m.setStatus(i18n.T("branches.none"))
+	}
+	m.branches = branches
+	m.branchCursor = 0
+	m.pickingBranch = true
+	return nil
+}
+
+// updateBranchPicker handles keys in the create dialog's branch picker
+func (m *model) updateBranchPicker(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	rows := len(m.branches) + 1
+	switch msg.String() {
+	case "esc", "ctrl+b":
+		m.pickingBranch = false
+	case "up", "k":
+		m.branchCursor = (m.branchCursor + rows - 1) % rows
+	case "down", "j":
+		m.branchCursor = (m.branchCursor + 1) % rows
+	case "enter":
+		m.pickingBranch = false
+		if m.branchCursor == 0 {
+			m.createFrom = nil
+			return m, nil
+		}
+		branch := m.branches[m.branchCursor-1]
+		m.createFrom = &branch
+		// Name the worktree after the branch unless a description was already typed
+		if m.textInput.Value() == "" || m.textInput.Value() == m.config.WorktreeNaming {
+			m.textInput.SetValue(strings.ReplaceAll(branch.LocalName(), "/", "-"))
+			m.textInput.CursorEnd()
+		}
+	}
+	return m, nil
+}
+
+// updateTrackRemote handles the answer to whether the new worktree should track an existing remote branch
+func (m *model) updateTrackRemote(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "y", "Y", "enter":
+		m.createFrom = m.trackRemote
+		m.trackRemote = nil
+		return m.handleCreateWorktree()
+	case "n", "N", "esc":
+		m.trackRemote = nil
+	}
+	return m, nil
+}
+
+// branchLabel describes what checking out a branch does
+func branchLabel(branch git.Branch) string {
+	if branch.Remote {
+		return i18n.T("branches.tracking", branch.LocalName(), branch.Name)
+	}
+	return i18n.T("branches.existing", branch.Name)
+}
+
+func (m *model) viewBranchPicker() string {
+	var rows strings.Builder
+	for i := 0; i <= len(m.branches); i++ {
+		line := i18n.T("branches.new")
+		if i > 0 {
+			line = m.branches[i-1].Name
+			if m.branches[i-1].Remote {
+				line = i18n.T("branches.remote", line)
+			}
+		}
+		if i == m.branchCursor {
+			rows.WriteString(highlightStyle.Bold(true).Render("> " + line))
+		} else {
+			rows.WriteString("  " + line)
+		}
+		rows.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("branches.title")),
+		rows.String(),
+		helpStyle.Render(i18n.T("branches.help")),
+	)
+}
@@ -0,0 +1,342 @@
+package tui
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/worktree"
+)
+
+// bulkAction is what to do with every marked item at once
+type bulkAction int
+
+const (
+	bulkDelete bulkAction = iota // Delete worktrees and their branches, and remove todos
+	bulkDone                     // Mark todos done, leaving worktrees in place
+	bulkPrune                    // Delete the worktrees that are finished: merged or with their todo done
+)
+
+// bulkOp is a confirmed-before-running action on the marked items
+type bulkOp struct {
+	action   bulkAction
+	items    []worktreeItem
+	skipped  []string       // Marked worktrees that are left alone, with why
+	unpushed map[string]int // Commits only on the branches being deleted, by worktree name
+	prune    bool           // Delete branches with unpushed commits too, toggled with b
+}
+
+// toggleMark marks or unmarks the selected item, then moves down so marking a run of items is quick
+func (m *model) toggleMark() tea.Cmd {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || item.pinKey() == "" {
+		return nil
+	}
+	if m.marked == nil {
+		m.marked = map[string]bool{}
+	}
+	if m.marked[item.pinKey()] {
+		delete(m.marked, item.pinKey())
+	} else {
+		m.marked[item.pinKey()] = true
+	}
+	m.applyMarks()
+	m.list.CursorDown()
+	return m.markStatus()
+}
+
+// markRange starts a range at the selected item, or marks every item from the start of the range to it
+func (m *model) markRange() tea.Cmd {
+	index := m.list.Index()
+	if m.markAnchor == nil {
+		m.markAnchor = &index
+		return m.setStatus(i18n.T("bulk.range"))
+	}
+	from, to := min(*m.markAnchor, index), max(*m.markAnchor, index)
+	m.markAnchor = nil
+
+	if m.marked == nil {
+		m.marked = map[string]bool{}
+	}
+	items := m.list.Items()
+	for i := from; i <= to && i < len(items); i++ {
+		if item, ok := items[i].(worktreeItem); ok && item.pinKey() != "" {
+			m.marked[item.pinKey()] = true
+		}
+	}
+	m.applyMarks()
+	return m.markStatus()
+}
+
+// clearMarks unmarks every item
+func (m *model) clearMarks() {
+	m.marked = nil
+	m.markAnchor = nil
+	m.applyMarks()
+}
+
+// markStatus says how many items are marked and what can be done with them
+func (m *model) markStatus() tea.Cmd {
+	if len(m.marked) == 0 {
+		return m.setStatus(i18n.T("bulk.unmarked"))
+	}
+	return m.setStatus(i18n.T("bulk.marked", len(m.marked)))
+}
+
+// applyMarks flags the marked list items, dropping marks for items that are no longer listed
+func (m *model) applyMarks() {
+	items := m.list.Items()
+	updated := make([]list.Item, len(items))
+	listed := map[string]bool{}
+	for i, it := range items {
+		if item, ok := it.(worktreeItem); ok {
+			item.marked = m.marked[item.pinKey()] && item.pinKey() != ""
+			if item.marked {
+				listed[item.pinKey()] = true
+			}
+			it = item
+		}
+		updated[i] = it
+	}
+	m.list.SetItems(updated)
+
+	for key := range m.marked {
+		if !listed[key] {
+			delete(m.marked, key)
+		}
+	}
+}
+
+// markedItems returns the marked items in list order
+func (m *model) markedItems() []worktreeItem {
+	var marked []worktreeItem
+	for _, it := range m.list.Items() {
+		if item, ok := it.(worktreeItem); ok && item.marked {
+			marked = append(marked, item)
+		}
+	}
+	return marked
+}
+
+// bulkPlannedMsg carries what a bulk action applies to, worked out in the background
+type bulkPlannedMsg struct {
+	op *bulkOp
+}
+
+// startBulk works out which marked items an action applies to, off the Update loop as that means asking
+// git about each worktree, then asks to confirm it
+func (m *model) startBulk(action bulkAction) tea.Cmd {
+	items := m.markedItems()
+	mainName := mainWorktreeName(m.worktrees)
+	trash := m.config.Settings != nil && m.config.Settings.Trash
+	return func() tea.Msg {
+		return bulkPlannedMsg{op: planBulk(action, items, mainName, trash)}
+	}
+}
+
+// applyBulkPlan opens the confirmation for a planned bulk action
+func (m *model) applyBulkPlan(op *bulkOp) tea.Cmd {
+	if len(op.items) == 0 && len(op.skipped) == 0 {
+		return m.setStatus(i18n.T("bulk.none." + bulkKey(op.action)))
+	}
+	m.bulk = op
+	return nil
+}
+
+// planBulk returns the action on the items it applies to
+func planBulk(action bulkAction, marked []worktreeItem, mainName string, trash bool) *bulkOp {
+	current, _ := git.GetCurrentWorktree()
+	op := &bulkOp{action: action, unpushed: map[string]int{}}
+	var items []worktreeItem
+	for _, item := range marked {
+		applies, err := bulkApplies(action, item, mainName, current)
+		if err != nil {
+			op.skipped = append(op.skipped, item.label()+" ("+i18n.T("bulk.unmerged", err)+")")
+		} else if applies {
+			items = append(items, item)
+		}
+	}
+	if action == bulkDone {
+		op.items = items
+	} else {
+		checkBulkSafety(op, items, trash)
+	}
+	return op
+}
+
+// checkBulkSafety adds the worktrees that can be deleted without losing work to op, leaving out dirty
+// and locked ones, and notes the branches with unpushed commits, which are only deleted when asked to
+func checkBulkSafety(op *bulkOp, items []worktreeItem, trash bool) {
+	for _, item := range items {
+		if !item.isCheckedOut {
+			op.items = append(op.items, item)
+			continue
+		}
+		report, err := git.CheckSafety(item.worktree)
+		switch {
+		case err != nil:
+			op.skipped = append(op.skipped, item.label()+" ("+err.Error()+")")
+			continue
+		case report.Dirty() && !trash:
+			op.skipped = append(op.skipped, item.label()+" ("+i18n.T("bulk.dirty")+")")
+			continue
+		case report.Locked:
+			op.skipped = append(op.skipped, item.label()+" ("+i18n.T("bulk.locked")+")")
+			continue
+		}
+		if report.Unpushed > 0 {
+			op.unpushed[item.worktree.Name()] = report.Unpushed
+		}
+		op.items = append(op.items, item)
+	}
+}
+
+// bulkApplies reports whether an action can be taken on an item, with an error when pruning couldn't
+// tell whether its branch was merged
+// The main worktree and the one lfg runs in are never deleted in bulk
+func bulkApplies(action bulkAction, item worktreeItem, mainName, current string) (bool, error) {
+	switch action {
+	case bulkDone:
+		return item.todo != nil && item.todo.Status != config.TodoStatusDone, nil
+	case bulkDelete, bulkPrune:
+		if item.isUnlinkedTodo() {
+			return action == bulkDelete, nil
+		}
+		if !item.isCheckedOut {
+			return false, nil
+		}
+		name := item.worktree.Name()
+		if name == mainName || name == current {
+			return false, nil
+		}
+		if action == bulkDelete || item.isDone() {
+			return true, nil
+		}
+		branch := item.worktree.BranchName()
+		if branch == "" {
+			branch = name
+		}
+		return git.IsBranchMerged(branch)
+	}
+	return false, nil
+}
+
+// bulkKey names an action in the catalog's bulk.* messages, which have one per action
+func bulkKey(action bulkAction) string {
+	switch action {
+	case bulkDone:
+		return "done"
+	case bulkPrune:
+		return "prune"
+	}
+	return "delete"
+}
+
+// updateBulk handles keys in the bulk action confirmation
+func (m *model) updateBulk(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "b", "B":
+		if len(m.bulk.unpushed) > 0 {
+			m.bulk.prune = !m.bulk.prune
+		}
+	case "y", "Y":
+		op := m.bulk
+		m.bulk = nil
+		m.clearMarks()
+		cfg := m.config
+		return m, m.worktreeEffect(func() (string, error) {
+			done, err := runBulk(cfg, op)
+			return i18n.N("bulk.result."+bulkKey(op.action), done), err
+		})
+	case "n", "N", "esc":
+		m.bulk = nil
+	}
+	return m, nil
+}
+
+// runBulk takes the action on each item, carrying on past failures, and returns how many it managed
+func runBulk(cfg *config.Config, op *bulkOp) (int, error) {
+	// Removing todos moves the rest around in the config, so work from names taken up front
+	type target struct {
+		worktree git.Worktree
+		todo     string // Description of a todo without a worktree
+		pinned   bool
+		pinKey   string
+	}
+	targets := make([]target, len(op.items))
+	for i, item := range op.items {
+		targets[i] = target{worktree: item.worktree, pinned: item.pinned, pinKey: item.pinKey()}
+		if item.isUnlinkedTodo() {
+			targets[i].todo = item.todo.Description
+		}
+	}
+
+	var errs []error
+	count := 0
+	for i, t := range targets {
+		switch {
+		case op.action == bulkDone && t.todo != "":
+			op.items[i].todo.MarkDone()
+		case op.action == bulkDone:
+			cfg.MarkTodoDone(op.items[i].todo.Worktree)
+		case t.todo != "":
+			cfg.RemoveUnlinkedTodo(t.todo)
+			if t.pinned {
+				cfg.TogglePin(t.pinKey)
+			}
+		default:
+			// Branches with commits nowhere else are only deleted when that was asked for
+			deleteBranch := op.unpushed[t.worktree.Name()] == 0 || op.prune
+			opts := worktree.DeleteOptions{DeleteBranch: deleteBranch, RemoveTodo: op.action == bulkDelete}
+			if _, err := worktree.Delete(cfg, t.worktree, opts); err != nil && !errors.Is(err, worktree.ErrNotSaved) {
+				errs = append(errs, fmt.Errorf("%s: %w", t.worktree.DisplayName(), err))
+				continue
+			}
+		}
+		count++
+	}
+	if err := cfg.Save(); err != nil {
+		errs = append(errs, fmt.Errorf("failed to save config: %w", err))
+	}
+	return count, errors.Join(errs...)
+}
+
+func (m *model) viewBulk() string {
+	var rows strings.Builder
+	for _, item := range m.bulk.items {
+		rows.WriteString("  " + item.label())
+		if commits := m.bulk.unpushed[item.worktree.Name()]; commits > 0 {
+			branch := i18n.T("bulk.branch_kept", i18n.N("safety.unpushed", commits))
+			if m.bulk.prune {
+				branch = i18n.T("bulk.branch_deleted", i18n.N("safety.unpushed", commits))
+			}
+			rows.WriteString(" " + warningStyle.Render(branch))
+		}
+		rows.WriteString("\n")
+	}
+	if len(m.bulk.skipped) > 0 {
+		rows.WriteString("\n" + i18n.T("bulk.skipped") + "\n")
+		for _, skipped := range m.bulk.skipped {
+			rows.WriteString(helpStyle.Render("  "+skipped) + "\n")
+		}
+	}
+
+	help := i18n.T("bulk.help")
+	if len(m.bulk.unpushed) > 0 {
+		help = i18n.T("bulk.help_unpushed", onOff(m.bulk.prune))
+	}
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n%s\n\n%s\n",
+		titleStyle.Render(i18n.T("bulk.title")),
+		i18n.T("bulk.prompt."+bulkKey(m.bulk.action)),
+		rows.String(),
+		i18n.T("bulk.confirm"),
+		helpStyle.Render(help),
+	)
+}
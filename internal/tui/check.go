@@ -0,0 +1,157 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// checkMsg carries the check result for one worktree, and the worktrees still to check
+type checkMsg struct {
+	name   string
+	result *git.CheckResult
+	queue  []git.Worktree
+}
+
+// cachedChecks returns the last check results, so badges show before anything is re-run
+func cachedChecks(cfg *config.Config, worktrees []git.Worktree) map[string]*git.CheckResult {
+	checks := make(map[string]*git.CheckResult)
+	if cfg.CheckCommand == "" {
+		return checks
+	}
+	for _, wt := range worktrees {
+		if result := git.CachedCheck(wt.Path); result != nil {
+			checks[wt.Name()] = result
+		}
+	}
+	return checks
+}
+
+// checkInterval is how often the refreshes start another pass of check_command runs, as even finding
+// which worktrees changed reads every one of them
+const checkInterval = 2 * time.Minute
+
+// runChecks runs the check command in each worktree that changed since its last check, one at a time,
+// at most once every checkInterval. A pass that is still going when the list refreshes is left to finish
+// rather than started again
+func (m *model) runChecks() tea.Cmd {
+	if m.config.CheckCommand == "" || m.checking || time.Since(m.checksRan) < checkInterval {
+		return nil
+	}
+	m.checking = true
+	m.checksRan = time.Now()
+	return m.nextCheck(checkable(m.config, m.worktrees))
+}
+
+// checkable leaves out the pull request review worktrees, which hold someone else's code (perhaps from a
+// fork) that check_command would run, unless you allowed it for that worktree with K
+func checkable(cfg *config.Config, worktrees []git.Worktree) []git.Worktree {
+	store := git.NewMetaStore(cacheKey(cfg))
+	var queue []git.Worktree
+	for _, wt := range worktrees {
+		if _, review := git.ReviewNumber(wt.Name()); review && !store.ChecksTrusted(wt.Name()) {
+			continue
+		}
+		queue = append(queue, wt)
+	}
+	return queue
+}
+
+// toggleChecksTrusted allows or stops check_command runs in the selected review worktree
+func (m *model) toggleChecksTrusted() tea.Cmd {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return nil
+	}
+	name := item.worktree.Name()
+	if _, review := git.ReviewNumber(name); !review {
+		return m.setStatus(i18n.T("check.not_review"))
+	}
+	store := git.NewMetaStore(cacheKey(m.config))
+	trusted := !store.ChecksTrusted(name)
+	if err := store.SetChecksTrusted(name, trusted); err != nil {
+		m.err = err
+		return nil
+	}
+	if !trusted {
+		return m.setStatus(fmt.Sprintf(i18n.T("check.untrusted"), name))
+	}
+	// Check it straight away rather than at the next pass
+	m.checksRan = time.Time{}
+	return tea.Batch(m.setStatus(fmt.Sprintf(i18n.T("check.trusted"), name)), m.runChecks())
+}
+
+// nextCheck checks the first worktree in the queue in the background
+func (m *model) nextCheck(queue []git.Worktree) tea.Cmd {
+	if len(queue) == 0 {
+		m.checking = false
+		return nil
+	}
+	wt := queue[0]
+	command := m.config.CheckCommand
+	return func() tea.Msg {
+		// Worktrees that can't be checked (e.g. a missing directory) just don't get a badge
+		result, _ := git.RunCheck(command, wt.Path)
+		return checkMsg{name: wt.Name(), result: result, queue: queue[1:]}
+	}
+}
+
+// applyCheck records a check result, updates the badge and moves on to the next worktree
+func (m *model) applyCheck(msg checkMsg) tea.Cmd {
+	if msg.result == nil {
+		delete(m.checks, msg.name)
+	} else {
+		m.checks[msg.name] = msg.result
+	}
+
+	items := m.list.Items()
+	updated := make([]list.Item, len(items))
+	for i, it := range items {
+		if item, ok := it.(worktreeItem); ok && item.isCheckedOut && item.worktree.Name() == msg.name {
+			item.check = msg.result
+			it = item
+		}
+		updated[i] = it
+	}
+	m.list.SetItems(updated)
+
+	return m.nextCheck(msg.queue)
+}
+
+// checkBadge renders a worktree's last check result
+func checkBadge(result *git.CheckResult) string {
+	if result.Passed {
+		return statusStyle.Render("[pass]")
+	}
+	return errorStyle.Render("[fail]")
+}
+
+// statusBadge renders a worktree's git status: ● for uncommitted changes, ⇡/⇣ for commits not pushed to
+// or pulled from its upstream, ↑/↓ for commits ahead of and behind the default branch, and panes whose
+// command crashed
+func statusBadge(status git.WorktreeStatus) string {
+	var parts []string
+	if status.Dirty {
+		parts = append(parts, errorStyle.Render("●"))
+	}
+	if status.Unpushed > 0 {
+		parts = append(parts, highlightStyle.Render(fmt.Sprintf("⇡%d", status.Unpushed)))
+	}
+	if status.Unpulled > 0 {
+		parts = append(parts, highlightStyle.Render(fmt.Sprintf("⇣%d", status.Unpulled)))
+	}
+	if status.Ahead > 0 || status.Behind > 0 {
+		parts = append(parts, fmt.Sprintf("↑%d ↓%d", status.Ahead, status.Behind))
+	}
+	if status.Crashed > 0 {
+		parts = append(parts, errorStyle.Render(fmt.Sprintf("crashed:%d", status.Crashed)))
+	}
+	return strings.Join(parts, " ")
+}
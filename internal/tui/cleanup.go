@@ -0,0 +1,78 @@
+package tui
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/worktree"
+)
+
+// cleanupCandidate is a throwaway worktree lfg offers to delete, with why
+type cleanupCandidate struct {
+	worktree git.Worktree
+	reason   string
+}
+
+// offerCleanup adds worktrees to the cleanup prompt, skipping ones it already lists
+func (m *model) offerCleanup(candidates []cleanupCandidate) {
+	for _, c := range candidates {
+		listed := false
+		for _, existing := range m.cleanup {
+			if existing.worktree.Name() == c.worktree.Name() {
+				listed = true
+				break
+			}
+		}
+		if !listed {
+			m.cleanup = append(m.cleanup, c)
+		}
+	}
+}
+
+// updateCleanup handles keys in the cleanup prompt
+func (m *model) updateCleanup(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "y", "Y":
+		candidates := m.cleanup
+		m.cleanup = nil
+
+		var errs []error
+		deleted := 0
+		for _, c := range candidates {
+			// Dirty worktrees are refused, or go to the trash with it on
+			if _, err := worktree.Delete(m.config, c.worktree, worktree.DeleteOptions{DeleteBranch: true}); err != nil {
+				errs = append(errs, fmt.Errorf("%s: %w", c.worktree.DisplayName(), err))
+				if !errors.Is(err, worktree.ErrNotSaved) {
+					continue
+				}
+			}
+			deleted++
+		}
+		m.err = errors.Join(errs...)
+		return m, tea.Batch(m.setStatus(i18n.N("cleanup.done", deleted)), m.refreshWorktrees)
+	case "n", "N", "esc":
+		m.cleanup = nil
+	}
+	return m, nil
+}
+
+func (m *model) viewCleanup() string {
+	var rows strings.Builder
+	for _, c := range m.cleanup {
+		rows.WriteString(fmt.Sprintf("  %-30s %s\n", c.worktree.DisplayName(), c.reason))
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n%s\n\n%s\n",
+		titleStyle.Render(i18n.T("cleanup.title")),
+		i18n.T("cleanup.prompt"),
+		rows.String(),
+		i18n.T("cleanup.confirm"),
+		helpStyle.Render(i18n.T("cleanup.help")),
+	)
+}
@@ -0,0 +1,91 @@
+package tui
+
+import (
+	"testing"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+func TestCreateNameEditing(t *testing.T) {
+	m := &model{config: &config.Config{Name: "myapp"}, creating: true, textInput: newTextInput(""), nameInput: newTextInput("")}
+	m.textInput.Focus()
+	typeText := func(text string) {
+		m.Update(tea.KeyMsg{Type: tea.KeyRunes, Runes: []rune(text)})
+	}
+	press := func(key tea.KeyType) {
+		m.Update(tea.KeyMsg{Type: key})
+	}
+
+	typeText("fix login")
+	if got := m.createName(); got != "myapp-fix-login" {
+		t.Fatalf("createName() = %q, want the generated name", got)
+	}
+
+	// Moving into the name field doesn't stop it following the description
+	press(tea.KeyTab)
+	press(tea.KeyLeft)
+	press(tea.KeyTab)
+	typeText(" page")
+	if got := m.createName(); got != "myapp-fix-login-page" {
+		t.Fatalf("createName() = %q, want it to follow the description", got)
+	}
+
+	press(tea.KeyTab)
+	for range "page" {
+		press(tea.KeyBackspace)
+	}
+	typeText("auth")
+	press(tea.KeyTab)
+	typeText(" again")
+	if got := m.createName(); got != "myapp-fix-login-auth" {
+		t.Errorf("createName() = %q, want the edited name", got)
+	}
+
+	// Clearing the name generates it again
+	press(tea.KeyTab)
+	press(tea.KeyCtrlU)
+	if got := m.createName(); got != "myapp-fix-login-page-again" {
+		t.Errorf("createName() = %q, want the generated name after clearing", got)
+	}
+}
+
+func TestCreateDuplicate(t *testing.T) {
+	cfg := &config.Config{Name: "myapp", Todos: []config.Todo{
+		{Description: "Add search"},
+		{Description: "Fix flaky login spec"},
+	}}
+	m := &model{
+		config:    cfg,
+		list:      list.New(unlinkedTodoItems(cfg), newDelegate("default"), 80, 20),
+		creating:  true,
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+	m.textInput.Focus()
+	m.Update(tea.KeyMsg{Type: tea.KeyRunes, Runes: []rune("fix flakey login spec")})
+
+	m.Update(keyMsg("enter"))
+	if m.duplicate != "Fix flaky login spec" || !m.creating {
+		t.Fatalf("duplicate = %q, want the prompt for the open todo", m.duplicate)
+	}
+	m.Update(keyMsg("esc"))
+	if m.duplicate != "" || !m.creating {
+		t.Fatalf("esc should go back to the dialog, duplicate = %q", m.duplicate)
+	}
+
+	// Jumping to a todo without a worktree selects it in the list
+	m.Update(keyMsg("enter"))
+	m.Update(keyMsg("j"))
+	if m.creating || m.duplicate != "" {
+		t.Fatal("jumping should close the dialog")
+	}
+	if item := m.list.SelectedItem().(worktreeItem); item.todo.Description != "Fix flaky login spec" {
+		t.Errorf("selected %q, want the existing todo", item.todo.Description)
+	}
+	if len(cfg.Todos) != 2 {
+		t.Errorf("todos = %d, want no new todo", len(cfg.Todos))
+	}
+}
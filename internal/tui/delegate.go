@@ -0,0 +1,63 @@
+package tui
+
+import (
+	"fmt"
+	"io"
+	"strings"
+
+	"github.com/charmbracelet/bubbles/list"
+)
+
+// maxCachedRows bounds the rendered row cache, which is cleared when it fills up
+const maxCachedRows = 4096
+
+// rowKey is everything a rendered row depends on
+type rowKey struct {
+	title       string
+	desc        string
+	selected    bool
+	width       int
+	filter      list.FilterState
+	filterEmpty bool
+	matches     string // Matched rune positions while filtering
+}
+
+// cachedDelegate renders rows like the default delegate, reusing the styled output of rows that
+// haven't changed since they were last drawn. The list only renders the visible page, so with
+// thousands of items this saves restyling the same few rows on every keypress
+type cachedDelegate struct {
+	list.DefaultDelegate
+	rows map[rowKey]string
+}
+
+func (d *cachedDelegate) Render(w io.Writer, m list.Model, index int, item list.Item) {
+	it, ok := item.(list.DefaultItem)
+	if !ok {
+		d.DefaultDelegate.Render(w, m, index, item)
+		return
+	}
+
+	key := rowKey{
+		title:       it.Title(),
+		desc:        it.Description(),
+		selected:    index == m.Index(),
+		width:       m.Width(),
+		filter:      m.FilterState(),
+		filterEmpty: m.FilterValue() == "",
+	}
+	if key.filter != list.Unfiltered && index < len(m.VisibleItems()) {
+		key.matches = fmt.Sprint(m.MatchesForItem(index))
+	}
+
+	row, ok := d.rows[key]
+	if !ok {
+		var b strings.Builder
+		d.DefaultDelegate.Render(&b, m, index, item)
+		row = b.String()
+		if len(d.rows) >= maxCachedRows {
+			d.rows = make(map[rowKey]string)
+		}
+		d.rows[key] = row
+	}
+	_, _ = io.WriteString(w, row)
+}
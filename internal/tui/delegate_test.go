@@ -0,0 +1,36 @@
+package tui
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/charmbracelet/bubbles/list"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+func TestCachedDelegate(t *testing.T) {
+	cfg := &config.Config{Name: "myapp"}
+	items := benchItems(cfg, 3)
+	cached := newDelegate("default")
+	l := list.New(items, cached, 80, 20)
+
+	render := func(d list.ItemDelegate, index int) string {
+		var b strings.Builder
+		d.Render(&b, l, index, items[index])
+		return b.String()
+	}
+
+	for i := range items {
+		want := render(cached.DefaultDelegate, i)
+		if got := render(cached, i); got != want {
+			t.Errorf("row %d = %q, want %q", i, got, want)
+		}
+		if got := render(cached, i); got != want {
+			t.Errorf("cached row %d = %q, want %q", i, got, want)
+		}
+	}
+	if len(cached.rows) != len(items) {
+		t.Errorf("cached %d rows, want %d", len(cached.rows), len(items))
+	}
+}
@@ -0,0 +1,47 @@
+package tui
+
+import (
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// updateDuplicate handles keys at the create dialog's prompt about an open todo like the new one
+func (m *model) updateDuplicate(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "j", "J", "enter":
+		existing := m.duplicate
+		m.duplicate = ""
+		m.creating = false
+		m.createFrom = nil
+		m.createNotes = ""
+		m.textInput.SetValue("")
+		m.resetName()
+		return m, m.jumpToTodo(existing)
+	case "c", "C":
+		m.duplicate = ""
+		m.keepDuplicate = true
+		return m.handleCreateWorktree()
+	case "esc":
+		m.duplicate = ""
+	}
+	return m, nil
+}
+
+// jumpToTodo opens the session of a todo's worktree, or selects the todo in the list when it has none
+func (m *model) jumpToTodo(description string) tea.Cmd {
+	m.list.ResetFilter()
+	for i, listItem := range m.list.Items() {
+		item, ok := listItem.(worktreeItem)
+		if !ok || item.todo == nil || item.todo.Description != description {
+			continue
+		}
+		if item.isCheckedOut {
+			m.selectedWorktree = item.worktree.Name()
+			return tea.Quit
+		}
+		m.list.Select(i)
+		return m.setStatus(i18n.T("create.selected", description))
+	}
+	return m.setStatus(i18n.T("create.not_listed", description))
+}
@@ -0,0 +1,135 @@
+package tui
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/ide"
+	"github.com/markcipolla/lfg/internal/worktree"
+)
+
+// editTarget is what the text written in the editor is for
+type editTarget int
+
+const (
+	editNotes       editTarget = iota // A todo's notes
+	editDescription                   // The create dialog's description, with notes after the first line
+	editConfig                        // lfg-config.yaml, reloaded afterwards
+)
+
+// editedMsg carries the text written in the editor once it has been closed
+type editedMsg struct {
+	target editTarget
+	todo   *config.Todo // The todo whose notes were edited
+	text   string
+	err    error
+}
+
+type committedMsg struct {
+	worktree string
+	err      error
+}
+
+type openedMsg struct {
+	worktree string
+	err      error
+}
+
+// editText suspends the TUI to edit text in the editor, restoring it once the editor is closed
+func editText(target editTarget, todo *config.Todo, text string) tea.Cmd {
+	file, err := os.CreateTemp("", "lfg-*.md")
+	if err == nil {
+		_, err = file.WriteString(text)
+		file.Close()
+	}
+	if err != nil {
+		return func() tea.Msg {
+			return editedMsg{target: target, err: fmt.Errorf("failed to create a file to edit: %w", err)}
+		}
+	}
+
+	path := file.Name()
+	return tea.ExecProcess(ide.EditorCommand("", path), func(err error) tea.Msg {
+		defer os.Remove(path)
+		if err != nil {
+			return editedMsg{target: target, err: fmt.Errorf("editor failed: %w", err)}
+		}
+		data, err := os.ReadFile(path)
+		return editedMsg{target: target, todo: todo, text: string(data), err: err}
+	})
+}
+
+// editConfigFile suspends the TUI to edit lfg-config.yaml in the editor
+func (m *model) editConfigFile() tea.Cmd {
+	return tea.ExecProcess(ide.EditorCommand("", m.config.GetConfigPath()), func(err error) tea.Msg {
+		if err != nil {
+			err = fmt.Errorf("editor failed: %w", err)
+		}
+		return editedMsg{target: editConfig, err: err}
+	})
+}
+
+// commitIn suspends the TUI to run git commit in a worktree, so git opens the editor with its commit template
+func commitIn(wt git.Worktree) tea.Cmd {
+	return tea.ExecProcess(git.Command("-C", wt.Path, "commit"), func(err error) tea.Msg {
+		return committedMsg{worktree: wt.DisplayName(), err: err}
+	})
+}
+
+// openIn suspends the TUI while a terminal editor has a worktree open
+func openIn(cmd *exec.Cmd, wt git.Worktree) tea.Cmd {
+	return tea.ExecProcess(cmd, func(err error) tea.Msg {
+		return openedMsg{worktree: wt.DisplayName(), err: err}
+	})
+}
+
+// applyEdited uses the text written in the editor
+func (m *model) applyEdited(msg editedMsg) tea.Cmd {
+	if msg.err != nil {
+		m.err = msg.err
+		return nil
+	}
+
+	switch msg.target {
+	case editNotes:
+		msg.todo.Notes = strings.TrimSpace(msg.text)
+		if err := m.config.Save(); err != nil {
+			m.err = fmt.Errorf("failed to save config: %w", err)
+			return nil
+		}
+		return m.setStatus(i18n.T("notes.saved", msg.todo.Description))
+
+	case editDescription:
+		description, notes, _ := strings.Cut(strings.TrimSpace(msg.text), "\n")
+		m.textInput.SetValue(strings.TrimSpace(description))
+		m.textInput.CursorEnd()
+		m.createNotes = strings.TrimSpace(notes)
+
+	case editConfig:
+		cfg, err := config.LoadFromPath(m.config.GetConfigPath())
+		if err != nil {
+			m.err = fmt.Errorf("kept the previous config: %w", err)
+			return nil
+		}
+		m.config = cfg
+		worktree.Configure(cfg)
+		return tea.Batch(m.setStatus(i18n.T("config.reloaded", cfg.GetConfigPath())), m.refresh())
+	}
+	return nil
+}
+
+// selectedTodo returns the todo of the selected item, nil when it has none
+func (m *model) selectedTodo() *config.Todo {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok {
+		return nil
+	}
+	return item.todo
+}
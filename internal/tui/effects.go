@@ -0,0 +1,49 @@
+package tui
+
+import (
+	tea "github.com/charmbracelet/bubbletea"
+)
+
+// Update only changes the model: anything that runs git, tmux or gh, or writes the config, is returned
+// from it as a tea.Cmd, an effect, which runs off the event loop and reports back with a message
+
+// effectMsg is the outcome of an effect
+type effectMsg struct {
+	status  string // Shown on success
+	err     error
+	refresh bool // The effect changed worktrees or the config, so the list is reloaded and keys are let through again
+}
+
+// effect runs fn in the background and shows its outcome
+func effect(fn func() (string, error)) tea.Cmd {
+	return func() tea.Msg {
+		status, err := fn()
+		return effectMsg{status: status, err: err}
+	}
+}
+
+// worktreeEffect runs fn, which changes worktrees or the config, in the background
+// Keys are held until it is done, as they'd act on the list and config it is changing
+func (m *model) worktreeEffect(fn func() (string, error)) tea.Cmd {
+	m.busy = true
+	return func() tea.Msg {
+		status, err := fn()
+		return effectMsg{status: status, err: err, refresh: true}
+	}
+}
+
+// applyEffect shows the outcome of an effect
+func (m *model) applyEffect(msg effectMsg) tea.Cmd {
+	if msg.err != nil {
+		m.err = msg.err
+	}
+	var cmds []tea.Cmd
+	if msg.status != "" {
+		cmds = append(cmds, m.setStatus(msg.status))
+	}
+	if msg.refresh {
+		m.busy = false
+		cmds = append(cmds, m.refreshWorktrees)
+	}
+	return tea.Batch(cmds...)
+}
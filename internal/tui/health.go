@@ -0,0 +1,114 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+)
+
+type healthMsg struct {
+	health   map[string][]git.HealthIssue
+	statuses map[string]git.WorktreeStatus
+}
+
+// checkHealth runs the worktree health checks in the background, reading the git status of healthy
+// worktrees through the shared status cache
+// Cached statuses are kept no longer than the auto-refresh interval, so each refresh shows current changes
+func (m *model) checkHealth() tea.Cmd {
+	worktrees := m.worktrees
+	cfg := m.config
+	cache := git.NewStatusCache(cacheKey(cfg))
+	if interval := m.refreshInterval(); interval > 0 && interval < cache.TTL {
+		cache.TTL = interval
+	}
+	return func() tea.Msg {
+		health := make(map[string][]git.HealthIssue)
+		var healthy []git.Worktree
+		for _, wt := range worktrees {
+			if issues := git.CheckHealth(wt); len(issues) > 0 {
+				health[wt.Name()] = issues
+				continue
+			}
+			healthy = append(healthy, wt)
+		}
+
+		statuses := cache.Statuses(healthy, func(wt git.Worktree) string {
+			return multiplexer.Current().SessionName(cfg.Name, wt.Name())
+		})
+		return healthMsg{health: health, statuses: statuses}
+	}
+}
+
+// applyHealth attaches the latest health check results to the list items
+func (m *model) applyHealth() {
+	items := m.list.Items()
+	updated := make([]list.Item, len(items))
+	for i, it := range items {
+		if item, ok := it.(worktreeItem); ok && item.isCheckedOut {
+			item.issues = m.health[item.worktree.Name()]
+			item.status = m.statuses[item.worktree.Name()]
+			it = item
+		}
+		updated[i] = it
+	}
+	m.list.SetItems(updated)
+}
+
+// updateHealth handles keys in the health details popup
+func (m *model) updateHealth(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok {
+		m.showingHealth = false
+		return m, nil
+	}
+
+	key := msg.String()
+	switch key {
+	case "esc", "q", "!":
+		m.showingHealth = false
+		return m, nil
+	case "1", "2", "3", "4", "5", "6", "7", "8", "9":
+		i := int(key[0] - '1')
+		if i >= len(item.issues) {
+			return m, nil
+		}
+		m.showingHealth = false
+		if err := git.Remediate(item.worktree, item.issues[i]); err != nil {
+			m.err = err
+			return m, nil
+		}
+		return m, tea.Batch(m.setStatus(i18n.T("health.ran", item.issues[i].Remedy)), m.refreshWorktrees)
+	}
+	return m, nil
+}
+
+func (m *model) viewHealth() string {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok {
+		return ""
+	}
+
+	var details strings.Builder
+	for i, issue := range item.issues {
+		details.WriteString(errorStyle.Render(fmt.Sprintf("%d. %s", i+1, issue.Summary)))
+		details.WriteString("\n   " + issue.Fix + "\n")
+		if issue.Remedy != "" {
+			details.WriteString(helpStyle.Render(i18n.T("health.remedy", i+1, issue.Remedy)))
+			details.WriteString("\n")
+		}
+		details.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s%s\n",
+		titleStyle.Render(i18n.T("health.title", item.worktree.DisplayName())),
+		details.String(),
+		helpStyle.Render(i18n.T("health.help")),
+	)
+}
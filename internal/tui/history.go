@@ -0,0 +1,81 @@
+package tui
+
+import (
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// loadHistory returns the worktrees opened from lfg in order, for the breadcrumbs and [ and ]
+func loadHistory(cfg *config.Config) git.AttachHistory {
+	if cfg.GetConfigPath() == "" {
+		return git.AttachHistory{}
+	}
+	return git.NewHistoryStore(cacheKey(cfg)).Load()
+}
+
+// stepHistory opens the worktree before (delta -1) or after (1) the current one in the attach history,
+// like a browser's back and forward
+func (m *model) stepHistory(delta int) tea.Cmd {
+	if m.config.GetConfigPath() == "" {
+		return nil
+	}
+	exists := func(name string) bool {
+		for _, wt := range m.worktrees {
+			if wt.Name() == name {
+				return true
+			}
+		}
+		return false
+	}
+	name, err := git.NewHistoryStore(cacheKey(m.config)).Step(delta, exists)
+	if err != nil {
+		m.err = err
+		return nil
+	}
+	if name == "" {
+		if delta < 0 {
+			return m.setStatus(i18n.T("history.start"))
+		}
+		return m.setStatus(i18n.T("history.end"))
+	}
+	m.selectedWorktree = name
+	return tea.Quit
+}
+
+// breadcrumbs shows the attach history around the current entry, empty until two worktrees were opened
+func (m *model) breadcrumbs() string {
+	history := m.history
+	if len(history.Entries) < 2 {
+		return ""
+	}
+	displayNames := make(map[string]string, len(m.worktrees))
+	for _, wt := range m.worktrees {
+		displayNames[wt.Name()] = wt.DisplayName()
+	}
+
+	start, end := max(history.Position-3, 0), min(history.Position+2, len(history.Entries))
+	var crumbs []string
+	if start > 0 {
+		crumbs = append(crumbs, helpStyle.Render("…"))
+	}
+	for i := start; i < end; i++ {
+		name := history.Entries[i]
+		if display, ok := displayNames[name]; ok {
+			name = display
+		}
+		if i == history.Position {
+			crumbs = append(crumbs, highlightStyle.Bold(true).Render(name))
+		} else {
+			crumbs = append(crumbs, helpStyle.Render(name))
+		}
+	}
+	if end < len(history.Entries) {
+		crumbs = append(crumbs, helpStyle.Render("…"))
+	}
+	return helpStyle.Render("[ ") + strings.Join(crumbs, helpStyle.Render(" › ")) + helpStyle.Render(" ]")
+}
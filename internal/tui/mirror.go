@@ -0,0 +1,52 @@
+package tui
+
+import (
+	"fmt"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/mirror"
+)
+
+// mirrorRetryDelay is how long mirroring waits after GitHub couldn't be reached before trying again
+const mirrorRetryDelay = time.Minute
+
+type mirroredMsg struct {
+	changes []mirror.Change
+	err     error
+}
+
+// mirrorTodos copies changed todos to the GitHub Project in the background with the hybrid backend,
+// so the list never waits on GitHub. Only one mirror runs at a time
+func (m *model) mirrorTodos() tea.Cmd {
+	backend := m.config.GitHubMirror()
+	if backend == nil || m.mirroring || time.Now().Before(m.mirrorRetry) || !mirror.Pending(m.config) {
+		return nil
+	}
+	m.mirroring = true
+	todos := append([]config.Todo(nil), m.config.Todos...)
+	removed := append([]string(nil), m.config.GitHubRemoved...)
+	return func() tea.Msg {
+		changes, err := mirror.Sync(backend, todos, removed)
+		return mirroredMsg{changes: changes, err: err}
+	}
+}
+
+// handleMirrored records what was mirrored, and runs again for todos that changed in the meantime
+func (m *model) handleMirrored(msg mirroredMsg) tea.Cmd {
+	m.mirroring = false
+	if mirror.Apply(m.config, msg.changes) {
+		if err := m.config.Save(); err != nil {
+			m.err = fmt.Errorf("failed to save config: %w", err)
+		}
+	}
+	if msg.err != nil {
+		// Mirroring is best effort, the todos are safe locally and go up on a later try
+		m.mirrorRetry = time.Now().Add(mirrorRetryDelay)
+		return m.setStatus(i18n.T("mirror.failed", msg.err))
+	}
+	return m.mirrorTodos()
+}
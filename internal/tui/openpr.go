@@ -0,0 +1,110 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/terminal"
+)
+
+type prOpenedMsg struct {
+	name     string
+	url      string
+	existing bool // The branch already had a pull request, so none was created
+	err      error
+}
+
+// startOpenPR asks for the title of a pull request for the item's branch, defaulting to its todo
+func (m *model) startOpenPR(item worktreeItem) tea.Cmd {
+	if !item.isCheckedOut || item.worktree.BranchName() == "" {
+		return nil
+	}
+	if item.worktree.Name() == mainWorktreeName(m.worktrees) {
+		m.err = fmt.Errorf("the main worktree's branch can't have a pull request opened from lfg")
+		return nil
+	}
+
+	title := item.worktree.BranchName()
+	if item.todo != nil && item.todo.Description != "" {
+		title = item.todo.Description
+	}
+	m.openingPR = &item
+	m.textInput.SetValue(title)
+	m.textInput.CursorEnd()
+	return m.textInput.Focus()
+}
+
+// updateOpenPR handles keys in the pull request title prompt
+func (m *model) updateOpenPR(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "enter":
+		item := *m.openingPR
+		title := strings.TrimSpace(m.textInput.Value())
+		m.openingPR = nil
+		m.textInput.SetValue("")
+		if title == "" {
+			m.err = fmt.Errorf("pull request title cannot be empty")
+			return m, nil
+		}
+		body := ""
+		if item.todo != nil {
+			body = item.todo.Notes
+		}
+		status := i18n.T("openpr.pushing", item.worktree.BranchName())
+		return m, tea.Batch(m.setStatus(status), openPullRequest(item.worktree, title, body))
+	case "esc":
+		m.openingPR = nil
+		m.textInput.SetValue("")
+		return m, nil
+	}
+	var cmd tea.Cmd
+	m.textInput, cmd = m.textInput.Update(msg)
+	return m, cmd
+}
+
+// openPullRequest pushes a worktree's branch and opens a pull request for it in the background,
+// unless the branch already has one
+func openPullRequest(wt git.Worktree, title, body string) tea.Cmd {
+	return func() tea.Msg {
+		branch := wt.BranchName()
+		if url, err := github.GetPullRequestURL(branch); err == nil && url != "" {
+			return prOpenedMsg{name: wt.Name(), url: url, existing: true}
+		}
+		if err := git.PushBranch(branch); err != nil {
+			return prOpenedMsg{name: wt.Name(), err: err}
+		}
+		url, err := github.CreatePullRequest(wt.Path, branch, title, body)
+		return prOpenedMsg{name: wt.Name(), url: url, err: err}
+	}
+}
+
+// applyOpenedPR shows the pull request's URL until the next status, and looks the branch up again so
+// the list shows its pull request
+func (m *model) applyOpenedPR(msg prOpenedMsg) tea.Cmd {
+	if msg.err != nil {
+		m.err = msg.err
+		return nil
+	}
+	link := terminal.Hyperlink(msg.url, msg.url)
+	if msg.existing {
+		m.keepStatus(i18n.T("openpr.existing", msg.name, link))
+		return nil
+	}
+	m.keepStatus(i18n.T("openpr.opened", msg.name, link))
+	return m.syncReviews()
+}
+
+func (m *model) viewOpenPR() string {
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n\n%s\n",
+		titleStyle.Render(i18n.T("openpr.title")),
+		i18n.T("openpr.prompt", m.openingPR.worktree.BranchName()),
+		m.textInput.View(),
+		helpStyle.Render(i18n.T("openpr.help")),
+	)
+}
@@ -0,0 +1,139 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/tmux"
+)
+
+// previewLines is how much of a pane's output the preview shows, at most
+const previewLines = 30
+
+// previewMsg carries the latest output of the previewed pane
+type previewMsg struct {
+	id     int
+	title  string
+	output string
+	err    error
+}
+
+// previewTickMsg asks for the preview to be captured again
+type previewTickMsg struct {
+	id int
+}
+
+// previewHeight is the number of output lines the preview has room for
+func (m *model) previewHeight() int {
+	return min(previewLines, max(m.height/2-2, 3))
+}
+
+// resizeList fits the list to the window, leaving room for the preview when it's open
+func (m *model) resizeList() {
+	// Account for header (2 lines) + potential error line (1 line)
+	height := m.height - 3
+	if m.preview {
+		height -= m.previewHeight() + 2
+	}
+	// The last column is kept for the scrollbar
+	m.list.SetSize(max(m.width-1, 1), max(height, 3))
+}
+
+// togglePreview shows or hides the live output of the selected worktree's session
+func (m *model) togglePreview() tea.Cmd {
+	if err := multiplexer.RequireTmux("The session preview"); err != nil && !m.preview {
+		m.err = err
+		return nil
+	}
+	m.preview = !m.preview
+	m.previewID++
+	m.previewOutput = ""
+	m.previewErr = nil
+	m.resizeList()
+	if !m.preview {
+		return nil
+	}
+	return m.capturePreview()
+}
+
+// cyclePreviewPane moves the preview on to the session's next pane
+func (m *model) cyclePreviewPane() tea.Cmd {
+	m.previewPane++
+	m.previewID++
+	return m.capturePreview()
+}
+
+// capturePreview reads the previewed pane of the selected worktree's session in the background
+func (m *model) capturePreview() tea.Cmd {
+	id := m.previewID
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return func() tea.Msg {
+			return previewMsg{id: id, err: fmt.Errorf("no worktree selected")}
+		}
+	}
+	sessionName := multiplexer.Current().SessionName(m.config.Name, item.worktree.Name())
+	paneIndex := m.previewPane
+	lines := m.previewHeight()
+
+	return func() tea.Msg {
+		if !tmux.SessionExists(sessionName) {
+			return previewMsg{id: id, title: sessionName, err: fmt.Errorf("no session running")}
+		}
+
+		// Sessions from before panes were tagged only have their active pane to show
+		pane := ""
+		if names, err := tmux.PaneNames(sessionName); err == nil && len(names) > 0 {
+			pane = names[paneIndex%len(names)]
+		}
+		output, err := tmux.CapturePane(sessionName, pane, lines)
+
+		title := sessionName
+		if pane != "" {
+			title += " · " + pane
+		}
+		return previewMsg{id: id, title: title, output: output, err: err}
+	}
+}
+
+// applyPreview shows a capture and schedules the next one while the preview stays open
+func (m *model) applyPreview(msg previewMsg) tea.Cmd {
+	if !m.preview || msg.id != m.previewID {
+		return nil
+	}
+	m.previewTitle = msg.title
+	m.previewOutput = msg.output
+	m.previewErr = msg.err
+
+	id := m.previewID
+	return tea.Tick(time.Second, func(time.Time) tea.Msg {
+		return previewTickMsg{id: id}
+	})
+}
+
+// viewPreview renders the tail of the previewed pane below the list
+func (m *model) viewPreview() string {
+	header := highlightStyle.Bold(true).Render(i18n.T("preview.title", m.previewTitle)) + helpStyle.MarginTop(0).Render(i18n.T("preview.help"))
+
+	var body string
+	switch {
+	case m.previewErr != nil:
+		body = errorStyle.Render(m.previewErr.Error())
+	case m.previewOutput == "":
+		body = helpStyle.MarginTop(0).Render(i18n.T("preview.no_output"))
+	default:
+		lines := strings.Split(m.previewOutput, "\n")
+		for i, line := range lines {
+			if m.width > 0 && len([]rune(line)) > m.width {
+				lines[i] = string([]rune(line)[:m.width])
+			}
+		}
+		body = strings.Join(lines, "\n")
+	}
+	return header + "\n" + body
+}
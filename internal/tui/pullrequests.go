@@ -0,0 +1,120 @@
+package tui
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+type pullRequestsMsg struct {
+	prs []github.PullRequest
+	err error
+}
+
+// fetchPullRequests lists the open pull requests for the review picker
+func fetchPullRequests() tea.Msg {
+	prs, err := github.ListPullRequests()
+	return pullRequestsMsg{prs: prs, err: err}
+}
+
+// reviewCreatedMsg is the review worktree checked out for a pull request
+type reviewCreatedMsg struct {
+	name string
+	err  error
+}
+
+// createReview fetches a pull request into a review worktree in the background, as that waits on the remote
+func (m *model) createReview(number int) tea.Cmd {
+	m.busy = true
+	return func() tea.Msg {
+		name, err := git.CreateReviewWorktree(number)
+		return reviewCreatedMsg{name: name, err: err}
+	}
+}
+
+// applyReviewCreated jumps to a review worktree once it's checked out
+func (m *model) applyReviewCreated(msg reviewCreatedMsg) tea.Cmd {
+	m.busy = false
+	if msg.err != nil {
+		m.err = msg.err
+		return m.refreshWorktrees
+	}
+	return m.jumpAfterSetup(msg.name)
+}
+
+// updatePullRequests handles keys in the review picker
+func (m *model) updatePullRequests(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "esc", "q", "v":
+		m.pullRequests = nil
+	case "up", "k":
+		m.prCursor = (m.prCursor + len(m.pullRequests) - 1) % len(m.pullRequests)
+	case "down", "j":
+		m.prCursor = (m.prCursor + 1) % len(m.pullRequests)
+	case "enter":
+		pr := m.pullRequests[m.prCursor]
+		m.pullRequests = nil
+		return m, m.createReview(pr.Number)
+	}
+	return m, nil
+}
+
+func (m *model) viewPullRequests() string {
+	var rows strings.Builder
+	for i, pr := range m.pullRequests {
+		line := fmt.Sprintf("#%-5d %s (%s, %s)", pr.Number, pr.Title, pr.HeadRefName, pr.Author.Login)
+		if i == m.prCursor {
+			rows.WriteString(highlightStyle.Bold(true).Render("> " + line))
+		} else {
+			rows.WriteString("  " + line)
+		}
+		rows.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("reviews.title")),
+		rows.String(),
+		helpStyle.Render(i18n.T("reviews.help")),
+	)
+}
+
+type closedReviewsMsg struct {
+	candidates []cleanupCandidate
+}
+
+// checkClosedReviews finds review worktrees whose pull request has been merged or closed
+func (m *model) checkClosedReviews() tea.Cmd {
+	var reviews []git.Worktree
+	for _, wt := range m.worktrees {
+		if _, ok := git.ReviewNumber(wt.Name()); ok {
+			reviews = append(reviews, wt)
+		}
+	}
+	if len(reviews) == 0 {
+		return nil
+	}
+
+	return func() tea.Msg {
+		current, _ := git.GetCurrentWorktree()
+		var closed []cleanupCandidate
+		for _, wt := range reviews {
+			number, _ := git.ReviewNumber(wt.Name())
+			state, err := github.GetPullRequestState(strconv.Itoa(number))
+			if err != nil || state == "OPEN" || wt.Name() == current {
+				continue
+			}
+			closed = append(closed, cleanupCandidate{
+				worktree: wt,
+				reason:   fmt.Sprintf("pull request #%d %s", number, strings.ToLower(state)),
+			})
+		}
+		return closedReviewsMsg{candidates: closed}
+	}
+}
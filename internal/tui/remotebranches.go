@@ -0,0 +1,120 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+	"unicode/utf8"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+type remoteBranchesMsg struct {
+	branches []git.RemoteBranchInfo
+	err      error
+}
+
+// fetchRemoteBranches lists teammates' remote branches for the remote branch picker
+func fetchRemoteBranches() tea.Msg {
+	branches, err := git.ListRemoteBranches()
+	return remoteBranchesMsg{branches: branches, err: err}
+}
+
+// filteredRemoteBranches returns the remote branches matching the picker's filter
+func (m *model) filteredRemoteBranches() []git.RemoteBranchInfo {
+	if m.remoteFilter == "" {
+		return m.remoteBranches
+	}
+	filter := strings.ToLower(m.remoteFilter)
+	var matches []git.RemoteBranchInfo
+	for _, branch := range m.remoteBranches {
+		text := strings.ToLower(branch.Name + " " + branch.Author + " " + branch.Subject)
+		if strings.Contains(text, filter) {
+			matches = append(matches, branch)
+		}
+	}
+	return matches
+}
+
+// updateRemoteBranches handles keys in the remote branch picker, where typing filters the list
+func (m *model) updateRemoteBranches(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	matches := m.filteredRemoteBranches()
+	switch msg.Type {
+	case tea.KeyEsc:
+		if m.remoteFilter != "" {
+			m.remoteFilter = ""
+			m.remoteCursor = 0
+			return m, nil
+		}
+		m.remoteBranches = nil
+	case tea.KeyUp:
+		if len(matches) > 0 {
+			m.remoteCursor = (m.remoteCursor + len(matches) - 1) % len(matches)
+		}
+	case tea.KeyDown:
+		if len(matches) > 0 {
+			m.remoteCursor = (m.remoteCursor + 1) % len(matches)
+		}
+	case tea.KeyBackspace:
+		if m.remoteFilter != "" {
+			_, size := utf8.DecodeLastRuneInString(m.remoteFilter)
+			m.remoteFilter = m.remoteFilter[:len(m.remoteFilter)-size]
+			m.remoteCursor = 0
+		}
+	case tea.KeyRunes, tea.KeySpace:
+		m.remoteFilter += string(msg.Runes)
+		m.remoteCursor = 0
+	case tea.KeyEnter:
+		if len(matches) == 0 {
+			return m, nil
+		}
+		return m.handleTrackRemoteBranch(matches[m.remoteCursor])
+	}
+	return m, nil
+}
+
+// handleTrackRemoteBranch checks a remote branch out into a new worktree with a todo, then sets it up and jumps to it
+func (m *model) handleTrackRemoteBranch(branch git.RemoteBranchInfo) (tea.Model, tea.Cmd) {
+	m.remoteBranches = nil
+	worktreeName := strings.ReplaceAll(branch.LocalName(), "/", "-")
+	if err := git.CreateWorktreeFromBranch(worktreeName, branch.Branch); err != nil {
+		m.err = err
+		return m, nil
+	}
+
+	m.config.AddTodo(branch.LocalName(), worktreeName)
+	if err := m.config.Save(); err != nil {
+		m.err = fmt.Errorf("failed to save config: %w", err)
+	}
+
+	return m, m.jumpAfterSetup(worktreeName)
+}
+
+func (m *model) viewRemoteBranches() string {
+	matches := m.filteredRemoteBranches()
+
+	var rows strings.Builder
+	if len(matches) == 0 {
+		rows.WriteString(helpStyle.MarginTop(0).Render(i18n.T("remote.no_matches")) + "\n")
+	}
+	for i, branch := range matches {
+		line := fmt.Sprintf("%-40s %-18s %-15s %s", branch.Name, branch.Author, branch.Date, branch.Subject)
+		if i == m.remoteCursor {
+			rows.WriteString(highlightStyle.Bold(true).Render("> " + line))
+		} else {
+			rows.WriteString("  " + line)
+		}
+		rows.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("remote.title")),
+		i18n.T("remote.filter"),
+		m.remoteFilter,
+		rows.String(),
+		helpStyle.Render(i18n.T("remote.help")),
+	)
+}
@@ -0,0 +1,226 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// reviewMsg carries the pull request for each worktree's branch
+type reviewMsg struct {
+	pullRequests map[string]*github.PullRequestStatus
+	drift        map[string]baseDrift // How far each open pull request's base has moved on
+}
+
+// baseDrift is how many commits a pull request's base branch has that its worktree doesn't
+type baseDrift struct {
+	base   string
+	behind int
+}
+
+// reviewSyncInterval is how often the auto-refresh looks up pull requests again, as that's one gh call
+// (and GitHub API request) per worktree
+const reviewSyncInterval = 3 * time.Minute
+
+// syncReviewsEvery runs syncReviews unless it already ran within interval
+func (m *model) syncReviewsEvery(interval time.Duration) tea.Cmd {
+	if time.Since(m.reviewsSynced) < interval {
+		return nil
+	}
+	return m.syncReviews()
+}
+
+// syncReviews looks up the pull request for each worktree's branch in the background, with its review
+// and checks, and for open ones how far the branch they merge into has moved on
+// Branches without a pull request are left out, and nothing is looked up without gh
+func (m *model) syncReviews() tea.Cmd {
+	if !github.IsInstalled() {
+		return nil
+	}
+	m.reviewsSynced = time.Now()
+
+	mainName := mainWorktreeName(m.worktrees)
+	worktrees := make(map[string]git.Worktree)
+	for _, wt := range m.worktrees {
+		if wt.Branch == "" || wt.Name() == mainName {
+			continue
+		}
+		worktrees[wt.Name()] = wt
+	}
+	if len(worktrees) == 0 {
+		return nil
+	}
+
+	return func() tea.Msg {
+		pullRequests := make(map[string]*github.PullRequestStatus)
+		drift := make(map[string]baseDrift)
+		for name, wt := range worktrees {
+			pr, err := github.GetPullRequestStatus(wt.BranchName())
+			if err != nil {
+				continue
+			}
+			pullRequests[name] = pr
+			if pr.State != "OPEN" || pr.Branch == "" {
+				continue
+			}
+			if d, err := git.CompareToBase(wt.Path, pr.Branch); err == nil {
+				drift[name] = baseDrift{base: pr.Branch, behind: d.Behind}
+			}
+		}
+		return reviewMsg{pullRequests: pullRequests, drift: drift}
+	}
+}
+
+// applyReviews moves open todos in and out of review, saving and refreshing if any changed, and shows
+// each worktree's pull request, marking those whose base has moved on
+func (m *model) applyReviews(msg reviewMsg) tea.Cmd {
+	m.drift = msg.drift
+	m.prStatuses = msg.pullRequests
+
+	changed := false
+	for name, pr := range msg.pullRequests {
+		todo := m.config.GetTodoForWorktree(name)
+		if todo != nil && todo.Status != config.TodoStatusDone && todo.ApplyPullRequestState(pr.State) {
+			changed = true
+		}
+	}
+	if !changed {
+		m.applyDrift()
+		return nil
+	}
+
+	if err := m.config.Save(); err != nil {
+		m.err = fmt.Errorf("failed to save config: %w", err)
+		return nil
+	}
+	return m.refresh()
+}
+
+// pullRequestBadge renders a branch's pull request: its number, then for open ones whether it's a draft,
+// its review decision and how its checks went
+func pullRequestBadge(pr *github.PullRequestStatus) string {
+	switch pr.State {
+	case "MERGED":
+		return statusStyle.Render(i18n.T("pr.merged", pr.Number))
+	case "CLOSED":
+		return helpStyle.Render(i18n.T("pr.closed", pr.Number))
+	}
+
+	parts := []string{i18n.T("pr.number", pr.Number)}
+	if pr.IsDraft {
+		parts = append(parts, helpStyle.Render(i18n.T("pr.draft")))
+	}
+	switch pr.ReviewDecision {
+	case "APPROVED":
+		parts = append(parts, statusStyle.Render(i18n.T("pr.approved")))
+	case "CHANGES_REQUESTED":
+		parts = append(parts, errorStyle.Render(i18n.T("pr.changes")))
+	case "REVIEW_REQUIRED":
+		parts = append(parts, warningStyle.Render(i18n.T("pr.review")))
+	}
+	switch pr.CI() {
+	case github.CIPassing:
+		parts = append(parts, statusStyle.Render("✓ CI"))
+	case github.CIFailing:
+		parts = append(parts, errorStyle.Render("✗ CI"))
+	case github.CIPending:
+		parts = append(parts, warningStyle.Render("◌ CI"))
+	}
+	return strings.Join(parts, " ")
+}
+
+// rebaseOnto returns the pull request base a worktree should be rebased onto, once it has moved on
+// by git.rebase_after commits, or ""
+func (m *model) rebaseOnto(name string) string {
+	if drift, ok := m.drift[name]; ok && drift.behind >= m.config.RebaseAfter() {
+		return drift.base
+	}
+	return ""
+}
+
+// applyDrift shows each list item's pull request, marking those whose base has moved on
+func (m *model) applyDrift() {
+	items := m.list.Items()
+	updated := make([]list.Item, len(items))
+	for i, it := range items {
+		if item, ok := it.(worktreeItem); ok && item.isCheckedOut {
+			item.rebaseOnto = m.rebaseOnto(item.worktree.Name())
+			item.pullRequest = m.prStatuses[item.worktree.Name()]
+			it = item
+		}
+		updated[i] = it
+	}
+	m.list.SetItems(updated)
+}
+
+type rebasedMsg struct {
+	name string
+	base string
+	err  error
+}
+
+// rebaseOnBase rebases a worktree onto its pull request's base in the background, as it fetches first
+func rebaseOnBase(wt git.Worktree, base string, autostash bool) tea.Cmd {
+	return func() tea.Msg {
+		return rebasedMsg{name: wt.Name(), base: base, err: git.RebaseOnBase(wt.Path, base, autostash)}
+	}
+}
+
+// startRebase rebases the item's worktree onto its pull request's base, first asking whether to stash
+// uncommitted changes around it when there are any
+func (m *model) startRebase(item worktreeItem) tea.Cmd {
+	if item.status.Dirty && m.rebasing == nil {
+		m.rebasing = &item
+		m.rebaseAutostash = m.config.Autostash()
+		return nil
+	}
+	autostash := m.config.Autostash()
+	if m.rebasing != nil {
+		autostash = m.rebaseAutostash
+		m.rebasing = nil
+	}
+	status := i18n.T("rebase.running", item.worktree.DisplayName(), item.rebaseOnto)
+	return tea.Batch(m.setStatus(status), rebaseOnBase(item.worktree, item.rebaseOnto, autostash))
+}
+
+// updateRebase handles keys in the rebase confirmation for a worktree with uncommitted changes
+func (m *model) updateRebase(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "tab":
+		m.rebaseAutostash = !m.rebaseAutostash
+	case "enter", "y", "Y":
+		return m, m.startRebase(*m.rebasing)
+	case "esc", "n", "N":
+		m.rebasing = nil
+	}
+	return m, nil
+}
+
+func (m *model) viewRebase() string {
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n",
+		titleStyle.Render(i18n.T("rebase.title", m.rebasing.rebaseOnto)),
+		i18n.T("rebase.prompt", m.rebasing.worktree.DisplayName()),
+		helpStyle.Render(i18n.T("rebase.help", onOff(m.rebaseAutostash))),
+	)
+}
+
+// applyRebase reports how a rebase went and refreshes the worktree's status
+func (m *model) applyRebase(msg rebasedMsg) tea.Cmd {
+	git.NewStatusCache(cacheKey(m.config)).Invalidate(msg.name)
+	if msg.err != nil {
+		m.showGitError(msg.err)
+		return m.checkHealth()
+	}
+	delete(m.drift, msg.name)
+	m.applyDrift()
+	return tea.Batch(m.setStatus(i18n.T("rebase.done", msg.name, msg.base)), m.checkHealth())
+}
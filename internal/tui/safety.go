@@ -0,0 +1,201 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// deleteSafety is what deleting a worktree would throw away, shown in the delete confirmation
+type deleteSafety struct {
+	name    string
+	report  *git.SafetyReport
+	prState string // "OPEN", "MERGED" or "CLOSED", empty without a pull request
+	err     error
+}
+
+// deleteStep is a question the delete confirmation asks, one at a time after confirming the delete,
+// for each check that deleting the worktree would otherwise be refused by
+type deleteStep int
+
+const (
+	confirmDelete deleteStep = iota // Delete the worktree at all
+	confirmDirty                    // Throw away its uncommitted changes and untracked files
+	confirmLocked                   // Remove it although it's locked
+	confirmBranch                   // Delete its branch although it has unpushed commits
+)
+
+// safetyMsg carries the safety report once it has been computed
+type safetyMsg struct {
+	safety *deleteSafety
+}
+
+// checkSafety computes the safety report for a worktree in the background
+func checkSafety(wt git.Worktree) tea.Cmd {
+	return func() tea.Msg {
+		safety := &deleteSafety{name: wt.Name()}
+		safety.report, safety.err = git.CheckSafety(wt)
+		if branch := wt.BranchName(); branch != "" {
+			// Without gh, or without a pull request, the state is simply left out
+			safety.prState, _ = github.GetPullRequestState(branch)
+		}
+		return safetyMsg{safety: safety}
+	}
+}
+
+// viewSafety renders the safety report for the worktree being deleted
+func (m *model) viewSafety(name string) string {
+	safety := m.safety
+	if safety == nil || safety.name != name {
+		return helpStyle.MarginTop(0).Render(i18n.T("safety.checking"))
+	}
+	if safety.err != nil {
+		return errorStyle.Render(i18n.T("safety.failed", safety.err.Error()))
+	}
+
+	var warnings []string
+	report := safety.report
+	if report.Modified > 0 {
+		warnings = append(warnings, i18n.N("safety.modified", report.Modified))
+	}
+	if report.Untracked > 0 {
+		warnings = append(warnings, i18n.N("safety.untracked", report.Untracked))
+	}
+	if report.Unpushed > 0 {
+		if report.NoUpstream {
+			warnings = append(warnings, i18n.N("safety.orphaned", report.Unpushed))
+		} else {
+			warnings = append(warnings, i18n.N("safety.unpushed", report.Unpushed))
+		}
+	}
+	if report.Stashes > 0 {
+		warnings = append(warnings, i18n.N("safety.stashes", report.Stashes))
+	}
+	if safety.prState == "OPEN" {
+		warnings = append(warnings, i18n.T("safety.pr_open"))
+	}
+
+	var lines []string
+	for _, warning := range warnings {
+		lines = append(lines, errorStyle.Render("⚠ "+warning))
+	}
+	if len(warnings) == 0 {
+		lines = append(lines, statusStyle.Render("✓ " + i18n.T("safety.clean")))
+	}
+	if safety.prState != "" && safety.prState != "OPEN" {
+		lines = append(lines, statusStyle.Render("✓ " + i18n.T("safety.pr_closed", strings.ToLower(safety.prState))))
+	}
+	return strings.Join(lines, "\n")
+}
+
+// safetyLoaded reports whether the safety check of the selected worktree has come back, which deleting
+// a worktree waits for
+func (m *model) safetyLoaded() bool {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return true
+	}
+	return m.safety != nil && m.safety.name == item.worktree.Name()
+}
+
+// needsConfirm reports whether deleting the selected worktree has to ask step's question, going by its
+// safety report. When the check failed, git still refuses dirty and locked worktrees, but would delete
+// the branch whatever it holds, so that is asked about
+func (m *model) needsConfirm(step deleteStep) bool {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return false
+	}
+	if m.safety == nil || m.safety.name != item.worktree.Name() || m.safety.report == nil {
+		return step == confirmBranch
+	}
+	report := m.safety.report
+	switch step {
+	case confirmDirty:
+		// Dirty worktrees go to the trash with it on, so nothing is thrown away
+		return report.Dirty() && (m.config.Settings == nil || !m.config.Settings.Trash)
+	case confirmLocked:
+		return report.Locked
+	case confirmBranch:
+		return report.Unpushed > 0
+	}
+	return false
+}
+
+// confirmDeleteStep accepts the delete confirmation's question and asks the next one deleting needs,
+// deleting the worktree once there are none left
+func (m *model) confirmDeleteStep() (tea.Model, tea.Cmd) {
+	switch m.deleteStep {
+	case confirmDirty:
+		m.deleteOpts.Force = true
+	case confirmLocked:
+		m.deleteOpts.IgnoreLocked = true
+	}
+	for step := m.deleteStep + 1; step <= confirmBranch; step++ {
+		if m.needsConfirm(step) {
+			m.deleteStep = step
+			return m, nil
+		}
+	}
+	return m.handleDeleteWorktree()
+}
+
+// viewDeleteStep renders the delete confirmation's questions after the first
+func (m *model) viewDeleteStep(item worktreeItem) string {
+	report := m.safety.report
+	name := item.worktree.DisplayName()
+	help := i18n.T("confirm.help")
+
+	var title, prompt string
+	switch m.deleteStep {
+	case confirmDirty:
+		var changes []string
+		if report.Modified > 0 {
+			changes = append(changes, i18n.N("safety.modified", report.Modified))
+		}
+		if report.Untracked > 0 {
+			changes = append(changes, i18n.N("safety.untracked", report.Untracked))
+		}
+		title, prompt = i18n.T("delete.dirty.title"), i18n.T("delete.dirty.prompt", name, strings.Join(changes, ", "))
+	case confirmLocked:
+		title, prompt = i18n.T("delete.locked.title"), i18n.T("delete.locked.prompt", name)
+		if report.LockReason != "" {
+			prompt = i18n.T("delete.locked.reason", name, report.LockReason)
+		}
+	case confirmBranch:
+		commits := i18n.T("safety.unchecked")
+		if report != nil && report.NoUpstream {
+			commits = i18n.N("safety.orphaned", report.Unpushed)
+		} else if report != nil {
+			commits = i18n.N("safety.unpushed", report.Unpushed)
+		}
+		title, prompt = i18n.T("delete.branch.title"), i18n.T("delete.branch.prompt", item.worktree.BranchName(), commits)
+		help = i18n.T("delete.branch.help")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n",
+		titleStyle.Render(title),
+		errorStyle.Render("⚠ "+prompt),
+		helpStyle.Render(help),
+	)
+}
+
+// deleteWhenChecked deletes the selected worktree without asking, once its safety report is in, keeping
+// its branch unless the report shows it has nothing unpushed
+func (m *model) deleteWhenChecked(safety *deleteSafety) (tea.Model, tea.Cmd) {
+	m.deleteUnasked = false
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut || item.worktree.Name() != safety.name {
+		return m, nil
+	}
+	if safety.report == nil || safety.report.Unpushed > 0 {
+		m.deleteOpts.DeleteBranch = false
+	}
+	return m.handleDeleteWorktree()
+}
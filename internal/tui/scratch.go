@@ -0,0 +1,47 @@
+package tui
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+)
+
+// scratchExpiry returns when a scratch worktree is due for cleanup, or the zero time for other worktrees
+func scratchExpiry(cfg *config.Config, wt git.Worktree) time.Time {
+	expiry, _ := wt.ScratchExpiry(git.ScratchExpiryDays(cfg.Scratch))
+	return expiry
+}
+
+// expiredScratch returns the scratch worktrees to offer for cleanup, leaving out the one lfg is
+// running in and any that have since been linked to a todo
+func expiredScratch(cfg *config.Config, worktrees []git.Worktree, current string) []cleanupCandidate {
+	days := git.ScratchExpiryDays(cfg.Scratch)
+	var expired []cleanupCandidate
+	for _, wt := range git.ExpiredScratch(worktrees, days, time.Now()) {
+		if wt.Name() != current && cfg.GetTodoForWorktree(wt.Name()) == nil {
+			expired = append(expired, cleanupCandidate{worktree: wt, reason: fmt.Sprintf("scratch, more than %d days old", days)})
+		}
+	}
+	return expired
+}
+
+// isScratch reports whether the item is a scratch worktree (one that has been given a todo is real work now)
+func (i worktreeItem) isScratch() bool {
+	return i.isCheckedOut && !i.expires.IsZero() && i.todo == nil
+}
+
+// scratchDescription says when a scratch worktree will be offered for cleanup
+func scratchDescription(expires time.Time) string {
+	days := int(time.Until(expires).Hours() / 24)
+	switch {
+	case !time.Now().Before(expires):
+		return "Scratch | expired, d: delete"
+	case days == 0:
+		return "Scratch | expires today"
+	case days == 1:
+		return "Scratch | expires tomorrow"
+	}
+	return fmt.Sprintf("Scratch | expires in %d days", days)
+}
@@ -0,0 +1,97 @@
+package tui
+
+import (
+	"strings"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+	"github.com/charmbracelet/lipgloss"
+
+	"github.com/markcipolla/lfg/internal/i18n"
+)
+
+// rowHeight is the number of lines each entry takes in the list, including the gap below it
+func (m *model) rowHeight() int {
+	return m.delegate.Height() + m.delegate.Spacing()
+}
+
+// listRow is the screen line the list starts on, below the header written by View
+func listRow() int {
+	return strings.Count(titleStyle.Render(i18n.T("list.title"))+"\n\n", "\n")
+}
+
+// itemsRow is the line within the list's view that its entries start on, below the filter and status bar
+func (m *model) itemsRow() int {
+	row := 0
+	if m.list.ShowTitle() || (m.list.ShowFilter() && m.list.FilteringEnabled()) {
+		row += lipgloss.Height(m.list.Styles.TitleBar.Render(""))
+	}
+	if m.list.ShowStatusBar() {
+		row += lipgloss.Height(m.list.Styles.StatusBar.Render(""))
+	}
+	return row
+}
+
+// scrollbar renders the column beside the list's entries showing where the page on screen is among
+// all of them, empty when they fit on one page
+func (m *model) scrollbar(height int) string {
+	pages := m.list.Paginator.TotalPages
+	if pages <= 1 {
+		return ""
+	}
+	top := m.itemsRow()
+	track := min(m.list.Paginator.PerPage*m.rowHeight(), height-top)
+	if track <= 0 {
+		return ""
+	}
+	thumb := max(track/pages, 1)
+	start := m.list.Paginator.Page * (track - thumb) / (pages - 1)
+
+	lines := make([]string, height)
+	for i := range track {
+		if i >= start && i < start+thumb {
+			lines[top+i] = highlightStyle.Render("┃")
+		} else {
+			lines[top+i] = helpStyle.Render("│")
+		}
+	}
+	return strings.Join(lines, "\n")
+}
+
+// updateMouse scrolls the list with the wheel and selects the entry clicked, on whichever page is shown
+// Clicking the scrollbar jumps to the page at that point of it
+func (m *model) updateMouse(msg tea.MouseMsg) (tea.Model, tea.Cmd) {
+	if m.inModal() || m.loading || m.list.FilterState() == list.Filtering {
+		return m, nil
+	}
+
+	switch msg.Button {
+	case tea.MouseButtonWheelUp:
+		m.list.CursorUp()
+		return m, nil
+	case tea.MouseButtonWheelDown:
+		m.list.CursorDown()
+		return m, nil
+	}
+	if msg.Button != tea.MouseButtonLeft || msg.Action != tea.MouseActionPress {
+		return m, nil
+	}
+
+	row := msg.Y - listRow() - m.itemsRow()
+	perPage := m.list.Paginator.PerPage
+	if row < 0 || row >= perPage*m.rowHeight() {
+		return m, nil
+	}
+
+	if pages := m.list.Paginator.TotalPages; msg.X >= m.list.Width() && pages > 1 {
+		page := min(row*pages/(perPage*m.rowHeight()), pages-1)
+		m.list.Select(page * perPage)
+		return m, nil
+	}
+
+	index := m.list.Paginator.Page*perPage + row/m.rowHeight()
+	if row%m.rowHeight() < m.delegate.Height() && index < len(m.list.VisibleItems()) {
+		m.list.Select(index)
+	}
+	return m, nil
+}
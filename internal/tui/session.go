@@ -0,0 +1,247 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/tmux"
+)
+
+// showSessionInfo opens the session details for the selected worktree
+func (m *model) showSessionInfo() {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return
+	}
+	if err := multiplexer.RequireTmux("Session details"); err != nil {
+		m.err = err
+		return
+	}
+	info, err := tmux.GetSessionInfo(multiplexer.Current().SessionName(m.config.Name, item.worktree.Name()))
+	if err != nil {
+		m.err = err
+		return
+	}
+	m.sessionInfo = info
+	m.sessionWindow = min(m.sessionWindow, max(len(info.Windows)-1, 0))
+}
+
+// updateSessionInfo handles keys in the session details view
+func (m *model) updateSessionInfo(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	if m.renamingWindow {
+		return m.updateRenameWindow(msg)
+	}
+
+	windows := m.sessionInfo.Windows
+	switch msg.String() {
+	case "esc", "q", "i":
+		m.sessionInfo = nil
+		m.sessionWindow = 0
+	case "r":
+		m.showSessionInfo()
+	case "f":
+		if len(m.sessionInfo.Failures) > 0 {
+			return m, m.retryFailedPanes()
+		}
+	case "x":
+		cmd := m.restartCrashed()
+		m.showSessionInfo()
+		return m, cmd
+	case "up", "k":
+		m.sessionWindow = max(m.sessionWindow-1, 0)
+	case "down", "j":
+		m.sessionWindow = min(m.sessionWindow+1, max(len(windows)-1, 0))
+	case "n":
+		if m.sessionWindow < len(windows) {
+			m.renamingWindow = true
+			m.windowInput.SetValue(windows[m.sessionWindow].Name)
+			m.windowInput.CursorEnd()
+			return m, m.windowInput.Focus()
+		}
+	case "K", "J":
+		if m.sessionWindow < len(windows) {
+			delta := 1
+			if msg.String() == "K" {
+				delta = -1
+			}
+			return m, m.moveWindow(windows[m.sessionWindow].Index, delta)
+		}
+	}
+	return m, nil
+}
+
+// updateRenameWindow handles keys while renaming the selected window
+func (m *model) updateRenameWindow(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "esc":
+		m.renamingWindow = false
+		m.windowInput.Blur()
+		return m, nil
+	case "enter":
+		m.renamingWindow = false
+		m.windowInput.Blur()
+		window := m.sessionInfo.Windows[m.sessionWindow]
+		name := strings.TrimSpace(m.windowInput.Value())
+		if name == window.Name {
+			return m, nil
+		}
+		if err := tmux.RenameWindow(m.sessionInfo.Name, window.Index, name); err != nil {
+			m.err = err
+			return m, nil
+		}
+		return m, m.saveWindows(i18n.T("session.renamed", window.Name, name))
+	}
+
+	var cmd tea.Cmd
+	m.windowInput, cmd = m.windowInput.Update(msg)
+	return m, cmd
+}
+
+// moveWindow moves a window of the session shown before or after its neighbour, keeping it selected
+func (m *model) moveWindow(index, delta int) tea.Cmd {
+	moved, err := tmux.MoveWindow(m.sessionInfo.Name, index, delta)
+	if err != nil {
+		m.err = err
+		return nil
+	}
+	if moved == index {
+		return nil
+	}
+	m.sessionWindow += delta
+	return m.saveWindows(i18n.T("session.moved"))
+}
+
+// saveWindows records the shown session's windows for the worktree's next session and reloads them
+func (m *model) saveWindows(status string) tea.Cmd {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok {
+		return nil
+	}
+	m.showSessionInfo()
+	if err := tmux.SaveWindowOverrides(multiplexer.Current().SessionName(m.config.Name, item.worktree.Name()), item.worktree.Path); err != nil {
+		m.err = err
+		return nil
+	}
+	return m.setStatus(status)
+}
+
+// retryFailedPanes retries the panes that failed to start in the selected worktree's session
+func (m *model) retryFailedPanes() tea.Cmd {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok {
+		return nil
+	}
+	remaining, err := tmux.RetryFailed(item.worktree.Name(), item.worktree.Path, m.config)
+	m.showSessionInfo()
+	if err != nil {
+		m.err = err
+		return nil
+	}
+	if len(remaining) > 0 {
+		return m.setStatus(i18n.N("session.still_failed", len(remaining)))
+	}
+	return m.setStatus(i18n.T("session.all_started"))
+}
+
+// restartCrashed restarts the panes of the selected worktree's session whose command exited
+func (m *model) restartCrashed() tea.Cmd {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return nil
+	}
+	if err := multiplexer.RequireTmux("Restarting crashed panes"); err != nil {
+		m.err = err
+		return nil
+	}
+	restarted, err := tmux.RestartCrashed(item.worktree.Name(), item.worktree.Path, m.config)
+	if err != nil {
+		m.err = err
+		return nil
+	}
+	if len(restarted) == 0 {
+		return m.setStatus(i18n.T("session.no_crashed", item.worktree.DisplayName()))
+	}
+	// The crashed badge goes once the status is read again
+	git.NewStatusCache(cacheKey(m.config)).Invalidate(item.worktree.Name())
+	return tea.Batch(m.setStatus(i18n.N("session.restarted", len(restarted), item.worktree.DisplayName())), m.refresh())
+}
+
+func (m *model) viewSessionInfo() string {
+	info := m.sessionInfo
+
+	var panes strings.Builder
+	for _, pane := range info.Panes {
+		name := pane.Name
+		if name == "" {
+			name = "-"
+		}
+		line := fmt.Sprintf("  %-20s %-3d %-12s %-8d %s", pane.Window, pane.Index, name, pane.PID, pane.Command)
+		if pane.Dead && pane.Status != 0 {
+			line = errorStyle.Render(line + fmt.Sprintf(i18n.T("session.crashed"), pane.Status))
+		} else if pane.Dead {
+			line = helpStyle.Render(line + i18n.T("session.exited"))
+		}
+		panes.WriteString(line + "\n")
+	}
+
+	failures, help := "", i18n.T("session.help")
+	if len(info.Failures) > 0 {
+		var b strings.Builder
+		b.WriteString(errorStyle.Bold(true).Render(i18n.T("session.failed")) + "\n")
+		for _, failure := range info.Failures {
+			name := failure.Pane
+			if name == "" {
+				name = i18n.T("session.unnamed")
+			}
+			b.WriteString(errorStyle.Render(fmt.Sprintf("  %-12s %s", name, failure.Err)) + "\n")
+		}
+		failures = b.String() + "\n"
+		help = i18n.T("session.help_retry", help)
+	}
+
+	var windows strings.Builder
+	for i, window := range info.Windows {
+		line := fmt.Sprintf("%-3d %s", window.Index, window.Name)
+		if window.Layout {
+			line += i18n.T("session.layout_window")
+		}
+		switch {
+		case m.renamingWindow && i == m.sessionWindow:
+			windows.WriteString(fmt.Sprintf("> %-3d %s\n", window.Index, m.windowInput.View()))
+		case i == m.sessionWindow:
+			windows.WriteString(highlightStyle.Bold(true).Render("> "+line) + "\n")
+		default:
+			windows.WriteString("  " + line + "\n")
+		}
+	}
+	if m.renamingWindow {
+		help = i18n.T("session.rename_help")
+	}
+
+	var env strings.Builder
+	if len(info.Env) == 0 {
+		env.WriteString(helpStyle.Render(i18n.T("session.no_env")) + "\n")
+	}
+	for _, v := range info.Env {
+		env.WriteString("  " + v + "\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s%s\n%s\n%s\n%s%s\n\n%s\n%s\n%s\n",
+		titleStyle.Render(i18n.T("session.title", info.Name)),
+		failures,
+		highlightStyle.Bold(true).Render(i18n.T("session.windows")),
+		windows.String(),
+		highlightStyle.Bold(true).Render(i18n.T("session.panes")),
+		helpStyle.Render(fmt.Sprintf("  %-20s %-3s %-12s %-8s %s", "WINDOW", "#", "NAME", "PID", "COMMAND")),
+		panes.String(),
+		highlightStyle.Bold(true).Render(i18n.T("session.environment")),
+		env.String(),
+		helpStyle.Render(help),
+	)
+}
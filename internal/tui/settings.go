@@ -0,0 +1,499 @@
+package tui
+
+import (
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+	"github.com/charmbracelet/lipgloss"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/tmux"
+)
+
+// theme is a colour palette for the TUI
+type theme struct {
+	accent  lipgloss.Color
+	muted   lipgloss.Color
+	warning lipgloss.Color
+	error   lipgloss.Color
+}
+
+var themeNames = []string{"default", "light", "high-contrast"}
+
+var themes = map[string]theme{
+	"default":       {accent: "86", muted: "241", warning: "214", error: "196"},
+	"light":         {accent: "25", muted: "245", warning: "130", error: "160"},
+	"high-contrast": {accent: "15", muted: "252", warning: "11", error: "9"},
+}
+
+// applyTheme recolours the shared styles
+func applyTheme(name string) {
+	t, ok := themes[name]
+	if !ok {
+		t = themes["default"]
+	}
+	titleStyle = titleStyle.Foreground(t.accent)
+	helpStyle = helpStyle.Foreground(t.muted)
+	warningStyle = warningStyle.Foreground(t.warning)
+	errorStyle = errorStyle.Foreground(t.error)
+	statusStyle = statusStyle.Foreground(t.accent)
+	highlightStyle = highlightStyle.Foreground(t.accent)
+}
+
+// newDelegate returns the list delegate, with the selection coloured by the theme
+// The default theme keeps the bubbles list colours
+func newDelegate(themeName string) *cachedDelegate {
+	delegate := list.NewDefaultDelegate()
+	delegate.ShowDescription = true
+
+	if t, ok := themes[themeName]; ok && themeName != "default" {
+		delegate.Styles.SelectedTitle = delegate.Styles.SelectedTitle.Foreground(t.accent).BorderForeground(t.accent)
+		delegate.Styles.SelectedDesc = delegate.Styles.SelectedDesc.Foreground(t.accent).BorderForeground(t.accent)
+	}
+	return &cachedDelegate{DefaultDelegate: delegate, rows: make(map[rowKey]string)}
+}
+
+// worktreeColors are the accent colours cycled with C, by tmux colour name
+var worktreeColors = []string{"", "red", "green", "yellow", "blue", "magenta", "cyan"}
+
+// colorCodes maps tmux colour names to terminal colours for the list
+var colorCodes = map[string]lipgloss.Color{
+	"red":     "1",
+	"green":   "2",
+	"yellow":  "3",
+	"blue":    "4",
+	"magenta": "5",
+	"cyan":    "6",
+}
+
+// colorSwatch renders a block in the worktree's accent colour
+func colorSwatch(color string) string {
+	code, ok := colorCodes[color]
+	if !ok {
+		code = lipgloss.Color(color) // Any other colour tmux and lipgloss both understand, e.g. "#ff8800"
+	}
+	return lipgloss.NewStyle().Foreground(code).Render("█")
+}
+
+// cycleColor moves the worktree to the next accent colour and recolours its tmux session
+func (m *model) cycleColor(item worktreeItem) tea.Cmd {
+	name := item.worktree.Name()
+	color := ""
+	for i, c := range worktreeColors {
+		if c == item.color {
+			color = worktreeColors[(i+1)%len(worktreeColors)]
+			break
+		}
+	}
+
+	m.config.SetWorktreeColor(name, color)
+	if err := m.config.Save(); err != nil {
+		m.err = fmt.Errorf("failed to save config: %w", err)
+		return nil
+	}
+
+	// Only tmux has a status bar to colour, the colour still shows in the list
+	sessionName := multiplexer.Current().SessionName(m.config.Name, name)
+	if multiplexer.IsTmux() && tmux.SessionExists(sessionName) && tmux.IsManaged(sessionName) {
+		if err := tmux.SetStatusColor(sessionName, color); err != nil {
+			m.err = fmt.Errorf("failed to set tmux status colour: %w", err)
+		}
+	}
+
+	status := i18n.T("colour.cleared", item.worktree.DisplayName())
+	if color != "" {
+		status = i18n.T("colour.set", item.worktree.DisplayName(), color)
+	}
+	return tea.Batch(m.setStatus(status), m.refresh())
+}
+
+// setting is a row on the settings screen
+type setting struct {
+	label string
+	value func(s *config.Settings) string
+	next  func(s *config.Settings)
+}
+
+var sortOrders = []string{"default", "name", "recent"}
+
+// groupings are how list items are grouped: by status (main, active, in review, scratch, issues, todos) or not at all
+var groupings = []string{"status", "none"}
+
+// View overrides how the list is filtered, sorted and grouped for one run, e.g. from a shell alias,
+// without changing the settings
+type View struct {
+	Filter string // Filters the list as if typed after /
+	Sort   string // Overrides settings.sort_order
+	Group  string // Overrides settings.group
+}
+
+// validate checks that the view's sort order and grouping are ones the list knows
+func (v View) validate() error {
+	if v.Sort != "" && !slices.Contains(sortOrders, v.Sort) {
+		return fmt.Errorf("unknown sort order %q, use one of: %s", v.Sort, strings.Join(sortOrders, ", "))
+	}
+	if v.Group != "" && !slices.Contains(groupings, v.Group) {
+		return fmt.Errorf("unknown grouping %q, use one of: %s", v.Group, strings.Join(groupings, ", "))
+	}
+	return nil
+}
+
+// settings returns the config's settings with the view's overrides applied
+func (v View) settings(cfg *config.Config) config.Settings {
+	var settings config.Settings
+	if cfg.Settings != nil {
+		settings = *cfg.Settings
+	}
+	if v.Sort != "" {
+		settings.SortOrder = v.Sort
+	}
+	if v.Group != "" {
+		settings.Group = v.Group
+	}
+	return settings
+}
+
+// defaultMaxFPS caps redraws low enough that holding a key over a slow SSH connection doesn't queue
+// up frames, while still feeling instant
+const defaultMaxFPS = 30
+
+// maxFPS returns the most frames the TUI draws per second
+// Frames are only written when they change, and then only the lines that changed
+func maxFPS(settings config.Settings) int {
+	if settings.MaxFPS <= 0 {
+		return defaultMaxFPS
+	}
+	return min(settings.MaxFPS, 120)
+}
+
+var autoRefreshIntervals = []int{0, 10, 30, 60, 300}
+
+// archiveAfterDays are the settings.archive_after values cycled on the settings screen
+var archiveAfterDays = []int{0, 1, 7, 30}
+
+var settingRows = []setting{
+	{
+		label: "settings.theme",
+		value: func(s *config.Settings) string { return orDefault(s.Theme, themeNames[0]) },
+		next:  func(s *config.Settings) { s.Theme = cycle(themeNames, s.Theme) },
+	},
+	{
+		label: "settings.sort_order",
+		value: func(s *config.Settings) string { return orDefault(s.SortOrder, sortOrders[0]) },
+		next:  func(s *config.Settings) { s.SortOrder = cycle(sortOrders, s.SortOrder) },
+	},
+	{
+		label: "settings.group",
+		value: func(s *config.Settings) string { return orDefault(s.Group, groupings[0]) },
+		next:  func(s *config.Settings) { s.Group = cycle(groupings, s.Group) },
+	},
+	{
+		label: "settings.confirm",
+		value: func(s *config.Settings) string { return onOff(!s.SkipConfirmations) },
+		next:  func(s *config.Settings) { s.SkipConfirmations = !s.SkipConfirmations },
+	},
+	{
+		label: "settings.auto_refresh",
+		value: func(s *config.Settings) string {
+			if s.AutoRefresh <= 0 {
+				return "off"
+			}
+			return fmt.Sprintf("every %ds", s.AutoRefresh)
+		},
+		next: func(s *config.Settings) {
+			for i, interval := range autoRefreshIntervals {
+				if interval == s.AutoRefresh {
+					s.AutoRefresh = autoRefreshIntervals[(i+1)%len(autoRefreshIntervals)]
+					return
+				}
+			}
+			s.AutoRefresh = autoRefreshIntervals[0]
+		},
+	},
+	{
+		label: "settings.hide_done",
+		value: func(s *config.Settings) string { return onOff(s.HideDone) },
+		next:  func(s *config.Settings) { s.HideDone = !s.HideDone },
+	},
+	{
+		label: "settings.hide_blocked",
+		value: func(s *config.Settings) string { return onOff(s.HideBlocked) },
+		next:  func(s *config.Settings) { s.HideBlocked = !s.HideBlocked },
+	},
+	{
+		label: "settings.archive_after",
+		value: func(s *config.Settings) string {
+			if s.ArchiveAfter <= 0 {
+				return "off"
+			}
+			return fmt.Sprintf("after %dd", s.ArchiveAfter)
+		},
+		next: func(s *config.Settings) {
+			for i, days := range archiveAfterDays {
+				if days == s.ArchiveAfter {
+					s.ArchiveAfter = archiveAfterDays[(i+1)%len(archiveAfterDays)]
+					return
+				}
+			}
+			s.ArchiveAfter = archiveAfterDays[0]
+		},
+	},
+	{
+		label: "settings.trash",
+		value: func(s *config.Settings) string { return onOff(s.Trash) },
+		next:  func(s *config.Settings) { s.Trash = !s.Trash },
+	},
+	{
+		label: "settings.save_sessions",
+		value: func(s *config.Settings) string { return onOff(s.SaveSessions) },
+		next:  func(s *config.Settings) { s.SaveSessions = !s.SaveSessions },
+	},
+	{
+		label: "settings.mouse",
+		value: func(s *config.Settings) string { return onOff(s.Mouse) },
+		next:  func(s *config.Settings) { s.Mouse = !s.Mouse },
+	},
+}
+
+func orDefault(value, fallback string) string {
+	if value == "" {
+		return fallback
+	}
+	return value
+}
+
+func onOff(b bool) string {
+	if b {
+		return "on"
+	}
+	return "off"
+}
+
+// cycle returns the value after current, wrapping around
+func cycle(values []string, current string) string {
+	for i, v := range values {
+		if v == current {
+			return values[(i+1)%len(values)]
+		}
+	}
+	// Unset means the first (default) value
+	if len(values) > 1 {
+		return values[1]
+	}
+	return values[0]
+}
+
+// updateSettings handles keys on the settings screen
+func (m *model) updateSettings(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	switch msg.String() {
+	case "esc", ",", "q":
+		m.editingSettings = false
+		return m, nil
+	case "up", "k":
+		m.settingsCursor = (m.settingsCursor + len(settingRows) - 1) % len(settingRows)
+	case "down", "j":
+		m.settingsCursor = (m.settingsCursor + 1) % len(settingRows)
+	case "enter", " ", "right", "l":
+		if m.config.Settings == nil {
+			m.config.Settings = &config.Settings{}
+		}
+		settingRows[m.settingsCursor].next(m.config.Settings)
+		// The command line's sort order and grouping give way once the settings are changed
+		m.view.Sort, m.view.Group = "", ""
+		if err := m.config.Save(); err != nil {
+			m.err = fmt.Errorf("failed to save config: %w", err)
+		}
+
+		applyTheme(m.config.Settings.Theme)
+		tmux.SaveOnKill = m.config.Settings.SaveSessions
+		m.delegate = newDelegate(m.config.Settings.Theme)
+		m.list.SetDelegate(m.delegate)
+
+		// Capturing the mouse stops the terminal selecting text, so it's only on while the setting is
+		mouse := tea.DisableMouse
+		if m.config.Settings.Mouse {
+			mouse = tea.EnableMouseCellMotion
+		}
+
+		// Restart auto-refresh with the new interval, and re-sort/filter the list
+		m.refreshID++
+		return m, tea.Batch(m.refresh(), m.scheduleRefresh(), mouse)
+	}
+	return m, nil
+}
+
+func (m *model) viewSettings() string {
+	settings := m.config.Settings
+	if settings == nil {
+		settings = &config.Settings{}
+	}
+
+	var rows strings.Builder
+	for i, row := range settingRows {
+		line := fmt.Sprintf("%-22s %s", i18n.T(row.label), row.value(settings))
+		if i == m.settingsCursor {
+			rows.WriteString(highlightStyle.Bold(true).Render("> " + line))
+		} else {
+			rows.WriteString("  " + line)
+		}
+		rows.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("settings.title")),
+		rows.String(),
+		helpStyle.Render(i18n.T("settings.help")),
+	)
+}
+
+type autoRefreshMsg struct {
+	id int
+}
+
+// refreshInterval returns the time between auto-refreshes, 0 when auto-refresh is off
+func (m *model) refreshInterval() time.Duration {
+	if m.config.Settings == nil || m.config.Settings.AutoRefresh <= 0 {
+		return 0
+	}
+	return time.Duration(m.config.Settings.AutoRefresh) * time.Second
+}
+
+// scheduleRefresh queues the next auto-refresh, or nothing if auto-refresh is off
+func (m *model) scheduleRefresh() tea.Cmd {
+	interval := m.refreshInterval()
+	if interval == 0 {
+		return nil
+	}
+	id := m.refreshID
+	return tea.Tick(interval, func(time.Time) tea.Msg {
+		return autoRefreshMsg{id: id}
+	})
+}
+
+// arrangeItems applies pins, blockers and the hide-done, hide-blocked, sort order and grouping settings to the list items
+// Pinned items come first in pin order, then, grouped by status, the main worktree, active worktrees,
+// those in review and scratch worktrees
+func arrangeItems(cfg *config.Config, view View, mainName string, items []list.Item) []list.Item {
+	settings := view.settings(cfg)
+
+	arranged := make([]list.Item, 0, len(items))
+	for _, it := range items {
+		item, ok := it.(worktreeItem)
+		if ok {
+			item.pinned = cfg.IsPinned(item.pinKey())
+			item.aging = cfg.Aging
+			item.blocker = ""
+			if blocker := cfg.Blocker(item.todo); blocker != nil {
+				item.blocker = blocker.Description
+			}
+			it = item
+		}
+		if ok && settings.HideDone && item.isDone() && !item.pinned && !(item.isCheckedOut && item.worktree.Name() == mainName) {
+			continue
+		}
+		if ok && settings.HideBlocked && item.blocker != "" && !item.pinned {
+			continue
+		}
+		arranged = append(arranged, it)
+	}
+
+	// Stale work goes in its own section at the bottom, when asked for
+	staleSection := cfg.Aging != nil && cfg.Aging.StaleSection
+	rank := func(it list.Item) int {
+		item, ok := it.(worktreeItem)
+		switch {
+		case !ok:
+			return 5
+		case item.pinned:
+			return -1
+		case settings.Group == "none":
+			return 0
+		case item.isCheckedOut && item.worktree.Name() == mainName:
+			return 0
+		case staleSection && item.isStale():
+			return 6
+		case item.isCheckedOut && item.isInReview():
+			return 2
+		case item.isScratch():
+			return 3
+		case item.isCheckedOut:
+			return 1
+		case item.githubItem != nil:
+			return 4
+		}
+		return 5
+	}
+	// Recent activity is the later of HEAD moving and the session being opened from lfg
+	// Worktrees with neither, and items without a worktree, come last when sorting by it
+	activity := make(map[string]time.Time)
+	if settings.SortOrder == "recent" {
+		var meta map[string]git.WorktreeMeta
+		if cfg.GetConfigPath() != "" {
+			meta = git.NewMetaStore(cacheKey(cfg)).All()
+		}
+		for _, it := range arranged {
+			if item, ok := it.(worktreeItem); ok && item.isCheckedOut {
+				last := git.LastActivity(item.worktree)
+				if attached := meta[item.worktree.Name()].LastAttached; attached.After(last) {
+					last = attached
+				}
+				activity[item.worktree.Path] = last
+			}
+		}
+	}
+	lastActive := func(it list.Item) time.Time {
+		if item, ok := it.(worktreeItem); ok && item.isCheckedOut {
+			return activity[item.worktree.Path]
+		}
+		return time.Time{}
+	}
+
+	sort.SliceStable(arranged, func(i, j int) bool {
+		ri, rj := rank(arranged[i]), rank(arranged[j])
+		if ri == -1 && rj == -1 {
+			return cfg.PinIndex(arranged[i].(worktreeItem).pinKey()) < cfg.PinIndex(arranged[j].(worktreeItem).pinKey())
+		}
+		if ri != rj {
+			return ri < rj
+		}
+		switch settings.SortOrder {
+		case "name":
+			return strings.ToLower(itemLabel(arranged[i])) < strings.ToLower(itemLabel(arranged[j]))
+		case "recent":
+			return lastActive(arranged[i]).After(lastActive(arranged[j]))
+		}
+		return false
+	})
+
+	return arranged
+}
+
+// itemLabel returns a list item's name for sorting
+func itemLabel(it list.Item) string {
+	if item, ok := it.(worktreeItem); ok {
+		return item.label()
+	}
+	return it.FilterValue()
+}
+
+// isInReview reports whether the item's todo is waiting on a pull request review
+func (i worktreeItem) isInReview() bool {
+	return i.todo != nil && i.todo.Status == config.TodoStatusInReview
+}
+
+// isDone reports whether the item's todo or GitHub item is finished
+func (i worktreeItem) isDone() bool {
+	if i.todo != nil && i.todo.Status == config.TodoStatusDone {
+		return true
+	}
+	return i.githubItem != nil && i.githubItem.Status == "Done"
+}
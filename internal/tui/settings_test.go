@@ -0,0 +1,107 @@
+package tui
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/charmbracelet/bubbles/list"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+)
+
+func TestArrangeItemsView(t *testing.T) {
+	root := t.TempDir()
+	worktree := func(name string, age time.Duration) worktreeItem {
+		path := filepath.Join(root, name)
+		reflog := filepath.Join(path, ".git", "logs", "HEAD")
+		if err := os.MkdirAll(filepath.Dir(reflog), 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(reflog, nil, 0644); err != nil {
+			t.Fatal(err)
+		}
+		modified := time.Now().Add(-age)
+		if err := os.Chtimes(reflog, modified, modified); err != nil {
+			t.Fatal(err)
+		}
+		return worktreeItem{worktree: git.Worktree{Path: path, ID: name}, isCheckedOut: true}
+	}
+	items := []list.Item{
+		worktree("myapp", 3*time.Hour),
+		worktree("zeta", 2*time.Hour),
+		worktree("beta", time.Hour),
+		worktreeItem{todo: &config.Todo{Description: "alpha"}},
+	}
+
+	tests := []struct {
+		name      string
+		sortOrder string // The sort_order setting
+		view      View
+		expected  []string
+	}{
+		{name: "settings", expected: []string{"myapp", "zeta", "beta", "alpha"}},
+		{name: "sort by name", view: View{Sort: "name"}, expected: []string{"myapp", "beta", "zeta", "alpha"}},
+		{name: "view overrides setting", sortOrder: "name", view: View{Sort: "default"}, expected: []string{"myapp", "zeta", "beta", "alpha"}},
+		{name: "ungrouped by name", view: View{Sort: "name", Group: "none"}, expected: []string{"alpha", "beta", "myapp", "zeta"}},
+		{name: "recent", view: View{Sort: "recent"}, expected: []string{"myapp", "beta", "zeta", "alpha"}},
+		{name: "ungrouped recent", view: View{Sort: "recent", Group: "none"}, expected: []string{"beta", "zeta", "myapp", "alpha"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cfg := &config.Config{Name: "myapp", Settings: &config.Settings{SortOrder: tt.sortOrder}}
+			var got []string
+			for _, it := range arrangeItems(cfg, tt.view, "myapp", items) {
+				got = append(got, itemLabel(it))
+			}
+			if !reflect.DeepEqual(got, tt.expected) {
+				t.Errorf("arrangeItems() = %v, want %v", got, tt.expected)
+			}
+		})
+	}
+}
+
+func TestStaleSection(t *testing.T) {
+	now := time.Now()
+	todo := func(description string, age time.Duration) worktreeItem {
+		return worktreeItem{todo: &config.Todo{Description: description, Status: config.TodoStatusPending, Created: now.Add(-age)}}
+	}
+	items := []list.Item{
+		todo("old", 30*24*time.Hour),
+		todo("new", time.Hour),
+		worktreeItem{worktree: git.Worktree{Path: "/src/myapp", ID: "myapp"}, isCheckedOut: true},
+	}
+	cfg := &config.Config{Name: "myapp", Aging: &config.Aging{StaleSection: true}}
+
+	var got []string
+	for _, it := range arrangeItems(cfg, View{}, "myapp", items) {
+		got = append(got, itemLabel(it))
+	}
+	if expected := []string{"myapp", "new", "old"}; !reflect.DeepEqual(got, expected) {
+		t.Errorf("arrangeItems() = %v, want %v", got, expected)
+	}
+
+	if badge := ageBadge(items[1].(worktreeItem).todo, nil, now); badge != "" {
+		t.Errorf("ageBadge() for a todo added today = %q, want none", badge)
+	}
+	if badge := ageBadge(items[0].(worktreeItem).todo, nil, now); !strings.Contains(badge, "30d") {
+		t.Errorf("ageBadge() = %q, want 30d", badge)
+	}
+}
+
+func TestViewValidate(t *testing.T) {
+	if err := (View{Filter: "login", Sort: "recent", Group: "none"}).validate(); err != nil {
+		t.Errorf("validate() error = %v", err)
+	}
+	if err := (View{Sort: "oldest"}).validate(); err == nil {
+		t.Error("validate() accepted an unknown sort order")
+	}
+	if err := (View{Group: "tag"}).validate(); err == nil {
+		t.Error("validate() accepted an unknown grouping")
+	}
+}
@@ -0,0 +1,140 @@
+package tui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea"
+
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/setup"
+)
+
+// setupSummary is the outcome of setting up a new worktree, shown until dismissed
+type setupSummary struct {
+	worktree string
+	results  []setup.Result
+	running  bool // Failed steps are being re-run
+}
+
+type setupMsg struct {
+	worktree string
+	results  []setup.Result
+	err      error
+}
+
+// runSetup runs setup steps for a new worktree in the background
+func runSetup(worktree string, steps []setup.Step) tea.Cmd {
+	if len(steps) == 0 {
+		return nil
+	}
+	return func() tea.Msg {
+		mainPath, err := git.GetMainWorktreePath()
+		if err != nil {
+			return setupMsg{worktree: worktree, err: err}
+		}
+		path, err := git.GetWorktreePath(worktree)
+		if err != nil {
+			return setupMsg{worktree: worktree, err: err}
+		}
+		return setupMsg{worktree: worktree, results: setup.Run(steps, mainPath, path)}
+	}
+}
+
+// jumpAfterSetup sets up a new worktree before jumping to it, so its session starts in a ready environment
+// The summary is only shown when a step fails, dismissing it continues to the worktree
+func (m *model) jumpAfterSetup(worktree string) tea.Cmd {
+	steps := setup.Steps(m.config.Setup)
+	if len(steps) == 0 {
+		m.selectedWorktree = worktree
+		return tea.Quit
+	}
+	m.jumpTo = worktree
+	return tea.Batch(m.setStatus(i18n.T("setup.starting", worktree)), runSetup(worktree, steps))
+}
+
+// applySetup shows the results of a setup run, merging re-run steps into the earlier results
+func (m *model) applySetup(msg setupMsg) tea.Cmd {
+	if msg.err != nil {
+		m.setup = nil
+		m.jumpTo = ""
+		m.err = fmt.Errorf("failed to set up %s: %w", msg.worktree, msg.err)
+		return nil
+	}
+
+	if m.setup == nil || m.setup.worktree != msg.worktree {
+		if msg.worktree == m.jumpTo && len(setup.Failed(msg.results)) == 0 {
+			m.selectedWorktree = msg.worktree
+			return tea.Quit
+		}
+		m.setup = &setupSummary{worktree: msg.worktree, results: msg.results}
+		return nil
+	}
+
+	m.setup.running = false
+	for _, rerun := range msg.results {
+		for i, r := range m.setup.results {
+			if r.Step == rerun.Step {
+				m.setup.results[i] = rerun
+			}
+		}
+	}
+	return nil
+}
+
+// updateSetup handles keys on the setup summary
+func (m *model) updateSetup(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
+	if m.setup.running {
+		return m, nil
+	}
+	switch msg.String() {
+	case "r":
+		if failed := setup.Failed(m.setup.results); len(failed) > 0 {
+			m.setup.running = true
+			return m, runSetup(m.setup.worktree, failed)
+		}
+	case "enter", "esc", "q":
+		if m.setup.worktree == m.jumpTo {
+			m.selectedWorktree = m.jumpTo
+			return m, tea.Quit
+		}
+		m.setup = nil
+	}
+	return m, nil
+}
+
+func (m *model) viewSetup() string {
+	var rows strings.Builder
+	for i, line := range setup.Summary(m.setup.results) {
+		result := m.setup.results[i]
+		if result.Err == nil {
+			rows.WriteString("  " + line + "\n")
+			continue
+		}
+		rows.WriteString(errorStyle.Render("  "+line) + "\n")
+
+		// The last few lines of output usually say what went wrong
+		detail := result.Err.Error()
+		if result.Output != "" {
+			lines := strings.Split(result.Output, "\n")
+			detail = strings.Join(lines[max(len(lines)-3, 0):], "\n    ")
+		}
+		rows.WriteString("    " + detail + "\n")
+	}
+
+	help := i18n.T("setup.help")
+	switch {
+	case m.setup.running:
+		help = i18n.T("setup.running")
+	case len(setup.Failed(m.setup.results)) > 0:
+		help = i18n.T("setup.rerun", help)
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("setup.title", m.setup.worktree)),
+		rows.String(),
+		helpStyle.Render(help),
+	)
+}
@@ -0,0 +1,38 @@
+package tui
+
+import (
+	"path/filepath"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+)
+
+// cacheKey identifies the repository in the worktree cache by its config, which is found without running git
+func cacheKey(cfg *config.Config) string {
+	return filepath.Dir(cfg.GetConfigPath())
+}
+
+// recentWorktree returns the worktree whose session was opened from lfg most recently, "" if none was
+func recentWorktree(cfg *config.Config) string {
+	if cfg.GetConfigPath() == "" {
+		return ""
+	}
+	return git.NewMetaStore(cacheKey(cfg)).MostRecent("")
+}
+
+// saveWorktreeCache remembers the listing for the next launch, a failure only costs a slower start
+func saveWorktreeCache(cfg *config.Config, worktrees []git.Worktree) {
+	if cfg.GetConfigPath() == "" {
+		return
+	}
+	_ = git.SaveWorktreeCache(cacheKey(cfg), worktrees)
+}
+
+// settleStartup runs what was held back while the list showed cached worktrees, once they're current
+func (m *model) settleStartup() {
+	if !m.stale {
+		return
+	}
+	m.stale = false
+	m.cleanup = expiredScratch(m.config, m.worktrees, git.CurrentWorktreeIn(m.worktrees))
+}
@@ -1,9 +1,12 @@
 package tui
 
 import (
+	"errors"
 	"fmt"
 	"os"
+	"path/filepath"
 	"strings"
+	"time"
 
 	"github.com/charmbracelet/bubbles/key"
 	"github.com/charmbracelet/bubbles/list"
@@ -12,22 +15,102 @@ import (
 	tea "github.com/charmbracelet/bubbletea"
 	"github.com/charmbracelet/lipgloss"
 
+	"github.com/markcipolla/lfg/internal/clipboard"
 	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
 	"github.com/markcipolla/lfg/internal/git"
 	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/i18n"
+	"github.com/markcipolla/lfg/internal/ide"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/setup"
+	"github.com/markcipolla/lfg/internal/terminal"
 	"github.com/markcipolla/lfg/internal/tmux"
+	"github.com/markcipolla/lfg/internal/worktree"
 )
 
 type model struct {
 	config         *config.Config
 	worktrees      []git.Worktree
 	list           list.Model
+	delegate       list.ItemDelegate // The list's delegate, which bubbles doesn't expose, for the height of its rows
 	creating       bool
 	deleting       bool
+	safety         *deleteSafety // Safety report for the worktree in the delete confirmation
+	deleteStep     deleteStep             // Question the delete confirmation is asking
+	deleteOpts     worktree.DeleteOptions // What the answers to the delete confirmation allow deleting past
+	deleteUnasked  bool                   // With skip_confirmations, delete once the safety report is in
+	repurposing    bool // Switching the selected worktree to a new branch for other work
+	renaming       bool // Moving the selected worktree, its branch and session to a new name
+	openingPR      *worktreeItem // Worktree whose branch is pushed and gets a pull request, titled in textInput
+	transferring   bool // Picking a worktree to cherry-pick or apply the selected worktree's changes into
+	transferTarget int  // Index into transferTargets()
+	transferCommits int // Number of commits to cherry-pick, 0 to apply uncommitted changes instead
+	editingSettings bool
+	settingsCursor int
+	view           View // Sort order and grouping given on the command line, overriding the settings
+	refreshID      int // Incremented when the auto-refresh interval changes so old ticks are dropped
+	health         map[string][]git.HealthIssue // Health check results by worktree name
+	statuses       map[string]git.WorktreeStatus // Changes and commits ahead of and behind by worktree name
+	drift          map[string]baseDrift          // How far open pull requests' bases have moved on, by worktree name
+	prStatuses     map[string]*github.PullRequestStatus // Pull request for each worktree's branch, by worktree name
+	reviewsSynced  time.Time                            // When syncReviews last asked gh, see reviewSyncInterval
+	lastUsed       string // Worktree whose session was opened from lfg most recently, marked in the list
+	history        git.AttachHistory // Worktrees opened from lfg in order, shown as breadcrumbs and stepped through with [ and ]
+	pickingBlocker *blockerPick    // Choosing what the selected todo waits on, opened with L
+	waiting        map[string]bool // Todos blocked as of the last refresh, to say when they're unblocked
+	archive        *archiveView    // Archived todos shown when non-nil, opened with a
+	rebasing       *worktreeItem // Worktree with uncommitted changes waiting to be rebased onto its pull request's base
+	rebaseAutostash bool         // Stash the changes around that rebase, toggled with tab
+	checks         map[string]*git.CheckResult  // check_command results by worktree name
+	checking       bool                         // A pass of check_command runs is in progress
+	checksRan      time.Time                    // When the last pass started, see checkInterval
+	busy           bool                         // A command changing worktrees is running, keys wait for it
+	showingHealth  bool
+	sessionInfo    *tmux.SessionInfo // Shown when non-nil, opened with i
+	sessionWindow  int               // Position of the selected window in sessionInfo.Windows
+	renamingWindow bool              // windowInput has the keyboard, renaming the selected window
+	windowInput    textinput.Model
+	cleanup        []cleanupCandidate // Expired scratch and closed review worktrees offered for deletion
+	marked         map[string]bool    // Items picked for a bulk action, by pin key
+	markAnchor     *int               // Where a V range selection started, nil when not selecting a range
+	bulk           *bulkOp            // Bulk action waiting for confirmation
+	pullRequests   []github.PullRequest // Open pull requests shown in the review picker, opened with v
+	setup          *setupSummary        // Results of setting up a new worktree
+	jumpTo         string               // New worktree to jump to once it has been set up
+	preview        bool   // Live output of the selected session shown below the list, toggled with o
+	previewPane    int    // Index of the previewed pane, cycled with tab
+	previewID      int    // Incremented when the preview changes so stale captures are dropped
+	previewTitle   string
+	previewOutput  string
+	previewErr     error
+	prCursor       int
+	remoteBranches []git.RemoteBranchInfo // Teammates' branches shown in the remote branch picker, opened with t
+	remoteFilter   string                 // Typed in the remote branch picker to narrow it down
+	remoteCursor   int
+	blockedBy      *git.OperationInProgressError // Unfinished rebase/merge that stopped the last action
 	textInput      textinput.Model
+	todoType       int // Index into config.Templates.Types in the create dialog
+	pushOnCreate   bool // Push the new branch to origin, toggled with ctrl+p in the create dialog
+	nameInput      textinput.Model // Worktree name in the create dialog, generated from the description until edited
+	editingName    bool            // The create dialog's focus is on nameInput, moved with tab
+	nameEdited     bool            // nameInput was edited, so it no longer follows the description
+	createFrom     *git.Branch  // Existing branch to check out in the create dialog, nil for a new branch
+	pickingBranch  bool         // Choosing createFrom, opened with ctrl+b in the create dialog
+	branches       []git.Branch // Branches offered by the picker
+	branchCursor   int          // Index into the picker's rows, 0 being a new branch
+	trackRemote    *git.Branch  // Remote branch named like the new branch, offered for tracking instead
+	duplicate      string       // Open todo the new todo's description closely matches, offered to jump to instead
+	keepDuplicate  bool         // Create the todo anyway, chosen at the duplicate prompt
+	createNotes    string       // Notes for the new todo, written in the editor with ctrl+e in the create dialog
 	spinner        spinner.Model
 	loading        bool
+	mirroring      bool      // Todos are being copied to the GitHub Project by the hybrid backend
+	mirrorRetry    time.Time // When mirroring may run again after failing
+	stale          bool      // The list shows the worktrees cached by the last run until the first refresh
 	err            error
+	status         string // Transient confirmation shown below the list
+	statusID       int    // Incremented per status so stale clear messages are ignored
 	width          int
 	height         int
 	selectedWorktree string
@@ -39,9 +122,69 @@ type worktreeItem struct {
 	todo        *config.Todo
 	githubItem  *github.ProjectItem
 	isCheckedOut bool // true if there's a worktree for this item
+	issues      []git.HealthIssue
+	color       string // Accent colour assigned with C
+	expires     time.Time // When a scratch worktree is offered for cleanup, zero otherwise
+	pinned      bool      // Kept at the top of the list with *
+	aging       *config.Aging // Thresholds for the todo's age badge
+	check       *git.CheckResult // Last check_command result, nil when not checked
+	status      git.WorktreeStatus // Uncommitted changes and commits ahead of and behind
+	rebaseOnto  string             // Pull request base that has moved on enough to rebase onto, "" otherwise
+	pullRequest *github.PullRequestStatus // The branch's pull request, nil without one
+	marked      bool               // Picked with space or V for a bulk action
+	lastUsed    bool               // Its session was the one opened from lfg most recently
+	blocker     string             // Open todo the item's todo waits on, "" when it isn't blocked
+}
+
+// isUnlinkedTodo reports whether the item is a todo that has no worktree yet
+func (i worktreeItem) isUnlinkedTodo() bool {
+	return !i.isCheckedOut && i.githubItem == nil && i.todo != nil && i.todo.Worktree == ""
+}
+
+// pinKey identifies the item in the config's pinned list
+func (i worktreeItem) pinKey() string {
+	switch {
+	case i.isCheckedOut:
+		return i.worktree.Name()
+	case i.isUnlinkedTodo():
+		return todoPinKey(i.todo.Description)
+	case i.githubItem != nil:
+		return "github:" + i.githubItem.ID
+	}
+	return ""
+}
+
+// todoPinKey identifies a todo without a worktree in the config's pinned list
+func todoPinKey(description string) string {
+	return "todo:" + description
 }
 
 func (i worktreeItem) Title() string {
+	title := i.title()
+	if badge := ageBadge(i.todo, i.aging, time.Now()); badge != "" {
+		title += " " + badge
+	}
+	if i.pinned {
+		title = "★ " + title
+	}
+	if i.lastUsed {
+		title += " " + helpStyle.Render("↩ last")
+	}
+	if i.blocker != "" {
+		title = helpStyle.Render("⛓ " + title)
+	}
+	if i.marked {
+		return highlightStyle.Render("◆") + " " + title
+	}
+	return title
+}
+
+func (i worktreeItem) title() string {
+	// Captured todo without worktree
+	if i.isUnlinkedTodo() {
+		return fmt.Sprintf("○ %s", i.todo.Description)
+	}
+
 	// GitHub item without worktree
 	if i.githubItem != nil && !i.isCheckedOut {
 		status := "○"
@@ -52,11 +195,23 @@ func (i worktreeItem) Title() string {
 	}
 
 	// Worktree with or without todo
-	name := git.GetWorktreeName(i.worktree.Path)
+	name := i.worktree.DisplayName()
+	if len(i.issues) > 0 {
+		name = "⚠ " + name
+	}
+	if i.check != nil {
+		name += " " + checkBadge(i.check)
+	}
+	if i.color != "" {
+		name = colorSwatch(i.color) + " " + name
+	}
 	if i.todo != nil {
 		status := "○"
-		if i.todo.Status == config.TodoStatusDone {
+		switch i.todo.Status {
+		case config.TodoStatusDone:
 			status = "✓"
+		case config.TodoStatusInReview:
+			status = "◐"
 		}
 		return fmt.Sprintf("%s %s - %s", status, name, i.todo.Description)
 	}
@@ -71,6 +226,13 @@ func (i worktreeItem) Title() string {
 }
 
 func (i worktreeItem) Description() string {
+	if i.isUnlinkedTodo() && i.blocker != "" {
+		return helpStyle.Render("Blocked by: " + i.blocker)
+	}
+	if i.isUnlinkedTodo() {
+		return "No worktree yet | Enter: start"
+	}
+
 	// GitHub item without worktree
 	if i.githubItem != nil && !i.isCheckedOut {
 		statusText := ""
@@ -78,14 +240,35 @@ func (i worktreeItem) Description() string {
 			statusText = fmt.Sprintf("Status: %s", i.githubItem.Status)
 		}
 		if i.githubItem.Content.Number > 0 {
-			return fmt.Sprintf("Issue #%d | %s", i.githubItem.Content.Number, statusText)
+			return fmt.Sprintf("%s | %s", i.issueRef(), statusText)
 		}
 		return statusText
 	}
 
+	// Broken worktrees show what's wrong instead of their branch
+	if len(i.issues) > 0 {
+		return fmt.Sprintf("%s | !: details", i.issues[0].Summary)
+	}
+
+	if i.isScratch() {
+		return scratchDescription(i.expires)
+	}
+
 	// Worktree
 	if i.worktree.Branch != "" {
 		branch := strings.TrimPrefix(i.worktree.Branch, "refs/heads/")
+		if i.githubItem != nil && i.githubItem.Content.Number > 0 {
+			branch = fmt.Sprintf("%s | %s", branch, i.issueRef())
+		}
+		if badge := statusBadge(i.status); badge != "" {
+			branch += " " + badge
+		}
+		if i.pullRequest != nil {
+			branch += " " + pullRequestBadge(i.pullRequest)
+		}
+		if i.rebaseOnto != "" {
+			branch += " " + warningStyle.Render(i18n.T("rebase.needed", i.rebaseOnto))
+		}
 		if i.githubItem != nil && i.githubItem.Status != "" {
 			return fmt.Sprintf("Branch: %s | Status: %s", branch, i.githubItem.Status)
 		}
@@ -94,11 +277,37 @@ func (i worktreeItem) Description() string {
 	return i.worktree.Path
 }
 
-func (i worktreeItem) FilterValue() string {
+// issueRef renders the linked issue number, as a clickable OSC 8 link where supported
+func (i worktreeItem) issueRef() string {
+	return terminal.Hyperlink(i.githubItem.Content.URL, fmt.Sprintf("Issue #%d", i.githubItem.Content.Number))
+}
+
+// label names the item in messages and when sorting by name
+func (i worktreeItem) label() string {
+	if i.isUnlinkedTodo() {
+		return i.todo.Description
+	}
 	if i.githubItem != nil && !i.isCheckedOut {
 		return i.githubItem.Title
 	}
-	return git.GetWorktreeName(i.worktree.Path)
+	return i.worktree.DisplayName()
+}
+
+// FilterValue is what / matches against: the worktree name first, then its branch and todo or issue
+func (i worktreeItem) FilterValue() string {
+	value := i.label()
+	if !i.isCheckedOut {
+		return value
+	}
+	if branch := i.worktree.BranchName(); branch != "" && branch != value {
+		value += " " + branch
+	}
+	if i.todo != nil {
+		value += " " + i.todo.Description
+	} else if i.githubItem != nil {
+		value += " " + i.githubItem.Title
+	}
+	return value
 }
 
 var (
@@ -114,6 +323,15 @@ var (
 	errorStyle = lipgloss.NewStyle().
 			Foreground(lipgloss.Color("196")).
 			Bold(true)
+
+	statusStyle = lipgloss.NewStyle().
+			Foreground(lipgloss.Color("86"))
+
+	highlightStyle = lipgloss.NewStyle().
+			Foreground(lipgloss.Color("86"))
+
+	warningStyle = lipgloss.NewStyle().
+			Foreground(lipgloss.Color("214"))
 )
 
 type Result struct {
@@ -121,49 +339,101 @@ type Result struct {
 	ExitToMain       bool
 }
 
-func Run(cfg *config.Config) (*Result, error) {
-	// Check tmux
-	if !tmux.IsInstalled() {
-		return nil, fmt.Errorf("tmux is not installed")
+func Run(cfg *config.Config, view View) (*Result, error) {
+	// Check the multiplexer sessions run in
+	if mux := multiplexer.Current(); !mux.IsInstalled() {
+		return nil, fmt.Errorf("%s is not installed", mux.Name())
+	}
+	if err := view.validate(); err != nil {
+		return nil, err
+	}
+
+	// Paint the worktrees from the last run straight away and list them again in the background,
+	// listing them first only when there's nothing cached
+	var worktrees []git.Worktree
+	if cfg.GetConfigPath() != "" {
+		worktrees = git.CachedWorktrees(cacheKey(cfg))
+	}
+	stale := worktrees != nil
+	if !stale {
+		var err error
+		worktrees, err = git.ListWorktrees()
+		if err != nil {
+			return nil, err
+		}
+		saveWorktreeCache(cfg, worktrees)
 	}
 
 	// Get current worktree if we're in one
-	currentWorktree, err := git.GetCurrentWorktree()
-	if err != nil {
-		// Non-fatal, just log
-		fmt.Fprintf(os.Stderr, "Warning: failed to detect current worktree: %v\n", err)
+	currentWorktree := git.CurrentWorktreeIn(worktrees)
+
+	var settings config.Settings
+	if cfg.Settings != nil {
+		settings = *cfg.Settings
+	}
+	applyTheme(settings.Theme)
+	if err := i18n.SetLanguage(i18n.Language(settings.Language)); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
 	}
 
-	// Get worktrees
-	worktrees, err := git.ListWorktrees()
+	// Pick up sessions older versions started under bare worktree names, so they show as running
+	names := make([]string, 0, len(worktrees))
+	for _, wt := range worktrees {
+		names = append(names, wt.Name())
+	}
+	multiplexer.AdoptLegacySessions(cfg.Name, names...)
+
+	// Bring over this repository's todos from the global config, where older versions kept them
+	moved, left, err := worktree.MigrateGlobalTodos(cfg, worktrees)
 	if err != nil {
-		return nil, err
+		fmt.Fprintf(os.Stderr, "Warning: failed to move todos from the global config: %v\n", err)
+	} else if moved > 0 {
+		fmt.Fprintf(os.Stderr, "Moved %d todo(s) from the global config into %s\n", moved, cfg.GetConfigPath())
+	}
+	if left > 0 {
+		globalPath, _ := config.GlobalPath()
+		fmt.Fprintf(os.Stderr, "Warning: %d todo(s) in %s aren't shown, todos are kept per repository. Ones linked to another repository's worktree move there when lfg opens it, move the rest into a %s by hand\n", left, globalPath, filepath.Base(cfg.GetConfigPath()))
+	}
+
+	// Put away the todos done archive_after days ago, before the list items point into the todos
+	if cfg.ArchiveDone(time.Now(), checkedOutIn(worktrees)) > 0 {
+		if err := cfg.Save(); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
 	}
 
 	// Create initial list items for worktrees (without GitHub data)
+	checks := cachedChecks(cfg, worktrees)
+	lastUsed := recentWorktree(cfg)
 	items := make([]list.Item, 0, len(worktrees))
-	currentWorktreeIndex := -1
-
 	for _, wt := range worktrees {
-		name := git.GetWorktreeName(wt.Path)
+		name := wt.Name()
 		todo := cfg.GetTodoForWorktree(name)
 
-		// Check if this is the current worktree
-		if currentWorktree != "" && name == currentWorktree {
-			currentWorktreeIndex = len(items)
-		}
-
 		items = append(items, worktreeItem{
 			worktree:    wt,
 			todo:        todo,
 			githubItem:  nil,
 			isCheckedOut: true,
+			color:       cfg.WorktreeColor(name),
+			check:       checks[name],
+			lastUsed:    name == lastUsed,
+			expires:     scratchExpiry(cfg, wt),
 		})
 	}
+	items = append(items, unlinkedTodoItems(cfg)...)
+	items = arrangeItems(cfg, view, mainWorktreeName(worktrees), items)
+
+	// Find the current worktree so it can be selected
+	currentWorktreeIndex := -1
+	for i, it := range items {
+		if item, ok := it.(worktreeItem); ok && item.isCheckedOut && currentWorktree != "" && item.worktree.Name() == currentWorktree {
+			currentWorktreeIndex = i
+		}
+	}
 
 	// Create list
-	delegate := list.NewDefaultDelegate()
-	delegate.ShowDescription = true
+	delegate := newDelegate(settings.Theme)
 	l := list.New(items, delegate, 80, 20) // Initial size, will be updated by WindowSizeMsg
 	l.Title = "" // No title - we show it in our custom header
 	l.SetShowTitle(false)
@@ -173,15 +443,111 @@ func Run(cfg *config.Config) (*Result, error) {
 		return []key.Binding{
 			key.NewBinding(
 				key.WithKeys("n", "c"),
-				key.WithHelp("n/c", "new"),
+				key.WithHelp("n/c", i18n.T("keys.new")),
 			),
 			key.NewBinding(
 				key.WithKeys("d"),
-				key.WithHelp("d", "delete"),
+				key.WithHelp("d", i18n.T("keys.delete")),
+			),
+			key.NewBinding(
+				key.WithKeys("s"),
+				key.WithHelp("s", i18n.T("keys.repurpose")),
+			),
+			key.NewBinding(
+				key.WithKeys("M"),
+				key.WithHelp("M", i18n.T("keys.rename")),
+			),
+			key.NewBinding(
+				key.WithKeys("P"),
+				key.WithHelp("P", i18n.T("keys.open_pr")),
+			),
+			key.NewBinding(
+				key.WithKeys("L"),
+				key.WithHelp("L", i18n.T("keys.blocker")),
+			),
+			key.NewBinding(
+				key.WithKeys("A", "a"),
+				key.WithHelp("A/a", i18n.T("keys.archive")),
+			),
+			key.NewBinding(
+				key.WithKeys("[", "]"),
+				key.WithHelp("[/]", i18n.T("keys.history")),
+			),
+			key.NewBinding(
+				key.WithKeys("p"),
+				key.WithHelp("p", i18n.T("keys.pick")),
 			),
 			key.NewBinding(
 				key.WithKeys("r"),
-				key.WithHelp("r", "refresh"),
+				key.WithHelp("r", i18n.T("keys.refresh")),
+			),
+			key.NewBinding(
+				key.WithKeys(","),
+				key.WithHelp(",", i18n.T("keys.settings")),
+			),
+			key.NewBinding(
+				key.WithKeys("!"),
+				key.WithHelp("!", i18n.T("keys.health")),
+			),
+			key.NewBinding(
+				key.WithKeys("e"),
+				key.WithHelp("e", i18n.T("keys.ide")),
+			),
+			key.NewBinding(
+				key.WithKeys("C"),
+				key.WithHelp("C", i18n.T("keys.colour")),
+			),
+			key.NewBinding(
+				key.WithKeys("N"),
+				key.WithHelp("N", i18n.T("keys.notes")),
+			),
+			key.NewBinding(
+				key.WithKeys("m"),
+				key.WithHelp("m", i18n.T("keys.commit")),
+			),
+			key.NewBinding(
+				key.WithKeys("E"),
+				key.WithHelp("E", i18n.T("keys.config")),
+			),
+			key.NewBinding(
+				key.WithKeys("v"),
+				key.WithHelp("v", i18n.T("keys.review")),
+			),
+			key.NewBinding(
+				key.WithKeys("t"),
+				key.WithHelp("t", i18n.T("keys.teammate")),
+			),
+			key.NewBinding(
+				key.WithKeys("*"),
+				key.WithHelp("*", i18n.T("keys.pin")),
+			),
+			key.NewBinding(
+				key.WithKeys("i"),
+				key.WithHelp("i", i18n.T("keys.session")),
+			),
+			key.NewBinding(
+				key.WithKeys("x"),
+				key.WithHelp("x", i18n.T("keys.restart")),
+			),
+			key.NewBinding(
+				key.WithKeys("o"),
+				key.WithHelp("o", i18n.T("keys.output")),
+			),
+			key.NewBinding(
+				key.WithKeys("R"),
+				key.WithHelp("R", i18n.T("keys.reconcile")),
+			),
+			key.NewBinding(
+				key.WithKeys("K"),
+				key.WithHelp("K", i18n.T("keys.trust")),
+			),
+			key.NewBinding(
+				key.WithKeys(" ", "V"),
+				key.WithHelp("space/V", i18n.T("keys.mark")),
+			),
+			key.NewBinding(
+				key.WithKeys("y", "b", "u"),
+				key.WithHelp("y/b/u", i18n.T("keys.copy")),
 			),
 		}
 	}
@@ -190,12 +556,12 @@ func Run(cfg *config.Config) (*Result, error) {
 	if currentWorktreeIndex >= 0 {
 		l.Select(currentWorktreeIndex)
 	}
+	if view.Filter != "" {
+		l.SetFilterText(view.Filter)
+	}
 
 	// Create text input for new worktree
-	ti := textinput.New()
-	ti.Placeholder = cfg.WorktreeNaming
-	ti.CharLimit = 100
-	ti.Width = 50
+	ti := newTextInput(cfg.WorktreeNaming)
 
 	// Create spinner
 	s := spinner.New()
@@ -203,15 +569,35 @@ func Run(cfg *config.Config) (*Result, error) {
 	s.Style = lipgloss.NewStyle().Foreground(lipgloss.Color("205"))
 
 	m := &model{
-		config:    cfg,
-		worktrees: worktrees,
-		list:      l,
-		textInput: ti,
-		spinner:   s,
-		loading:   cfg.StorageBackend != nil && cfg.StorageBackend.Type == "github",
+		config:         cfg,
+		view:           view,
+		worktrees:      worktrees,
+		list:           l,
+		delegate:       delegate,
+		textInput:      ti,
+		nameInput:      newTextInput(""),
+		windowInput:    newTextInput(""),
+		spinner:        s,
+		loading:        cfg.StorageBackend != nil && cfg.StorageBackend.Type == "github",
+		checks:         checks,
+		lastUsed:       lastUsed,
+		history:        loadHistory(cfg),
+		waiting:        blockedTodos(cfg),
+		stale:          stale,
 	}
+	if !stale {
+		m.cleanup = expiredScratch(cfg, worktrees, currentWorktree)
+	}
+
+	// The TUI owns stdout, so --events-json output is written once it exits
+	release := events.Hold()
+	defer release()
 
-	p := tea.NewProgram(m, tea.WithAltScreen())
+	options := []tea.ProgramOption{tea.WithAltScreen(), tea.WithFPS(maxFPS(settings))}
+	if settings.Mouse {
+		options = append(options, tea.WithMouseCellMotion())
+	}
+	p := tea.NewProgram(m, options...)
 	finalModel, err := p.Run()
 	if err != nil {
 		return nil, err
@@ -226,11 +612,21 @@ func Run(cfg *config.Config) (*Result, error) {
 }
 
 func (m *model) Init() tea.Cmd {
+	setTitle := tea.SetWindowTitle("lfg: " + m.config.Name)
+
 	// Start spinner and fetch GitHub data if configured
 	if m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
-		return tea.Batch(m.spinner.Tick, m.fetchGithubItems)
+		fetch := m.fetchGithubItems
+		if m.stale {
+			fetch = m.refreshAll
+		}
+		return tea.Batch(setTitle, m.scheduleRefresh(), m.syncReviews(), m.checkClosedReviews(), m.spinner.Tick, fetch)
+	}
+	if m.stale {
+		// The refresh runs the health checks once the listing is current
+		return tea.Batch(setTitle, m.scheduleRefresh(), m.syncReviews(), m.checkClosedReviews(), m.refreshWorktrees)
 	}
-	return nil
+	return tea.Batch(setTitle, m.scheduleRefresh(), m.syncReviews(), m.checkClosedReviews(), m.checkHealth(), m.mirrorTodos())
 }
 
 type githubItemsMsg struct {
@@ -254,28 +650,215 @@ func (m *model) fetchGithubItems() tea.Msg {
 func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 	switch msg := msg.(type) {
 	case spinner.TickMsg:
+		// Let the spinner stop once loading is done, rather than redrawing an idle screen forever
+		if !m.loading {
+			return m, nil
+		}
 		var cmd tea.Cmd
 		m.spinner, cmd = m.spinner.Update(msg)
 		return m, cmd
 
 	case githubItemsMsg:
 		m.loading = false
+		m.settleStartup()
 		if msg.err != nil {
 			m.err = fmt.Errorf("failed to fetch GitHub items: %w", msg.err)
 		} else if msg.items != nil {
 			// Merge GitHub items with existing worktree items
 			m.mergeGithubItems(msg.items)
 		}
+		return m, m.checkHealth()
+
+	case healthMsg:
+		m.health = msg.health
+		m.statuses = msg.statuses
+		m.applyHealth()
+		return m, m.runChecks()
+
+	case checkMsg:
+		return m, m.applyCheck(msg)
+
+	case reviewMsg:
+		return m, m.applyReviews(msg)
+
+	case rebasedMsg:
+		return m, m.applyRebase(msg)
+
+	case setupMsg:
+		return m, m.applySetup(msg)
+
+	case safetyMsg:
+		m.safety = msg.safety
+		if m.deleteUnasked {
+			return m.deleteWhenChecked(msg.safety)
+		}
+		return m, nil
+
+	case previewMsg:
+		return m, m.applyPreview(msg)
+
+	case previewTickMsg:
+		if !m.preview || msg.id != m.previewID {
+			return m, nil
+		}
+		return m, m.capturePreview()
+
+	case editedMsg:
+		return m, m.applyEdited(msg)
+
+	case committedMsg:
+		if msg.err != nil {
+			m.err = fmt.Errorf("git commit in %s failed: %w", msg.worktree, msg.err)
+			return m, nil
+		}
+		return m, tea.Batch(m.setStatus(i18n.T("commit.done", msg.worktree)), m.refresh())
+
+	case openedMsg:
+		if msg.err != nil {
+			m.err = fmt.Errorf("editor failed in %s: %w", msg.worktree, msg.err)
+		}
+		return m, nil
+
+	case pushedMsg:
+		if msg.err != nil {
+			m.err = fmt.Errorf("created the worktree, but %w", msg.err)
+			return m, nil
+		}
+		return m, m.setStatus(i18n.T("push.done", msg.branch))
+
+	case closedReviewsMsg:
+		m.offerCleanup(msg.candidates)
+		return m, nil
+
+	case pullRequestsMsg:
+		if msg.err != nil {
+			m.err = msg.err
+		} else if len(msg.prs) == 0 {
+			return m, m.setStatus(i18n.T("reviews.none"))
+		}
+		m.pullRequests = msg.prs
+		m.prCursor = 0
+		return m, nil
+
+	case remoteBranchesMsg:
+		if msg.err != nil {
+			m.err = msg.err
+			return m, nil
+		}
+		if len(msg.branches) == 0 {
+			return m, m.setStatus(i18n.T("remote.none"))
+		}
+		m.remoteBranches = msg.branches
+		m.remoteFilter = ""
+		m.remoteCursor = 0
 		return m, nil
 
+	case bulkPlannedMsg:
+		return m, m.applyBulkPlan(msg.op)
+
+	case effectMsg:
+		return m, m.applyEffect(msg)
+
+	case reviewCreatedMsg:
+		return m, m.applyReviewCreated(msg)
+
 	case tea.KeyMsg:
+		if m.busy && msg.String() != "ctrl+c" {
+			return m, nil
+		}
 		// Handle text input mode
 		if m.creating {
+			if m.pickingBranch {
+				return m.updateBranchPicker(msg)
+			}
+			if m.trackRemote != nil {
+				return m.updateTrackRemote(msg)
+			}
+			if m.duplicate != "" {
+				return m.updateDuplicate(msg)
+			}
 			switch msg.String() {
 			case "enter":
 				return m.handleCreateWorktree()
 			case "esc":
 				m.creating = false
+				m.createFrom = nil
+				m.createNotes = ""
+				m.textInput.SetValue("")
+				m.resetName()
+				return m, nil
+			case "ctrl+b":
+				return m, m.openBranchPicker()
+			case "ctrl+e":
+				text := m.textInput.Value()
+				if m.createNotes != "" {
+					text += "\n\n" + m.createNotes
+				}
+				return m, editText(editDescription, nil, text)
+			case "ctrl+p":
+				m.pushOnCreate = !m.pushOnCreate
+				return m, nil
+			case "tab", "shift+tab":
+				return m, m.focusName(!m.editingName)
+			case "up", "down":
+				// Cycle the todo type picker, or move between the fields without one
+				types := m.todoTypes()
+				if len(types) == 0 {
+					return m, m.focusName(msg.String() == "down")
+				}
+				if msg.String() == "down" {
+					m.todoType = (m.todoType + 1) % len(types)
+				} else {
+					m.todoType = (m.todoType + len(types) - 1) % len(types)
+				}
+				return m, nil
+			default:
+				var cmd tea.Cmd
+				if m.editingName {
+					m.nameInput, cmd = m.nameInput.Update(msg)
+					// Clearing the name goes back to generating it
+					name := m.nameInput.Value()
+					m.nameEdited = name != "" && name != git.GenerateWorktreeName(m.config.Name, m.textInput.Value(), m.config.Naming)
+					return m, cmd
+				}
+				m.textInput, cmd = m.textInput.Update(msg)
+				return m, cmd
+			}
+		}
+
+		if m.openingPR != nil {
+			return m.updateOpenPR(msg)
+		}
+		if m.pickingBlocker != nil {
+			return m.updateBlockerPick(msg)
+		}
+		if m.archive != nil {
+			return m.updateArchive(msg)
+		}
+
+		// Handle rename input mode
+		if m.renaming {
+			switch msg.String() {
+			case "enter":
+				return m.handleRenameWorktree()
+			case "esc":
+				m.renaming = false
+				m.textInput.SetValue("")
+				return m, nil
+			default:
+				var cmd tea.Cmd
+				m.textInput, cmd = m.textInput.Update(msg)
+				return m, cmd
+			}
+		}
+
+		// Handle repurpose input mode
+		if m.repurposing {
+			switch msg.String() {
+			case "enter":
+				return m.handleRepurposeWorktree()
+			case "esc":
+				m.repurposing = false
 				m.textInput.SetValue("")
 				return m, nil
 			default:
@@ -285,11 +868,97 @@ func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 			}
 		}
 
+		if m.editingSettings {
+			return m.updateSettings(msg)
+		}
+
+		if m.showingHealth {
+			return m.updateHealth(msg)
+		}
+
+		if m.sessionInfo != nil {
+			return m.updateSessionInfo(msg)
+		}
+
+		if len(m.cleanup) > 0 {
+			return m.updateCleanup(msg)
+		}
+
+		if m.bulk != nil {
+			return m.updateBulk(msg)
+		}
+
+		if len(m.pullRequests) > 0 {
+			return m.updatePullRequests(msg)
+		}
+
+		if len(m.remoteBranches) > 0 {
+			return m.updateRemoteBranches(msg)
+		}
+
+		if m.setup != nil {
+			return m.updateSetup(msg)
+		}
+
+		if m.rebasing != nil {
+			return m.updateRebase(msg)
+		}
+
+		// Handle the abort prompt for an unfinished rebase/merge
+		if m.blockedBy != nil {
+			switch msg.String() {
+			case "a", "A", "y", "Y":
+				blocked := m.blockedBy
+				m.blockedBy = nil
+				if err := git.AbortOperation(blocked.Path); err != nil {
+					m.err = err
+					return m, nil
+				}
+				return m, tea.Batch(m.setStatus(i18n.T("blocked.aborted", blocked.Operation)), m.refreshWorktrees)
+			case "n", "N", "esc":
+				m.blockedBy = nil
+			}
+			return m, nil
+		}
+
+		// Handle transfer target picker
+		if m.transferring {
+			targets := m.transferTargets()
+			switch key := msg.String(); key {
+			case "enter":
+				return m.handleTransfer()
+			case "esc":
+				m.transferring = false
+				return m, nil
+			case "up", "k":
+				if len(targets) > 0 {
+					m.transferTarget = (m.transferTarget + len(targets) - 1) % len(targets)
+				}
+			case "down", "j":
+				if len(targets) > 0 {
+					m.transferTarget = (m.transferTarget + 1) % len(targets)
+				}
+			case "0", "1", "2", "3", "4", "5", "6", "7", "8", "9":
+				m.transferCommits = int(key[0] - '0')
+			}
+			return m, nil
+		}
+
 		// Handle delete confirmation
 		if m.deleting {
 			switch msg.String() {
 			case "y", "Y":
-				return m.handleDeleteWorktree()
+				// The branch could hold unpushed commits until the safety report says otherwise
+				if !m.safetyLoaded() {
+					return m, nil
+				}
+				return m.confirmDeleteStep()
+			case "k", "K":
+				// Delete the worktree but keep the branch with unpushed commits
+				if m.deleteStep == confirmBranch {
+					m.deleteOpts.DeleteBranch = false
+					return m.handleDeleteWorktree()
+				}
 			case "n", "N", "esc":
 				m.deleting = false
 				return m, nil
@@ -297,6 +966,15 @@ func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 			return m, nil
 		}
 
+		// While filtering, keys narrow the list instead of triggering shortcuts
+		// Enter accepts the filter and jumps to the selected match, like fzf
+		if m.list.FilterState() == list.Filtering {
+			if msg.String() != "enter" {
+				break
+			}
+			m.list, _ = m.list.Update(msg)
+		}
+
 		// Normal mode
 		switch msg.String() {
 		case "ctrl+c", "q":
@@ -309,11 +987,16 @@ func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 					return m.handleCreateWorktreeFromGithub(item.githubItem)
 				}
 
+				// If it's a captured todo without a worktree, start one
+				if item.isUnlinkedTodo() {
+					return m.handleStartTodo(item.todo)
+				}
+
 				// Check if this is the main worktree (first in the list)
-				name := git.GetWorktreeName(item.worktree.Path)
+				name := item.worktree.Name()
 				isMainWorktree := false
 				if len(m.worktrees) > 0 {
-					mainName := git.GetWorktreeName(m.worktrees[0].Path)
+					mainName := m.worktrees[0].Name()
 					isMainWorktree = (name == mainName)
 				}
 
@@ -331,54 +1014,330 @@ func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 
 		case "n", "c":
 			m.creating = true
+			m.createFrom = nil
+			m.keepDuplicate = false
+			m.pushOnCreate = m.config.PushOnCreate()
+			m.resetName()
 			m.textInput.SetValue(m.config.WorktreeNaming)
-			m.textInput.Focus()
 			m.textInput.CursorEnd()
-			return m, nil
+			return m, m.textInput.Focus()
 
 		case "d":
+			if len(m.marked) > 0 {
+				return m, m.startBulk(bulkDelete)
+			}
+			m.deleteStep = confirmDelete
+			m.deleteOpts = worktree.DeleteOptions{DeleteBranch: true, RemoveTodo: true}
+			m.safety = nil
+			item, ok := m.list.SelectedItem().(worktreeItem)
+			checkedOut := ok && item.isCheckedOut
+			if m.config.Settings != nil && m.config.Settings.SkipConfirmations {
+				if !checkedOut {
+					return m.handleDeleteWorktree()
+				}
+				m.deleteUnasked = true
+				return m, checkSafety(item.worktree)
+			}
 			m.deleting = true
+			if checkedOut {
+				return m, checkSafety(item.worktree)
+			}
+			return m, nil
+
+		case "!":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && len(item.issues) > 0 {
+				m.showingHealth = true
+			}
+			return m, nil
+
+		case ",":
+			m.editingSettings = true
+			m.settingsCursor = 0
+			return m, nil
+
+		case "p":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				if len(m.worktrees) < 2 {
+					m.err = fmt.Errorf("no other worktree to transfer changes into")
+					return m, nil
+				}
+				m.transferring = true
+				m.transferTarget = 0
+				m.transferCommits = 1
+			}
+			return m, nil
+
+		case "s":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				if len(m.worktrees) > 0 && item.worktree.Name() == m.worktrees[0].Name() {
+					m.err = fmt.Errorf("the main worktree can't be repurposed")
+					return m, nil
+				}
+				m.repurposing = true
+				m.textInput.SetValue("")
+				return m, m.textInput.Focus()
+			}
+			return m, nil
+
+		case "L":
+			return m, m.startBlockerPick()
+
+		case "A":
+			return m, m.archiveSelected()
+
+		case "a":
+			m.archive = &archiveView{}
+			return m, nil
+
+		case "M":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				if item.worktree.Name() == mainWorktreeName(m.worktrees) {
+					m.err = fmt.Errorf("the main worktree can't be renamed")
+					return m, nil
+				}
+				m.renaming = true
+				m.textInput.SetValue(item.worktree.Name())
+				return m, m.textInput.Focus()
+			}
+			return m, nil
+
+		case "e":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				if cmd := ide.TerminalCommand(item.worktree.Path, m.config); cmd != nil {
+					return m, openIn(cmd, item.worktree)
+				}
+				path, cfg := item.worktree.Path, m.config
+				return m, effect(func() (string, error) { return "", ide.Open(path, cfg) })
+			}
+			return m, nil
+
+		case "y", "b", "u":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok {
+				return m, m.copySelection(item, msg.String())
+			}
+			return m, nil
+
+		case "N":
+			if todo := m.selectedTodo(); todo != nil {
+				return m, editText(editNotes, todo, todo.Notes)
+			}
+			return m, m.setStatus(i18n.T("notes.only_todos"))
+
+		case "m":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				return m, commitIn(item.worktree)
+			}
+			return m, nil
+
+		case "E":
+			return m, m.editConfigFile()
+
+		case "C":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				return m, m.cycleColor(item)
+			}
+			return m, nil
+
+		case "i":
+			m.showSessionInfo()
+			return m, nil
+
+		case "x":
+			return m, m.restartCrashed()
+
+		case " ":
+			return m, m.toggleMark()
+
+		case "V":
+			return m, m.markRange()
+
+		case "D":
+			if len(m.marked) > 0 {
+				return m, m.startBulk(bulkDone)
+			}
+			return m, nil
+
+		case "P":
+			if len(m.marked) > 0 {
+				return m, m.startBulk(bulkPrune)
+			}
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok {
+				return m, m.startOpenPR(item)
+			}
+			return m, nil
+
+		case "esc":
+			if len(m.marked) > 0 || m.markAnchor != nil {
+				m.clearMarks()
+				return m, m.setStatus(i18n.T("bulk.cleared"))
+			}
+
+		case "o":
+			return m, m.togglePreview()
+
+		case "tab":
+			if m.preview {
+				return m, m.cyclePreviewPane()
+			}
+			return m, nil
+
+		case "[":
+			return m, m.stepHistory(-1)
+
+		case "]":
+			return m, m.stepHistory(1)
+
+		case "*":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.pinKey() != "" {
+				status := i18n.T("pin.removed", item.label())
+				if m.config.TogglePin(item.pinKey()) {
+					status = i18n.T("pin.added", item.label())
+				}
+				if err := m.config.Save(); err != nil {
+					m.err = fmt.Errorf("failed to save config: %w", err)
+					return m, nil
+				}
+				return m, tea.Batch(m.setStatus(status), m.refresh())
+			}
+			return m, nil
+
+		case "v":
+			return m, tea.Batch(m.setStatus(i18n.T("reviews.fetching")), fetchPullRequests)
+
+		case "t":
+			return m, tea.Batch(m.setStatus(i18n.T("remote.fetching")), fetchRemoteBranches)
+
+		case "B":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut && item.rebaseOnto != "" {
+				return m, m.startRebase(item)
+			}
+			return m, nil
+
+		case "K":
+			return m, m.toggleChecksTrusted()
+
+		case "R":
+			if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isCheckedOut {
+				if err := multiplexer.RequireTmux("Reconciling the layout"); err != nil {
+					m.err = err
+					return m, nil
+				}
+				wt, cfg := item.worktree, m.config
+				return m, effect(func() (string, error) {
+					result, err := tmux.Reconcile(wt.Name(), wt.Path, cfg, false)
+					if err != nil {
+						return "", err
+					}
+					if len(result.Added) == 0 {
+						return i18n.T("reconcile.complete"), nil
+					}
+					return i18n.T("reconcile.added", strings.Join(result.Added, ", ")), nil
+				})
+			}
 			return m, nil
 
 		case "r":
+			// A refresh asked for reads every status again rather than showing cached ones, and checks again
+			m.checksRan = time.Time{}
+			if err := git.NewStatusCache(cacheKey(m.config)).Invalidate(); err != nil {
+				m.err = err
+			}
 			// Show spinner if GitHub is configured
 			if m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
 				m.loading = true
-				return m, tea.Batch(m.spinner.Tick, m.refreshAll)
+				return m, tea.Batch(m.spinner.Tick, m.refreshAll, m.syncReviews())
 			}
-			return m, m.refreshWorktrees
+			return m, tea.Batch(m.refreshWorktrees, m.syncReviews())
 		}
 
 	case tea.WindowSizeMsg:
 		m.width = msg.Width
 		m.height = msg.Height
-		// Account for header (2 lines) + potential error line (1 line)
-		m.list.SetSize(msg.Width, msg.Height-3)
+		m.resizeList()
+
+	case tea.MouseMsg:
+		return m.updateMouse(msg)
 
 	case refreshMsg:
 		m.worktrees = msg.worktrees
+		m.settleStartup()
+		m.lastUsed = recentWorktree(m.config)
+		m.history = loadHistory(m.config)
 		// Just update worktrees list with current items (no GitHub fetch)
 		items := make([]list.Item, 0, len(m.worktrees))
 		for _, wt := range m.worktrees {
-			name := git.GetWorktreeName(wt.Path)
+			name := wt.Name()
 			todo := m.config.GetTodoForWorktree(name)
 			items = append(items, worktreeItem{
 				worktree:    wt,
 				todo:        todo,
 				githubItem:  nil,
 				isCheckedOut: true,
+				issues:      m.health[name],
+				color:       m.config.WorktreeColor(name),
+				check:       m.checks[name],
+				status:      m.statuses[name],
+				rebaseOnto:  m.rebaseOnto(name),
+				pullRequest: m.prStatuses[name],
+				lastUsed:    name == m.lastUsed,
+				expires:     scratchExpiry(m.config, wt),
 			})
 		}
-		m.list.SetItems(items)
-		return m, nil
+		items = append(items, unlinkedTodoItems(m.config)...)
+		m.list.SetItems(arrangeItems(m.config, m.view, mainWorktreeName(m.worktrees), items))
+		m.applyMarks()
+		m.notifyUnblocked()
+		return m, tea.Batch(m.checkHealth(), m.mirrorTodos())
+
+	case autoRefreshMsg:
+		// Ticks from before the interval changed are dropped, their replacement is already scheduled
+		if msg.id != m.refreshID {
+			return m, nil
+		}
+		if m.busy {
+			return m, m.scheduleRefresh()
+		}
+		return m, tea.Batch(m.refresh(), m.scheduleRefresh(), m.syncReviewsEvery(reviewSyncInterval))
+
+	case mirroredMsg:
+		return m, m.handleMirrored(msg)
+
+	case prOpenedMsg:
+		return m, m.applyOpenedPR(msg)
 
 	case errMsg:
 		m.err = msg.err
 		return m, nil
+
+	case copiedMsg:
+		if msg.err != nil {
+			m.err = msg.err
+			return m, nil
+		}
+		return m, m.setStatus(i18n.T("copy.done", msg.label))
+
+	case clearStatusMsg:
+		if msg.id == m.statusID {
+			m.status = ""
+		}
+		return m, nil
+	}
+
+	// Keep the focused input's cursor blinking
+	if m.renamingWindow {
+		var cmd tea.Cmd
+		m.windowInput, cmd = m.windowInput.Update(msg)
+		return m, cmd
+	}
+	if m.creating || m.repurposing || m.renaming || m.openingPR != nil {
+		var cmd, nameCmd tea.Cmd
+		m.textInput, cmd = m.textInput.Update(msg)
+		m.nameInput, nameCmd = m.nameInput.Update(msg)
+		return m, tea.Batch(cmd, nameCmd)
 	}
 
 	// Update list
-	if !m.creating && !m.deleting {
+	if !m.inModal() {
 		var cmd tea.Cmd
 		m.list, cmd = m.list.Update(msg)
 		return m, cmd
@@ -387,7 +1346,19 @@ func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 	return m, nil
 }
 
+// inModal reports whether a dialog or popup has the keyboard instead of the list
+// Each mode's state is listed here, so a new mode can't leave the list handling its keys as well
+func (m *model) inModal() bool {
+	return m.creating || m.deleting || m.repurposing || m.renaming || m.openingPR != nil || m.pickingBlocker != nil || m.archive != nil || m.transferring || m.editingSettings || m.showingHealth ||
+		m.sessionInfo != nil || len(m.cleanup) > 0 || m.bulk != nil || len(m.pullRequests) > 0 || len(m.remoteBranches) > 0 ||
+		m.setup != nil || m.rebasing != nil || m.blockedBy != nil
+}
+
 func (m *model) View() string {
+	if m.creating && m.pickingBranch {
+		return m.viewBranchPicker()
+	}
+
 	if m.creating {
 		return m.viewCreateWorktree()
 	}
@@ -396,11 +1367,75 @@ func (m *model) View() string {
 		return m.viewDeleteConfirm()
 	}
 
+	if m.repurposing {
+		return m.viewRepurposeWorktree()
+	}
+
+	if m.renaming {
+		return m.viewRenameWorktree()
+	}
+	if m.openingPR != nil {
+		return m.viewOpenPR()
+	}
+	if m.pickingBlocker != nil {
+		return m.viewBlockerPick()
+	}
+	if m.archive != nil {
+		return m.viewArchive()
+	}
+
+	if m.transferring {
+		return m.viewTransfer()
+	}
+
+	if m.editingSettings {
+		return m.viewSettings()
+	}
+
+	if m.showingHealth {
+		return m.viewHealth()
+	}
+
+	if m.sessionInfo != nil {
+		return m.viewSessionInfo()
+	}
+
+	if len(m.cleanup) > 0 {
+		return m.viewCleanup()
+	}
+
+	if m.bulk != nil {
+		return m.viewBulk()
+	}
+
+	if len(m.pullRequests) > 0 {
+		return m.viewPullRequests()
+	}
+
+	if len(m.remoteBranches) > 0 {
+		return m.viewRemoteBranches()
+	}
+
+	if m.setup != nil {
+		return m.viewSetup()
+	}
+
+	if m.rebasing != nil {
+		return m.viewRebase()
+	}
+
+	if m.blockedBy != nil {
+		return m.viewBlocked()
+	}
+
 	// Build the view with header
 	var view strings.Builder
 
 	// Show header
-	header := titleStyle.Render("LFG - Git Worktrees")
+	header := titleStyle.Render(i18n.T("list.title"))
+	if crumbs := m.breadcrumbs(); crumbs != "" {
+		header = lipgloss.JoinHorizontal(lipgloss.Top, header, "  ", crumbs)
+	}
 	view.WriteString(header)
 	view.WriteString("\n")
 
@@ -414,8 +1449,17 @@ func (m *model) View() string {
 
 	view.WriteString("\n")
 
-	// Show list
-	view.WriteString(m.list.View())
+	// Show list, with a scrollbar beside its entries when they don't fit on one page
+	listView := m.list.View()
+	if bar := m.scrollbar(lipgloss.Height(listView)); bar != "" {
+		listView = lipgloss.JoinHorizontal(lipgloss.Top, lipgloss.NewStyle().Width(m.list.Width()).Render(listView), bar)
+	}
+	view.WriteString(listView)
+
+	if m.preview {
+		view.WriteString("\n")
+		view.WriteString(m.viewPreview())
+	}
 
 	// Show error if present
 	if m.err != nil {
@@ -423,6 +1467,15 @@ func (m *model) View() string {
 		view.WriteString(errorStyle.Render(fmt.Sprintf("Error: %v", m.err)))
 	}
 
+	// Show transient status (e.g. clipboard confirmation)
+	if m.busy {
+		view.WriteString("\n")
+		view.WriteString(statusStyle.Render(i18n.T("status.working")))
+	} else if m.status != "" {
+		view.WriteString("\n")
+		view.WriteString(statusStyle.Render(m.status))
+	}
+
 	return view.String()
 }
 
@@ -430,10 +1483,25 @@ func (m *model) mergeGithubItems(githubItems []github.ProjectItem) {
 	// Create a map of worktree names for quick lookup
 	worktreeMap := make(map[string]git.Worktree)
 	for _, wt := range m.worktrees {
-		name := git.GetWorktreeName(wt.Path)
+		name := wt.Name()
 		worktreeMap[name] = wt
 	}
 
+	// Index GitHub items by the worktree names they match, generating each name once rather than
+	// once per worktree, which adds up on boards with thousands of items. The first item wins
+	githubByName := make(map[string]int, len(githubItems))
+	for i, item := range githubItems {
+		names := []string{git.GenerateWorktreeName(m.config.Name, item.Title, m.config.Naming)}
+		if item.Content.Number > 0 {
+			names = append(names, fmt.Sprintf("issue-%d", item.Content.Number))
+		}
+		for _, name := range names {
+			if _, ok := githubByName[name]; !ok {
+				githubByName[name] = i
+			}
+		}
+	}
+
 	// Track which GitHub items have been matched to worktrees
 	matchedGithubItems := make(map[string]bool)
 
@@ -441,54 +1509,48 @@ func (m *model) mergeGithubItems(githubItems []github.ProjectItem) {
 	items := make([]list.Item, 0, len(m.worktrees)+len(githubItems))
 
 	for _, wt := range m.worktrees {
-		name := git.GetWorktreeName(wt.Path)
+		name := wt.Name()
 		todo := m.config.GetTodoForWorktree(name)
 
-		// Try to match with GitHub item
+		// Try to match with GitHub item, by worktree name or issue number
 		var matchedItem *github.ProjectItem
-		for i := range githubItems {
+		if i, ok := githubByName[name]; ok {
 			item := &githubItems[i]
-			// Match by worktree name or issue number
-			itemName := generateWorktreeName(m.config.Name, item.Title)
-			if itemName == name || (item.Content.Number > 0 && fmt.Sprintf("issue-%d", item.Content.Number) == name) {
-				matchedItem = item
-				matchedGithubItems[item.ID] = true
-
-				// Update the todo with GitHub data if it exists
-				if todo != nil {
-					// Get the body from the content if available
-					if item.Content.Body != "" {
-						todo.GitHubBody = item.Content.Body
-					} else if item.Body != "" {
-						todo.GitHubBody = item.Body
-					}
-					if item.Content.URL != "" {
-						todo.GitHubURL = item.Content.URL
-					}
-					// Save the updated config
-					m.config.Save()
+			matchedItem = item
+			matchedGithubItems[item.ID] = true
+
+			// Update the todo with GitHub data if it exists
+			if todo != nil {
+				// Get the body from the content if available
+				if item.Content.Body != "" {
+					todo.GitHubBody = item.Content.Body
+				} else if item.Body != "" {
+					todo.GitHubBody = item.Body
+				}
+				if item.Content.URL != "" {
+					todo.GitHubURL = item.Content.URL
 				}
+				// Save the updated config
+				m.config.Save()
+			}
 
-				// If this item has a worktree but isn't in "In Progress" or "Done", move it to "In Progress"
-				if m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
-					if item.Status != "In Progress" && item.Status != "Done" {
-						err := github.UpdateProjectItemStatus(
-							m.config.StorageBackend.Owner,
-							m.config.StorageBackend.Repo,
-							m.config.StorageBackend.ProjectNumber,
-							item.ID,
-							"In Progress",
-						)
-						if err != nil {
-							fmt.Fprintf(os.Stderr, "Warning: failed to update item status to In Progress: %v\n", err)
-						} else {
-							// Update the local copy
-							item.Status = "In Progress"
-						}
+			// If this item has a worktree but isn't in "In Progress" or "Done", move it to "In Progress"
+			if m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
+				if item.Status != "In Progress" && item.Status != "Done" {
+					err := github.UpdateProjectItemStatus(
+						m.config.StorageBackend.Owner,
+						m.config.StorageBackend.Repo,
+						m.config.StorageBackend.ProjectNumber,
+						item.ID,
+						"In Progress",
+					)
+					if err != nil {
+						fmt.Fprintf(os.Stderr, "Warning: failed to update item status to In Progress: %v\n", err)
+					} else {
+						// Update the local copy
+						item.Status = "In Progress"
 					}
 				}
-
-				break
 			}
 		}
 
@@ -497,6 +1559,14 @@ func (m *model) mergeGithubItems(githubItems []github.ProjectItem) {
 			todo:        todo,
 			githubItem:  matchedItem,
 			isCheckedOut: true,
+			issues:      m.health[name],
+			color:       m.config.WorktreeColor(name),
+			check:       m.checks[name],
+			status:      m.statuses[name],
+			rebaseOnto:  m.rebaseOnto(name),
+			pullRequest: m.prStatuses[name],
+			lastUsed:    name == m.lastUsed,
+			expires:     scratchExpiry(m.config, wt),
 		})
 	}
 
@@ -510,67 +1580,364 @@ func (m *model) mergeGithubItems(githubItems []github.ProjectItem) {
 			})
 		}
 	}
+	items = append(items, unlinkedTodoItems(m.config)...)
 
-	m.list.SetItems(items)
+	m.list.SetItems(arrangeItems(m.config, m.view, mainWorktreeName(m.worktrees), items))
+	m.applyMarks()
+	m.notifyUnblocked()
+}
+
+// newTextInput returns a single line editor for the create and repurpose dialogs
+// It inserts at the cursor, which moves with ←/→, Alt+←/→ by word and Home/End, and Ctrl+W deletes a word
+func newTextInput(placeholder string) textinput.Model {
+	ti := textinput.New()
+	ti.Placeholder = placeholder
+	ti.CharLimit = 100
+	ti.Width = 50
+	return ti
+}
+
+// mainWorktreeName returns the name of the main worktree, which git always lists first
+func mainWorktreeName(worktrees []git.Worktree) string {
+	if len(worktrees) == 0 {
+		return ""
+	}
+	return worktrees[0].Name()
+}
+
+// unlinkedTodoItems returns list items for pending todos without a worktree yet (e.g. from `lfg capture`)
+func unlinkedTodoItems(cfg *config.Config) []list.Item {
+	var items []list.Item
+	for i := range cfg.Todos {
+		todo := &cfg.Todos[i]
+		if todo.Worktree == "" && todo.Status != config.TodoStatusDone {
+			items = append(items, worktreeItem{todo: todo})
+		}
+	}
+	return items
 }
 
 func (m *model) viewCreateWorktree() string {
-	// Show preview of what the worktree will be named
+	highlight := highlightStyle
+
+	// Show the type picker if templates define types
+	typePicker := ""
+	help := i18n.T("create.help", onOff(m.pushOnCreate))
+	if m.trackRemote != nil {
+		help = i18n.T("create.track_help")
+	}
+	if m.duplicate != "" {
+		help = i18n.T("create.duplicate_help")
+	}
+	if types := m.todoTypes(); len(types) > 0 {
+		var options []string
+		for i, t := range types {
+			if i == m.todoType {
+				options = append(options, highlight.Bold(true).Render("["+t+"]"))
+			} else {
+				options = append(options, " "+t+" ")
+			}
+		}
+		typePicker = i18n.T("create.type") + strings.Join(options, " ") + "\n\n"
+		help = i18n.T("create.help_types", help)
+	}
+
+	// Show preview of what the worktree will be named, or the name being edited
+	preview := ""
+	if m.editingName || m.nameEdited {
+		preview = "\n\n" + i18n.T("create.name") + "\n" + m.nameInput.View()
+	}
+	if m.textInput.Value() != "" {
+		todoType := m.selectedTodoType()
+		worktreeName := m.createName()
+		if !m.editingName && !m.nameEdited {
+			preview = "\n" + i18n.T("create.preview_name", highlight.Render(worktreeName))
+			if git.IsNameShortened(m.config.Name, m.textInput.Value(), m.config.Naming) {
+				preview += i18n.T("create.shortened", git.MaxNameLength(m.config.Naming))
+			}
+		}
+		if m.createFrom != nil {
+			preview += "\n" + i18n.T("create.preview_branch", highlight.Render(branchLabel(*m.createFrom)))
+		} else if branch := m.config.Templates.BranchName(todoType, worktreeName); branch != worktreeName {
+			preview += "\n" + i18n.T("create.preview_branch", highlight.Render(branch))
+		}
+		preview += "\n" + i18n.T("create.preview_session", highlight.Render(multiplexer.Current().SessionName(m.config.Name, worktreeName)))
+		if m.pushOnCreate && m.createFrom == nil {
+			preview += "\n" + i18n.T("create.preview_push", highlight.Render("origin/"+m.config.Templates.BranchName(todoType, worktreeName)))
+		}
+		if description := m.config.Templates.RenderDescription(todoType, m.textInput.Value()); description != m.textInput.Value() {
+			preview += "\n" + i18n.T("create.preview_todo", highlight.Render(description))
+		}
+	}
+	if m.trackRemote != nil {
+		preview += "\n\n" + errorStyle.Render(i18n.T("create.track_prompt", m.trackRemote.LocalName(), m.trackRemote.Name))
+	}
+	if m.duplicate != "" {
+		preview += "\n\n" + errorStyle.Render(i18n.T("create.duplicate_prompt", m.duplicate))
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s%s\n%s%s\n\n%s\n",
+		titleStyle.Render(i18n.T("create.title")),
+		typePicker,
+		i18n.T("create.description"),
+		m.textInput.View(),
+		preview,
+		helpStyle.Render(help),
+	)
+}
+
+func (m *model) viewRepurposeWorktree() string {
+	highlight := highlightStyle
+
+	name := ""
+	if item, ok := m.list.SelectedItem().(worktreeItem); ok {
+		name = item.worktree.DisplayName()
+	}
+
+	// Show preview of the branch the worktree will switch to
 	preview := ""
 	if m.textInput.Value() != "" {
-		worktreeName := generateWorktreeName(m.config.Name, m.textInput.Value())
-		preview = fmt.Sprintf("\nWorktree will be created as: %s",
-			lipgloss.NewStyle().Foreground(lipgloss.Color("86")).Render(worktreeName))
+		branch := git.GenerateWorktreeName(m.config.Name, m.textInput.Value(), m.config.Naming)
+		preview = "\n" + i18n.T("repurpose.preview", highlight.Render(branch))
 	}
 
 	return fmt.Sprintf(
-		"%s\n\nFeature Description:\n%s%s\n\n%s\n",
-		titleStyle.Render("Create New Worktree"),
+		"%s\n\n%s\n%s%s\n\n%s\n",
+		titleStyle.Render(i18n.T("repurpose.title")),
+		i18n.T("repurpose.prompt", name),
 		m.textInput.View(),
 		preview,
-		helpStyle.Render("Enter: Create | Esc: Cancel"),
+		helpStyle.Render(i18n.T("repurpose.help")),
+	)
+}
+
+func (m *model) viewRenameWorktree() string {
+	name := ""
+	if item, ok := m.list.SelectedItem().(worktreeItem); ok {
+		name = item.worktree.DisplayName()
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n%s\n\n%s\n",
+		titleStyle.Render(i18n.T("rename.title")),
+		i18n.T("rename.prompt", name),
+		m.textInput.View(),
+		helpStyle.Render(i18n.T("rename.help")),
+	)
+}
+
+// transferTargets returns the worktrees the selected worktree's changes can be moved into
+func (m *model) transferTargets() []git.Worktree {
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok {
+		return nil
+	}
+
+	var targets []git.Worktree
+	for _, wt := range m.worktrees {
+		if wt.Name() != item.worktree.Name() {
+			targets = append(targets, wt)
+		}
+	}
+	return targets
+}
+
+func (m *model) viewTransfer() string {
+	highlight := highlightStyle
+
+	source := ""
+	if item, ok := m.list.SelectedItem().(worktreeItem); ok {
+		source = item.worktree.DisplayName()
+	}
+
+	what := i18n.T("transfer.uncommitted")
+	if m.transferCommits > 0 {
+		what = i18n.N("transfer.commits", m.transferCommits)
+	}
+
+	var targets strings.Builder
+	for i, wt := range m.transferTargets() {
+		if i == m.transferTarget {
+			targets.WriteString(highlight.Bold(true).Render("> " + wt.DisplayName()))
+		} else {
+			targets.WriteString("  " + wt.DisplayName())
+		}
+		targets.WriteString("\n")
+	}
+
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n%s\n",
+		titleStyle.Render(i18n.T("transfer.title")),
+		i18n.T("transfer.prompt", highlight.Render(what), source),
+		targets.String(),
+		helpStyle.Render(i18n.T("transfer.help")),
 	)
 }
 
+func (m *model) viewBlocked() string {
+	op := m.blockedBy.Operation
+	return fmt.Sprintf(
+		"%s\n\n%s\n\n%s\n",
+		titleStyle.Render(i18n.T("blocked.title", strings.ToUpper(op[:1])+op[1:])),
+		i18n.T("blocked.prompt", op, filepath.Base(m.blockedBy.Path), op),
+		helpStyle.Render(i18n.T("blocked.help", op)),
+	)
+}
+
+// showGitError shows err, offering to abort an unfinished rebase/merge if that's what caused it
+func (m *model) showGitError(err error) {
+	var blocked *git.OperationInProgressError
+	if errors.As(err, &blocked) {
+		m.blockedBy = blocked
+		return
+	}
+	m.err = err
+}
+
 func (m *model) viewDeleteConfirm() string {
+	if item, ok := m.list.SelectedItem().(worktreeItem); ok && item.isUnlinkedTodo() {
+		return fmt.Sprintf(
+			"%s\n\n%s\n\n%s\n",
+			titleStyle.Render(i18n.T("delete.todo.title")),
+			i18n.T("delete.todo.prompt", item.todo.Description),
+			helpStyle.Render(i18n.T("confirm.help")),
+		)
+	}
 	if item, ok := m.list.SelectedItem().(worktreeItem); ok {
-		name := git.GetWorktreeName(item.worktree.Path)
+		if m.deleteStep != confirmDelete {
+			return m.viewDeleteStep(item)
+		}
+		name := item.worktree.DisplayName()
+		safety := ""
+		if item.isCheckedOut {
+			safety = "\n" + m.viewSafety(item.worktree.Name()) + "\n"
+		}
 		return fmt.Sprintf(
-			"%s\n\nAre you sure you want to delete worktree '%s'?\n\n%s\n",
-			titleStyle.Render("Delete Worktree"),
-			name,
-			helpStyle.Render("Y: Yes | N: No"),
+			"%s\n\n%s\n%s\n%s\n",
+			titleStyle.Render(i18n.T("delete.worktree.title")),
+			i18n.T("delete.worktree.prompt", name),
+			safety,
+			helpStyle.Render(i18n.T("confirm.help")),
 		)
 	}
 	return ""
 }
 
+// createName returns the name of the worktree the create dialog will create, generated from the
+// description unless it was edited
+func (m *model) createName() string {
+	if m.nameEdited {
+		return strings.TrimSpace(m.nameInput.Value())
+	}
+	return git.GenerateWorktreeName(m.config.Name, m.textInput.Value(), m.config.Naming)
+}
+
+// focusName moves the create dialog's focus to the worktree name, starting from the generated name,
+// or back to the description
+func (m *model) focusName(name bool) tea.Cmd {
+	m.editingName = name
+	if !name {
+		m.nameInput.Blur()
+		return m.textInput.Focus()
+	}
+	if !m.nameEdited {
+		m.nameInput.SetValue(m.createName())
+		m.nameInput.CursorEnd()
+	}
+	m.textInput.Blur()
+	return m.nameInput.Focus()
+}
+
+// resetName makes the create dialog's worktree name follow the description again
+func (m *model) resetName() {
+	m.editingName = false
+	m.nameEdited = false
+	m.nameInput.SetValue("")
+	m.nameInput.Blur()
+}
+
+// todoTypes returns the types offered by the create dialog's type picker
+func (m *model) todoTypes() []string {
+	if m.config.Templates == nil {
+		return nil
+	}
+	return m.config.Templates.Types
+}
+
+// selectedTodoType returns the type picked in the create dialog, or "" if templates aren't configured
+func (m *model) selectedTodoType() string {
+	types := m.todoTypes()
+	if m.todoType < 0 || m.todoType >= len(types) {
+		return ""
+	}
+	return types[m.todoType]
+}
+
 func (m *model) handleCreateWorktree() (tea.Model, tea.Cmd) {
-	description := m.textInput.Value()
-	if description == "" {
+	summary := m.textInput.Value()
+	if summary == "" {
 		m.err = fmt.Errorf("feature description cannot be empty")
 		m.creating = false
 		return m, nil
 	}
 
-	// Generate worktree name: [project-name]-[dasherized-description]
-	worktreeName := generateWorktreeName(m.config.Name, description)
+	// Generate worktree name: [project-name]-[dasherized-description], unless it was edited
+	// Names come from the summary so template decorations like "[feat]" don't end up in them
+	todoType := m.selectedTodoType()
+	worktreeName := m.createName()
+	branch := m.config.Templates.BranchName(todoType, worktreeName)
+	description := m.config.Templates.RenderDescription(todoType, summary)
+
+	// The same bug tends to get a second worktree when its todo is forgotten, so offer the open one first
+	if !m.keepDuplicate {
+		if existing := m.config.FindDuplicate(description); existing != nil {
+			m.duplicate = existing.Description
+			return m, nil
+		}
+	}
+
+	// A teammate may already have pushed the branch, offer to continue it rather than failing on -b
+	from := m.createFrom
+	if from == nil && git.ValidateBranchName(branch) == nil {
+		if remote, ok := git.RemoteBranch(branch); ok {
+			m.trackRemote = &remote
+			return m, nil
+		}
+	}
 
-	// Create worktree
-	if err := git.CreateWorktree(worktreeName); err != nil {
-		m.err = err
+	// Create worktree, on a new branch or the one picked with ctrl+b, with its todo
+	_, err := worktree.Create(m.config, worktree.CreateOptions{
+		Name:        worktreeName,
+		Branch:      branch,
+		From:        from,
+		Description: description,
+		Notes:       m.createNotes,
+	})
+	m.err = err
+	if err != nil && !errors.Is(err, worktree.ErrNotSaved) {
 		m.creating = false
 		return m, nil
 	}
-
-	// Add todo with the original description
-	m.config.AddTodo(description, worktreeName)
-	if err := m.config.Save(); err != nil {
-		m.err = fmt.Errorf("failed to save config: %w", err)
-	}
+	m.createNotes = ""
 
 	m.creating = false
 	m.textInput.SetValue("")
+	m.resetName()
+
+	// Pushing is slow and may fail (no remote, no access) without affecting the new worktree
+	// Existing branches are left alone, they may already be on origin
+	var push tea.Cmd
+	if m.pushOnCreate && m.createFrom == nil {
+		push = pushBranch(branch)
+	}
+	m.createFrom = nil
+
+	// Copying files and installing dependencies can take a while, the summary opens when they're done
+	setupCmd := runSetup(worktreeName, setup.Steps(m.config.Setup))
+	if setupCmd != nil {
+		setupCmd = tea.Batch(m.setStatus(i18n.T("setup.starting", worktreeName)), setupCmd)
+	}
 
 	// If GitHub is configured, show spinner and create item + refresh in background
 	if m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
@@ -578,11 +1945,37 @@ func (m *model) handleCreateWorktree() (tea.Model, tea.Cmd) {
 		return m, tea.Batch(
 			m.spinner.Tick,
 			m.createGithubItemAndRefresh(description, worktreeName),
+			push,
+			setupCmd,
 		)
 	}
 
 	// Otherwise just refresh
-	return m, m.refreshWorktrees
+	return m, tea.Batch(m.refreshWorktrees, push, setupCmd)
+}
+
+// describeBranch seeds a new worktree's branch description and commit template with its todo
+// Failing to do so doesn't stop the worktree being used, so it only warns
+func (m *model) describeBranch(worktreeName, branch, description string) {
+	path, err := git.GetWorktreePath(worktreeName)
+	if err == nil {
+		err = git.DescribeBranch(path, branch, description, m.config.Templates)
+	}
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+}
+
+type pushedMsg struct {
+	branch string
+	err    error
+}
+
+// pushBranch pushes a newly created branch in the background
+func pushBranch(branch string) tea.Cmd {
+	return func() tea.Msg {
+		return pushedMsg{branch: branch, err: git.PushBranch(branch)}
+	}
 }
 
 type createItemMsg struct {
@@ -620,39 +2013,14 @@ func (m *model) createGithubItemAndRefresh(description, worktreeName string) tea
 	}
 }
 
-// generateWorktreeName creates a worktree name from project name and feature description
-// Format: [project-name]-[dasherized-feature-name]
-func generateWorktreeName(projectName, description string) string {
-	// Dasherize the description
-	dasherized := strings.ToLower(description)
-	dasherized = strings.ReplaceAll(dasherized, " ", "-")
-	// Remove special characters
-	var result strings.Builder
-	for _, r := range dasherized {
-		if (r >= 'a' && r <= 'z') || (r >= '0' && r <= '9') || r == '-' {
-			result.WriteRune(r)
-		}
-	}
-	dasherized = result.String()
-
-	// Remove consecutive dashes
-	for strings.Contains(dasherized, "--") {
-		dasherized = strings.ReplaceAll(dasherized, "--", "-")
-	}
-
-	// Trim dashes from start/end
-	dasherized = strings.Trim(dasherized, "-")
-
-	return projectName + "-" + dasherized
-}
-
 func (m *model) handleCreateWorktreeFromGithub(item *github.ProjectItem) (tea.Model, tea.Cmd) {
 	// Generate worktree name from the GitHub item title
-	worktreeName := generateWorktreeName(m.config.Name, item.Title)
+	worktreeName := git.GenerateWorktreeName(m.config.Name, item.Title, m.config.Naming)
 
-	// Create worktree
-	if err := git.CreateWorktree(worktreeName); err != nil {
-		m.err = err
+	// Create worktree with a todo for the GitHub item
+	_, err := worktree.Create(m.config, worktree.CreateOptions{Name: worktreeName, Description: item.Title})
+	m.err = err
+	if err != nil && !errors.Is(err, worktree.ErrNotSaved) {
 		return m, nil
 	}
 
@@ -670,8 +2038,7 @@ func (m *model) handleCreateWorktreeFromGithub(item *github.ProjectItem) (tea.Mo
 		}
 	}
 
-	// Add todo with the GitHub item title and body
-	m.config.AddTodo(item.Title, worktreeName)
+	// Keep the GitHub item's body and link with the todo
 	todo := m.config.GetTodoForWorktree(worktreeName)
 	if todo != nil {
 		todo.GitHubBody = item.Content.Body
@@ -681,17 +2048,132 @@ func (m *model) handleCreateWorktreeFromGithub(item *github.ProjectItem) (tea.Mo
 		m.err = fmt.Errorf("failed to save config: %w", err)
 	}
 
-	// Set as selected and quit to jump to it
-	m.selectedWorktree = worktreeName
-	return m, tea.Quit
+	// Set up and jump to it
+	return m, m.jumpAfterSetup(worktreeName)
+}
+
+// handleStartTodo creates a worktree for a todo that doesn't have one and jumps to it
+func (m *model) handleStartTodo(todo *config.Todo) (tea.Model, tea.Cmd) {
+	worktreeName := git.GenerateWorktreeName(m.config.Name, todo.Description, m.config.Naming)
+
+	if err := git.CreateWorktree(worktreeName); err != nil {
+		m.err = err
+		return m, nil
+	}
+
+	m.describeBranch(worktreeName, worktreeName, todo.Description)
+
+	// Link the todo to its new worktree
+	todo.Worktree = worktreeName
+	m.config.RenamePin(todoPinKey(todo.Description), worktreeName)
+	if err := m.config.Save(); err != nil {
+		m.err = fmt.Errorf("failed to save config: %w", err)
+	}
+
+	return m, m.jumpAfterSetup(worktreeName)
+}
+
+// handleRenameWorktree moves the selected worktree to the entered name, with its branch, todo and session
+func (m *model) handleRenameWorktree() (tea.Model, tea.Cmd) {
+	name := strings.TrimSpace(m.textInput.Value())
+	m.renaming = false
+	m.textInput.SetValue("")
+
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut || name == item.worktree.Name() {
+		return m, nil
+	}
+
+	renamed, err := worktree.Rename(m.config, item.worktree, name)
+	if err != nil && !errors.Is(err, worktree.ErrNotSaved) {
+		m.showGitError(err)
+		return m, nil
+	}
+	m.err = err
+	return m, tea.Batch(m.setStatus(i18n.T("rename.done", item.worktree.DisplayName(), renamed.DisplayName())), m.refreshWorktrees)
+}
+
+// handleRepurposeWorktree switches the selected worktree to a new branch and links it to a new todo
+func (m *model) handleRepurposeWorktree() (tea.Model, tea.Cmd) {
+	description := m.textInput.Value()
+	m.repurposing = false
+	m.textInput.SetValue("")
+
+	if description == "" {
+		m.err = fmt.Errorf("feature description cannot be empty")
+		return m, nil
+	}
+
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	if !ok || !item.isCheckedOut {
+		return m, nil
+	}
+
+	// The directory (and so the worktree name and tmux session) stays the same, only the branch changes
+	branch := git.GenerateWorktreeName(m.config.Name, description, m.config.Naming)
+	if err := git.SwitchBranch(item.worktree.Path, branch); err != nil {
+		m.showGitError(err)
+		return m, nil
+	}
+
+	// Shown in the TUI rather than on stderr, which the alt screen would hide
+	m.err = git.DescribeBranch(item.worktree.Path, branch, description, m.config.Templates)
+
+	m.config.RepurposeWorktree(item.worktree.Name(), description)
+	if err := m.config.Save(); err != nil {
+		m.err = fmt.Errorf("failed to save config: %w", err)
+	}
+
+	return m, tea.Batch(m.setStatus(i18n.T("repurpose.done", item.worktree.DisplayName(), branch)), m.refreshWorktrees)
+}
+
+// handleTransfer cherry-picks commits or applies uncommitted changes from the selected worktree into the picked one
+func (m *model) handleTransfer() (tea.Model, tea.Cmd) {
+	m.transferring = false
+
+	item, ok := m.list.SelectedItem().(worktreeItem)
+	targets := m.transferTargets()
+	if !ok || m.transferTarget >= len(targets) {
+		return m, nil
+	}
+	target := targets[m.transferTarget]
+
+	var err error
+	if m.transferCommits > 0 {
+		err = git.CherryPick(item.worktree.Path, target.Path, m.transferCommits)
+	} else {
+		err = git.ApplyChanges(item.worktree.Path, target.Path)
+	}
+	if err != nil {
+		m.showGitError(err)
+		return m, nil
+	}
+
+	if m.transferCommits > 0 {
+		return m, m.setStatus(i18n.N("transfer.picked", m.transferCommits, target.DisplayName()))
+	}
+	return m, m.setStatus(i18n.T("transfer.applied", target.DisplayName()))
 }
 
 func (m *model) handleDeleteWorktree() (tea.Model, tea.Cmd) {
 	if item, ok := m.list.SelectedItem().(worktreeItem); ok {
+		// Captured todos only need removing from the config
+		if item.isUnlinkedTodo() {
+			m.config.RemoveUnlinkedTodo(item.todo.Description)
+			if item.pinned {
+				m.config.TogglePin(item.pinKey())
+			}
+			if err := m.config.Save(); err != nil {
+				m.err = fmt.Errorf("failed to save config: %w", err)
+			}
+			m.deleting = false
+			return m, m.refreshWorktrees
+		}
+
 		// Get the name from either the worktree or the todo
 		var name string
 		if item.isCheckedOut {
-			name = git.GetWorktreeName(item.worktree.Path)
+			name = item.worktree.Name()
 		} else if item.todo != nil {
 			name = item.todo.Worktree
 		} else if item.githubItem != nil {
@@ -717,12 +2199,37 @@ func (m *model) handleDeleteWorktree() (tea.Model, tea.Cmd) {
 			return m, nil
 		}
 
-		// Check if branch is merged
-		isMerged, err := git.IsBranchMerged(name)
+		// Check if branch is merged (the branch may be prefixed, e.g. "feat/<name>") before it's deleted
+		branch := item.worktree.BranchName()
+		if branch == "" {
+			branch = name
+		}
+		isMerged, err := git.IsBranchMerged(branch)
 		if err != nil {
 			fmt.Fprintf(os.Stderr, "Warning: failed to check if branch is merged: %v\n", err)
 		}
 
+		// Check if we're deleting the current worktree
+		currentWorktree, err := git.GetCurrentWorktree()
+		isDeletingCurrent := err == nil && currentWorktree == name
+
+		// Remove the todo entirely rather than marking it done
+		wt := item.worktree
+		if !item.isCheckedOut {
+			wt = git.Worktree{ID: name}
+		}
+		trashed, err := worktree.Delete(m.config, wt, m.deleteOpts)
+		if err != nil && !errors.Is(err, worktree.ErrNotSaved) {
+			m.deleting = false
+			m.showGitError(err)
+			return m, nil
+		}
+		m.err = err
+		status := ""
+		if trashed != nil {
+			status = i18n.T("delete.trashed", item.worktree.DisplayName(), trashed.ID)
+		}
+
 		// Update GitHub item status to Done if merged
 		if isMerged && item.githubItem != nil && m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
 			err := github.UpdateProjectItemStatus(
@@ -737,31 +2244,6 @@ func (m *model) handleDeleteWorktree() (tea.Model, tea.Cmd) {
 			}
 		}
 
-		// Check if we're deleting the current worktree
-		currentWorktree, err := git.GetCurrentWorktree()
-		isDeletingCurrent := err == nil && currentWorktree == name
-
-		// Kill tmux session if it exists
-		sessionName := tmux.SanitizeSessionName(name)
-		if tmux.SessionExists(sessionName) {
-			if err := tmux.KillSession(sessionName); err != nil {
-				fmt.Fprintf(os.Stderr, "Warning: failed to kill tmux session: %v\n", err)
-			}
-		}
-
-		// Delete worktree
-		if err := git.DeleteWorktree(name, true); err != nil {
-			m.err = err
-			m.deleting = false
-			return m, nil
-		}
-
-		// Remove todo entirely (don't just mark as done)
-		m.config.RemoveTodo(name)
-		if err := m.config.Save(); err != nil {
-			m.err = fmt.Errorf("failed to save config: %w", err)
-		}
-
 		m.deleting = false
 
 		// If we deleted the current worktree, exit the TUI
@@ -770,6 +2252,9 @@ func (m *model) handleDeleteWorktree() (tea.Model, tea.Cmd) {
 			return m, tea.Quit
 		}
 
+		if status != "" {
+			return m, tea.Batch(m.setStatus(status), m.refreshWorktrees)
+		}
 		return m, m.refreshWorktrees
 	}
 
@@ -777,6 +2262,77 @@ func (m *model) handleDeleteWorktree() (tea.Model, tea.Cmd) {
 	return m, nil
 }
 
+type copiedMsg struct {
+	label string
+	err   error
+}
+
+type clearStatusMsg struct {
+	id int
+}
+
+// setStatus shows a transient message below the list for a couple of seconds
+func (m *model) setStatus(status string) tea.Cmd {
+	m.status = status
+	m.statusID++
+	id := m.statusID
+	return tea.Tick(2*time.Second, func(time.Time) tea.Msg {
+		return clearStatusMsg{id: id}
+	})
+}
+
+// keepStatus shows a status until the next one replaces it, for one worth coming back to such as a URL
+func (m *model) keepStatus(status string) {
+	m.status = status
+	m.statusID++
+}
+
+// copySelection copies the selected item's path (y), branch (b) or PR URL (u) to the clipboard
+func (m *model) copySelection(item worktreeItem, key string) tea.Cmd {
+	branch := strings.TrimPrefix(item.worktree.Branch, "refs/heads/")
+
+	return func() tea.Msg {
+		var label, text string
+		switch key {
+		case "y":
+			label, text = "path", item.worktree.Path
+		case "b":
+			label, text = "branch", branch
+		case "u":
+			label = "PR URL"
+			if branch != "" {
+				text = m.pullRequestURL(item.worktree.Name(), branch)
+			}
+			// Fall back to the linked issue when there's no PR yet
+			if text == "" && item.todo != nil && item.todo.GitHubURL != "" {
+				label, text = "issue URL", item.todo.GitHubURL
+			}
+			if text == "" && item.githubItem != nil && item.githubItem.Content.URL != "" {
+				label, text = "issue URL", item.githubItem.Content.URL
+			}
+		}
+
+		if text == "" {
+			return copiedMsg{label: label, err: fmt.Errorf("selected item has no %s", label)}
+		}
+		return copiedMsg{label: label, err: clipboard.Copy(text)}
+	}
+}
+
+// pullRequestURL asks GitHub for the pull request of a worktree's branch and remembers it, falling back
+// to the remembered one when GitHub can't be reached
+func (m *model) pullRequestURL(name, branch string) string {
+	store := git.NewMetaStore(cacheKey(m.config))
+	url, err := github.GetPullRequestURL(branch)
+	if err != nil || url == "" {
+		return store.PullRequestURL(name)
+	}
+	if err := store.SetPullRequestURL(name, url); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to remember pull request: %v\n", err)
+	}
+	return url
+}
+
 type refreshMsg struct {
 	worktrees []git.Worktree
 }
@@ -790,15 +2346,25 @@ func (m *model) refreshWorktrees() tea.Msg {
 	if err != nil {
 		return errMsg{err: err}
 	}
+	saveWorktreeCache(m.config, worktrees)
 	return refreshMsg{worktrees: worktrees}
 }
 
+// refresh reloads worktrees, and GitHub items when configured, without showing the spinner
+func (m *model) refresh() tea.Cmd {
+	if m.config.StorageBackend != nil && m.config.StorageBackend.Type == "github" {
+		return m.refreshAll
+	}
+	return m.refreshWorktrees
+}
+
 func (m *model) refreshAll() tea.Msg {
 	// First refresh worktrees
 	worktrees, err := git.ListWorktrees()
 	if err != nil {
 		return errMsg{err: err}
 	}
+	saveWorktreeCache(m.config, worktrees)
 	m.worktrees = worktrees
 
 	// Then fetch GitHub items
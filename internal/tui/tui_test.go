@@ -0,0 +1,557 @@
+package tui
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/charmbracelet/bubbles/list"
+	tea "github.com/charmbracelet/bubbletea"
+	"github.com/charmbracelet/lipgloss"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/runner"
+	"github.com/markcipolla/lfg/internal/setup"
+	"github.com/markcipolla/lfg/internal/tmux"
+)
+
+// keyMsg returns the key press for a key name as Bubble Tea reports it, e.g. "esc" or "n"
+func keyMsg(key string) tea.KeyMsg {
+	switch key {
+	case "esc":
+		return tea.KeyMsg{Type: tea.KeyEsc}
+	case "enter":
+		return tea.KeyMsg{Type: tea.KeyEnter}
+	case "tab":
+		return tea.KeyMsg{Type: tea.KeyTab}
+	case "backspace":
+		return tea.KeyMsg{Type: tea.KeyBackspace}
+	}
+	return tea.KeyMsg{Type: tea.KeyRunes, Runes: []rune(key)}
+}
+
+func TestModeTransitions(t *testing.T) {
+	mainWorktree := git.Worktree{Path: "/src/myapp", ID: "myapp", Branch: "refs/heads/main"}
+	feature := git.Worktree{Path: "/src/myapp-login", ID: "myapp-login", Branch: "refs/heads/login"}
+	featureItem := worktreeItem{
+		worktree:     feature,
+		isCheckedOut: true,
+		status:       git.WorktreeStatus{Dirty: true},
+		rebaseOnto:   "main",
+		issues:       []git.HealthIssue{{Kind: git.IssueRebase, Summary: "Rebase in progress"}},
+	}
+
+	todoItem := worktreeItem{todo: &config.Todo{Description: "Fix login"}}
+	failedSetup := setupMsg{worktree: "myapp-login", results: []setup.Result{{Err: errors.New("npm install failed")}}}
+
+	tests := []struct {
+		name     string
+		before   func(m *model) // Opens a mode that isn't opened by a key, e.g. one that needs tmux
+		msgs     []tea.Msg      // Sent before the keys, e.g. the results of a background fetch
+		keys     []string
+		mode     func(m *model) bool // Whether the mode the keys should have opened is open
+		expected bool
+	}{
+		{name: "create", keys: []string{"n"}, mode: func(m *model) bool { return m.creating }, expected: true},
+		{name: "create cancelled", keys: []string{"n", "esc"}, mode: func(m *model) bool { return m.creating }},
+		{name: "delete", keys: []string{"d"}, mode: func(m *model) bool { return m.deleting }, expected: true},
+		{name: "delete cancelled", keys: []string{"d", "n"}, mode: func(m *model) bool { return m.deleting }},
+		{name: "repurpose", keys: []string{"s"}, mode: func(m *model) bool { return m.repurposing }, expected: true},
+		{name: "repurpose cancelled", keys: []string{"s", "esc"}, mode: func(m *model) bool { return m.repurposing }},
+		{name: "rename", keys: []string{"M"}, mode: func(m *model) bool { return m.renaming }, expected: true},
+		{name: "rename cancelled", keys: []string{"M", "esc"}, mode: func(m *model) bool { return m.renaming }},
+		{name: "open PR", keys: []string{"P"}, mode: func(m *model) bool { return m.openingPR != nil && m.textInput.Value() == "login" }, expected: true},
+		{name: "open PR cancelled", keys: []string{"P", "esc"}, mode: func(m *model) bool { return m.openingPR != nil }},
+		{name: "transfer", keys: []string{"p"}, mode: func(m *model) bool { return m.transferring }, expected: true},
+		{name: "transfer cancelled", keys: []string{"p", "esc"}, mode: func(m *model) bool { return m.transferring }},
+		{name: "settings", keys: []string{","}, mode: func(m *model) bool { return m.editingSettings }, expected: true},
+		{name: "settings closed", keys: []string{",", "esc"}, mode: func(m *model) bool { return m.editingSettings }},
+		{name: "health", keys: []string{"!"}, mode: func(m *model) bool { return m.showingHealth }, expected: true},
+		{name: "health closed", keys: []string{"!", "esc"}, mode: func(m *model) bool { return m.showingHealth }},
+		{name: "rebase with changes", keys: []string{"B"}, mode: func(m *model) bool { return m.rebasing != nil }, expected: true},
+		{name: "rebase cancelled", keys: []string{"B", "esc"}, mode: func(m *model) bool { return m.rebasing != nil }},
+		{name: "rebase autostash toggled", keys: []string{"B", "tab"}, mode: func(m *model) bool { return m.rebaseAutostash }, expected: true},
+		{name: "session info", before: func(m *model) { m.sessionInfo = &tmux.SessionInfo{} }, mode: func(m *model) bool { return m.sessionInfo != nil }, expected: true},
+		{name: "session info closed", before: func(m *model) { m.sessionInfo = &tmux.SessionInfo{} }, keys: []string{"esc"}, mode: func(m *model) bool { return m.sessionInfo != nil }},
+		{name: "cleanup", msgs: []tea.Msg{closedReviewsMsg{candidates: []cleanupCandidate{{worktree: feature, reason: "merged"}}}}, mode: func(m *model) bool { return len(m.cleanup) > 0 }, expected: true},
+		{name: "cleanup declined", msgs: []tea.Msg{closedReviewsMsg{candidates: []cleanupCandidate{{worktree: feature, reason: "merged"}}}}, keys: []string{"n"}, mode: func(m *model) bool { return len(m.cleanup) > 0 }},
+		{name: "pull requests", msgs: []tea.Msg{pullRequestsMsg{prs: []github.PullRequest{{Number: 7}}}}, mode: func(m *model) bool { return len(m.pullRequests) > 0 }, expected: true},
+		{name: "pull requests closed", msgs: []tea.Msg{pullRequestsMsg{prs: []github.PullRequest{{Number: 7}}}}, keys: []string{"esc"}, mode: func(m *model) bool { return len(m.pullRequests) > 0 }},
+		{name: "no pull requests", msgs: []tea.Msg{pullRequestsMsg{}}, mode: func(m *model) bool { return len(m.pullRequests) > 0 }},
+		{name: "remote branches", msgs: []tea.Msg{remoteBranchesMsg{branches: []git.RemoteBranchInfo{{}}}}, mode: func(m *model) bool { return len(m.remoteBranches) > 0 }, expected: true},
+		{name: "remote branches filter cleared", msgs: []tea.Msg{remoteBranchesMsg{branches: []git.RemoteBranchInfo{{}}}}, keys: []string{"x", "esc"}, mode: func(m *model) bool { return len(m.remoteBranches) > 0 && m.remoteFilter == "" }, expected: true},
+		{name: "remote branches filter backspaced", msgs: []tea.Msg{remoteBranchesMsg{branches: []git.RemoteBranchInfo{{}}}}, keys: []string{"fé", "backspace"}, mode: func(m *model) bool { return len(m.remoteBranches) > 0 && m.remoteFilter == "f" }, expected: true},
+		{name: "remote branches closed", msgs: []tea.Msg{remoteBranchesMsg{branches: []git.RemoteBranchInfo{{}}}}, keys: []string{"esc"}, mode: func(m *model) bool { return len(m.remoteBranches) > 0 }},
+		{name: "setup failures", msgs: []tea.Msg{failedSetup}, mode: func(m *model) bool { return m.setup != nil }, expected: true},
+		{name: "setup failures closed", msgs: []tea.Msg{failedSetup}, keys: []string{"esc"}, mode: func(m *model) bool { return m.setup != nil }},
+		{name: "blocked by a rebase", before: func(m *model) { m.showGitError(&git.OperationInProgressError{Operation: "rebase", Path: feature.Path}) }, mode: func(m *model) bool { return m.blockedBy != nil }, expected: true},
+		{name: "blocked by a rebase declined", before: func(m *model) { m.showGitError(&git.OperationInProgressError{Operation: "rebase", Path: feature.Path}) }, keys: []string{"n"}, mode: func(m *model) bool { return m.blockedBy != nil }},
+		{name: "pick blocker", before: func(m *model) { m.list.SetItems([]list.Item{todoItem}) }, keys: []string{"L"}, mode: func(m *model) bool { return m.pickingBlocker != nil }, expected: true},
+		{name: "pick blocker cancelled", before: func(m *model) { m.list.SetItems([]list.Item{todoItem}) }, keys: []string{"L", "esc"}, mode: func(m *model) bool { return m.pickingBlocker != nil }},
+		{name: "archive", keys: []string{"a"}, mode: func(m *model) bool { return m.archive != nil }, expected: true},
+		{name: "archive closed", keys: []string{"a", "esc"}, mode: func(m *model) bool { return m.archive != nil }},
+		{name: "archive toggled closed", keys: []string{"a", "a"}, mode: func(m *model) bool { return m.archive != nil }},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			m := &model{
+				config:    &config.Config{Name: "myapp"},
+				worktrees: []git.Worktree{mainWorktree, feature},
+				list:      list.New([]list.Item{featureItem}, newDelegate("default"), 80, 20),
+				textInput: newTextInput(""),
+				nameInput: newTextInput(""),
+			}
+			if tt.before != nil {
+				tt.before(m)
+			}
+			for _, msg := range tt.msgs {
+				m.Update(msg)
+			}
+			for _, key := range tt.keys {
+				m.Update(keyMsg(key))
+			}
+			if got := tt.mode(m); got != tt.expected {
+				t.Errorf("after %v, mode open = %v, want %v", tt.keys, got, tt.expected)
+			}
+			if m.inModal() != tt.expected {
+				t.Errorf("after %v, inModal() = %v, want %v", tt.keys, m.inModal(), tt.expected)
+			}
+		})
+	}
+}
+
+func TestBulkMarks(t *testing.T) {
+	cfg := &config.Config{Name: "myapp", Todos: []config.Todo{
+		{Description: "Fix login"},
+		{Description: "Add search"},
+		{Description: "Write docs"},
+	}}
+	m := &model{
+		config:    cfg,
+		list:      list.New(unlinkedTodoItems(cfg), newDelegate("default"), 80, 20),
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+
+	// Space marks and moves down, V twice marks the range between
+	for _, key := range []string{" ", "V", "j", "V"} {
+		m.Update(keyMsg(key))
+	}
+	if len(m.marked) != 3 || len(m.markedItems()) != 3 {
+		t.Fatalf("marked = %v, want all three todos", m.marked)
+	}
+
+	m.Update(keyMsg(" "))
+	if len(m.marked) != 2 {
+		t.Errorf("after unmarking, marked = %v, want two todos", m.marked)
+	}
+
+	// Which items apply is worked out in a command, the prompt opens when its result comes back
+	_, cmd := m.Update(keyMsg("D"))
+	if m.bulk != nil || cmd == nil {
+		t.Fatalf("D opened %+v in Update, want it planned in a command", m.bulk)
+	}
+	m.Update(cmd())
+	if m.bulk == nil || m.bulk.action != bulkDone || len(m.bulk.items) != 2 {
+		t.Fatalf("D opened %+v, want marking two todos done", m.bulk)
+	}
+	if !m.inModal() {
+		t.Error("inModal() = false while confirming a bulk action")
+	}
+	m.Update(keyMsg("n"))
+	if m.bulk != nil || len(m.marked) != 2 {
+		t.Errorf("cancelling left bulk = %+v and marked = %v, want no prompt and marks kept", m.bulk, m.marked)
+	}
+
+	m.Update(keyMsg("esc"))
+	if len(m.marked) != 0 || len(m.markedItems()) != 0 {
+		t.Errorf("esc left marked = %v", m.marked)
+	}
+}
+
+func TestWorktreeEffectHoldsKeys(t *testing.T) {
+	cfg := &config.Config{Name: "myapp", Todos: []config.Todo{{Description: "Fix login"}}}
+	m := &model{
+		config:    cfg,
+		list:      list.New(unlinkedTodoItems(cfg), newDelegate("default"), 80, 20),
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+
+	cmd := m.worktreeEffect(func() (string, error) { return "Done", nil })
+	if _, held := m.Update(keyMsg("n")); held != nil || m.creating {
+		t.Error("n opened the create dialog while an effect was running")
+	}
+	m.Update(cmd())
+	if m.busy || m.status != "Done" {
+		t.Errorf("after the effect, busy = %v and status = %q, want false and Done", m.busy, m.status)
+	}
+}
+
+func TestBulkDeleteSafety(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		command := strings.Join(args, " ")
+		switch {
+		case strings.Contains(command, "myapp-dirty status"):
+			return []byte(" M app.go\n"), nil
+		case strings.Contains(command, "myapp-wip rev-list"):
+			return []byte("3\n"), nil
+		case strings.Contains(command, "rev-list"):
+			return []byte("0\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	var items []worktreeItem
+	for _, name := range []string{"myapp-clean", "myapp-dirty", "myapp-wip"} {
+		wt := git.Worktree{Path: filepath.Join(t.TempDir(), name), Branch: "refs/heads/" + name}
+		items = append(items, worktreeItem{worktree: wt, isCheckedOut: true, marked: true})
+	}
+	m := &model{config: &config.Config{Name: "myapp"}}
+
+	op := &bulkOp{action: bulkDelete, unpushed: map[string]int{}}
+	checkBulkSafety(op, items, false)
+	if len(op.items) != 2 || len(op.skipped) != 1 || !strings.Contains(op.skipped[0], "myapp-dirty") {
+		t.Fatalf("items = %d, skipped = %v, want the dirty worktree skipped", len(op.items), op.skipped)
+	}
+	if op.unpushed["myapp-wip"] != 3 || len(op.unpushed) != 1 {
+		t.Errorf("unpushed = %v, want only myapp-wip with 3 commits", op.unpushed)
+	}
+
+	m.bulk = op
+	m.updateBulk(keyMsg("b"))
+	if !m.bulk.prune {
+		t.Error("b didn't turn on pruning branches with unpushed commits")
+	}
+}
+
+func TestSyncReviewsThrottled(t *testing.T) {
+	synced := time.Now().Add(-time.Minute)
+	m := &model{config: &config.Config{Name: "myapp"}, reviewsSynced: synced}
+
+	if cmd := m.syncReviewsEvery(reviewSyncInterval); cmd != nil || !m.reviewsSynced.Equal(synced) {
+		t.Errorf("syncReviewsEvery() a minute after the last sync ran again (synced %v)", m.reviewsSynced)
+	}
+}
+
+func TestCheckableSkipsReviews(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	cfg := &config.Config{Name: "myapp"}
+	worktrees := []git.Worktree{
+		{Path: "/src/myapp-login", ID: "login"},
+		{Path: "/src/myapp-review-7", ID: "review-7"},
+		{Path: "/src/myapp-review-8", ID: "review-8"},
+	}
+	if err := git.NewMetaStore(cacheKey(cfg)).SetChecksTrusted("review-8", true); err != nil {
+		t.Fatalf("SetChecksTrusted() error = %v", err)
+	}
+
+	var names []string
+	for _, wt := range checkable(cfg, worktrees) {
+		names = append(names, wt.Name())
+	}
+	if got := strings.Join(names, ","); got != "login,review-8" {
+		t.Errorf("checkable() = %s, want login,review-8", got)
+	}
+}
+
+func TestPullRequestBadge(t *testing.T) {
+	tests := []struct {
+		name     string
+		pr       github.PullRequestStatus
+		expected []string
+	}{
+		{name: "merged", pr: github.PullRequestStatus{Number: 7, State: "MERGED"}, expected: []string{"PR #7 merged"}},
+		{name: "draft", pr: github.PullRequestStatus{Number: 8, State: "OPEN", IsDraft: true}, expected: []string{"PR #8", "draft"}},
+		{
+			name: "approved and passing",
+			pr: github.PullRequestStatus{Number: 9, State: "OPEN", ReviewDecision: "APPROVED",
+				Checks: []github.StatusCheck{{Status: "COMPLETED", Conclusion: "SUCCESS"}}},
+			expected: []string{"PR #9", "approved", "✓ CI"},
+		},
+		{
+			name: "changes requested and failing",
+			pr: github.PullRequestStatus{Number: 10, State: "OPEN", ReviewDecision: "CHANGES_REQUESTED",
+				Checks: []github.StatusCheck{{State: "FAILURE"}}},
+			expected: []string{"PR #10", "changes requested", "✗ CI"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			badge := pullRequestBadge(&tt.pr)
+			for _, want := range tt.expected {
+				if !strings.Contains(badge, want) {
+					t.Errorf("pullRequestBadge() = %q, want it to contain %q", badge, want)
+				}
+			}
+		})
+	}
+}
+
+func TestBlockers(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "lfg-config.yaml")
+	todos := "todos:\n  - description: Add sessions\n    status: pending\n  - description: Add login\n    status: pending\n"
+	if err := os.WriteFile(path, []byte("name: myapp\n"+todos), 0644); err != nil {
+		t.Fatal(err)
+	}
+	cfg, err := config.LoadFromPath(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	m := &model{
+		config:    cfg,
+		list:      list.New(arrangeItems(cfg, View{}, "", unlinkedTodoItems(cfg)), newDelegate("default"), 80, 20),
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+
+	// L on the first todo, then the second todo as its blocker
+	for _, key := range []string{"L", "j", "enter"} {
+		m.Update(keyMsg(key))
+	}
+	if m.pickingBlocker != nil || m.err != nil {
+		t.Fatalf("picker still open or failed: %v", m.err)
+	}
+	if got := cfg.Todos[0].BlockedBy; got != "Add login" {
+		t.Fatalf("BlockedBy = %q, want Add login", got)
+	}
+	if item := arrangeItems(cfg, View{}, "", unlinkedTodoItems(cfg))[0].(worktreeItem); item.blocker != "Add login" {
+		t.Errorf("blocked item's blocker = %q, want Add login", item.blocker)
+	}
+
+	// Finishing the blocker is announced once
+	cfg.Todos[1].Status = config.TodoStatusDone
+	m.notifyUnblocked()
+	if !strings.Contains(m.status, "Now unblocked: Add sessions") {
+		t.Errorf("status = %q, want the unblocked todo", m.status)
+	}
+	m.status = ""
+	m.notifyUnblocked()
+	if m.status != "" {
+		t.Errorf("status after announcing = %q, want none", m.status)
+	}
+}
+
+func TestArchive(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "lfg-config.yaml")
+	todos := "todos:\n  - description: Add sessions\n    status: done\n  - description: Add login\n    status: pending\n"
+	if err := os.WriteFile(path, []byte("name: myapp\n"+todos), 0644); err != nil {
+		t.Fatal(err)
+	}
+	cfg, err := config.LoadFromPath(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	items := []list.Item{worktreeItem{todo: &cfg.Todos[0]}, worktreeItem{todo: &cfg.Todos[1]}}
+	m := &model{
+		config:    cfg,
+		list:      list.New(items, newDelegate("default"), 80, 20),
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+
+	m.Update(keyMsg("A"))
+	if m.err != nil || len(cfg.Todos) != 1 || len(cfg.Archived) != 1 {
+		t.Fatalf("after A, %d todos and %d archived (%v), want 1 and 1", len(cfg.Todos), len(cfg.Archived), m.err)
+	}
+	saved, err := config.LoadFromPath(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(saved.Archived) != 1 || saved.Archived[0].Description != "Add sessions" {
+		t.Errorf("saved archive = %+v, want Add sessions", saved.Archived)
+	}
+
+	// Open todos stay put
+	m.list.SetItems([]list.Item{worktreeItem{todo: &cfg.Todos[0]}})
+	m.Update(keyMsg("A"))
+	if m.err == nil || len(cfg.Todos) != 1 {
+		t.Errorf("archived a pending todo")
+	}
+	m.err = nil
+
+	// a shows the archive, where u restores the selected todo
+	m.Update(keyMsg("a"))
+	if m.archive == nil || !strings.Contains(m.View(), "Add sessions") {
+		t.Fatalf("archive not shown:\n%s", m.View())
+	}
+	m.Update(keyMsg("u"))
+	if len(cfg.Archived) != 0 || cfg.Todos[0].Description != "Add sessions" {
+		t.Errorf("after u, todos %+v and archive %+v", cfg.Todos, cfg.Archived)
+	}
+	m.Update(keyMsg("esc"))
+	if m.archive != nil {
+		t.Error("esc didn't close the archive")
+	}
+}
+
+func TestDeleteConfirmations(t *testing.T) {
+	feature := git.Worktree{Path: "/src/myapp-login", ID: "myapp-login", Branch: "refs/heads/login"}
+	report := &git.SafetyReport{Modified: 2, Unpushed: 1, Locked: true, LockReason: "on a USB drive"}
+
+	tests := []struct {
+		name  string
+		trash bool
+		steps []deleteStep
+	}{
+		{name: "every check", steps: []deleteStep{confirmDirty, confirmLocked, confirmBranch}},
+		{name: "changes go to the trash", trash: true, steps: []deleteStep{confirmLocked, confirmBranch}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			m := &model{
+				config:    &config.Config{Name: "myapp", Settings: &config.Settings{Trash: tt.trash}},
+				list:      list.New([]list.Item{worktreeItem{worktree: feature, isCheckedOut: true}}, newDelegate("default"), 80, 20),
+				textInput: newTextInput(""),
+				nameInput: newTextInput(""),
+			}
+			m.Update(keyMsg("d"))
+			m.safety = &deleteSafety{name: "myapp-login", report: report}
+
+			// Each check is asked about on its own after the delete itself
+			for _, step := range tt.steps {
+				m.Update(keyMsg("y"))
+				if m.deleteStep != step {
+					t.Fatalf("deleteStep = %d, want %d", m.deleteStep, step)
+				}
+			}
+			if !strings.Contains(m.View(), "Its branch 'login' has 1 unpushed commit") {
+				t.Errorf("branch question not shown:\n%s", m.View())
+			}
+			if m.deleteOpts.Force == tt.trash || !m.deleteOpts.IgnoreLocked || !m.deleteOpts.DeleteBranch {
+				t.Errorf("deleteOpts = %+v after confirming every question", m.deleteOpts)
+			}
+
+			m.Update(keyMsg("esc"))
+			if m.deleting {
+				t.Error("esc didn't cancel the delete")
+			}
+		})
+	}
+}
+
+func TestDeleteWaitsForSafety(t *testing.T) {
+	feature := git.Worktree{Path: "/src/myapp-login", ID: "myapp-login", Branch: "refs/heads/login"}
+	m := &model{
+		config:    &config.Config{Name: "myapp"},
+		list:      list.New([]list.Item{worktreeItem{worktree: feature, isCheckedOut: true}}, newDelegate("default"), 80, 20),
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+	m.Update(keyMsg("d"))
+
+	m.Update(keyMsg("y"))
+	if !m.deleting || m.deleteStep != confirmDelete {
+		t.Fatalf("y before the safety report moved on (deleting %v, step %d)", m.deleting, m.deleteStep)
+	}
+
+	// A failed check can't rule out unpushed commits, so the branch is asked about
+	m.Update(safetyMsg{safety: &deleteSafety{name: "myapp-login", err: fmt.Errorf("git status failed")}})
+	m.Update(keyMsg("y"))
+	if m.deleteStep != confirmBranch {
+		t.Fatalf("deleteStep = %d after a failed check, want the branch question", m.deleteStep)
+	}
+	if !strings.Contains(m.View(), "couldn't be checked") {
+		t.Errorf("branch question doesn't say the commits weren't checked:\n%s", m.View())
+	}
+}
+
+func TestScrolling(t *testing.T) {
+	cfg := &config.Config{Name: "myapp"}
+	for i := range 30 {
+		cfg.Todos = append(cfg.Todos, config.Todo{Description: fmt.Sprintf("Todo %d", i)})
+	}
+	delegate := newDelegate("default")
+	delegate.SetSpacing(0) // Rows packed tighter than the default, which clicks have to follow
+	m := &model{
+		config:    cfg,
+		list:      list.New(unlinkedTodoItems(cfg), delegate, 79, 20),
+		delegate:  delegate,
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+	}
+	perPage := m.list.Paginator.PerPage
+	if m.list.Paginator.TotalPages < 2 {
+		t.Fatalf("30 todos fit on one page of %d", perPage)
+	}
+	if bar := m.scrollbar(lipgloss.Height(m.list.View())); !strings.Contains(bar, "┃") {
+		t.Errorf("scrollbar = %q, want a thumb", bar)
+	}
+
+	// Clicks select the entry under them on the page shown
+	m.list.NextPage()
+	top := listRow() + m.itemsRow()
+	m.Update(tea.MouseMsg{X: 10, Y: top + m.rowHeight(), Button: tea.MouseButtonLeft, Action: tea.MouseActionPress})
+	if got := m.list.Index(); got != perPage+1 {
+		t.Errorf("clicked the second entry of page 2, index = %d, want %d", got, perPage+1)
+	}
+
+	m.Update(tea.MouseMsg{Button: tea.MouseButtonWheelDown, Action: tea.MouseActionPress})
+	if got := m.list.Index(); got != perPage+2 {
+		t.Errorf("after scrolling down, index = %d, want %d", got, perPage+2)
+	}
+
+	// Clicking the bottom of the scrollbar goes to the last page
+	m.Update(tea.MouseMsg{X: m.list.Width(), Y: top + perPage*m.rowHeight() - 1, Button: tea.MouseButtonLeft, Action: tea.MouseActionPress})
+	if got, last := m.list.Paginator.Page, m.list.Paginator.TotalPages-1; got != last {
+		t.Errorf("clicking the end of the scrollbar showed page %d, want %d", got, last)
+	}
+}
+
+func TestAttachHistory(t *testing.T) {
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	path := filepath.Join(t.TempDir(), "lfg-config.yaml")
+	if err := os.WriteFile(path, []byte("name: myapp\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	cfg, err := config.LoadFromPath(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	store := git.NewHistoryStore(cacheKey(cfg))
+	for _, name := range []string{"myapp", "myapp-login", "myapp-search"} {
+		if err := store.Visit(name); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	worktrees := []git.Worktree{
+		{Path: "/src/myapp", ID: "myapp"},
+		{Path: "/src/myapp-search", ID: "myapp-search"},
+	}
+	m := &model{
+		config:    cfg,
+		worktrees: worktrees,
+		list:      list.New(nil, newDelegate("default"), 80, 20),
+		textInput: newTextInput(""),
+		nameInput: newTextInput(""),
+		history:   loadHistory(cfg),
+	}
+	if crumbs := m.breadcrumbs(); !strings.Contains(crumbs, "myapp-login") || !strings.Contains(crumbs, "myapp-search") {
+		t.Errorf("breadcrumbs = %q, want the opened worktrees", crumbs)
+	}
+
+	// Back skips the deleted login worktree
+	if _, cmd := m.Update(keyMsg("[")); cmd == nil || m.selectedWorktree != "myapp" {
+		t.Fatalf("[ opened %q, want myapp", m.selectedWorktree)
+	}
+	m.selectedWorktree = ""
+	m.Update(keyMsg("["))
+	if m.selectedWorktree != "" || !strings.Contains(m.status, "further back") {
+		t.Errorf("[ at the start opened %q, status %q", m.selectedWorktree, m.status)
+	}
+	if m.Update(keyMsg("]")); m.selectedWorktree != "myapp-search" {
+		t.Errorf("] opened %q, want myapp-search", m.selectedWorktree)
+	}
+}
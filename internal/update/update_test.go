@@ -0,0 +1,98 @@
+package update
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestIsNewer(t *testing.T) {
+	tests := []struct {
+		current  string
+		latest   string
+		expected bool
+	}{
+		{current: "1.2.0", latest: "v1.3.0", expected: true},
+		{current: "v1.2.0", latest: "1.2.0", expected: false},
+		{current: "1.10.0", latest: "1.9.0", expected: false},
+		{current: "1.2.0", latest: "1.2.1", expected: true},
+		{current: "2.0.0-rc.1", latest: "2.0.0", expected: false},
+		{current: "dev", latest: "1.0.0", expected: false},
+		{current: "1.0.0", latest: "nightly", expected: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.current+"->"+tt.latest, func(t *testing.T) {
+			result := IsNewer(tt.current, tt.latest)
+			if result != tt.expected {
+				t.Errorf("IsNewer(%q, %q) = %v, want %v", tt.current, tt.latest, result, tt.expected)
+			}
+		})
+	}
+}
+
+func TestIsRelease(t *testing.T) {
+	for version, expected := range map[string]bool{"1.2.0": true, "v2.0.0-rc.1": true, "1.2.0-3-gabc123": true, "dev": false, "": false} {
+		if got := IsRelease(version); got != expected {
+			t.Errorf("IsRelease(%q) = %v, want %v", version, got, expected)
+		}
+	}
+}
+
+func TestParseChecksums(t *testing.T) {
+	data := []byte("abc123  lfg-darwin-arm64\nDEF456 *lfg-linux-amd64\n\nmalformed line here\n")
+	sums := parseChecksums(data)
+
+	if sums["lfg-darwin-arm64"] != "abc123" {
+		t.Errorf("Expected darwin checksum abc123, got %q", sums["lfg-darwin-arm64"])
+	}
+	if sums["lfg-linux-amd64"] != "def456" {
+		t.Errorf("Expected binary-mode checksum to be parsed and lowercased, got %q", sums["lfg-linux-amd64"])
+	}
+	if len(sums) != 2 {
+		t.Errorf("Expected 2 checksums, got %d", len(sums))
+	}
+}
+
+func TestManagedBy(t *testing.T) {
+	tests := []struct {
+		path     string
+		expected string
+	}{
+		{path: "/opt/homebrew/Cellar/lfg/1.0.0/bin/lfg", expected: "brew"},
+		{path: "/home/me/go/bin/lfg", expected: "go install"},
+		{path: "/usr/local/bin/lfg", expected: ""},
+	}
+
+	for _, tt := range tests {
+		if result := ManagedBy(tt.path); result != tt.expected {
+			t.Errorf("ManagedBy(%q) = %q, want %q", tt.path, result, tt.expected)
+		}
+	}
+}
+
+func TestReplaceExecutable(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "lfg")
+	if err := os.WriteFile(path, []byte("old"), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := replaceExecutable(path, []byte("new")); err != nil {
+		t.Fatalf("replaceExecutable() error = %v", err)
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(data) != "new" {
+		t.Errorf("Expected executable to be replaced, got %q", data)
+	}
+	info, err := os.Stat(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if info.Mode().Perm()&0100 == 0 {
+		t.Errorf("Expected executable to be executable, got mode %v", info.Mode())
+	}
+}
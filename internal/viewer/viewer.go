@@ -48,6 +48,9 @@ func Run(worktreeName string, cfg *config.Config) error {
 		if todo.GitHubBody != "" {
 			content.WriteString(todo.GitHubBody + "\n\n")
 		}
+		if todo.Notes != "" {
+			content.WriteString(todo.Notes + "\n\n")
+		}
 
 		content.WriteString("**Status:** `" + string(todo.Status) + "`\n\n")
 
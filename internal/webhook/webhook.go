@@ -0,0 +1,113 @@
+// Package webhook sends lfg's events to the webhooks in the config, so chat, time trackers or dashboards
+// can follow worktrees being created, opened, renamed and deleted without lfg knowing about each of them
+package webhook
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"os"
+	"os/exec"
+	"slices"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// timeout bounds each delivery, so a slow service can't hold up lfg exiting
+const timeout = 5 * time.Second
+
+var (
+	mu       sync.Mutex
+	repo     string
+	hooks    []config.Webhook
+	inflight sync.WaitGroup
+)
+
+// payload is the event with the repository it happened in
+type payload struct {
+	Repo string `json:"repo,omitempty"`
+	events.Event
+}
+
+// Configure sets the webhooks events are sent to, from the repository's config
+func Configure(cfg *config.Config) {
+	mu.Lock()
+	defer mu.Unlock()
+	repo, hooks = cfg.Name, cfg.Webhooks
+}
+
+// Deliver sends an event to each webhook that wants it, in the background
+// Subscribe it to the event stream with events.Subscribe. Failures only warn: webhooks never stop lfg
+func Deliver(e events.Event) {
+	mu.Lock()
+	targets := hooks
+	data, err := json.Marshal(payload{Repo: repo, Event: e})
+	mu.Unlock()
+	if err != nil {
+		return
+	}
+
+	for _, hook := range targets {
+		if len(hook.Events) > 0 && !slices.Contains(hook.Events, e.Type) {
+			continue
+		}
+		inflight.Add(1)
+		go func() {
+			defer inflight.Done()
+			if err := send(hook, e.Type, data); err != nil {
+				fmt.Fprintf(os.Stderr, "Warning: webhook for %s failed: %v\n", e.Type, err)
+			}
+		}()
+	}
+}
+
+// Wait waits for deliveries still in progress, for as long as one may take
+func Wait() {
+	done := make(chan struct{})
+	go func() {
+		inflight.Wait()
+		close(done)
+	}()
+	select {
+	case <-done:
+	case <-time.After(timeout):
+	}
+}
+
+func send(hook config.Webhook, eventType string, data []byte) error {
+	ctx, cancel := context.WithTimeout(context.Background(), timeout)
+	defer cancel()
+
+	if hook.Command != "" {
+		cmd := exec.CommandContext(ctx, "sh", "-c", hook.Command)
+		cmd.Stdin = bytes.NewReader(data)
+		cmd.Env = append(os.Environ(), "LFG_EVENT="+eventType)
+		if output, err := runner.CombinedOutput(cmd); err != nil {
+			return fmt.Errorf("%s: %s", hook.Command, strings.TrimSpace(string(output)))
+		}
+	}
+
+	if hook.URL != "" {
+		req, err := http.NewRequestWithContext(ctx, http.MethodPost, hook.URL, bytes.NewReader(data))
+		if err != nil {
+			return err
+		}
+		req.Header.Set("Content-Type", "application/json")
+		resp, err := http.DefaultClient.Do(req)
+		if err != nil {
+			return err
+		}
+		resp.Body.Close()
+		if resp.StatusCode >= 300 {
+			return fmt.Errorf("%s responded %s", hook.URL, resp.Status)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,58 @@
+package webhook
+
+import (
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"reflect"
+	"sync"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestDeliver(t *testing.T) {
+	var mu sync.Mutex
+	var received []payload
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+		var p payload
+		if err := json.Unmarshal(body, &p); err != nil {
+			t.Errorf("webhook body is not an event: %q", body)
+		}
+		mu.Lock()
+		received = append(received, p)
+		mu.Unlock()
+	}))
+	defer server.Close()
+
+	fake := &runner.Fake{}
+	defer runner.Set(fake)()
+	defer Configure(&config.Config{})
+
+	Configure(&config.Config{Name: "myapp", Webhooks: []config.Webhook{
+		{URL: server.URL},
+		{Command: "notify-team", Events: []string{events.WorktreeDeleted}},
+	}})
+	Deliver(events.Event{Type: events.WorktreeCreated, Worktree: "myapp-login"})
+	Deliver(events.Event{Type: events.WorktreeDeleted, Worktree: "myapp-login"})
+	Wait()
+
+	if len(received) != 2 {
+		t.Fatalf("URL received %d events, want 2", len(received))
+	}
+	for _, p := range received {
+		if p.Repo != "myapp" || p.Worktree != "myapp-login" {
+			t.Errorf("URL received %+v, want myapp's myapp-login", p)
+		}
+	}
+
+	// The command only wants deletions
+	expected := [][]string{{"sh", "-c", "notify-team"}}
+	if !reflect.DeepEqual(fake.Calls, expected) {
+		t.Errorf("commands run = %q, want %q", fake.Calls, expected)
+	}
+}
@@ -0,0 +1,235 @@
+// Package worktree creates, renames and deletes worktrees together with their todos and tmux sessions, so the
+// TUI, the command line and the editor integration all keep the same bookkeeping
+package worktree
+
+import (
+	"errors"
+	"fmt"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+	"github.com/markcipolla/lfg/internal/multiplexer"
+	"github.com/markcipolla/lfg/internal/tmux"
+	"github.com/markcipolla/lfg/internal/webhook"
+)
+
+// ErrNotSaved is wrapped by errors from saving the config after the worktree was already created or
+// deleted, so callers can carry on
+var ErrNotSaved = errors.New("failed to save config")
+
+// CreateOptions describes a worktree to create
+type CreateOptions struct {
+	Name        string
+	Branch      string      // New branch to create, the worktree name when empty
+	From        *git.Branch // Existing branch to check out instead of creating one
+	Description string      // Todo linked to the worktree, also recorded as the branch description
+	Notes       string      // Notes for the todo
+}
+
+// Configure applies the config's git, tmux, multiplexer and webhook settings, once it's loaded and before
+// anything runs git or touches a session
+func Configure(cfg *config.Config) {
+	git.Configure(cfg.Git)
+	tmux.Configure(cfg.TmuxOptions())
+	tmux.SaveOnKill = cfg.Settings != nil && cfg.Settings.SaveSessions
+	multiplexer.Configure(cfg.Multiplexer)
+	webhook.Configure(cfg)
+}
+
+// SplitPasted turns pasted text, such as an issue's title and body or a chat message, into a todo:
+// its first line (without a leading markdown heading marker) is the description and the rest the notes
+func SplitPasted(text string) (description, notes string) {
+	text = strings.TrimSpace(strings.ReplaceAll(text, "\r\n", "\n"))
+	first, rest, _ := strings.Cut(text, "\n")
+	description = strings.TrimSpace(strings.TrimLeft(first, "# "))
+	return description, strings.TrimSpace(rest)
+}
+
+// Create creates a worktree, records its todo as the branch description and links the todo to it,
+// then saves the config. It returns the new worktree's path
+func Create(cfg *config.Config, opts CreateOptions) (string, error) {
+	if err := git.ValidateWorktreeName(opts.Name); err != nil {
+		return "", err
+	}
+	branch := opts.Branch
+	if branch == "" {
+		branch = opts.Name
+	}
+
+	var err error
+	if opts.From != nil {
+		branch = opts.From.LocalName()
+		err = git.CreateWorktreeFromBranch(opts.Name, *opts.From)
+	} else if err = git.ValidateBranchName(branch); err == nil {
+		err = git.CreateWorktreeWithBranch(opts.Name, branch)
+	}
+	if err != nil {
+		return "", err
+	}
+
+	path, err := git.GetWorktreePath(opts.Name)
+	if err != nil {
+		return "", err
+	}
+	// The worktree is usable without a branch description, so failing to set one only warns
+	if err := git.DescribeBranch(path, branch, opts.Description, cfg.Templates); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+	}
+
+	cfg.AddTodo(opts.Description, opts.Name)
+	if todo := cfg.GetTodoForWorktree(opts.Name); todo != nil {
+		todo.Notes = opts.Notes
+	}
+	if err := cfg.Save(); err != nil {
+		return path, fmt.Errorf("%w: %w", ErrNotSaved, err)
+	}
+	return path, nil
+}
+
+// DeleteOptions says how far deleting a worktree may go
+type DeleteOptions struct {
+	Force        bool // Delete even with uncommitted changes or untracked files
+	IgnoreLocked bool // Delete even when it's locked with `git worktree lock`
+	DeleteBranch bool // Delete the worktree's branch too
+	RemoveTodo   bool // Remove the linked todo instead of marking it done
+}
+
+// Delete kills a worktree's tmux session (saved first when save_sessions is on), then deletes the
+// worktree and forgets it in the config and metadata store. Worktrees with uncommitted work are moved to the trash instead
+// when it's enabled, keeping their branch so they can be restored, and the trash entry is returned
+// Worktrees with an unfinished rebase, merge or cherry-pick, and dirty or locked worktrees opts doesn't
+// force past, are refused before anything is touched, so their session keeps running
+func Delete(cfg *config.Config, wt git.Worktree, opts DeleteOptions) (*git.TrashEntry, error) {
+	name := wt.Name()
+	trash := cfg.Settings != nil && cfg.Settings.Trash
+	dirty := false
+	if wt.Path != "" {
+		if err := git.EnsureNoOperation(wt.Path); err != nil {
+			return nil, err
+		}
+		if report, err := git.CheckSafety(wt); err == nil {
+			dirty = report.Dirty()
+			if dirty && !opts.Force && !trash {
+				return nil, fmt.Errorf("%s has uncommitted changes or untracked files", wt.DisplayName())
+			}
+			if report.Locked && !opts.IgnoreLocked {
+				return nil, fmt.Errorf("%s is locked with git worktree lock", wt.DisplayName())
+			}
+		}
+	}
+
+	multiplexer.AdoptLegacySessions(cfg.Name, name)
+	mux := multiplexer.Current()
+	session := mux.SessionName(cfg.Name, name)
+	// Killed even when the session itself is gone, with tmux that also kills the separate sessions
+	// started alongside it
+	if err := mux.KillSession(session); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: failed to kill %s session: %v\n", mux.Name(), err)
+	}
+	tmux.ForgetWindowOverrides(session)
+
+	var trashed *git.TrashEntry
+	var err error
+	if dirty && trash {
+		trashed, err = git.TrashWorktree(name)
+	} else {
+		err = git.RemoveWorktree(name, git.RemoveOptions{Force: opts.Force, IgnoreLocked: opts.IgnoreLocked, DeleteBranch: opts.DeleteBranch})
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	if opts.RemoveTodo {
+		if todo := cfg.GetTodoForWorktree(name); todo != nil {
+			events.Emit(events.Event{Type: events.TodoCompleted, Worktree: name, Todo: todo.Description})
+		}
+		cfg.RemoveTodo(name)
+	} else {
+		cfg.MarkTodoDone(name)
+	}
+	cfg.ForgetWorktree(name)
+	if mainPath, err := git.GetMainWorktreePath(); err == nil {
+		_ = git.NewMetaStore(mainPath).Forget(name)
+	}
+	if err := cfg.Save(); err != nil {
+		return trashed, fmt.Errorf("%w: %w", ErrNotSaved, err)
+	}
+	return trashed, nil
+}
+
+// Rename moves a worktree to a new name with its branch, when that is named after it, then renames its
+// tmux session and moves its todo, colour, pin and metadata to the new name and saves the config.
+// It returns the renamed worktree
+func Rename(cfg *config.Config, wt git.Worktree, newName string) (git.Worktree, error) {
+	oldName := wt.Name()
+	multiplexer.AdoptLegacySessions(cfg.Name, oldName)
+	renamed, err := git.RenameWorktree(wt, newName)
+	if err != nil {
+		return wt, err
+	}
+
+	// The worktree has moved by now, so a session that can't follow only warns
+	mux := multiplexer.Current()
+	oldSession, newSession := mux.SessionName(cfg.Name, oldName), mux.SessionName(cfg.Name, renamed.Name())
+	if multiplexer.IsTmux() {
+		if err := tmux.RenameSession(oldSession, newSession, oldName, renamed.Name()); err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: %v\n", err)
+		}
+	} else if mux.SessionExists(oldSession) {
+		fmt.Fprintf(os.Stderr, "Warning: %s sessions can't be renamed, %s keeps its old name\n", mux.Name(), oldSession)
+	}
+
+	cfg.RenameWorktree(oldName, renamed.Name())
+	if mainPath, err := git.GetMainWorktreePath(); err == nil {
+		_ = git.NewMetaStore(mainPath).Rename(oldName, renamed.Name())
+	}
+	if err := cfg.Save(); err != nil {
+		return renamed, fmt.Errorf("%w: %w", ErrNotSaved, err)
+	}
+	return renamed, nil
+}
+
+// MigrateGlobalTodos moves the todos in the global config that belong to the repository into its config:
+// those whose worktree is one of the repository's worktree directories, found by path as worktree names
+// repeat across repositories, and those linked to an issue on its GitHub remote. It returns how many
+// were moved and how many were left behind
+func MigrateGlobalTodos(cfg *config.Config, worktrees []git.Worktree) (int, int, error) {
+	mainPath, err := git.GetMainWorktreePath()
+	if err != nil {
+		return 0, 0, err
+	}
+	// New worktrees are made next to the main one, as older versions did too
+	paths := make(map[string]bool)
+	for _, wt := range worktrees {
+		if filepath.Clean(wt.Path) != filepath.Clean(mainPath) {
+			paths[filepath.Clean(wt.Path)] = true
+		}
+	}
+	repo, _ := github.GetRepoInfo()
+
+	return cfg.MigrateGlobalTodos(func(todo config.Todo) bool {
+		if todo.Worktree != "" && paths[filepath.Join(filepath.Dir(mainPath), todo.Worktree)] {
+			return true
+		}
+		return repo != nil && onRemote(todo.GitHubURL, repo)
+	})
+}
+
+// onRemote reports whether a GitHub URL, such as an issue's, is in the repository
+func onRemote(link string, repo *github.RepoInfo) bool {
+	u, err := url.Parse(link)
+	if err != nil || link == "" {
+		return false
+	}
+	if repo.Host != "" && !strings.EqualFold(u.Host, repo.Host) {
+		return false
+	}
+	parts := strings.Split(strings.Trim(u.Path, "/"), "/")
+	return len(parts) >= 2 && strings.EqualFold(parts[0], repo.Owner) && strings.EqualFold(parts[1], repo.Name)
+}
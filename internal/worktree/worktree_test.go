@@ -0,0 +1,280 @@
+package worktree
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/github"
+)
+
+// newTestProject creates a repository with one commit and a config for it, working from the repository
+func newTestProject(t *testing.T, settings string) (*config.Config, string) {
+	t.Helper()
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git not installed, skipping test")
+	}
+
+	t.Setenv("GIT_AUTHOR_NAME", "lfg")
+	t.Setenv("GIT_AUTHOR_EMAIL", "lfg@example.com")
+	t.Setenv("GIT_COMMITTER_NAME", "lfg")
+	t.Setenv("GIT_COMMITTER_EMAIL", "lfg@example.com")
+
+	tmpDir := t.TempDir()
+	mainPath := filepath.Join(tmpDir, "myapp")
+	runGit(t, tmpDir, "init", "-q", mainPath)
+	writeFile(t, filepath.Join(mainPath, "README"), "hello\n")
+	runGit(t, mainPath, "add", "README")
+	runGit(t, mainPath, "commit", "-q", "-m", "initial")
+
+	configPath := filepath.Join(tmpDir, "lfg-config.yaml")
+	writeFile(t, configPath, "name: myapp\n"+settings)
+	cfg, err := config.LoadFromPath(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	wd, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chdir(mainPath); err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { os.Chdir(wd) })
+
+	return cfg, configPath
+}
+
+func TestCreateAndDelete(t *testing.T) {
+	cfg, configPath := newTestProject(t, "")
+
+	path, err := Create(cfg, CreateOptions{Name: "myapp-login", Branch: "feat/login", Description: "Add login", Notes: "Use OAuth"})
+	if err != nil {
+		t.Fatalf("Create() error = %v", err)
+	}
+	if branch := strings.TrimSpace(runGit(t, path, "branch", "--show-current")); branch != "feat/login" {
+		t.Errorf("branch = %q, want %q", branch, "feat/login")
+	}
+
+	saved, err := config.LoadFromPath(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	todo := saved.GetTodoForWorktree("myapp-login")
+	if todo == nil || todo.Description != "Add login" || todo.Notes != "Use OAuth" {
+		t.Fatalf("saved todo = %+v, want Add login with its notes", todo)
+	}
+
+	wt, err := git.FindWorktree("myapp-login")
+	if err != nil {
+		t.Fatal(err)
+	}
+	trashed, err := Delete(cfg, *wt, DeleteOptions{DeleteBranch: true})
+	if err != nil {
+		t.Fatalf("Delete() error = %v", err)
+	}
+	if trashed != nil {
+		t.Errorf("Delete() trashed a clean worktree")
+	}
+	if _, err := os.Stat(path); !os.IsNotExist(err) {
+		t.Errorf("worktree directory still exists: %v", err)
+	}
+	if branches := runGit(t, ".", "branch", "--list", "feat/login"); branches != "" {
+		t.Errorf("branch still exists: %q", branches)
+	}
+
+	saved, err = config.LoadFromPath(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if todo := saved.GetTodoForWorktree("myapp-login"); todo == nil || todo.Status != config.TodoStatusDone {
+		t.Errorf("saved todo = %+v, want it marked done", todo)
+	}
+}
+
+func TestCreateInvalidName(t *testing.T) {
+	cfg, _ := newTestProject(t, "")
+
+	for _, name := range []string{"", "../escaped", "feat/login", ".."} {
+		if _, err := Create(cfg, CreateOptions{Name: name, Branch: "feat-login", Description: "Add login"}); err == nil {
+			t.Errorf("Create() with name %q succeeded, want an error", name)
+		}
+	}
+	if len(cfg.Todos) != 0 {
+		t.Errorf("Create() with invalid names added todos: %+v", cfg.Todos)
+	}
+}
+
+func TestDeleteDirty(t *testing.T) {
+	tests := []struct {
+		name      string
+		settings  string
+		lock      bool
+		opts      DeleteOptions
+		wantErr   bool
+		wantTrash bool
+	}{
+		{name: "refused", wantErr: true},
+		{name: "forced", opts: DeleteOptions{Force: true}},
+		{name: "trashed", settings: "settings:\n  trash: true\n", wantTrash: true},
+		{name: "locked", lock: true, opts: DeleteOptions{Force: true}, wantErr: true},
+		{name: "lock ignored", lock: true, opts: DeleteOptions{Force: true, IgnoreLocked: true}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cfg, configPath := newTestProject(t, tt.settings)
+			t.Setenv("XDG_DATA_HOME", t.TempDir())
+
+			path, err := Create(cfg, CreateOptions{Name: "myapp-login", Description: "Add login"})
+			if err != nil {
+				t.Fatalf("Create() error = %v", err)
+			}
+			writeFile(t, filepath.Join(path, "notes.txt"), "wip\n")
+			if tt.lock {
+				runGit(t, path, "worktree", "lock", path)
+			}
+
+			wt, err := git.FindWorktree("myapp-login")
+			if err != nil {
+				t.Fatal(err)
+			}
+			tt.opts.RemoveTodo = true
+			trashed, err := Delete(cfg, *wt, tt.opts)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("Delete() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if (trashed != nil) != tt.wantTrash {
+				t.Errorf("Delete() trashed = %v, want %v", trashed != nil, tt.wantTrash)
+			}
+
+			saved, err := config.LoadFromPath(configPath)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if todo := saved.GetTodoForWorktree("myapp-login"); (todo != nil) != tt.wantErr {
+				t.Errorf("saved todo = %+v, want it kept only when refused", todo)
+			}
+		})
+	}
+}
+
+func runGit(t *testing.T, dir string, args ...string) string {
+	t.Helper()
+	cmd := exec.Command("git", args...)
+	cmd.Dir = dir
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		t.Fatalf("git %s: %v\n%s", strings.Join(args, " "), err, output)
+	}
+	return string(output)
+}
+
+func writeFile(t *testing.T, path, content string) {
+	t.Helper()
+	if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestRename(t *testing.T) {
+	t.Setenv("XDG_DATA_HOME", t.TempDir())
+	cfg, configPath := newTestProject(t, "")
+
+	if _, err := Create(cfg, CreateOptions{Name: "myapp-login", Branch: "feat/myapp-login", Description: "Add login"}); err != nil {
+		t.Fatalf("Create() error = %v", err)
+	}
+	cfg.SetWorktreeColor("myapp-login", "blue")
+	cfg.TogglePin("myapp-login")
+
+	wt, err := git.FindWorktree("myapp-login")
+	if err != nil {
+		t.Fatal(err)
+	}
+	renamed, err := Rename(cfg, *wt, "myapp-signin")
+	if err != nil {
+		t.Fatalf("Rename() error = %v", err)
+	}
+	if renamed.Name() != "myapp-signin" || filepath.Base(renamed.Path) != "myapp-signin" {
+		t.Errorf("Rename() = %+v, want the worktree at myapp-signin", renamed)
+	}
+
+	// Git's id follows the name, so the worktree is found by it
+	found, err := git.FindWorktree("myapp-signin")
+	if err != nil {
+		t.Fatalf("FindWorktree() after renaming: %v", err)
+	}
+	if found.Path != renamed.Path {
+		t.Errorf("FindWorktree() = %q, want %q", found.Path, renamed.Path)
+	}
+	if branch := strings.TrimSpace(runGit(t, found.Path, "branch", "--show-current")); branch != "feat/myapp-signin" {
+		t.Errorf("branch = %q, want %q", branch, "feat/myapp-signin")
+	}
+	if _, err := git.FindWorktree("myapp-login"); err == nil {
+		t.Error("FindWorktree() still finds the old name")
+	}
+
+	saved, err := config.LoadFromPath(configPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if todo := saved.GetTodoForWorktree("myapp-signin"); todo == nil || todo.Description != "Add login" {
+		t.Errorf("saved todo = %+v, want Add login linked to the new name", todo)
+	}
+	if saved.WorktreeColor("myapp-signin") != "blue" || !saved.IsPinned("myapp-signin") {
+		t.Errorf("colour and pin didn't follow the rename: colors %v, pinned %v", saved.Colors, saved.Pinned)
+	}
+
+	if _, err := Rename(cfg, renamed, "myapp"); err == nil {
+		t.Error("Rename() onto an existing directory should fail")
+	}
+}
+
+func TestOnRemote(t *testing.T) {
+	repo := &github.RepoInfo{Owner: "acme", Name: "shop", Host: "github.com"}
+	tests := []struct {
+		link     string
+		expected bool
+	}{
+		{link: "https://github.com/acme/shop/issues/12", expected: true},
+		{link: "https://github.com/Acme/Shop/issues/12", expected: true},
+		{link: "https://github.com/acme/shop-api/issues/12", expected: false},
+		{link: "https://github.example.com/acme/shop/issues/12", expected: false},
+		{link: "", expected: false},
+	}
+
+	for _, tt := range tests {
+		if got := onRemote(tt.link, repo); got != tt.expected {
+			t.Errorf("onRemote(%q) = %v, want %v", tt.link, got, tt.expected)
+		}
+	}
+}
+
+func TestSplitPasted(t *testing.T) {
+	tests := []struct {
+		name        string
+		text        string
+		description string
+		notes       string
+	}{
+		{name: "single line", text: "Fix the flaky login spec\n", description: "Fix the flaky login spec"},
+		{name: "title and body", text: "Checkout times out\n\nSeen on large carts.\nSee the logs.", description: "Checkout times out", notes: "Seen on large carts.\nSee the logs."},
+		{name: "markdown heading", text: "## Add dark mode\r\nUsers keep asking", description: "Add dark mode", notes: "Users keep asking"},
+		{name: "leading blank lines", text: "\n\n  Rotate the keys  \n", description: "Rotate the keys"},
+		{name: "empty", text: "  \n"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			description, notes := SplitPasted(tt.text)
+			if description != tt.description || notes != tt.notes {
+				t.Errorf("SplitPasted() = %q, %q, want %q, %q", description, notes, tt.description, tt.notes)
+			}
+		})
+	}
+}
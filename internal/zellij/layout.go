@@ -0,0 +1,103 @@
+package zellij
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+)
+
+// Layout returns the KDL layout a worktree's session starts with: the agent pane on top, taking 45%
+// of the tab, and the config's rows below it, in a tab named after the worktree
+// Pane commands run in shell, which stays open once they exit unless the pane has remain_on_exit,
+// where zellij keeps the exited command's pane around to re-run it instead
+func Layout(worktreeName, path, lfg, shell string, cfg *config.Config) string {
+	var b strings.Builder
+	b.WriteString("layout {\n")
+	b.WriteString("    default_tab_template {\n")
+	b.WriteString("        pane size=1 borderless=true {\n")
+	b.WriteString("            plugin location=\"zellij:tab-bar\"\n")
+	b.WriteString("        }\n")
+	b.WriteString("        children\n")
+	b.WriteString("        pane size=2 borderless=true {\n")
+	b.WriteString("            plugin location=\"zellij:status-bar\"\n")
+	b.WriteString("        }\n")
+	b.WriteString("    }\n")
+	fmt.Fprintf(&b, "    tab name=%s cwd=%s focus=true {\n", quote(worktreeName), quote(path))
+	fmt.Fprintf(&b, "        pane size=\"45%%\" name=\"agent\" focus=true command=%s {\n", quote(lfg))
+	fmt.Fprintf(&b, "            args \"--agent\" \"--config\" %s %s\n", quote(cfg.GetConfigPath()), quote(worktreeName))
+	b.WriteString("        }\n")
+	b.WriteString("        pane size=\"55%\" {\n")
+	for _, row := range cfg.GetLayout() {
+		size := percentage(row.Height)
+		if len(row.Panes) > 0 {
+			writePanes(&b, 3, size, row.Panes, true, shell)
+			continue
+		}
+		writePane(&b, 3, size, row.Name, row.Command, row.RemainOnExit, shell)
+	}
+	b.WriteString("        }\n")
+	b.WriteString("    }\n")
+	b.WriteString("}\n")
+	return b.String()
+}
+
+// writePanes writes a pane split into panes, side by side or stacked, each of which may be split further
+// lfg's "horizontal" split puts panes side by side, which zellij calls a vertical split direction
+func writePanes(b *strings.Builder, depth int, size string, panes []config.Pane, sideBySide bool, shell string) {
+	indent := strings.Repeat("    ", depth)
+	fmt.Fprintf(b, "%spane%s", indent, size)
+	if sideBySide {
+		b.WriteString(" split_direction=\"vertical\"")
+	}
+	b.WriteString(" {\n")
+	for _, pane := range panes {
+		size := percentage(pane.Height)
+		if sideBySide {
+			size = percentage(pane.Width)
+		}
+		if len(pane.Panes) > 0 {
+			writePanes(b, depth+1, size, pane.Panes, pane.Split == config.SplitHorizontal, shell)
+			continue
+		}
+		writePane(b, depth+1, size, pane.Name, pane.Command, pane.RemainOnExit, shell)
+	}
+	fmt.Fprintf(b, "%s}\n", indent)
+}
+
+// writePane writes a single pane, running its command in shell
+func writePane(b *strings.Builder, depth int, size, name string, command *string, remain bool, shell string) {
+	indent := strings.Repeat("    ", depth)
+	fmt.Fprintf(b, "%spane%s", indent, size)
+	if name != "" {
+		fmt.Fprintf(b, " name=%s", quote(name))
+	}
+	if command == nil || *command == "" {
+		b.WriteString("\n")
+		return
+	}
+
+	script := *command
+	if !remain {
+		script += "; exec " + shell
+	}
+	fmt.Fprintf(b, " command=%s {\n", quote(shell))
+	fmt.Fprintf(b, "%s    args \"-c\" %s\n", indent, quote(script))
+	fmt.Fprintf(b, "%s}\n", indent)
+}
+
+// percentage returns a size attribute for a percentage like "40%", empty for anything else so zellij
+// shares the space out
+func percentage(s string) string {
+	value, err := strconv.Atoi(strings.TrimSuffix(strings.TrimSpace(s), "%"))
+	if err != nil || value <= 0 {
+		return ""
+	}
+	return fmt.Sprintf(" size=\"%d%%\"", value)
+}
+
+// quote returns s as a KDL string
+func quote(s string) string {
+	return `"` + strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`, "\t", `\t`).Replace(s) + `"`
+}
@@ -0,0 +1,176 @@
+// Package zellij runs worktree sessions in Zellij, for configs with multiplexer: zellij
+// Sessions are started from a generated KDL layout with the same agent pane and rows as the tmux ones.
+// Zellij has no way to tag its sessions, so any session with the worktree's name is taken as lfg's
+package zellij
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+// IsInstalled checks if zellij is available
+func IsInstalled() bool {
+	_, err := exec.LookPath("zellij")
+	return err == nil
+}
+
+// Command returns a zellij command
+func Command(args ...string) *exec.Cmd {
+	return exec.Command("zellij", args...)
+}
+
+// InsideZellij reports whether lfg is running inside a zellij session
+func InsideZellij() bool {
+	return os.Getenv("ZELLIJ") != ""
+}
+
+// SessionName returns the zellij session name for a worktree, namespaced by project like the tmux ones
+// Zellij names its session sockets after sessions, so the "/" between them becomes "_"
+func SessionName(project, worktreeName string) string {
+	name := worktreeName
+	if project != "" {
+		name = project + "_" + worktreeName
+	}
+	return strings.NewReplacer("/", "_", " ", "_").Replace(name)
+}
+
+// ListSessions returns the running zellij sessions, leaving out exited ones that can only be resurrected
+func ListSessions() ([]string, error) {
+	return listSessions(false)
+}
+
+// listSessions returns the zellij sessions, with the exited ones too when exited is set
+func listSessions(exited bool) ([]string, error) {
+	output, err := runner.Output(Command("list-sessions", "--no-formatting"))
+	if err != nil {
+		// Zellij exits with an error when there are no sessions
+		if strings.TrimSpace(string(output)) == "" {
+			return []string{}, nil
+		}
+		return nil, err
+	}
+
+	var sessions []string
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		fields := strings.Fields(line)
+		if len(fields) == 0 || (!exited && strings.Contains(line, "EXITED")) {
+			continue
+		}
+		sessions = append(sessions, fields[0])
+	}
+	return sessions, nil
+}
+
+// SessionExists checks if a zellij session is running
+func SessionExists(name string) bool {
+	sessions, err := ListSessions()
+	if err != nil {
+		return false
+	}
+	for _, session := range sessions {
+		if session == name {
+			return true
+		}
+	}
+	return false
+}
+
+// CreateOrAttachSession attaches to the worktree's session, starting it from the config's layout first
+// when it isn't running
+func CreateOrAttachSession(name, path string, cfg *config.Config) error {
+	if !IsInstalled() {
+		return fmt.Errorf("zellij is not installed")
+	}
+	// Zellij can't switch a client to another session from the command line
+	if InsideZellij() {
+		return fmt.Errorf("can't open %s from inside zellij, detach first (Ctrl+o d)", name)
+	}
+
+	sessionName := SessionName(cfg.Name, name)
+	if SessionExists(sessionName) {
+		return attach(Command("attach", sessionName))
+	}
+
+	if _, err := os.Stat(path); os.IsNotExist(err) {
+		return fmt.Errorf("path does not exist: %s", path)
+	}
+	if len(cfg.GetLayout()) == 0 {
+		return fmt.Errorf("no layout defined in config")
+	}
+
+	layoutPath, err := writeLayout(sessionName, Layout(name, path, lfgPath(), paneShell(cfg), cfg))
+	if err != nil {
+		return err
+	}
+	events.Emit(events.Event{Type: events.SessionStarted, Worktree: name, Path: path, Session: sessionName})
+
+	cmd := Command("--session", sessionName, "--new-session-with-layout", layoutPath)
+	cmd.Dir = path
+	return attach(cmd)
+}
+
+// attach runs a zellij client in the terminal until it detaches or the session ends
+func attach(cmd *exec.Cmd) error {
+	cmd.Stdin = os.Stdin
+	cmd.Stdout = os.Stdout
+	cmd.Stderr = os.Stderr
+	return runner.Run(cmd)
+}
+
+// KillSession kills a zellij session and deletes it, so it isn't offered for resurrection
+// An exited session is deleted too, or it would be resurrected under the worktree's session name
+func KillSession(name string) error {
+	sessions, err := listSessions(true)
+	if err != nil || !slices.Contains(sessions, name) {
+		return nil
+	}
+	if output, err := runner.CombinedOutput(Command("delete-session", "--force", name)); err != nil {
+		return fmt.Errorf("failed to kill zellij session %s: %s", name, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// writeLayout saves a session's layout, ~/.local/share/lfg/zellij unless XDG_DATA_HOME is set
+func writeLayout(sessionName, layout string) (string, error) {
+	dir, err := config.DataDir()
+	if err != nil {
+		return "", err
+	}
+	dir = filepath.Join(dir, "zellij")
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return "", fmt.Errorf("failed to create layout directory: %w", err)
+	}
+	path := filepath.Join(dir, sessionName+".kdl")
+	if err := os.WriteFile(path, []byte(layout), 0644); err != nil {
+		return "", fmt.Errorf("failed to write layout: %w", err)
+	}
+	return path, nil
+}
+
+// lfgPath returns the lfg binary the agent pane runs, by absolute path when it can be found
+func lfgPath() string {
+	if path, err := exec.LookPath("lfg"); err == nil {
+		return path
+	}
+	return "lfg"
+}
+
+// paneShell returns the shell panes fall back to once their command exits: the tmux section's shell,
+// then $SHELL, then sh
+func paneShell(cfg *config.Config) string {
+	if shell := cfg.TmuxOptions().Shell; shell != "" {
+		return shell
+	}
+	if shell := os.Getenv("SHELL"); shell != "" {
+		return shell
+	}
+	return "sh"
+}
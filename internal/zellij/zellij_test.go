@@ -0,0 +1,112 @@
+package zellij
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/runner"
+)
+
+func TestSessionName(t *testing.T) {
+	tests := []struct {
+		project  string
+		worktree string
+		expected string
+	}{
+		{project: "myapp", worktree: "fix-login", expected: "myapp_fix-login"},
+		{project: "", worktree: "fix-login", expected: "fix-login"},
+		{project: "acme/web", worktree: "fix login", expected: "acme_web_fix_login"},
+	}
+
+	for _, tt := range tests {
+		if got := SessionName(tt.project, tt.worktree); got != tt.expected {
+			t.Errorf("SessionName(%q, %q) = %q, want %q", tt.project, tt.worktree, got, tt.expected)
+		}
+	}
+}
+
+func TestListSessions(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		return []byte("myapp_fix-login [Created 2h ago] (current)\nmyapp_old [Created 3days ago] (EXITED - attach to resurrect)\nnotes [Created 5m ago]\n"), nil
+	}}
+	defer runner.Set(fake)()
+
+	sessions, err := ListSessions()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if strings.Join(sessions, ",") != "myapp_fix-login,notes" {
+		t.Errorf("ListSessions() = %v, want the running sessions", sessions)
+	}
+	if !SessionExists("notes") || SessionExists("myapp_old") {
+		t.Error("SessionExists() should only find running sessions")
+	}
+}
+
+func TestKillSession(t *testing.T) {
+	fake := &runner.Fake{Handler: func(args []string) ([]byte, error) {
+		if args[1] == "list-sessions" {
+			return []byte("myapp_fix-login [Created 2h ago]\nmyapp_old [Created 3d ago] (EXITED - attach to resurrect)\n"), nil
+		}
+		return nil, nil
+	}}
+	defer runner.Set(fake)()
+
+	if err := KillSession("myapp_fix-login"); err != nil {
+		t.Fatal(err)
+	}
+	if err := KillSession("myapp_old"); err != nil {
+		t.Fatal(err)
+	}
+	if err := KillSession("myapp_gone"); err != nil {
+		t.Fatal(err)
+	}
+	var killed []string
+	for _, call := range fake.Calls {
+		if call[1] == "delete-session" {
+			killed = append(killed, strings.Join(call[1:], " "))
+		}
+	}
+	if fmt.Sprint(killed) != "[delete-session --force myapp_fix-login delete-session --force myapp_old]" {
+		t.Errorf("killed %v, want the running and the exited session", killed)
+	}
+}
+
+func TestLayout(t *testing.T) {
+	server, logs := "bin/rails server", "tail -f log/development.log"
+	cfg := &config.Config{Name: "myapp", Layout: []config.LayoutRow{
+		{Height: "60%", Name: "code"},
+		{Height: "40%", Panes: []config.Pane{
+			{Name: "server", Width: "50%", Command: &server, RemainOnExit: true},
+			{Name: "logs", Command: &logs},
+		}},
+	}}
+
+	layout := Layout("fix-login", "/src/myapp-fix-login", "/usr/local/bin/lfg", "zsh", cfg)
+	for _, want := range []string{
+		`tab name="fix-login" cwd="/src/myapp-fix-login" focus=true {`,
+		`pane size="45%" name="agent" focus=true command="/usr/local/bin/lfg" {`,
+		`args "--agent" "--config" "" "fix-login"`,
+		`pane size="60%" name="code"` + "\n",
+		`pane size="40%" split_direction="vertical" {`,
+		`pane size="50%" name="server" command="zsh" {`,
+		`args "-c" "bin/rails server"`,
+		`pane name="logs" command="zsh" {`,
+		`args "-c" "tail -f log/development.log; exec zsh"`,
+	} {
+		if !strings.Contains(layout, want) {
+			t.Errorf("layout is missing %s:\n%s", want, layout)
+		}
+	}
+	if strings.Count(layout, "{") != strings.Count(layout, "}") {
+		t.Errorf("layout has unbalanced braces:\n%s", layout)
+	}
+}
+
+func TestQuote(t *testing.T) {
+	if got := quote(`echo "hi" \ there`); got != `"echo \"hi\" \\ there"` {
+		t.Errorf("quote() = %s", got)
+	}
+}
@@ -1,6 +1,7 @@
 package main
 
 import (
+	"errors"
 	"flag"
 	"fmt"
 	"os"
@@ -9,28 +10,70 @@ import (
 
 	"github.com/markcipolla/lfg/internal/agent"
 	"github.com/markcipolla/lfg/internal/config"
+	"github.com/markcipolla/lfg/internal/events"
 	"github.com/markcipolla/lfg/internal/git"
+	"github.com/markcipolla/lfg/internal/runner"
+	"github.com/markcipolla/lfg/internal/tmux"
 	"github.com/markcipolla/lfg/internal/tui"
 	"github.com/markcipolla/lfg/internal/viewer"
+	"github.com/markcipolla/lfg/internal/webhook"
+	"github.com/markcipolla/lfg/internal/worktree"
 )
 
+// debugTranscript is set by --debug-transcript
+var debugTranscript bool
+
+// version is set at build time with -ldflags "-X main.version=1.2.3"
+var version = "dev"
+
 func main() {
 	viewMode := flag.Bool("view", false, "View description for a worktree")
 	agentMode := flag.Bool("agent", false, "Run agent wrapper for a worktree")
 	configPath := flag.String("config", "", "Path to config file (for viewer/agent mode)")
+	flag.BoolVar(&debugTranscript, "debug-transcript", false, "Print every git/tmux/gh command run, with timings and output, to stderr on exit")
+	flag.BoolVar(&tmux.DetachOthers, "d", false, "Detach other terminals attached to the worktree's session instead of asking")
+	lastMode := flag.Bool("last", false, "Jump back to the worktree opened before the current one")
+	eventsJSON := flag.Bool("events-json", false, "Emit newline-delimited JSON events on stdout for mutating commands")
+	var dir string
+	flag.StringVar(&dir, "C", "", "Run as if lfg was started in `path`")
+	flag.StringVar(&dir, "cwd", "", "Run as if lfg was started in `path` (same as -C)")
+	var view tui.View
+	flag.StringVar(&view.Filter, "filter", "", "Start the TUI with the list filtered by `text`, as if typed after /")
+	flag.StringVar(&view.Sort, "sort", "", "Sort the TUI's list by `order` (default, name or recent) instead of the sort_order setting")
+	flag.StringVar(&view.Group, "group", "", "Group the TUI's list by `grouping` (status or none) instead of the group setting")
 	flag.Parse()
 
+	if debugTranscript {
+		runner.EnableTranscript()
+		defer runner.WriteTranscript(os.Stderr)
+	}
+
+	if *eventsJSON {
+		events.Enable(os.Stdout)
+	}
+	events.Subscribe(git.InvalidateOnEvent)
+	events.Subscribe(webhook.Deliver)
+	defer webhook.Wait()
+
+	// Operate on another repository without cd-ing into it, like git -C
+	if dir != "" {
+		if err := os.Chdir(dir); err != nil {
+			fmt.Fprintf(os.Stderr, "Error: cannot change to %s: %v\n", dir, err)
+			exit(1)
+		}
+	}
+
 	// Check if worktree name was provided
-	worktree := ""
+	worktreeName := ""
 	if flag.NArg() > 0 {
-		worktree = flag.Arg(0)
+		worktreeName = flag.Arg(0)
 	}
 
 	// View mode: show description viewer
 	if *viewMode {
-		if worktree == "" {
+		if worktreeName == "" {
 			fmt.Fprintf(os.Stderr, "Error: --view requires a worktree name\n")
-			os.Exit(1)
+			exit(1)
 		}
 
 		// Load config from specified path (viewer doesn't need git repo)
@@ -39,26 +82,26 @@ func main() {
 		if *configPath != "" {
 			cfg, err = config.LoadFromPath(*configPath)
 		} else {
-			cfg, err = config.Load()
+			cfg, err = config.LoadOrDefault()
 		}
 
 		if err != nil {
 			fmt.Fprintf(os.Stderr, "Error loading config: %v\n", err)
-			os.Exit(1)
+			exit(1)
 		}
 
-		if err := viewer.Run(worktree, cfg); err != nil {
+		if err := viewer.Run(worktreeName, cfg); err != nil {
 			fmt.Fprintf(os.Stderr, "Error running viewer: %v\n", err)
-			os.Exit(1)
+			exit(1)
 		}
 		return
 	}
 
 	// Agent mode: run Claude Code wrapper with conversation capture
 	if *agentMode {
-		if worktree == "" {
+		if worktreeName == "" {
 			fmt.Fprintf(os.Stderr, "Error: --agent requires a worktree name\n")
-			os.Exit(1)
+			exit(1)
 		}
 
 		// Load config from specified path
@@ -67,24 +110,48 @@ func main() {
 		if *configPath != "" {
 			cfg, err = config.LoadFromPath(*configPath)
 		} else {
-			cfg, err = config.Load()
+			cfg, err = config.LoadOrDefault()
 		}
 
 		if err != nil {
 			fmt.Fprintf(os.Stderr, "Error loading config: %v\n", err)
-			os.Exit(1)
+			exit(1)
 		}
+		worktree.Configure(cfg)
 
 		// Run the agent wrapper
-		if err := agent.Run(worktree, cfg); err != nil {
+		if err := agent.Run(worktreeName, cfg); err != nil {
 			fmt.Fprintf(os.Stderr, "Error running agent: %v\n", err)
-			os.Exit(1)
+			exit(1)
+		}
+		return
+	}
+
+	// Subcommands: lfg <command> [args...]
+	if run, ok := subcommands[worktreeName]; ok {
+		if err := run(flag.Args()[1:]); err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			var exitErr *exitError
+			if errors.As(err, &exitErr) {
+				exit(exitErr.code)
+			}
+			exit(1)
 		}
 		return
 	}
 
+	// Go back to the previous worktree, like cd -
+	if *lastMode && worktreeName == "" {
+		last, err := git.LastWorktree()
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			exit(1)
+		}
+		worktreeName = last
+	}
+
 	// Check if we're in a tmux session managed by lfg (before loading config!)
-	if os.Getenv("TMUX") != "" && worktree == "" && os.Getenv("LFG_POPUP") == "" {
+	if os.Getenv("TMUX") != "" && worktreeName == "" && os.Getenv("LFG_POPUP") == "" {
 		// We're in tmux - show the main selector in a popup overlay
 
 		// Find lfg binary
@@ -95,7 +162,7 @@ func main() {
 
 		// Get the main repo root (where we want to run lfg from)
 		// Try to get the main worktree root by listing all worktrees
-		cmd := exec.Command("git", "worktree", "list", "--porcelain")
+		cmd := git.Command("worktree", "list", "--porcelain")
 		output, err := cmd.Output()
 		var repoRootStr string
 		if err == nil {
@@ -110,7 +177,7 @@ func main() {
 		}
 		if repoRootStr == "" {
 			// Fallback to rev-parse if worktree list fails
-			cmd = exec.Command("git", "rev-parse", "--show-toplevel")
+			cmd = git.Command("rev-parse", "--show-toplevel")
 			repoRoot, err := cmd.Output()
 			if err == nil && len(repoRoot) > 0 {
 				repoRootStr = string(repoRoot[:len(repoRoot)-1]) // trim newline
@@ -126,30 +193,36 @@ func main() {
 		cmd = exec.Command("tmux", "display-popup", "-E", "-w", "100%", "-h", "100%", popupCmd)
 		cmd.Run() // Ignore errors
 
-		os.Exit(0)
+		exit(0)
 	}
 
-	// Load config (creates default if missing)
-	cfg, err := config.Load()
+	// Load config, running the init wizard if missing, unless only jumping to a worktree, which just
+	// reads it
+	load := config.Load
+	if worktreeName != "" {
+		load = config.LoadOrDefault
+	}
+	cfg, err := load()
 	if err != nil {
 		fmt.Fprintf(os.Stderr, "Error loading config: %v\n", err)
-		os.Exit(1)
+		exit(1)
 	}
+	worktree.Configure(cfg)
 
 	// If worktree specified, jump directly to it
-	if worktree != "" {
-		if err := git.JumpToWorktree(worktree, cfg); err != nil {
+	if worktreeName != "" {
+		if err := git.JumpToWorktree(worktreeName, cfg); err != nil {
 			fmt.Fprintf(os.Stderr, "Error jumping to worktree: %v\n", err)
-			os.Exit(1)
+			exit(1)
 		}
 		return
 	}
 
 	// Otherwise, show TUI
-	result, err := tui.Run(cfg)
+	result, err := tui.Run(cfg, view)
 	if err != nil {
 		fmt.Fprintf(os.Stderr, "Error running TUI: %v\n", err)
-		os.Exit(1)
+		exit(1)
 	}
 
 	// Handle the result
@@ -165,8 +238,8 @@ func main() {
 				if os.Getenv("TMUX") != "" {
 					// Get current session name
 					sessionName := ""
-					cmd := exec.Command("tmux", "display-message", "-p", "#{session_name}")
-					if output, err := cmd.Output(); err == nil {
+					cmd := tmux.Command("display-message", "-p", "#{session_name}")
+					if output, err := runner.Output(cmd); err == nil {
 						sessionName = strings.TrimSpace(string(output))
 					}
 
@@ -174,7 +247,7 @@ func main() {
 						// Send command to cd to main path and then kill the session
 						// This will happen after the popup closes
 						cdCmd := fmt.Sprintf("cd '%s' && tmux kill-session", mainPath)
-						exec.Command("tmux", "send-keys", "-t", sessionName, cdCmd, "Enter").Run()
+						tmux.SendCommand(sessionName, cdCmd)
 					}
 				} else {
 					// Not in tmux, just cd
@@ -187,7 +260,17 @@ func main() {
 		// Otherwise, jump to the selected worktree
 		if err := git.JumpToWorktree(result.SelectedWorktree, cfg); err != nil {
 			fmt.Fprintf(os.Stderr, "Error jumping to worktree: %v\n", err)
-			os.Exit(1)
+			exit(1)
 		}
 	}
 }
+
+// exit exits with code once webhooks have been delivered, printing the command transcript first when
+// --debug-transcript is set (deferred calls don't run on os.Exit)
+func exit(code int) {
+	webhook.Wait()
+	if debugTranscript {
+		runner.WriteTranscript(os.Stderr)
+	}
+	os.Exit(code)
+}